@@ -9,6 +9,9 @@ import android.net.Uri
 import android.os.Build
 import android.os.Handler
 import android.os.Looper
+import android.os.VibrationEffect
+import android.os.Vibrator
+import android.os.VibratorManager
 import android.provider.Settings
 import android.util.Log
 import android.webkit.WebView
@@ -84,6 +87,7 @@ class FloatingBubblePlugin(private val activity: Activity) : Plugin(activity) {
     companion object {
         private const val TAG = "FloatingBubblePlugin"
         private const val REQUEST_MICROPHONE_PERMISSION = 1001
+        private const val HAPTIC_PULSE_MS = 40L
 
         // Static flag to track bubble visibility across service restarts
         @Volatile
@@ -479,6 +483,60 @@ class FloatingBubblePlugin(private val activity: Activity) : Plugin(activity) {
         invoke.resolve()
     }
 
+    /**
+     * Start native capture in the foreground service.
+     * Lets an in-app recording continue after the app is backgrounded.
+     */
+    @Command
+    fun startNativeCapture(invoke: Invoke) {
+        if (!isBubbleVisible) {
+            invoke.reject("Floating bubble service is not running")
+            return
+        }
+        if (isNativeCapture) {
+            invoke.resolve()
+            return
+        }
+        Log.d(TAG, "startNativeCapture command received")
+        FloatingBubbleService.startCapture()
+        invoke.resolve()
+    }
+
+    /**
+     * Stop native capture. Emits capture-stop once pending data is flushed.
+     */
+    @Command
+    fun stopNativeCapture(invoke: Invoke) {
+        Log.d(TAG, "stopNativeCapture command received")
+        FloatingBubbleService.stopCapture()
+        invoke.resolve()
+    }
+
+    /**
+     * Short vibration marking a recording state change.
+     */
+    @Command
+    fun hapticPulse(invoke: Invoke) {
+        val vibrator = if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.S) {
+            val manager = activity.getSystemService(VibratorManager::class.java)
+            manager?.defaultVibrator
+        } else {
+            @Suppress("DEPRECATION")
+            activity.getSystemService(Vibrator::class.java)
+        }
+        if (vibrator?.hasVibrator() == true) {
+            if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.O) {
+                vibrator.vibrate(
+                    VibrationEffect.createOneShot(HAPTIC_PULSE_MS, VibrationEffect.DEFAULT_AMPLITUDE)
+                )
+            } else {
+                @Suppress("DEPRECATION")
+                vibrator.vibrate(HAPTIC_PULSE_MS)
+            }
+        }
+        invoke.resolve()
+    }
+
     /**
      * Internal helper to check overlay permission.
      */
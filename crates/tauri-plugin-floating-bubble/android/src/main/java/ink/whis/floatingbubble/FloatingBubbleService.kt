@@ -208,6 +208,11 @@ class FloatingBubbleService : Service() {
             audioRecord?.release()
             audioRecord = null
             FloatingBubblePlugin.isNativeCapture = false
+
+            // Let the consumer finalize the recording via its normal stop path,
+            // otherwise the in-progress recording would be left dangling
+            pendingStopCallback = null
+            notifyCaptureStopped()
         }
 
         instance = null
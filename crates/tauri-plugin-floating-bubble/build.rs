@@ -9,6 +9,9 @@ const COMMANDS: &[&str] = &[
     "set_bubble_state",
     "handle_bubble_click",
     "handle_bubble_close",
+    "start_native_capture",
+    "stop_native_capture",
+    "haptic_pulse",
 ];
 
 fn main() {
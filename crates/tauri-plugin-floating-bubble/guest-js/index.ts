@@ -271,6 +271,39 @@ export async function setBubbleState(state: string): Promise<void> {
   await invoke('plugin:floating-bubble|set_bubble_state', { state })
 }
 
+/**
+ * Start native audio capture in the bubble's foreground service.
+ *
+ * Use this to keep recording when the app is backgrounded: audio is then
+ * delivered via capture events instead of the WebView's microphone.
+ * Requires the bubble to be visible.
+ *
+ * @example
+ * ```typescript
+ * import { startNativeCapture } from 'tauri-plugin-floating-bubble'
+ * await startNativeCapture()
+ * ```
+ */
+export async function startNativeCapture(): Promise<void> {
+  await invoke('plugin:floating-bubble|start_native_capture')
+}
+
+/**
+ * Stop native audio capture.
+ *
+ * A capture-stop event is emitted once all pending audio has been flushed.
+ */
+export async function stopNativeCapture(): Promise<void> {
+  await invoke('plugin:floating-bubble|stop_native_capture')
+}
+
+/**
+ * Vibrate briefly, e.g. to mark a recording state change.
+ */
+export async function hapticPulse(): Promise<void> {
+  await invoke('plugin:floating-bubble|haptic_pulse')
+}
+
 /**
  * Event payload when the bubble is clicked.
  */
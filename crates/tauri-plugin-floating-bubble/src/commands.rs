@@ -86,3 +86,24 @@ pub(crate) async fn handle_bubble_click<R: Runtime>(app: AppHandle<R>) -> Result
 pub(crate) async fn handle_bubble_close<R: Runtime>(app: AppHandle<R>) -> Result<()> {
     emit_event(&app, "floating-bubble://close", "close")
 }
+
+/// Start native audio capture in the foreground service.
+///
+/// Used to hand an in-app recording over to the service when the app is
+/// backgrounded, so audio keeps flowing while the bubble is active.
+#[command]
+pub(crate) async fn start_native_capture<R: Runtime>(app: AppHandle<R>) -> Result<()> {
+    app.floating_bubble().start_native_capture()
+}
+
+/// Stop native audio capture (emits `floating-bubble://capture-stop` once flushed).
+#[command]
+pub(crate) async fn stop_native_capture<R: Runtime>(app: AppHandle<R>) -> Result<()> {
+    app.floating_bubble().stop_native_capture()
+}
+
+/// Vibrate briefly to mark a recording state change.
+#[command]
+pub(crate) async fn haptic_pulse<R: Runtime>(app: AppHandle<R>) -> Result<()> {
+    app.floating_bubble().haptic_pulse()
+}
@@ -56,4 +56,16 @@ impl<R: Runtime> FloatingBubble<R> {
     pub fn has_microphone_permission(&self) -> crate::Result<PermissionResponse> {
         Err(crate::Error::UnsupportedPlatform)
     }
+
+    pub fn start_native_capture(&self) -> crate::Result<()> {
+        Err(crate::Error::UnsupportedPlatform)
+    }
+
+    pub fn stop_native_capture(&self) -> crate::Result<()> {
+        Err(crate::Error::UnsupportedPlatform)
+    }
+
+    pub fn haptic_pulse(&self) -> crate::Result<()> {
+        Err(crate::Error::UnsupportedPlatform)
+    }
 }
@@ -68,6 +68,9 @@ pub fn init<R: Runtime>() -> TauriPlugin<R> {
             commands::set_bubble_state,
             commands::handle_bubble_click,
             commands::handle_bubble_close,
+            commands::start_native_capture,
+            commands::stop_native_capture,
+            commands::haptic_pulse,
         ])
         .setup(|app, api| {
             #[cfg(mobile)]
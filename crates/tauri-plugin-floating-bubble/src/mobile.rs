@@ -81,4 +81,25 @@ impl<R: Runtime> FloatingBubble<R> {
             .run_mobile_plugin("hasMicrophonePermission", ())
             .map_err(Into::into)
     }
+
+    /// Start native audio capture in the foreground service.
+    pub fn start_native_capture(&self) -> crate::Result<()> {
+        self.0
+            .run_mobile_plugin("startNativeCapture", ())
+            .map_err(Into::into)
+    }
+
+    /// Stop native audio capture in the foreground service.
+    pub fn stop_native_capture(&self) -> crate::Result<()> {
+        self.0
+            .run_mobile_plugin("stopNativeCapture", ())
+            .map_err(Into::into)
+    }
+
+    /// Vibrate briefly to mark a recording state change.
+    pub fn haptic_pulse(&self) -> crate::Result<()> {
+        self.0
+            .run_mobile_plugin("hapticPulse", ())
+            .map_err(Into::into)
+    }
 }
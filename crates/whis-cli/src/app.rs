@@ -4,9 +4,44 @@ use crossterm::{
     terminal::{disable_raw_mode, enable_raw_mode},
 };
 use std::io::{IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::thread;
 use std::time::Duration;
-use whis_core::{Settings, TranscriptionProvider};
+use whis_core::{Preset, Settings, TranscriptionProvider};
+
+/// When set (`--quiet`), status/progress goes to stderr so stdout only carries results
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Route status/progress output to stderr (`--quiet`)
+pub fn set_quiet(enabled: bool) {
+    QUIET.store(enabled, Ordering::SeqCst);
+}
+
+/// Check if `--quiet` is active
+pub fn is_quiet() -> bool {
+    QUIET.load(Ordering::SeqCst)
+}
+
+/// Writer for status/progress output (stderr under `--quiet`, stdout otherwise)
+fn status_writer() -> Box<dyn Write> {
+    if is_quiet() {
+        Box::new(std::io::stderr())
+    } else {
+        Box::new(std::io::stdout())
+    }
+}
+
+/// Print a status line (never on stdout under `--quiet`)
+pub fn status_line(message: &str) {
+    let _ = writeln!(status_writer(), "{}", message);
+}
+
+/// Print status text without a newline and flush it
+pub fn status_inline(message: &str) {
+    let mut out = status_writer();
+    let _ = write!(out, "{}", message);
+    let _ = out.flush();
+}
 
 /// Configuration for transcription, including provider, API key, and language
 pub struct TranscriptionConfig {
@@ -15,19 +50,101 @@ pub struct TranscriptionConfig {
     pub language: Option<String>,
 }
 
+/// Resolve the preset to apply for a run
+///
+/// An explicit preset name (e.g. `--as`) always wins. Otherwise the active
+/// preset from settings is used; if it can no longer be loaded, a warning is
+/// printed and no preset is applied.
+pub fn resolve_preset(name: Option<&str>, settings: &Settings) -> Result<Option<Preset>> {
+    if let Some(name) = name {
+        let (preset, _source) = Preset::load(name).map_err(|e| anyhow::anyhow!("{}", e))?;
+        return Ok(Some(preset));
+    }
+
+    let Some(active) = settings.ui.active_preset.as_deref() else {
+        return Ok(None);
+    };
+
+    match Preset::load(active) {
+        Ok((preset, _source)) => Ok(Some(preset)),
+        Err(e) => {
+            eprintln!("Warning: Active preset '{}' ignored: {}", active, e);
+            eprintln!("Run 'whis preset use <name>' or 'whis preset use --clear' to fix it.");
+            Ok(None)
+        }
+    }
+}
+
+/// Check that the provider supports the configured language
+///
+/// Warns (or fails when `strict`) if the language isn't in the provider's
+/// documented set, suggesting providers that do support it. With `strict`,
+/// also fails when no language is set and the provider can't auto-detect.
+pub fn check_language_support(config: &TranscriptionConfig, strict: bool) -> Result<()> {
+    let provider = &config.provider;
+
+    let problem = match &config.language {
+        Some(lang) if !provider.supports_language(lang) => {
+            let alternatives: Vec<_> = TranscriptionProvider::supporting_language(lang)
+                .map(|p| p.as_str())
+                .collect();
+            format!(
+                "{} does not support language '{}'. Try: {}",
+                provider.display_name(),
+                lang,
+                alternatives.join(", ")
+            )
+        }
+        None if strict && !provider.supports_auto_detect() => format!(
+            "{} does not auto-detect the language and will assume English. \
+             Set one with --language or 'whis config language <code>'",
+            provider.display_name()
+        ),
+        _ => return Ok(()),
+    };
+
+    if strict {
+        anyhow::bail!(problem);
+    }
+    eprintln!("Warning: {problem}");
+    Ok(())
+}
+
 /// Load transcription config with optional language override
 pub fn load_transcription_config_with_language(
     language_override: Option<String>,
+) -> Result<TranscriptionConfig> {
+    load_transcription_config_with_overrides(None, language_override, None)
+}
+
+/// Load transcription config with optional provider and language overrides
+///
+/// The language comes from `language_override` (`--language`), else
+/// `preset_language`, else the setting; see [`whis_core::resolve_language`].
+pub fn load_transcription_config_with_overrides(
+    provider_override: Option<TranscriptionProvider>,
+    language_override: Option<String>,
+    preset_language: Option<&str>,
 ) -> Result<TranscriptionConfig> {
     // Check if settings file exists (fresh install detection)
     let settings_path = Settings::path();
     let is_fresh_install = !settings_path.exists();
 
     let settings = Settings::load();
-    let provider = settings.transcription.provider.clone();
+    let provider = provider_override.unwrap_or_else(|| settings.transcription.provider.clone());
+    whis_core::http::set_request_timeouts((&settings.transcription.timeouts).into());
+    whis_core::set_upload_compression(settings.transcription.compress_uploads);
+    whis_core::set_mp3_bitrate(settings.transcription.mp3_bitrate);
+    whis_core::set_smart_formatting(settings.transcription.smart_format);
+    whis_core::set_numeral_formatting(settings.transcription.format_numbers);
 
-    // Use override if provided, otherwise use configured language
-    let language = language_override.or_else(|| settings.transcription.language.clone());
+    // Flag > preset > setting, rejecting unknown overrides before recording;
+    // all normalized to ISO-639-1
+    let language = whis_core::resolve_language(
+        language_override.as_deref(),
+        preset_language,
+        settings.transcription.language.as_deref(),
+    )?;
 
     // Handle different provider types:
     // - Cloud providers: require API key
@@ -46,7 +163,7 @@ pub fn load_transcription_config_with_language(
                     );
                     eprintln!("Or set the LOCAL_WHISPER_MODEL_PATH environment variable.");
                     eprintln!("\nTip: Run 'whis setup local' for guided setup.");
-                    std::process::exit(1);
+                    std::process::exit(crate::error::EXIT_CONFIG);
                 }
             }
         }
@@ -63,7 +180,21 @@ pub fn load_transcription_config_with_language(
                     );
                     eprintln!("Or set the LOCAL_PARAKEET_MODEL_PATH environment variable.");
                     eprintln!("\nTip: Run 'whis setup local' for guided setup.");
-                    std::process::exit(1);
+                    std::process::exit(crate::error::EXIT_CONFIG);
+                }
+            }
+        }
+        TranscriptionProvider::Mock => {
+            // Mock: the "key" is the transcript to return
+            match settings.transcription.api_key_for(&provider) {
+                Some(transcript) => transcript,
+                None => {
+                    eprintln!("Error: The mock provider needs WHIS_MOCK_TRANSCRIPT to be set.");
+                    eprintln!("\nExample:");
+                    eprintln!(
+                        "  WHIS_MOCK_TRANSCRIPT=\"Hello world\" whis -f recording.wav --print\n"
+                    );
+                    std::process::exit(crate::error::EXIT_CONFIG);
                 }
             }
         }
@@ -90,7 +221,7 @@ pub fn load_transcription_config_with_language(
                             provider.api_key_env_var()
                         );
                     }
-                    std::process::exit(1);
+                    std::process::exit(crate::error::EXIT_AUTH);
                 }
             }
         }
@@ -112,7 +243,7 @@ pub fn load_transcription_config() -> Result<TranscriptionConfig> {
 /// In TTY mode: waits for Enter key press.
 /// In non-TTY mode: blocks indefinitely (use --duration for timed recording).
 pub fn wait_for_stop() -> Result<()> {
-    std::io::stdout().flush()?;
+    status_writer().flush()?;
 
     if std::io::stdin().is_terminal() {
         // TTY mode: wait for Enter key
@@ -144,13 +275,13 @@ pub fn wait_for_stop() -> Result<()> {
 /// When delay_ms is 0, prints instantly (no animation)
 pub fn typewriter(text: &str, delay_ms: u64) {
     if delay_ms == 0 {
-        print!("{}", text);
-        std::io::stdout().flush().ok();
+        status_inline(text);
         return;
     }
+    let mut out = status_writer();
     for c in text.chars() {
-        print!("{}", c);
-        std::io::stdout().flush().ok();
+        let _ = write!(out, "{}", c);
+        out.flush().ok();
         thread::sleep(Duration::from_millis(delay_ms));
     }
 }
@@ -163,7 +294,7 @@ pub fn typewriter(text: &str, delay_ms: u64) {
 pub fn print_status(message: &str, provider: Option<&TranscriptionProvider>) {
     if whis_core::verbose::is_verbose() {
         // Verbose mode: print on own line to avoid interleaving
-        println!("{}", message.trim());
+        status_line(message.trim());
         return;
     }
 
@@ -174,8 +305,7 @@ pub fn print_status(message: &str, provider: Option<&TranscriptionProvider>) {
         typewriter(message, 25);
     } else {
         // Instant output for real-time providers (no newline, continues on same line)
-        print!("{}", message);
-        std::io::stdout().flush().ok();
+        status_inline(message);
     }
 }
 
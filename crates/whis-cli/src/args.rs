@@ -30,9 +30,29 @@ fn parse_duration(s: &str) -> Result<Duration, String> {
 #[derive(Args)]
 pub struct InputOptions {
     /// Transcribe an audio file instead of recording from microphone
-    /// Supported formats: WAV
+    /// ('-' reads the audio from stdin). Supported formats: WAV
     #[arg(short = 'f', long, value_name = "PATH", value_hint = ValueHint::FilePath)]
     pub file: Option<std::path::PathBuf>,
+
+    /// Download and transcribe an audio file from an http(s) URL
+    /// Supported formats: WAV (detected from the data)
+    #[arg(long, value_name = "URL", value_hint = ValueHint::Url, conflicts_with = "file")]
+    pub url: Option<String>,
+
+    /// Format of the --file/--url audio (e.g. "wav"), for input the
+    /// detection misses: stdin, files without an extension
+    #[arg(long, value_name = "FORMAT")]
+    pub input_format: Option<String>,
+
+    /// Keep this recording's audio so it can be re-run with 'whis reprocess'
+    /// (microphone recordings only)
+    #[arg(long, conflicts_with_all = ["file", "url"])]
+    pub save_last: bool,
+
+    /// Write the audio sent to the provider to PATH, to hear what whis heard
+    /// (MP3 as uploaded; WAV for local and streaming providers)
+    #[arg(long, value_name = "PATH", value_hint = ValueHint::FilePath)]
+    pub save_recording: Option<std::path::PathBuf>,
 }
 
 /// Processing options for transcription
@@ -46,6 +66,16 @@ pub struct ProcessingOptions {
     #[arg(long = "as", value_name = "PRESET")]
     pub preset: Option<String>,
 
+    /// One-off post-processing instruction for this run (e.g. "make this a
+    /// bulleted list"). Replaces the stored or preset prompt. Implies --post-process
+    #[arg(long, value_name = "TEXT")]
+    pub instruction: Option<String>,
+
+    /// Give the LLM the current clipboard text as context (e.g. a message
+    /// you are replying to). Implies --post-process
+    #[arg(long)]
+    pub with_clipboard: bool,
+
     /// Record for a fixed duration (e.g., "10s", "30s", "1m")
     /// Useful for non-interactive environments like AI assistant shell modes
     #[arg(short = 'd', long, value_parser = parse_duration)]
@@ -55,10 +85,26 @@ pub struct ProcessingOptions {
     #[arg(long)]
     pub no_vad: bool,
 
-    /// Language code for transcription (e.g., "en", "de", "fr", "auto")
-    /// Overrides the configured language for this invocation only
-    #[arg(short = 'l', long)]
+    /// Language for transcription (e.g., "en", "de", "English", "pt-BR", "auto")
+    /// Overrides the preset's and the configured language for this invocation only
+    #[arg(short = 'l', long, visible_alias = "lang")]
     pub language: Option<String>,
+
+    /// Write spoken numbers as digits ("twenty twenty four" -> "2024"),
+    /// as with the `format-numbers` setting
+    #[arg(long)]
+    pub numerals: bool,
+
+    /// Fail instead of warning when the language isn't supported by the
+    /// provider, when post-processing fails, or when transcription confidence
+    /// is below `confidence-warn` (default: warn and keep the transcript)
+    #[arg(long)]
+    pub strict: bool,
+
+    /// Record (or load) audio and show what would be sent, without
+    /// calling the transcription provider or LLM
+    #[arg(long)]
+    pub dry_run: bool,
 }
 
 /// Output format for transcription
@@ -74,6 +120,11 @@ pub enum OutputFormat {
 }
 
 impl OutputFormat {
+    /// Whether this is a subtitle format (needs segment timestamps)
+    pub fn is_subtitle(self) -> bool {
+        matches!(self, Self::Srt | Self::Vtt)
+    }
+
     /// Detect format from file extension
     pub fn from_extension(path: &std::path::Path) -> Option<Self> {
         match path.extension().and_then(|e| e.to_str()) {
@@ -98,13 +149,46 @@ pub struct OutputOptions {
     /// Output format (txt, srt, vtt)
     #[arg(long, value_enum, default_value = "txt")]
     pub format: OutputFormat,
+
+    /// Print SRT subtitles with the provider's timestamps (--format srt,
+    /// to stdout unless --output is given)
+    #[arg(long, conflicts_with_all = ["format", "vtt"])]
+    pub srt: bool,
+
+    /// Print WebVTT subtitles with the provider's timestamps (--format vtt,
+    /// to stdout unless --output is given)
+    #[arg(long, conflicts_with = "format")]
+    pub vtt: bool,
+
+    /// Also write a JSON sidecar next to the --output file (talk.txt ->
+    /// talk.json): timestamp, duration, provider, model, language, device,
+    /// cost estimate and per-stage latency
+    #[arg(long)]
+    pub metadata: bool,
+}
+
+impl OutputOptions {
+    /// Format from --srt/--vtt or --format
+    pub fn requested_format(&self) -> OutputFormat {
+        if self.srt {
+            OutputFormat::Srt
+        } else if self.vtt {
+            OutputFormat::Vtt
+        } else {
+            self.format
+        }
+    }
 }
 
 #[derive(Parser)]
 #[command(name = "whis")]
 #[command(version)]
 #[command(about = "Voice-to-text CLI - transcribe speech from your microphone")]
-#[command(after_help = "Run 'whis' without arguments to record once (press Enter to stop).")]
+#[command(
+    after_help = "Run 'whis' without arguments to record once (press Enter to stop).
+
+Exit status: 0 on success, 1 on error, 3 when no speech was detected."
+)]
 pub struct Cli {
     #[command(subcommand)]
     pub command: Option<Commands>,
@@ -113,6 +197,27 @@ pub struct Cli {
     #[arg(short, long, global = true)]
     pub verbose: bool,
 
+    /// Keep stdout clean for piping: status and progress go to stderr
+    #[arg(short, long, global = true)]
+    pub quiet: bool,
+
+    /// Named service instance, so several services can run side by side
+    /// (default: $WHIS_SOCKET, or the unnamed instance)
+    #[arg(long, global = true, value_name = "NAME")]
+    pub instance: Option<String>,
+
+    /// Load variables such as OPENAI_API_KEY from an environment file
+    /// (default: .env in the working directory, or the env-file setting).
+    /// Variables already set in the environment take precedence
+    #[arg(
+        long,
+        global = true,
+        value_name = "PATH",
+        num_args = 0..=1,
+        default_missing_value = ".env"
+    )]
+    pub env_file: Option<std::path::PathBuf>,
+
     // Input options (file)
     #[command(flatten)]
     pub input: InputOptions,
@@ -138,6 +243,10 @@ pub enum Commands {
         /// Output preset for transcript (run 'whis preset list' to see all)
         #[arg(long = "as", value_name = "PRESET")]
         preset: Option<String>,
+
+        /// Transcribe recordings shorter than min-recording instead of discarding them
+        #[arg(long)]
+        allow_short: bool,
     },
 
     /// Stop the background service
@@ -152,10 +261,18 @@ pub enum Commands {
         /// Output preset for transcript (run 'whis preset list' to see all)
         #[arg(long = "as", value_name = "PRESET")]
         preset: Option<String>,
+
+        /// Transcribe recordings shorter than min-recording instead of discarding them
+        #[arg(long)]
+        allow_short: bool,
     },
 
     /// Check service status
-    Status,
+    Status {
+        /// Also show the active provider, model, language and post-processor
+        #[arg(long)]
+        config: bool,
+    },
 
     /// Toggle recording state (for compositor keybindings)
     Toggle,
@@ -164,7 +281,11 @@ pub enum Commands {
     Setup,
 
     /// Configure settings (git-style interface)
+    #[command(args_conflicts_with_subcommands = true)]
     Config {
+        #[command(subcommand)]
+        action: Option<ConfigAction>,
+
         /// Configuration key to get or set
         key: Option<String>,
 
@@ -175,9 +296,104 @@ pub enum Commands {
         #[arg(long, conflicts_with_all = ["key", "value"])]
         list: bool,
 
+        /// With --list: dump every setting by field path
+        #[arg(long, requires = "list")]
+        all: bool,
+
         /// Show configuration file path
         #[arg(long, conflicts_with_all = ["key", "value", "list"])]
         path: bool,
+
+        /// Print API keys unmasked when getting a single value
+        /// (e.g. `whis config openai-api-key --reveal`)
+        #[arg(long, conflicts_with_all = ["value", "list", "path"])]
+        reveal: bool,
+
+        /// Show each setting's resolved value and its source
+        /// (default, file, env, or flag such as `whis -l de config --show-effective`)
+        #[arg(long, conflicts_with_all = ["key", "value", "list", "path"])]
+        show_effective: bool,
+
+        /// Set the default transcription language (e.g. "en", "de", or
+        /// "auto" to auto-detect); same as `whis config language <code>`
+        #[arg(
+            long = "language",
+            visible_alias = "lang",
+            value_name = "CODE",
+            conflicts_with_all = ["key", "value", "list", "path", "reveal", "show_effective"]
+        )]
+        set_language: Option<String>,
+    },
+
+    /// Keep listening and transcribe each utterance separately, one per line
+    /// (press Enter to end an utterance, or use --auto; Ctrl+C to quit)
+    Listen {
+        /// End utterances automatically on pauses in speech (VAD)
+        #[arg(long)]
+        auto: bool,
+
+        /// With --auto: silence in milliseconds, after the VAD's ~0.5s
+        /// trailing hangover, that ends an utterance
+        #[arg(long, value_name = "MS", requires = "auto")]
+        pause: Option<u64>,
+
+        /// Print one JSON object per utterance (start/end seconds and text)
+        #[arg(long)]
+        json: bool,
+
+        /// Language for transcription (e.g., "en", "de", "English", "pt-BR", "auto")
+        #[arg(short = 'l', long, visible_alias = "lang")]
+        language: Option<String>,
+    },
+
+    /// Serve live captions over a local WebSocket (JSON, e.g. for OBS or a
+    /// browser overlay)
+    Serve {
+        /// Port to listen on
+        #[arg(long, default_value_t = 8080)]
+        port: u16,
+
+        /// Address to bind; anything but localhost exposes the captions on
+        /// the network
+        #[arg(long, default_value = "127.0.0.1")]
+        host: std::net::IpAddr,
+
+        /// Send interim captions of the utterance in progress at most every
+        /// MS milliseconds (0 = final captions only). Each interim is an
+        /// extra transcription request
+        #[arg(long, value_name = "MS", default_value_t = 1500)]
+        interim: u64,
+
+        /// Silence in milliseconds, after the VAD's ~0.5s trailing hangover,
+        /// that ends an utterance
+        #[arg(long, value_name = "MS")]
+        pause: Option<u64>,
+
+        /// Language for transcription (e.g., "en", "de", "English", "pt-BR", "auto")
+        #[arg(short = 'l', long, visible_alias = "lang")]
+        language: Option<String>,
+    },
+
+    /// Re-run transcription on the last recording saved with --save-last
+    Reprocess {
+        /// Output preset for transcript (run 'whis preset list' to see all)
+        #[arg(long = "as", visible_alias = "preset", value_name = "PRESET")]
+        preset: Option<String>,
+
+        /// Transcription provider to use instead of the configured one
+        #[arg(long)]
+        provider: Option<String>,
+
+        /// Post-process transcript with LLM (cleanup grammar, filler words)
+        #[arg(long)]
+        post_process: bool,
+
+        /// Language for transcription (e.g., "en", "de", "English", "pt-BR", "auto")
+        #[arg(short = 'l', long, visible_alias = "lang")]
+        language: Option<String>,
+
+        #[command(flatten)]
+        output: OutputOptions,
     },
 
     /// Manage output presets
@@ -191,6 +407,12 @@ pub enum Commands {
         #[command(subcommand)]
         action: Option<ModelAction>,
     },
+
+    /// Diagnose the Ollama post-processing setup
+    Ollama {
+        #[command(subcommand)]
+        action: Option<OllamaAction>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -212,11 +434,35 @@ pub enum PresetAction {
         name: String,
     },
 
-    /// Edit a preset in your editor ($EDITOR or $VISUAL)
+    /// Create a new user preset (opens $EDITOR unless --prompt is given)
+    Create {
+        /// Name for the new preset
+        #[arg(value_hint = ValueHint::Other)]
+        name: String,
+
+        #[command(flatten)]
+        fields: PresetFields,
+    },
+
+    /// Edit a preset (opens $EDITOR unless field flags are given)
     Edit {
         /// Name of the preset to edit (creates if doesn't exist)
         #[arg(value_hint = ValueHint::Other)]
         name: String,
+
+        #[command(flatten)]
+        fields: PresetFields,
+    },
+
+    /// Set the active preset applied by default (override per run with --as)
+    Use {
+        /// Name of the preset to activate
+        #[arg(value_hint = ValueHint::Other, required_unless_present = "clear")]
+        name: Option<String>,
+
+        /// Clear the active preset
+        #[arg(long, conflicts_with = "name")]
+        clear: bool,
     },
 
     /// Delete a user preset
@@ -224,6 +470,79 @@ pub enum PresetAction {
         /// Name of the preset to delete
         #[arg(value_hint = ValueHint::Other)]
         name: String,
+
+        /// Delete even if it is the active preset (clears the active preset)
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+/// Preset fields settable from the command line
+#[derive(Args)]
+pub struct PresetFields {
+    /// Human-readable description
+    #[arg(long)]
+    pub description: Option<String>,
+
+    /// System prompt for the LLM
+    #[arg(long)]
+    pub prompt: Option<String>,
+
+    /// Post-processor override (openai, mistral, ollama)
+    #[arg(
+        long = "post-processor",
+        visible_alias = "provider",
+        value_name = "PROCESSOR"
+    )]
+    pub post_processor: Option<String>,
+
+    /// Model override for the post-processor
+    #[arg(long)]
+    pub model: Option<String>,
+
+    /// Transcription language for this preset (e.g. "de", or "auto");
+    /// a --language flag still takes precedence
+    #[arg(long, visible_alias = "lang")]
+    pub language: Option<String>,
+}
+
+impl PresetFields {
+    /// Check if any field was provided on the command line
+    pub fn is_empty(&self) -> bool {
+        self.description.is_none()
+            && self.prompt.is_none()
+            && self.post_processor.is_none()
+            && self.model.is_none()
+            && self.language.is_none()
+    }
+}
+
+/// Generic access to any setting by its field path in settings.json
+/// (e.g. `transcription.timeouts.timeout_secs`, `ui.vad.enabled`)
+#[derive(Subcommand)]
+pub enum ConfigAction {
+    /// Set a setting by field path (value is checked against the field's type)
+    Set {
+        /// Field path, e.g. ui.vad.threshold
+        #[arg(value_hint = ValueHint::Other)]
+        path: String,
+
+        /// New value (JSON for lists and objects)
+        value: String,
+    },
+
+    /// Print a setting by field path (objects print as JSON)
+    Get {
+        /// Field path, e.g. transcription.provider
+        #[arg(value_hint = ValueHint::Other)]
+        path: String,
+    },
+
+    /// Reset a setting to its default (removes map entries such as API keys)
+    Unset {
+        /// Field path, e.g. transcription.confidence_warn
+        #[arg(value_hint = ValueHint::Other)]
+        path: String,
     },
 }
 
@@ -251,3 +570,38 @@ pub enum ModelType {
         url: Option<String>,
     },
 }
+
+#[derive(Subcommand)]
+pub enum OllamaAction {
+    /// Show installation, server, and model status (default)
+    Status {
+        /// Ollama server URL (default: configured URL or http://localhost:11434)
+        #[arg(long)]
+        url: Option<String>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_last_rejects_file_and_url_input() {
+        assert!(Cli::try_parse_from(["whis", "--save-last"]).is_ok());
+        assert!(Cli::try_parse_from(["whis", "--save-last", "--file", "talk.wav"]).is_err());
+        assert!(
+            Cli::try_parse_from(["whis", "--save-last", "--url", "https://example.com/a.wav"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_config_language_rejects_show_effective() {
+        assert!(Cli::try_parse_from(["whis", "config", "--language", "de"]).is_ok());
+        assert!(Cli::try_parse_from(["whis", "-l", "de", "config", "--show-effective"]).is_ok());
+        assert!(
+            Cli::try_parse_from(["whis", "config", "--language", "de", "--show-effective"])
+                .is_err()
+        );
+    }
+}
@@ -1,36 +1,77 @@
 use anyhow::{Context, Result, anyhow};
 use whis_core::defaults::{DEFAULT_OLLAMA_MODEL, DEFAULT_OLLAMA_URL};
-use whis_core::settings::CliShortcutMode;
-use whis_core::{PostProcessor, Preset, Settings, TranscriptionProvider};
+use whis_core::settings::{
+    CliHotkeyBackend, CliShortcutMode, DefaultOutput, SettingSource, mask_api_key,
+};
+use whis_core::{
+    MAX_CHUNK_DURATION_SECS, MIN_CHUNK_DURATION_SECS, PostProcessor, Preset, Settings,
+    TranscriptionProvider,
+};
 
-use crate::ui::mask_key;
+use crate::args::{ConfigAction, ProcessingOptions};
 
 /// Supported configuration keys
 const VALID_KEYS: &[&str] = &[
     "provider",
     "language",
+    "confidence-warn",
+    "smart-format",
+    "format-numbers",
     "openai-api-key",
     "mistral-api-key",
     "groq-api-key",
     "deepgram-api-key",
     "elevenlabs-api-key",
+    "keyring",
     "whisper-model-path",
     "parakeet-model-path",
     "post-processor",
     "post-processing-prompt",
+    "post-processing-chunk-chars",
+    "post-processing-cohesion",
     "ollama-url",
     "ollama-model",
+    "ollama-system-prompt",
     "microphone-device",
     "cli-mode",
     "cli-key",
     "cli-push-to-talk",
     "desktop-key",
+    "hotkey-debounce",
+    "cli-hotkey-backend",
     "vad",
     "vad-threshold",
+    "sound-cues",
+    "cue-volume",
     "chunk-size",
+    "min-recording",
+    "service-idle-shutdown",
+    "connect-timeout",
+    "upload-timeout",
+    "timeout",
+    "compress-uploads",
+    "mp3-bitrate",
+    "copy-as-you-go",
+    "default-output",
+    "default-output-path",
+    "cache-dir",
+    "env-file",
 ];
 
-pub fn run(key: Option<String>, value: Option<String>, list: bool, path: bool) -> Result<()> {
+pub fn run(
+    action: Option<ConfigAction>,
+    key: Option<String>,
+    value: Option<String>,
+    list: bool,
+    all: bool,
+    path: bool,
+    reveal: bool,
+) -> Result<()> {
+    // Generic field-path access (set/get/unset)
+    if let Some(action) = action {
+        return run_action(action, reveal);
+    }
+
     // Handle --path flag
     if path {
         println!("{}", Settings::path().display());
@@ -39,7 +80,11 @@ pub fn run(key: Option<String>, value: Option<String>, list: bool, path: bool) -
 
     // Handle --list flag
     if list {
-        return show_all_settings();
+        return if all {
+            show_all_paths()
+        } else {
+            show_all_settings()
+        };
     }
 
     // Handle get/set operations
@@ -55,7 +100,8 @@ pub fn run(key: Option<String>, value: Option<String>, list: bool, path: bool) -
                 eprintln!("  {}", k);
             }
             eprintln!();
-            eprintln!("Run 'whis config --list' to see current values");
+            eprintln!("Any other setting: whis config set <field.path> <value>");
+            eprintln!("Run 'whis config --list --all' to see every field path");
             std::process::exit(1);
         }
 
@@ -64,7 +110,7 @@ pub fn run(key: Option<String>, value: Option<String>, list: bool, path: bool) -
             set_config(&key_normalized, &val)
         } else {
             // Get operation
-            get_config(&key_normalized)
+            get_config(&key_normalized, reveal)
         }
     } else {
         // No arguments - show usage
@@ -73,6 +119,130 @@ pub fn run(key: Option<String>, value: Option<String>, list: bool, path: bool) -
     }
 }
 
+fn run_action(action: ConfigAction, reveal: bool) -> Result<()> {
+    let mut settings = Settings::load();
+
+    match action {
+        ConfigAction::Set { path, value } => {
+            settings.set_path(&path, &value)?;
+            settings.save()?;
+            println!("{} = {}", path, display_path_value(&settings, &path)?);
+        }
+        ConfigAction::Get { path } if reveal => {
+            println!("{}", format_value(settings.get_path(&path)?));
+        }
+        ConfigAction::Get { path } => {
+            println!("{}", display_path_value(&settings, &path)?);
+        }
+        ConfigAction::Unset { path } => {
+            settings.unset_path(&path)?;
+            settings.save()?;
+            match settings.get_path(&path) {
+                Ok(_) => println!("{} = {}", path, display_path_value(&settings, &path)?),
+                Err(_) => println!("{} removed", path),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Value at a field path for display (API keys masked)
+fn display_path_value(settings: &Settings, path: &str) -> Result<String> {
+    let value = settings.get_path(path)?;
+    Ok(format_path_value(path, &value))
+}
+
+fn format_path_value(path: &str, value: &serde_json::Value) -> String {
+    format_value(mask_secrets(path, value))
+}
+
+fn format_value(value: serde_json::Value) -> String {
+    use serde_json::Value;
+
+    match value {
+        Value::Null => "(not set)".to_string(),
+        Value::String(s) => s,
+        object @ Value::Object(_) => serde_json::to_string_pretty(&object).unwrap_or_default(),
+        other => other.to_string(),
+    }
+}
+
+/// Copy of `value` with every string below an API key path masked
+fn mask_secrets(path: &str, value: &serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+
+    match value {
+        Value::String(s) if path.replace('-', "_").contains("api_key") => {
+            Value::from(mask_api_key(s))
+        }
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), mask_secrets(&format!("{path}.{k}"), v)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+fn show_all_paths() -> Result<()> {
+    let settings = Settings::load();
+
+    println!("Configuration file: {}", Settings::path().display());
+    println!();
+    for (path, value) in settings.flatten_paths()? {
+        println!("{} = {}", path, format_path_value(&path, &value));
+    }
+
+    Ok(())
+}
+
+/// Print every setting's resolved value and where it comes from
+///
+/// Flags given before `config` (`--language`, `--no-vad`, `--as`) are shown
+/// as they would apply to a recording run.
+pub fn show_effective(flags: &ProcessingOptions) -> Result<()> {
+    let settings = Settings::load();
+    let mut entries = settings.effective()?;
+
+    let mut overrides = Vec::new();
+    if let Some(language) = &flags.language {
+        let language = whis_core::parse_language(language)?;
+        overrides.push((
+            "transcription.language",
+            serde_json::to_value(language)?,
+            "--language",
+        ));
+    }
+    if flags.no_vad {
+        overrides.push(("ui.vad.enabled", serde_json::Value::Bool(false), "--no-vad"));
+    }
+    if let Some(preset) = &flags.preset {
+        overrides.push(("ui.active_preset", preset.clone().into(), "--as"));
+    }
+    for (path, value, flag) in overrides {
+        if let Some(entry) = entries.iter_mut().find(|e| e.path == path) {
+            entry.value = value;
+            entry.source = SettingSource::Flag(flag);
+        }
+    }
+
+    println!("Configuration file: {}", Settings::path().display());
+    println!();
+
+    let width = entries.iter().map(|e| e.path.len()).max().unwrap_or(0);
+    for entry in entries {
+        println!(
+            "{:<width$} = {}  [{}]",
+            entry.path,
+            format_path_value(&entry.path, &entry.value),
+            entry.source,
+        );
+    }
+
+    Ok(())
+}
+
 fn set_config(key: &str, value: &str) -> Result<()> {
     let mut settings = Settings::load();
     let value_trimmed = value.trim();
@@ -85,60 +255,58 @@ fn set_config(key: &str, value: &str) -> Result<()> {
             settings.transcription.provider = provider;
             println!("provider = {}", value_trimmed);
         }
-        "language" => {
-            if value_trimmed.to_lowercase() == "auto" {
+        "language" => match whis_core::parse_language(value_trimmed)? {
+            None => {
                 settings.transcription.language = None;
                 println!("language = auto-detect");
-            } else {
-                let lang_lower = value_trimmed.to_lowercase();
-                if lang_lower.len() != 2 || !lang_lower.chars().all(|c| c.is_ascii_lowercase()) {
-                    anyhow::bail!(
-                        "Invalid language code. Use ISO-639-1 format (e.g., 'en', 'de', 'fr') or 'auto'"
-                    );
-                }
-                settings.transcription.language = Some(lang_lower.clone());
-                println!("language = {}", lang_lower);
             }
-        }
-        "openai-api-key" => {
-            if !value_trimmed.starts_with("sk-") {
-                anyhow::bail!("Invalid key format. OpenAI keys start with 'sk-'");
+            Some(code) => {
+                println!("language = {}", code);
+                settings.transcription.language = Some(code);
             }
+        },
+        "openai-api-key" => {
+            TranscriptionProvider::OpenAI.validate_key_format(value_trimmed)?;
             settings
                 .transcription
                 .set_api_key(&TranscriptionProvider::OpenAI, value_trimmed.to_string());
-            println!("openai-api-key = {}", mask_key(value_trimmed));
+            println!("openai-api-key = {}", mask_api_key(value_trimmed));
         }
         "mistral-api-key" => {
-            validate_api_key(value_trimmed, "Mistral")?;
+            TranscriptionProvider::Mistral.validate_key_format(value_trimmed)?;
             settings
                 .transcription
                 .set_api_key(&TranscriptionProvider::Mistral, value_trimmed.to_string());
-            println!("mistral-api-key = {}", mask_key(value_trimmed));
+            println!("mistral-api-key = {}", mask_api_key(value_trimmed));
         }
         "groq-api-key" => {
-            if !value_trimmed.starts_with("gsk_") {
-                anyhow::bail!("Invalid key format. Groq keys start with 'gsk_'");
-            }
+            TranscriptionProvider::Groq.validate_key_format(value_trimmed)?;
             settings
                 .transcription
                 .set_api_key(&TranscriptionProvider::Groq, value_trimmed.to_string());
-            println!("groq-api-key = {}", mask_key(value_trimmed));
+            println!("groq-api-key = {}", mask_api_key(value_trimmed));
         }
         "deepgram-api-key" => {
-            validate_api_key(value_trimmed, "Deepgram")?;
+            TranscriptionProvider::Deepgram.validate_key_format(value_trimmed)?;
             settings
                 .transcription
                 .set_api_key(&TranscriptionProvider::Deepgram, value_trimmed.to_string());
-            println!("deepgram-api-key = {}", mask_key(value_trimmed));
+            println!("deepgram-api-key = {}", mask_api_key(value_trimmed));
         }
         "elevenlabs-api-key" => {
-            validate_api_key(value_trimmed, "ElevenLabs")?;
+            TranscriptionProvider::ElevenLabs.validate_key_format(value_trimmed)?;
             settings.transcription.set_api_key(
                 &TranscriptionProvider::ElevenLabs,
                 value_trimmed.to_string(),
             );
-            println!("elevenlabs-api-key = {}", mask_key(value_trimmed));
+            println!("elevenlabs-api-key = {}", mask_api_key(value_trimmed));
+        }
+        "keyring" => {
+            let enabled = value_trimmed
+                .parse::<bool>()
+                .context("Invalid value. Use 'true' or 'false'")?;
+            settings.transcription.use_keyring = enabled;
+            println!("keyring = {}", enabled);
         }
         "whisper-model-path" => {
             if value_trimmed.is_empty() {
@@ -160,6 +328,11 @@ fn set_config(key: &str, value: &str) -> Result<()> {
             let processor = value_trimmed
                 .parse::<PostProcessor>()
                 .map_err(|e| anyhow!("{}", e))?;
+            // Any post-processor works with any transcription provider, but
+            // say now what it still needs rather than on the next recording
+            if let Some(missing) = settings.post_processor_missing(&processor, None) {
+                eprintln!("Warning: {}", missing);
+            }
             settings.post_processing.processor = processor;
             println!("post-processor = {}", value_trimmed);
         }
@@ -173,6 +346,20 @@ fn set_config(key: &str, value: &str) -> Result<()> {
                 truncate_prompt(value_trimmed)
             );
         }
+        "post-processing-chunk-chars" => {
+            let chars = value_trimmed
+                .parse::<usize>()
+                .context("Invalid value. Use a number of characters (0 never chunks)")?;
+            settings.post_processing.chunk_chars = chars;
+            println!("post-processing-chunk-chars = {}", chars);
+        }
+        "post-processing-cohesion" => {
+            let enabled = value_trimmed
+                .parse::<bool>()
+                .context("Invalid value. Use 'true' or 'false'")?;
+            settings.post_processing.cohesion_pass = enabled;
+            println!("post-processing-cohesion = {}", enabled);
+        }
         "ollama-url" => {
             if value_trimmed.is_empty() {
                 anyhow::bail!("Invalid Ollama URL: cannot be empty");
@@ -187,6 +374,13 @@ fn set_config(key: &str, value: &str) -> Result<()> {
             settings.services.ollama.model = Some(value_trimmed.to_string());
             println!("ollama-model = {}", value_trimmed);
         }
+        "ollama-system-prompt" => {
+            if value_trimmed.is_empty() {
+                anyhow::bail!("Invalid Ollama system prompt: cannot be empty");
+            }
+            settings.services.ollama.system_prompt = Some(value_trimmed.to_string());
+            println!("ollama-system-prompt = {}", truncate_prompt(value_trimmed));
+        }
         "microphone-device" => {
             if value_trimmed.to_lowercase() == "default" || value_trimmed.is_empty() {
                 settings.ui.microphone_device = None;
@@ -196,6 +390,35 @@ fn set_config(key: &str, value: &str) -> Result<()> {
                 println!("microphone-device = {}", value_trimmed);
             }
         }
+        "confidence-warn" => {
+            if matches!(value_trimmed, "" | "off" | "none") {
+                settings.transcription.confidence_warn = None;
+                println!("confidence-warn = off");
+            } else {
+                let threshold = value_trimmed
+                    .parse::<f32>()
+                    .context("Invalid threshold. Use a number between 0.0 and 1.0, or 'off'")?;
+                if !(0.0..=1.0).contains(&threshold) {
+                    anyhow::bail!("Invalid confidence threshold: must be between 0.0 and 1.0");
+                }
+                settings.transcription.confidence_warn = Some(threshold);
+                println!("confidence-warn = {:.2}", threshold);
+            }
+        }
+        "smart-format" => {
+            let enabled = value_trimmed
+                .parse::<bool>()
+                .context("Invalid value. Use 'true' or 'false'")?;
+            settings.transcription.smart_format = enabled;
+            println!("smart-format = {}", enabled);
+        }
+        "format-numbers" => {
+            let enabled = value_trimmed
+                .parse::<bool>()
+                .context("Invalid value. Use 'true' or 'false'")?;
+            settings.transcription.format_numbers = enabled;
+            println!("format-numbers = {}", enabled);
+        }
         "vad" => {
             let enabled = value_trimmed
                 .parse::<bool>()
@@ -213,16 +436,139 @@ fn set_config(key: &str, value: &str) -> Result<()> {
             settings.ui.vad.threshold = threshold;
             println!("vad-threshold = {:.2}", threshold);
         }
+        "sound-cues" => {
+            let enabled = value_trimmed
+                .parse::<bool>()
+                .context("Invalid value. Use 'true' or 'false'")?;
+            settings.ui.cues.sound = enabled;
+            println!("sound-cues = {}", enabled);
+        }
+        "cue-volume" => {
+            let volume = value_trimmed
+                .parse::<f32>()
+                .context("Invalid volume. Use a number between 0.0 and 1.0")?;
+            if !(0.0..=1.0).contains(&volume) {
+                anyhow::bail!("Invalid cue volume: must be between 0.0 and 1.0");
+            }
+            settings.ui.cues.volume = volume;
+            println!("cue-volume = {:.2}", volume);
+        }
         "chunk-size" => {
             let size = value_trimmed
                 .parse::<u64>()
                 .context("Invalid chunk size. Use a number of seconds (e.g., 30, 60, 90)")?;
-            if !(10..=300).contains(&size) {
-                anyhow::bail!("Invalid chunk size: must be between 10 and 300 seconds");
+            if !(MIN_CHUNK_DURATION_SECS..=MAX_CHUNK_DURATION_SECS).contains(&size) {
+                anyhow::bail!(
+                    "Invalid chunk size: must be between {} and {} seconds",
+                    MIN_CHUNK_DURATION_SECS,
+                    MAX_CHUNK_DURATION_SECS
+                );
             }
             settings.ui.chunk_duration_secs = size;
             println!("chunk-size = {}s", size);
         }
+        "min-recording" => {
+            let ms = value_trimmed
+                .trim_end_matches("ms")
+                .trim()
+                .parse::<u64>()
+                .map_err(|_| {
+                    anyhow!("Invalid minimum recording length: expected milliseconds (0 disables)")
+                })?;
+            if ms > 10_000 {
+                anyhow::bail!("Invalid minimum recording length: must be at most 10000ms");
+            }
+            settings.ui.min_recording_ms = ms;
+            println!("min-recording = {}ms", ms);
+        }
+        "service-idle-shutdown" => {
+            let minutes = value_trimmed
+                .trim_end_matches("min")
+                .trim()
+                .parse::<u32>()
+                .map_err(|_| anyhow!("Invalid idle shutdown: expected minutes (0 disables)"))?;
+            settings.ui.service_idle_shutdown_minutes = minutes;
+            println!("service-idle-shutdown = {}", format_idle_shutdown(minutes));
+        }
+        "connect-timeout" => {
+            let secs = parse_timeout(value_trimmed)?;
+            settings.transcription.timeouts.connect_timeout_secs = secs;
+            println!("connect-timeout = {}s", secs);
+        }
+        "upload-timeout" => {
+            let secs = parse_timeout(value_trimmed)?;
+            settings.transcription.timeouts.upload_timeout_secs = secs;
+            println!("upload-timeout = {}s", secs);
+        }
+        "timeout" => {
+            let secs = parse_timeout(value_trimmed)?;
+            settings.transcription.timeouts.timeout_secs = secs;
+            println!("timeout = {}s", secs);
+        }
+        "compress-uploads" => {
+            let enabled = value_trimmed
+                .parse::<bool>()
+                .context("Invalid value. Use 'true' or 'false'")?;
+            settings.transcription.compress_uploads = enabled;
+            println!("compress-uploads = {}", enabled);
+        }
+        "mp3-bitrate" => {
+            let requested = value_trimmed
+                .trim_end_matches("kbps")
+                .trim()
+                .parse::<u32>()
+                .map_err(|_| anyhow!("Invalid bitrate: expected kbps (e.g. 64)"))?;
+            let kbps = whis_core::audio::clamp_mp3_bitrate(requested);
+            if kbps != requested {
+                println!("Using the nearest supported bitrate, {}kbps", kbps);
+            }
+            settings.transcription.mp3_bitrate = kbps;
+            println!("mp3-bitrate = {}kbps", kbps);
+        }
+        "copy-as-you-go" => {
+            let enabled = value_trimmed
+                .parse::<bool>()
+                .context("Invalid value. Use 'true' or 'false'")?;
+            settings.ui.copy_as_you_go = enabled;
+            println!("copy-as-you-go = {}", enabled);
+        }
+        "default-output" => {
+            let output: DefaultOutput = value_trimmed
+                .parse()
+                .map_err(|e: String| anyhow!("{}", e))?;
+            settings.ui.default_output = output;
+            println!("default-output = {}", output);
+        }
+        "default-output-path" => {
+            if value_trimmed.is_empty() {
+                settings.ui.default_output_path = None;
+                println!("default-output-path = (not set)");
+            } else {
+                settings.ui.default_output_path = Some(value_trimmed.into());
+                println!("default-output-path = {}", value_trimmed);
+            }
+        }
+        "cache-dir" => {
+            if value_trimmed.is_empty() {
+                settings.ui.cache_dir = None;
+                println!(
+                    "cache-dir = (default: {})",
+                    settings.ui.cache_dir().display()
+                );
+            } else {
+                settings.ui.cache_dir = Some(value_trimmed.into());
+                println!("cache-dir = {}", value_trimmed);
+            }
+        }
+        "env-file" => {
+            if value_trimmed.is_empty() {
+                settings.ui.env_file = None;
+                println!("env-file = (not set)");
+            } else {
+                settings.ui.env_file = Some(value_trimmed.into());
+                println!("env-file = {}", value_trimmed);
+            }
+        }
         "cli-mode" => {
             let mode: CliShortcutMode = value_trimmed
                 .parse()
@@ -236,10 +582,12 @@ fn set_config(key: &str, value: &str) -> Result<()> {
             if value_trimmed.is_empty() {
                 anyhow::bail!("Invalid CLI shortcut key: cannot be empty");
             }
-            settings.shortcuts.cli_key = value_trimmed.to_string();
+            // Normalize, and reject triggers this platform can't listen for
+            let hotkey = crate::hotkey::validate(value_trimmed)?;
+            settings.shortcuts.cli_key = hotkey.clone();
             // Validate before saving (check for conflicts)
             settings.shortcuts.validate()?;
-            println!("cli-key = {}", value_trimmed);
+            println!("cli-key = {}", hotkey);
         }
         "desktop-key" => {
             if value_trimmed.is_empty() {
@@ -250,6 +598,27 @@ fn set_config(key: &str, value: &str) -> Result<()> {
             settings.shortcuts.validate()?;
             println!("desktop-key = {}", value_trimmed);
         }
+        "hotkey-debounce" => {
+            let ms = value_trimmed
+                .trim_end_matches("ms")
+                .trim()
+                .parse::<u64>()
+                .map_err(|_| {
+                    anyhow!("Invalid hotkey debounce: expected milliseconds (0 disables)")
+                })?;
+            if ms > 1000 {
+                anyhow::bail!("Invalid hotkey debounce: must be at most 1000ms");
+            }
+            settings.shortcuts.hotkey_debounce_ms = ms;
+            println!("hotkey-debounce = {}ms", ms);
+        }
+        "cli-hotkey-backend" => {
+            let backend: CliHotkeyBackend = value_trimmed
+                .parse()
+                .map_err(|e: String| anyhow!("{}", e))?;
+            settings.shortcuts.cli_hotkey_backend = backend;
+            println!("cli-hotkey-backend = {}", backend);
+        }
         "cli-push-to-talk" => {
             let enabled = value_trimmed
                 .parse::<bool>()
@@ -264,7 +633,7 @@ fn set_config(key: &str, value: &str) -> Result<()> {
     Ok(())
 }
 
-fn get_config(key: &str) -> Result<()> {
+fn get_config(key: &str, reveal: bool) -> Result<()> {
     let settings = Settings::load();
 
     match key {
@@ -273,11 +642,17 @@ fn get_config(key: &str) -> Result<()> {
             "{}",
             settings.transcription.language.as_deref().unwrap_or("auto")
         ),
-        "openai-api-key" => print_api_key(&settings, &TranscriptionProvider::OpenAI),
-        "mistral-api-key" => print_api_key(&settings, &TranscriptionProvider::Mistral),
-        "groq-api-key" => print_api_key(&settings, &TranscriptionProvider::Groq),
-        "deepgram-api-key" => print_api_key(&settings, &TranscriptionProvider::Deepgram),
-        "elevenlabs-api-key" => print_api_key(&settings, &TranscriptionProvider::ElevenLabs),
+        "confidence-warn" => println!("{}", confidence_warn_display(&settings)),
+        "smart-format" => println!("{}", settings.transcription.smart_format),
+        "format-numbers" => println!("{}", settings.transcription.format_numbers),
+        "openai-api-key" => print_api_key(&settings, &TranscriptionProvider::OpenAI, reveal),
+        "mistral-api-key" => print_api_key(&settings, &TranscriptionProvider::Mistral, reveal),
+        "groq-api-key" => print_api_key(&settings, &TranscriptionProvider::Groq, reveal),
+        "deepgram-api-key" => print_api_key(&settings, &TranscriptionProvider::Deepgram, reveal),
+        "elevenlabs-api-key" => {
+            print_api_key(&settings, &TranscriptionProvider::ElevenLabs, reveal)
+        }
+        "keyring" => println!("{}", settings.transcription.use_keyring),
         "whisper-model-path" => {
             if let Some(path) = &settings.transcription.local_models.whisper_path {
                 println!("{}", path);
@@ -300,6 +675,8 @@ fn get_config(key: &str) -> Result<()> {
                 println!("(default)");
             }
         }
+        "post-processing-chunk-chars" => println!("{}", settings.post_processing.chunk_chars),
+        "post-processing-cohesion" => println!("{}", settings.post_processing.cohesion_pass),
         "ollama-url" => {
             if let Some(url) = &settings.services.ollama.url {
                 println!("{}", url);
@@ -314,6 +691,13 @@ fn get_config(key: &str) -> Result<()> {
                 println!("{}", DEFAULT_OLLAMA_MODEL);
             }
         }
+        "ollama-system-prompt" => {
+            if let Some(prompt) = &settings.services.ollama.system_prompt {
+                println!("{}", prompt);
+            } else {
+                println!("(default)");
+            }
+        }
         "microphone-device" => {
             if let Some(device) = &settings.ui.microphone_device {
                 println!("{}", device);
@@ -323,20 +707,51 @@ fn get_config(key: &str) -> Result<()> {
         }
         "vad" => println!("{}", settings.ui.vad.enabled),
         "vad-threshold" => println!("{:.2}", settings.ui.vad.threshold),
+        "sound-cues" => println!("{}", settings.ui.cues.sound),
+        "cue-volume" => println!("{:.2}", settings.ui.cues.volume),
         "chunk-size" => println!("{}s", settings.ui.chunk_duration_secs),
+        "min-recording" => println!("{}ms", settings.ui.min_recording_ms),
+        "service-idle-shutdown" => println!(
+            "{}",
+            format_idle_shutdown(settings.ui.service_idle_shutdown_minutes)
+        ),
+        "connect-timeout" => println!("{}s", settings.transcription.timeouts.connect_timeout_secs),
+        "upload-timeout" => println!("{}s", settings.transcription.timeouts.upload_timeout_secs),
+        "timeout" => println!("{}s", settings.transcription.timeouts.timeout_secs),
+        "compress-uploads" => println!("{}", settings.transcription.compress_uploads),
+        "mp3-bitrate" => println!("{}kbps", settings.transcription.mp3_bitrate),
+        "copy-as-you-go" => println!("{}", settings.ui.copy_as_you_go),
+        "default-output" => println!("{}", settings.ui.default_output),
+        "default-output-path" => match &settings.ui.default_output_path {
+            Some(path) => println!("{}", path.display()),
+            None => println!("(not set)"),
+        },
+        "cache-dir" => println!("{}", settings.ui.cache_dir().display()),
+        "env-file" => match &settings.ui.env_file {
+            Some(path) => println!("{}", path.display()),
+            None => println!("(not set)"),
+        },
         "cli-mode" => println!("{}", settings.shortcuts.cli_mode),
         "cli-key" => println!("{}", settings.shortcuts.cli_key),
         "cli-push-to-talk" => println!("{}", settings.shortcuts.cli_push_to_talk),
         "desktop-key" => println!("{}", settings.shortcuts.desktop_key),
+        "hotkey-debounce" => println!("{}ms", settings.shortcuts.hotkey_debounce_ms),
+        "cli-hotkey-backend" => println!("{}", settings.shortcuts.cli_hotkey_backend),
         _ => unreachable!("Key validation should prevent this"),
     }
 
     Ok(())
 }
 
-fn print_api_key(settings: &Settings, provider: &TranscriptionProvider) {
-    if let Some(key) = settings.transcription.api_key_for(provider) {
-        println!("{}", mask_key(&key));
+/// Print the key for `provider`, masked unless `reveal`
+fn print_api_key(settings: &Settings, provider: &TranscriptionProvider, reveal: bool) {
+    let key = if reveal {
+        settings.transcription.api_key_for(provider)
+    } else {
+        settings.transcription.masked_api_key_for(provider)
+    };
+    if let Some(key) = key {
+        println!("{}", key);
     } else {
         println!("(not set, using ${})", provider.api_key_env_var());
     }
@@ -354,6 +769,9 @@ fn show_all_settings() -> Result<()> {
         "language = {}",
         settings.transcription.language.as_deref().unwrap_or("auto")
     );
+    println!("confidence-warn = {}", confidence_warn_display(&settings));
+    println!("smart-format = {}", settings.transcription.smart_format);
+    println!("format-numbers = {}", settings.transcription.format_numbers);
 
     for provider in TranscriptionProvider::all() {
         let key_name = format!(
@@ -362,7 +780,7 @@ fn show_all_settings() -> Result<()> {
         );
         let key_status = if settings.transcription.has_configured_api_key(provider) {
             // Key is in settings.json
-            mask_key(&settings.transcription.api_key_for(provider).unwrap())
+            mask_api_key(&settings.transcription.api_key_for(provider).unwrap())
         } else if settings.transcription.api_key_for(provider).is_some() {
             // Key only in environment variable
             format!("(from ${})", provider.api_key_env_var())
@@ -372,6 +790,7 @@ fn show_all_settings() -> Result<()> {
         };
         println!("{} = {}", key_name, key_status);
     }
+    println!("keyring = {}", settings.transcription.use_keyring);
 
     println!();
     println!("[Local Models]");
@@ -389,12 +808,26 @@ fn show_all_settings() -> Result<()> {
 
     println!();
     println!("[Post-Processing]");
-    println!("post-processor = {}", settings.post_processing.processor);
+    match settings.post_processor_missing(&settings.post_processing.processor, None) {
+        Some(missing) => println!(
+            "post-processor = {} (not ready: {})",
+            settings.post_processing.processor, missing
+        ),
+        None => println!("post-processor = {}", settings.post_processing.processor),
+    }
     if let Some(prompt) = &settings.post_processing.prompt {
         println!("post-processing-prompt = {}", truncate_prompt(prompt));
     } else {
         println!("post-processing-prompt = (default)");
     }
+    println!(
+        "post-processing-chunk-chars = {}",
+        settings.post_processing.chunk_chars
+    );
+    println!(
+        "post-processing-cohesion = {}",
+        settings.post_processing.cohesion_pass
+    );
 
     println!();
     println!("[Services]");
@@ -408,6 +841,11 @@ fn show_all_settings() -> Result<()> {
     } else {
         println!("ollama-model = {}", DEFAULT_OLLAMA_MODEL);
     }
+    if let Some(prompt) = &settings.services.ollama.system_prompt {
+        println!("ollama-system-prompt = {}", truncate_prompt(prompt));
+    } else {
+        println!("ollama-system-prompt = (default)");
+    }
 
     println!();
     println!("[Audio]");
@@ -422,9 +860,45 @@ fn show_all_settings() -> Result<()> {
     println!("vad = {}", settings.ui.vad.enabled);
     println!("vad-threshold = {:.2}", settings.ui.vad.threshold);
 
+    println!();
+    println!("[Sound Cues]");
+    println!("sound-cues = {}", settings.ui.cues.sound);
+    println!("cue-volume = {:.2}", settings.ui.cues.volume);
+
     println!();
     println!("[Audio Chunking]");
     println!("chunk-size = {}s", settings.ui.chunk_duration_secs);
+    println!("min-recording = {}ms", settings.ui.min_recording_ms);
+    println!(
+        "service-idle-shutdown = {}",
+        format_idle_shutdown(settings.ui.service_idle_shutdown_minutes)
+    );
+
+    println!();
+    println!("[Network]");
+    let timeouts = &settings.transcription.timeouts;
+    println!("connect-timeout = {}s", timeouts.connect_timeout_secs);
+    println!("upload-timeout = {}s", timeouts.upload_timeout_secs);
+    println!("timeout = {}s", timeouts.timeout_secs);
+    println!(
+        "compress-uploads = {}",
+        settings.transcription.compress_uploads
+    );
+    println!("mp3-bitrate = {}kbps", settings.transcription.mp3_bitrate);
+
+    println!();
+    println!("[Output]");
+    println!("default-output = {}", settings.ui.default_output);
+    println!("copy-as-you-go = {}", settings.ui.copy_as_you_go);
+    match &settings.ui.default_output_path {
+        Some(path) => println!("default-output-path = {}", path.display()),
+        None => println!("default-output-path = (not set)"),
+    }
+    println!("cache-dir = {}", settings.ui.cache_dir().display());
+    match &settings.ui.env_file {
+        Some(path) => println!("env-file = {}", path.display()),
+        None => println!("env-file = (not set)"),
+    }
 
     println!();
     println!("[Shortcuts]");
@@ -432,6 +906,14 @@ fn show_all_settings() -> Result<()> {
     println!("cli-key = {}", settings.shortcuts.cli_key);
     println!("cli-push-to-talk = {}", settings.shortcuts.cli_push_to_talk);
     println!("desktop-key = {}", settings.shortcuts.desktop_key);
+    println!(
+        "hotkey-debounce = {}ms",
+        settings.shortcuts.hotkey_debounce_ms
+    );
+    println!(
+        "cli-hotkey-backend = {}",
+        settings.shortcuts.cli_hotkey_backend
+    );
 
     println!();
     println!("[Presets]");
@@ -440,12 +922,37 @@ fn show_all_settings() -> Result<()> {
     Ok(())
 }
 
+/// Parse a timeout in whole seconds (at least 1)
+fn parse_timeout(value: &str) -> Result<u64> {
+    let secs = value
+        .parse::<u64>()
+        .context("Invalid timeout. Use a number of seconds (e.g., 30, 300)")?;
+    if secs == 0 {
+        anyhow::bail!("Invalid timeout: must be at least 1 second");
+    }
+    Ok(secs)
+}
+
+/// Confidence threshold for display ("off" when unset)
+fn confidence_warn_display(settings: &Settings) -> String {
+    match settings.transcription.confidence_warn {
+        Some(threshold) => format!("{:.2}", threshold),
+        None => "off".to_string(),
+    }
+}
+
 fn show_usage() {
     eprintln!("Usage:");
     eprintln!("  whis config <key> <value>    Set a configuration value");
     eprintln!("  whis config <key>            Get a configuration value");
     eprintln!("  whis config --list           List all configuration");
     eprintln!("  whis config --path           Show configuration file path");
+    eprintln!("  whis config --show-effective Show resolved values and their source");
+    eprintln!();
+    eprintln!("Any setting by field path (see 'whis config --list --all'):");
+    eprintln!("  whis config set <path> <value>");
+    eprintln!("  whis config get <path>");
+    eprintln!("  whis config unset <path>     Reset to default");
     eprintln!();
     eprintln!("Examples:");
     eprintln!("  whis config provider openai");
@@ -454,6 +961,11 @@ fn show_usage() {
     eprintln!("  whis config post-processor ollama");
     eprintln!("  whis config vad true");
     eprintln!("  whis config chunk-size 30");
+    eprintln!("  whis config default-output stdout");
+    eprintln!("  whis config confidence-warn 0.6");
+    eprintln!("  whis config upload-timeout 600");
+    eprintln!("  whis config hotkey-debounce 50");
+    eprintln!("  whis config set ui.bubble.enabled false");
     eprintln!();
     eprintln!("Run 'whis config --list' to see all available keys and current values");
 }
@@ -467,16 +979,6 @@ fn expand_home_dir(path: &str) -> String {
     path.to_string()
 }
 
-fn validate_api_key(key: &str, provider_name: &str) -> Result<()> {
-    if key.is_empty() {
-        anyhow::bail!("Invalid {} API key: cannot be empty", provider_name);
-    }
-    if key.len() < 20 {
-        anyhow::bail!("Invalid {} API key: key appears too short", provider_name);
-    }
-    Ok(())
-}
-
 fn truncate_prompt(prompt: &str) -> String {
     if prompt.len() > 50 {
         format!("{}...", &prompt[..47])
@@ -484,3 +986,12 @@ fn truncate_prompt(prompt: &str) -> String {
         prompt.to_string()
     }
 }
+
+/// Display form of `service-idle-shutdown`
+fn format_idle_shutdown(minutes: u32) -> String {
+    if minutes == 0 {
+        "off".to_string()
+    } else {
+        format!("{}min", minutes)
+    }
+}
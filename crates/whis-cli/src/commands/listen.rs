@@ -0,0 +1,188 @@
+//! Listen Command - always-on transcription, one utterance at a time
+//!
+//! Keeps the microphone open and transcribes each utterance on its own,
+//! printing one line per utterance to stdout (or one JSON object with
+//! `--json`), so the output can be piped into other tools.
+//!
+//! ```text
+//! Microphone ─→ Segmenter ─→ utterance queue ─→ Transcribe ─→ stdout
+//!  (stream)    (VAD/Enter)    (in order)        (one at a time)
+//! ```
+//!
+//! The session itself is `whis_core::listen_with_events`; this command
+//! adds Enter to cut utterances and prints the final transcripts.
+//!
+//! Utterances end on pauses (`--auto`, see `whis_core::UtteranceSegmenter`)
+//! or when Enter is pressed. Capture never waits for transcription: the
+//! next utterance is recorded while the previous one is being transcribed,
+//! and cuts fall between samples, so no audio is lost between utterances.
+//!
+//! Ctrl+C stops listening; the utterance in progress is still transcribed
+//! (press Ctrl+C again to quit right away).
+
+use anyhow::Result;
+use std::io::BufRead;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use whis_core::{ListenEvent, SegmenterConfig, Settings, TranscribeOptions, listen_with_events};
+
+use crate::app;
+
+/// How often to check whether listening ended on its own (rejected API key)
+const FINISHED_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Lines read from stdin
+enum Input {
+    /// Enter pressed
+    Cut,
+    /// stdin closed
+    Closed,
+}
+
+pub fn run(auto: bool, pause: Option<u64>, json: bool, language: Option<String>) -> Result<()> {
+    let runtime = tokio::runtime::Runtime::new()?;
+
+    let transcription_config = app::load_transcription_config_with_overrides(None, language, None)?;
+    let settings = Settings::load();
+    let mut opts = TranscribeOptions::new(
+        transcription_config.provider.clone(),
+        transcription_config.api_key.clone(),
+    )
+    .format_numbers(settings.transcription.format_numbers)
+    .segmenter(segmenter_config(auto, pause, &settings));
+    if let Some(language) = transcription_config.language.clone() {
+        opts = opts.language(language);
+    }
+    if let Some(device) = settings.ui.microphone_device.clone() {
+        opts = opts.microphone_device(device);
+    }
+
+    preload_model(&settings, &transcription_config.provider);
+
+    runtime.block_on(listen(auto, json, opts))
+}
+
+/// Where utterance boundaries come from
+///
+/// With `--auto`, pauses in speech (Enter still cuts early); otherwise
+/// Enter presses only, so pauses never end an utterance.
+fn segmenter_config(auto: bool, pause: Option<u64>, settings: &Settings) -> SegmenterConfig {
+    let mut config = SegmenterConfig {
+        threshold: settings.ui.vad.threshold,
+        ..SegmenterConfig::default()
+    };
+    if !auto {
+        config.pause = Duration::MAX;
+        config.max_utterance = Duration::MAX;
+    } else if let Some(pause) = pause {
+        config.pause = Duration::from_millis(pause);
+    }
+    config
+}
+
+async fn listen(auto: bool, json: bool, opts: TranscribeOptions) -> Result<()> {
+    // Utterances are transcribed in order while capture goes on
+    let handle = listen_with_events(&opts, None, move |event| {
+        if let ListenEvent::Final {
+            start, end, result, ..
+        } = event
+        {
+            print_utterance(start, end, &result.text, json);
+        }
+    })?;
+
+    let (input_tx, mut input_rx) = mpsc::unbounded_channel();
+    std::thread::spawn(move || read_stdin(input_tx));
+
+    if !app::is_quiet() {
+        if auto {
+            eprintln!("Listening... (utterances end on pauses, Ctrl+C to quit)");
+        } else {
+            eprintln!("Listening... (press Enter to end an utterance, Ctrl+C to quit)");
+        }
+    }
+
+    let mut stdin_open = true;
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+    let mut finished_poll = tokio::time::interval(FINISHED_POLL_INTERVAL);
+
+    loop {
+        tokio::select! {
+            input = input_rx.recv(), if stdin_open => match input {
+                Some(Input::Cut) => handle.cut(),
+                // Without --auto, Enter is the only way to end utterances:
+                // closing stdin ends the session
+                Some(Input::Closed) | None if !auto => break,
+                Some(Input::Closed) | None => stdin_open = false,
+            },
+            _ = &mut ctrl_c => {
+                // A second Ctrl+C skips transcribing the last utterance
+                tokio::spawn(async {
+                    let _ = tokio::signal::ctrl_c().await;
+                    std::process::exit(130);
+                });
+                break;
+            }
+            // Transcription only stops early on a rejected API key
+            _ = finished_poll.tick() => {
+                if handle.is_finished() {
+                    break;
+                }
+            }
+        }
+    }
+
+    // Release the microphone and transcribe what was captured so far
+    Ok(handle.stop().await?)
+}
+
+/// Print one utterance's transcript (a JSON object with `--json`)
+fn print_utterance(start: Duration, end: Duration, text: &str, json: bool) {
+    if json {
+        let line = serde_json::json!({
+            "start": round_secs(start),
+            "end": round_secs(end),
+            "text": text,
+        });
+        println!("{line}");
+    } else {
+        println!("{text}");
+    }
+}
+
+/// Forward Enter presses (lines) from stdin until it closes
+fn read_stdin(tx: mpsc::UnboundedSender<Input>) {
+    for line in std::io::stdin().lock().lines() {
+        if line.is_err() || tx.send(Input::Cut).is_err() {
+            break;
+        }
+    }
+    let _ = tx.send(Input::Closed);
+}
+
+/// Load the local model up front so the first utterance isn't slowed down
+fn preload_model(settings: &Settings, provider: &whis_core::TranscriptionProvider) {
+    #[cfg(feature = "local-transcription")]
+    match provider {
+        whis_core::TranscriptionProvider::LocalWhisper => {
+            if let Some(model_path) = settings.transcription.whisper_model_path() {
+                whis_core::whisper_preload_model(&model_path);
+            }
+        }
+        whis_core::TranscriptionProvider::LocalParakeet => {
+            if let Some(model_path) = settings.transcription.parakeet_model_path() {
+                whis_core::preload_parakeet(&model_path);
+            }
+        }
+        _ => {} // Cloud providers don't need preload
+    }
+
+    #[cfg(not(feature = "local-transcription"))]
+    let _ = (settings, provider);
+}
+
+/// Seconds rounded to centiseconds for JSON output
+fn round_secs(duration: Duration) -> f64 {
+    (duration.as_secs_f64() * 100.0).round() / 100.0
+}
@@ -1,8 +1,12 @@
 pub mod config;
+pub mod listen;
 pub mod model;
+pub mod ollama;
 pub mod preset;
 pub mod record;
+pub mod reprocess;
 pub mod restart;
+pub mod serve;
 pub mod setup;
 pub mod start;
 pub mod status;
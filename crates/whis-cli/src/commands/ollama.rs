@@ -0,0 +1,83 @@
+//! Ollama diagnostics commands
+
+use anyhow::Result;
+use whis_core::{Settings, ollama};
+
+use crate::args::OllamaAction;
+
+/// Run the ollama command
+pub fn run(action: Option<OllamaAction>) -> Result<()> {
+    match action {
+        None => status(None),
+        Some(OllamaAction::Status { url }) => status(url),
+    }
+}
+
+/// Report Ollama installation, connectivity, and model availability
+fn status(url: Option<String>) -> Result<()> {
+    let settings = Settings::load();
+    let url = url
+        .or_else(|| settings.services.ollama.url())
+        .unwrap_or_else(|| ollama::DEFAULT_OLLAMA_URL.to_string());
+    let model = settings
+        .services
+        .ollama
+        .model()
+        .unwrap_or_else(|| ollama::DEFAULT_OLLAMA_MODEL.to_string());
+
+    println!("URL:       {}", url);
+
+    let installed = ollama::is_ollama_installed();
+    println!("Installed: {}", if installed { "yes" } else { "no" });
+
+    let running = match ollama::is_ollama_running(&url) {
+        Ok(_) => {
+            println!("Running:   yes");
+            true
+        }
+        Err(e) => {
+            println!("Running:   no ({})", e);
+            false
+        }
+    };
+
+    if !running {
+        println!();
+        if !installed {
+            println!("Install Ollama from: https://ollama.com/download");
+        } else {
+            println!("Start Ollama with: ollama serve");
+        }
+        println!("Or point whis at a different server: whis config ollama-url http://...");
+        return Ok(());
+    }
+
+    let models = ollama::list_models(&url)?;
+    if models.is_empty() {
+        println!("Models:    none");
+    } else {
+        println!("Models:");
+        for m in &models {
+            let size = m.size_str();
+            if size.is_empty() {
+                println!("  {}", m.name);
+            } else {
+                println!("  {} ({})", m.name, size);
+            }
+        }
+    }
+
+    let has_model = ollama::has_model(&url, &model)?;
+    println!(
+        "Configured model: {} [{}]",
+        model,
+        if has_model { "available" } else { "missing" }
+    );
+
+    if !has_model {
+        println!();
+        println!("Pull it with: ollama pull {}", model);
+    }
+
+    Ok(())
+}
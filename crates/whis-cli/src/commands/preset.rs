@@ -1,18 +1,55 @@
 use anyhow::{Context, Result, anyhow};
-use whis_core::{Preset, PresetSource};
+use std::path::PathBuf;
+use whis_core::{PostProcessor, Preset, PresetSource, Settings};
 
-use crate::args::PresetAction;
+use crate::args::{PresetAction, PresetFields};
 
 pub fn run(action: Option<PresetAction>) -> Result<()> {
     match action {
         None | Some(PresetAction::List) => list(),
         Some(PresetAction::Show { name }) => show(&name),
         Some(PresetAction::New { name }) => new(&name),
-        Some(PresetAction::Edit { name }) => edit(&name),
-        Some(PresetAction::Delete { name }) => delete(&name),
+        Some(PresetAction::Create { name, fields }) => create(&name, fields),
+        Some(PresetAction::Edit { name, fields }) => edit(&name, fields),
+        Some(PresetAction::Use { name, clear }) => use_preset(name.as_deref(), clear),
+        Some(PresetAction::Delete { name, force }) => delete(&name, force),
     }
 }
 
+/// Path of the user preset file for a name
+fn preset_path(name: &str) -> PathBuf {
+    Preset::presets_dir().join(format!("{}.json", name))
+}
+
+/// Apply command-line field overrides to a preset
+fn apply_fields(preset: &mut Preset, fields: PresetFields) -> Result<()> {
+    if let Some(description) = fields.description {
+        preset.description = description;
+    }
+    if let Some(prompt) = fields.prompt {
+        if prompt.trim().is_empty() {
+            anyhow::bail!("Preset prompt cannot be empty");
+        }
+        preset.prompt = prompt;
+    }
+    if let Some(post_processor) = fields.post_processor {
+        // Validate against known post-processors, store the canonical name
+        let parsed: PostProcessor = post_processor.parse().map_err(|e: String| anyhow!(e))?;
+        preset.post_processor = Some(parsed.to_string());
+    }
+    if let Some(model) = fields.model {
+        preset.model = Some(model);
+    }
+    if let Some(language) = fields.language {
+        // Validate now rather than on the next recording; keep "auto" as is
+        preset.language = Some(match whis_core::parse_language(&language)? {
+            Some(code) => code,
+            None => "auto".to_string(),
+        });
+    }
+    Ok(())
+}
+
 fn list() -> Result<()> {
     let presets = Preset::list_all();
 
@@ -45,6 +82,9 @@ fn list() -> Result<()> {
     }
 
     println!();
+    if let Some(active) = Settings::load().ui.active_preset {
+        println!("Active preset: {}", active);
+    }
     println!("User presets: {}", Preset::presets_dir().display());
 
     Ok(())
@@ -64,7 +104,7 @@ fn show(name: &str) -> Result<()> {
     }
 
     // Show overrides if any
-    if preset.post_processor.is_some() || preset.model.is_some() {
+    if preset.post_processor.is_some() || preset.model.is_some() || preset.language.is_some() {
         println!();
         println!("Overrides:");
         if let Some(post_processor) = &preset.post_processor {
@@ -73,6 +113,9 @@ fn show(name: &str) -> Result<()> {
         if let Some(model) = &preset.model {
             println!("  Model: {}", model);
         }
+        if let Some(language) = &preset.language {
+            println!("  Language: {}", language);
+        }
     }
 
     // Show file location for user presets
@@ -103,34 +146,123 @@ fn new(name: &str) -> Result<()> {
     Ok(())
 }
 
-fn edit(name: &str) -> Result<()> {
-    let editor = std::env::var("EDITOR")
-        .or_else(|_| std::env::var("VISUAL"))
-        .unwrap_or_else(|_| "nano".to_string());
+fn create(name: &str, fields: PresetFields) -> Result<()> {
+    Preset::validate_name(name, false).map_err(|e| anyhow!("{}", e))?;
+
+    if Preset::load(name).is_ok() {
+        anyhow::bail!(
+            "A preset named '{}' already exists. Use 'whis preset edit {}' to change it.",
+            name,
+            name
+        );
+    }
+
+    let open_editor = fields.prompt.is_none();
+
+    let mut preset = Preset::template(name);
+    if !open_editor {
+        // Created entirely from flags: don't keep the template placeholder text
+        preset.description = String::new();
+    }
+    apply_fields(&mut preset, fields)?;
+    preset.save().map_err(|e| anyhow!("{}", e))?;
+
+    if open_editor {
+        println!("Created new preset: {}", preset_path(name).display());
+        return open_in_editor(name);
+    }
+
+    println!("Created preset: {}", name);
+    Ok(())
+}
+
+fn edit(name: &str, fields: PresetFields) -> Result<()> {
+    Preset::validate_name(name, true).map_err(|e| anyhow!("{}", e))?;
+
+    if !fields.is_empty() {
+        // Non-interactive edit: update the given fields only
+        let (mut preset, source) = Preset::load(name).map_err(|e| anyhow!("{}", e))?;
+        apply_fields(&mut preset, fields)?;
+        preset.save().map_err(|e| anyhow!("{}", e))?;
 
-    let presets_dir = Preset::presets_dir();
-    let file_path = presets_dir.join(format!("{}.json", name));
+        if source == PresetSource::BuiltIn {
+            println!("Saved user override of built-in preset: {}", name);
+        } else {
+            println!("Updated preset: {}", name);
+        }
+        return Ok(());
+    }
+
+    let file_path = preset_path(name);
 
-    // If file doesn't exist, create from template
+    // If file doesn't exist, seed it from the built-in preset or a template
     if !file_path.exists() {
-        std::fs::create_dir_all(&presets_dir)?;
-        let template = Preset::template(name);
-        std::fs::write(&file_path, serde_json::to_string_pretty(&template)?)?;
+        let seed = Preset::builtins()
+            .into_iter()
+            .find(|p| p.name == name)
+            .unwrap_or_else(|| Preset::template(name));
+        seed.save().map_err(|e| anyhow!("{}", e))?;
         println!("Created new preset: {}", file_path.display());
     }
 
+    open_in_editor(name)
+}
+
+/// Open a user preset file in $EDITOR and validate the result
+fn open_in_editor(name: &str) -> Result<()> {
+    let editor = std::env::var("EDITOR")
+        .or_else(|_| std::env::var("VISUAL"))
+        .unwrap_or_else(|_| "nano".to_string());
+
+    let file_path = preset_path(name);
+
     // Open in editor
     let status = std::process::Command::new(&editor)
         .arg(&file_path)
-        .status()?;
+        .status()
+        .with_context(|| format!("Failed to launch editor '{}'", editor))?;
 
-    if status.success() {
-        println!("Preset saved: {}", file_path.display());
+    if !status.success() {
+        return Ok(());
     }
+
+    // Make sure the saved file still parses, so the preset isn't silently ignored
+    let content = std::fs::read_to_string(&file_path)
+        .with_context(|| format!("Failed to read {}", file_path.display()))?;
+    serde_json::from_str::<Preset>(&content).with_context(|| {
+        format!(
+            "Preset file is not valid: {}\nRun 'whis preset edit {}' to fix it.",
+            file_path.display(),
+            name
+        )
+    })?;
+
+    println!("Preset saved: {}", file_path.display());
     Ok(())
 }
 
-fn delete(name: &str) -> Result<()> {
+fn use_preset(name: Option<&str>, clear: bool) -> Result<()> {
+    let mut settings = Settings::load();
+
+    if clear {
+        settings.ui.active_preset = None;
+        settings.save()?;
+        println!("Cleared active preset");
+        return Ok(());
+    }
+
+    let name = name.ok_or_else(|| anyhow!("Preset name required"))?;
+    let (preset, _source) = Preset::load(name).map_err(|e| anyhow!("{}", e))?;
+
+    settings.ui.active_preset = Some(preset.name.clone());
+    settings.save()?;
+    println!("Active preset: {}", preset.name);
+    println!("Applied to 'whis' and the service unless overridden with --as/--preset.");
+
+    Ok(())
+}
+
+fn delete(name: &str, force: bool) -> Result<()> {
     // Check if preset exists and get its source
     let (_, source) = Preset::load(name).map_err(|e| anyhow!("{}", e))?;
 
@@ -142,12 +274,30 @@ fn delete(name: &str) -> Result<()> {
         );
     }
 
+    // Refuse to delete the active preset unless forced
+    let mut settings = Settings::load();
+    let is_active = settings.ui.active_preset.as_deref() == Some(name);
+    if is_active && !force {
+        anyhow::bail!(
+            "Preset '{}' is the active preset.\n\
+             Use 'whis preset delete {} --force' to delete it and clear the active preset.",
+            name,
+            name
+        );
+    }
+
     // Delete the file
-    let file_path = Preset::presets_dir().join(format!("{}.json", name));
+    let file_path = preset_path(name);
 
     std::fs::remove_file(&file_path)
         .with_context(|| format!("Failed to delete preset file: {}", file_path.display()))?;
 
+    if is_active {
+        settings.ui.active_preset = None;
+        settings.save()?;
+        println!("Cleared active preset");
+    }
+
     println!("Deleted preset: {}", name);
     Ok(())
 }
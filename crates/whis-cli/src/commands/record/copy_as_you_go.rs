@@ -0,0 +1,24 @@
+//! Copy-as-you-go for progressive transcription (`copy-as-you-go` setting)
+//!
+//! After each chunk, the merged raw transcript so far replaces the clipboard
+//! contents, so partial results of a long dictation can be pasted before
+//! recording ends. The output phase still copies the complete transcript.
+//!
+//! Only used when the final output is the unprocessed transcript on the
+//! clipboard; post-processing needs the full text, so it turns this off.
+
+use whis_core::{ClipboardMethod, PartialTranscriptCallback, copy_to_clipboard};
+
+/// Callback that copies the merged transcript after every chunk
+pub fn partial_callback(method: ClipboardMethod) -> PartialTranscriptCallback {
+    Box::new(move |sequence, merged| {
+        let text = merged.trim();
+        if text.is_empty() {
+            return;
+        }
+        // A failed copy only loses the partial result; the final output reports errors
+        if let Err(e) = copy_to_clipboard(text, method.clone()) {
+            whis_core::verbose!("Copy-as-you-go failed after chunk {sequence}: {e}");
+        }
+    })
+}
@@ -0,0 +1,191 @@
+//! Dry-run mode: report the pipeline plan without calling any provider
+//!
+//! Records (or loads) audio as usual, then prints the provider, endpoint,
+//! model, language, post-processing and output choices that a real run
+//! would use. Nothing is sent to a provider (URL inputs are still downloaded).
+
+use anyhow::Result;
+use whis_core::{
+    AudioRecorder, PostProcessor, Settings, TranscriptionProvider, resample::WHISPER_SAMPLE_RATE,
+};
+
+use super::modes;
+use super::types::RecordConfig;
+use crate::app;
+
+/// Record or load audio, then print what a real run would do
+pub async fn run(
+    config: &RecordConfig,
+    transcription_config: &app::TranscriptionConfig,
+) -> Result<()> {
+    let settings = Settings::load();
+
+    let samples = match &config.input {
+        Some(input) => modes::read_input(input, config.input_format.as_deref(), false).await?,
+        None => record(config, &settings).await?,
+    };
+
+    let duration_secs = samples.len() as f64 / WHISPER_SAMPLE_RATE as f64;
+    let provider = &transcription_config.provider;
+    let backend = whis_core::provider::registry().get_by_kind(provider)?;
+
+    println!("Dry run (nothing was sent)");
+    println!(
+        "  Audio:         {:.1}s ({} samples, {} Hz mono)",
+        duration_secs,
+        samples.len(),
+        WHISPER_SAMPLE_RATE
+    );
+    println!(
+        "  Provider:      {} ({})",
+        backend.display_name(),
+        backend.name()
+    );
+
+    match provider {
+        TranscriptionProvider::LocalWhisper | TranscriptionProvider::LocalParakeet => {
+            let model_path = match provider {
+                TranscriptionProvider::LocalParakeet => {
+                    settings.transcription.parakeet_model_path()
+                }
+                _ => Some(transcription_config.api_key.clone()),
+            };
+            println!(
+                "  Model:         {} (local)",
+                model_path.as_deref().unwrap_or("not configured")
+            );
+        }
+        _ => {
+            println!("  Endpoint:      {}", backend.endpoint().unwrap_or("-"));
+            println!(
+                "  Model:         {}",
+                backend.default_model().unwrap_or("-")
+            );
+        }
+    }
+
+    println!(
+        "  Language:      {}",
+        transcription_config.language.as_deref().unwrap_or("auto")
+    );
+    println!(
+        "  Requests:      {}",
+        describe_requests(config, provider, duration_secs, &settings)
+    );
+    println!(
+        "  Post-process:  {}",
+        describe_post_processing(config, &settings)
+    );
+    println!("  Output:        {}", describe_output(config, &settings));
+
+    Ok(())
+}
+
+/// Record from the microphone until Enter or the configured duration
+async fn record(config: &RecordConfig, settings: &Settings) -> Result<Vec<f32>> {
+    let mut recorder = AudioRecorder::new()?;
+    let vad_enabled = settings.ui.vad.enabled && !config.no_vad;
+    recorder.set_vad(vad_enabled, settings.ui.vad.threshold);
+    recorder.start_recording_with_device(settings.ui.microphone_device.as_deref())?;
+
+    if let Some(dur) = config.duration {
+        eprintln!("Recording for {} seconds (dry run)...", dur.as_secs());
+        tokio::time::sleep(dur).await;
+    } else {
+        eprintln!("Recording (dry run). Press Enter to stop");
+        tokio::task::spawn_blocking(app::wait_for_stop).await??;
+    }
+
+    Ok(recorder.stop_recording()?.finalize_raw())
+}
+
+/// Describe how audio would be sent to the provider
+fn describe_requests(
+    config: &RecordConfig,
+    provider: &TranscriptionProvider,
+    duration_secs: f64,
+    settings: &Settings,
+) -> String {
+    if config.input.is_some() {
+        return match provider {
+            TranscriptionProvider::LocalWhisper | TranscriptionProvider::LocalParakeet => {
+                "1 local inference".to_string()
+            }
+            _ => "1 request (MP3 upload)".to_string(),
+        };
+    }
+
+    if whis_core::is_realtime_provider(provider) {
+        return "audio streamed over WebSocket".to_string();
+    }
+
+    let chunk_secs = settings.ui.chunk_duration_secs.max(1);
+    let chunks = ((duration_secs / chunk_secs as f64).ceil() as u64).max(1);
+    let unit = match provider {
+        TranscriptionProvider::LocalParakeet => "local inference",
+        _ => "request",
+    };
+    format!(
+        "~{} {}{} (chunks of ~{}s)",
+        chunks,
+        unit,
+        if chunks == 1 { "" } else { "s" },
+        chunk_secs
+    )
+}
+
+/// Describe the post-processing step without contacting the LLM
+fn describe_post_processing(config: &RecordConfig, settings: &Settings) -> String {
+    let enabled = config.post_process
+        || config.preset.is_some()
+        || config.instruction.is_some()
+        || config.with_clipboard;
+    if !enabled {
+        return "off".to_string();
+    }
+
+    let preset = config.preset.as_ref();
+    let processor = preset
+        .and_then(|p| p.post_processor.as_deref())
+        .and_then(|p| p.parse::<PostProcessor>().ok())
+        .unwrap_or_else(|| settings.post_processing.processor.clone());
+
+    let model = preset.and_then(|p| p.model.clone()).or_else(|| {
+        (processor == PostProcessor::Ollama)
+            .then(|| settings.services.ollama.model())
+            .flatten()
+    });
+
+    let prompt_source = if config.instruction.is_some() {
+        "--instruction".to_string()
+    } else if let Some(p) = preset {
+        format!("preset '{}'", p.name)
+    } else if settings.post_processing.prompt.is_some() {
+        "configured prompt".to_string()
+    } else {
+        "default prompt".to_string()
+    };
+
+    let mut description = format!(
+        "{} (model: {}, prompt: {})",
+        processor,
+        model.as_deref().unwrap_or("default"),
+        prompt_source
+    );
+    if config.with_clipboard {
+        description.push_str(", with clipboard context");
+    }
+    description
+}
+
+/// Describe where the result would go
+fn describe_output(config: &RecordConfig, settings: &Settings) -> String {
+    let format = format!("{:?}", config.format).to_lowercase();
+    if config.print {
+        format!("stdout ({})", format)
+    } else if let Some(path) = &config.output_path {
+        format!("{} ({})", path.display(), format)
+    } else {
+        format!("{} ({})", settings.ui.output_method, format)
+    }
+}
@@ -0,0 +1,52 @@
+//! Storage for the most recent recording (`--save-last` / `whis reprocess`)
+//!
+//! Only the latest recording is kept, as 16kHz mono 16-bit WAV in the cache
+//! directory (`cache-dir`), so disk use stays bounded. WAV is used so the file can be fed
+//! straight back through the file transcription path.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use whis_core::Settings;
+use whis_core::resample::WHISPER_SAMPLE_RATE;
+
+/// Path of the saved last recording (~/.cache/whis/last-recording.wav by default)
+pub fn path() -> PathBuf {
+    Settings::load().ui.cache_dir().join("last-recording.wav")
+}
+
+/// Save samples (16kHz mono) as the last recording, replacing any previous one
+pub fn save(samples: &[f32]) -> Result<PathBuf> {
+    let path = path();
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+
+    // Write to a temp file first so a failed write never clobbers the old recording
+    let tmp_path = path.with_extension("wav.tmp");
+    write_wav(&tmp_path, samples)?;
+
+    std::fs::rename(&tmp_path, &path)
+        .with_context(|| format!("Failed to save {}", path.display()))?;
+
+    whis_core::verbose!("Saved last recording to {}", path.display());
+    Ok(path)
+}
+
+/// Write samples (16kHz mono) to `path` as 16-bit WAV
+pub fn write_wav(path: &Path, samples: &[f32]) -> Result<()> {
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: WHISPER_SAMPLE_RATE,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+
+    let mut writer = hound::WavWriter::create(path, spec)
+        .with_context(|| format!("Failed to create {}", path.display()))?;
+    for &sample in samples {
+        writer.write_sample((sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)?;
+    }
+    writer.finalize().context("Failed to finalize WAV file")?;
+    Ok(())
+}
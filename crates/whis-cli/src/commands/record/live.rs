@@ -0,0 +1,112 @@
+//! Live transcript display for progressive recording
+//!
+//! After each chunk is transcribed, the text it added is printed above a
+//! spinner, so long recordings show text as they go. The spinner shows the
+//! recording status from the start, then "Transcribing chunk n/m..." once
+//! the total is known.
+//!
+//! Disabled with `--print` and `--quiet`, where only the final transcript is
+//! written. The final (post-processed) output supersedes everything shown here.
+
+use std::sync::{Arc, Mutex};
+use whis_core::PartialTranscriptCallback;
+
+use crate::progress::Progress;
+
+#[derive(Default)]
+struct LiveState {
+    /// Merged transcript already printed
+    printed: String,
+    /// Chunks transcribed so far
+    done: usize,
+    /// Total chunks, known once recording stopped
+    total: Option<usize>,
+    /// Status while recording (e.g. "Recording...")
+    recording: String,
+    /// Status spinner, shown from `start` on
+    progress: Option<Progress>,
+}
+
+impl LiveState {
+    /// Show the current status, starting the spinner if needed
+    fn update_status(&mut self) {
+        let status = status(self);
+        match &self.progress {
+            Some(progress) => progress.set_message(status),
+            None => self.progress = Some(Progress::spinner(status)),
+        }
+    }
+}
+
+/// Shared display state (cheap to clone into the transcription task)
+#[derive(Clone, Default)]
+pub struct LiveTranscript {
+    state: Arc<Mutex<LiveState>>,
+}
+
+impl LiveTranscript {
+    /// Live display unless quiet (see `RecordConfig::is_quiet`)
+    pub fn new(quiet: bool) -> Option<Self> {
+        (!quiet).then(Self::default)
+    }
+
+    /// Recording started: show `status` until the total is known
+    pub fn start(&self, status: impl Into<String>) {
+        let mut state = self.state.lock().unwrap();
+        state.recording = status.into();
+        state.update_status();
+    }
+
+    /// Callback for `progressive_transcribe_cloud_with_timings`
+    pub fn partial_callback(&self) -> PartialTranscriptCallback {
+        let live = self.clone();
+        Box::new(move |_sequence, merged| live.show(merged))
+    }
+
+    /// Recording stopped: `total` chunks will be transcribed
+    pub fn set_total(&self, total: usize) {
+        let mut state = self.state.lock().unwrap();
+        state.total = Some(total);
+        state.update_status();
+    }
+
+    /// Transcription finished: clear the spinner
+    pub fn finish(&self) {
+        if let Some(progress) = self.state.lock().unwrap().progress.take() {
+            progress.finish(None);
+        }
+    }
+
+    fn show(&self, merged: &str) {
+        let mut state = self.state.lock().unwrap();
+        state.done += 1;
+
+        // Overlap removal only trims the start of the new chunk, so the text
+        // printed so far stays a prefix of the merged transcript
+        let merged = merged.trim();
+        let Some(new_text) = merged.strip_prefix(state.printed.as_str()) else {
+            return;
+        };
+        let new_text = new_text.trim().to_string();
+
+        state.update_status();
+        if !new_text.is_empty()
+            && let Some(progress) = &state.progress
+        {
+            progress.println(&new_text);
+        }
+        state.printed = merged.to_string();
+    }
+}
+
+/// Status line shown below the transcript
+fn status(state: &LiveState) -> String {
+    match state.total {
+        Some(total) if state.done < total => {
+            format!("Transcribing chunk {}/{}...", state.done + 1, total)
+        }
+        Some(_) => "Transcribing...".to_string(),
+        None if state.recording.is_empty() => "Recording...".to_string(),
+        None => state.recording.clone(),
+    }
+}
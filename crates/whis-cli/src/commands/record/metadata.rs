@@ -0,0 +1,152 @@
+//! Metadata sidecar (`--metadata`)
+//!
+//! Writes a JSON file next to the `--output` transcript (`talk.txt` →
+//! `talk.json`) describing the recording: when it was made, how long it is,
+//! where the audio came from and which provider, model and language
+//! transcribed it, plus a cost estimate and the per-stage latency from
+//! `StageTimings`. Meant for building and analyzing transcript corpora.
+//!
+//! The cost estimate covers transcription at list price only, not
+//! post-processing.
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use whis_core::{Settings, StageTimings, TranscriptionProvider};
+
+use super::types::AudioInput;
+use crate::app;
+
+/// Contents of the sidecar file
+#[derive(Serialize)]
+struct RecordingMetadata {
+    /// When the transcript was written (local time, RFC 3339)
+    timestamp: String,
+    /// Length of the transcribed audio
+    duration_secs: Option<f64>,
+    /// "microphone", or the input file/URL name
+    source: String,
+    /// Microphone used ("default" for the system default)
+    device: Option<String>,
+    provider: String,
+    model: Option<String>,
+    /// Requested language (None = auto-detected by the provider)
+    language: Option<String>,
+    /// Language the provider detected, if it reports one
+    detected_language: Option<String>,
+    post_processed: bool,
+    cost_estimate_usd: Option<f64>,
+    /// Seconds per pipeline stage (stages overlap while recording)
+    timings: BTreeMap<&'static str, f64>,
+}
+
+/// Sidecar path for the transcript at `output`
+pub fn sidecar_path(output: &Path) -> PathBuf {
+    let path = output.with_extension("json");
+    if path == output {
+        output.with_extension("meta.json")
+    } else {
+        path
+    }
+}
+
+/// Write the sidecar for a finished transcript at `output`
+pub fn write(
+    output: &Path,
+    input: Option<&AudioInput>,
+    duration: Option<Duration>,
+    detected_language: Option<&str>,
+    post_processed: bool,
+    transcription_config: &app::TranscriptionConfig,
+    timings: &StageTimings,
+) -> Result<PathBuf> {
+    let settings = Settings::load();
+    let provider = &transcription_config.provider;
+    let duration_secs = duration.map(|d| d.as_secs_f64());
+
+    let metadata = RecordingMetadata {
+        timestamp: chrono::Local::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
+        duration_secs,
+        source: input.map_or_else(|| "microphone".to_string(), AudioInput::name),
+        device: input.is_none().then(|| {
+            settings
+                .ui
+                .microphone_device
+                .clone()
+                .unwrap_or_else(|| "default".to_string())
+        }),
+        provider: provider.as_str().to_string(),
+        model: model_name(provider, transcription_config, &settings),
+        language: transcription_config.language.clone(),
+        detected_language: detected_language.map(str::to_string),
+        post_processed,
+        cost_estimate_usd: duration_secs.map(|secs| provider.cost_per_minute_usd() * secs / 60.0),
+        timings: timings
+            .measured()
+            .into_iter()
+            .map(|(name, elapsed)| (name, elapsed.as_secs_f64()))
+            .collect(),
+    };
+
+    let path = sidecar_path(output);
+    let json = serde_json::to_string_pretty(&metadata)?;
+    std::fs::write(&path, json + "\n")
+        .with_context(|| format!("Failed to write metadata to {}", path.display()))?;
+    Ok(path)
+}
+
+/// Model that transcribed: the model file for local providers
+fn model_name(
+    provider: &TranscriptionProvider,
+    transcription_config: &app::TranscriptionConfig,
+    settings: &Settings,
+) -> Option<String> {
+    let model_path = match provider {
+        TranscriptionProvider::LocalWhisper => Some(transcription_config.api_key.clone()),
+        TranscriptionProvider::LocalParakeet => settings.transcription.parakeet_model_path(),
+        _ => {
+            return whis_core::provider::registry()
+                .get_by_kind(provider)
+                .ok()?
+                .default_model()
+                .map(str::to_string);
+        }
+    };
+    model_path.map(|path| {
+        Path::new(&path)
+            .file_name()
+            .map_or(path.clone(), |name| name.to_string_lossy().into_owned())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sidecar_replaces_extension() {
+        assert_eq!(
+            sidecar_path(Path::new("notes/talk.txt")),
+            PathBuf::from("notes/talk.json")
+        );
+        assert_eq!(
+            sidecar_path(Path::new("talk.srt")),
+            PathBuf::from("talk.json")
+        );
+    }
+
+    #[test]
+    fn test_sidecar_without_extension() {
+        assert_eq!(sidecar_path(Path::new("talk")), PathBuf::from("talk.json"));
+    }
+
+    #[test]
+    fn test_sidecar_never_overwrites_json_output() {
+        assert_eq!(
+            sidecar_path(Path::new("talk.json")),
+            PathBuf::from("talk.meta.json")
+        );
+    }
+}
@@ -28,6 +28,10 @@
 //!    - Audio chunked into ~90s segments with overlap
 //!    - Chunks transcribed sequentially (cloud or local)
 //!    - Results merged with overlap deduplication
+//!    - Each chunk's text is shown as it completes (`live.rs`), except with
+//!      `--print`/`--quiet`
+//!    - With `copy-as-you-go`, the clipboard is updated after each chunk
+//!      (`copy_as_you_go.rs`)
 //!
 //! 3. **Process Phase** (`pipeline/process.rs`): Enhance transcript
 //!    - Apply LLM post-processing (grammar, filler words)
@@ -37,6 +41,12 @@
 //!    - Copy to clipboard (default)
 //!    - Print to stdout (--print flag)
 //!
+//! With `--dry-run` (`dry_run.rs`), audio is recorded/loaded and the plan is
+//! printed instead of running phases 2-4.
+//!
+//! With `--verbose`, a per-phase latency breakdown (recording, encoding,
+//! upload, transcription, post-processing) is printed after output.
+//!
 //! # Configuration
 //!
 //! The record command respects user settings from `~/.config/whis/config.toml`:
@@ -45,16 +55,25 @@
 //! - VAD settings and hotkeys
 //! - Clipboard method
 
+mod copy_as_you_go;
+mod dry_run;
+pub mod last_recording;
+mod live;
+mod metadata;
 mod modes;
 mod pipeline;
 mod types;
 
 // Re-export public types for external use
-pub use types::RecordConfig;
+pub use types::{AudioInput, RecordConfig, resolve_format, resolve_output};
 
 use anyhow::Result;
+use std::sync::Arc;
+use std::time::Instant;
+use whis_core::{StageTimings, TranscriptionStage};
 
 use crate::app;
+use crate::progress::Progress;
 
 /// Execute the record command with clean pipeline phases
 pub fn run(config: RecordConfig) -> Result<()> {
@@ -63,58 +82,170 @@ pub fn run(config: RecordConfig) -> Result<()> {
     // Create Tokio runtime for async operations
     let runtime = tokio::runtime::Runtime::new()?;
 
-    // Load transcription configuration (with optional language override)
-    let transcription_config =
-        app::load_transcription_config_with_language(config.language.clone())?;
+    // Load transcription configuration (language: flag > preset > setting)
+    let transcription_config = app::load_transcription_config_with_overrides(
+        config.provider.clone(),
+        config.language.clone(),
+        config
+            .preset
+            .as_ref()
+            .and_then(|preset| preset.language.as_deref()),
+    )?;
+    app::check_language_support(&transcription_config, config.strict)?;
+    if config.metadata && config.output_path.is_none() {
+        anyhow::bail!("--metadata writes next to the transcript file: add --output <PATH>");
+    }
+    if config.format.is_subtitle() && !transcription_config.provider.supports_timestamps() {
+        anyhow::bail!(
+            "{} doesn't return timestamps, so it can't produce subtitles\n\
+             Subtitles need OpenAI or Groq: run 'whis setup cloud' to switch",
+            transcription_config.provider.display_name()
+        );
+    }
+    let format_numbers =
+        config.numerals || whis_core::Settings::load().transcription.format_numbers;
+    whis_core::set_numeral_formatting(format_numbers);
 
-    // Branch: file transcription vs microphone recording
-    let transcription_result = if let Some(ref input_file) = config.input_file {
+    // Dry run: report the plan and exit before any provider call
+    if config.dry_run {
+        return runtime.block_on(dry_run::run(&config, &transcription_config));
+    }
+
+    let will_post_process = config.post_process
+        || config.preset.is_some()
+        || config.instruction.is_some()
+        || config.with_clipboard;
+
+    // Keep a copy of what gets uploaded (see `finish_save_recording`)
+    let dump = config
+        .save_recording
+        .clone()
+        .map(whis_core::audio::EncodedAudioDump::new);
+
+    // Per-phase latency, reported in verbose mode
+    let timings = StageTimings::new();
+    let started = Instant::now();
+
+    // Branch: file/URL transcription vs microphone recording
+    let transcription_result = if let Some(ref input) = config.input {
         // File transcription mode
-        runtime.block_on(transcribe_file(input_file, &transcription_config, quiet))?
+        runtime.block_on(transcribe_file(
+            input,
+            config.input_format.as_deref(),
+            dump.as_ref(),
+            // Subtitles get the provider's segment timings when it has them
+            config.format.is_subtitle(),
+            &transcription_config,
+            &timings,
+            quiet,
+        ))?
     } else {
         // Microphone: Record and transcribe concurrently (streaming)
         let mic_config = modes::MicrophoneConfig {
             duration: config.duration,
             no_vad: config.no_vad,
             provider: transcription_config.provider.clone(),
-            will_post_process: config.post_process || config.preset.is_some(),
+            will_post_process,
+            save_last: config.save_last,
+            save_recording: dump.clone(),
+            // Partial copies only make sense for raw text headed for the clipboard
+            copy_as_you_go: whis_core::Settings::load().ui.copy_as_you_go
+                && !will_post_process
+                && !config.print
+                && config.output_path.is_none()
+                && config.format == crate::args::OutputFormat::Txt,
         };
         runtime.block_on(progressive_record_and_transcribe(
             mic_config,
             &transcription_config,
+            &timings,
             quiet,
         ))?
     };
 
+    let audio_duration = transcription_result.duration;
+    let detected_language = transcription_result.language.clone();
+
     // Phase 3: Post-process and apply presets
     let processing_cfg = pipeline::ProcessingConfig {
         enabled: config.post_process,
         preset: config.preset,
+        instruction: config.instruction,
+        with_clipboard: config.with_clipboard,
+        language: transcription_config.language.clone(),
+        format_numbers: format_numbers
+            && whis_core::formats_numbers_locally(
+                &transcription_config.provider,
+                transcription_config.language.as_deref(),
+            ),
+        strict: config.strict,
     };
+    let post_process_started = Instant::now();
     let processed_result = runtime.block_on(pipeline::process(
         transcription_result,
         &processing_cfg,
         quiet,
     ))?;
+    if will_post_process {
+        timings.add(
+            TranscriptionStage::PostProcessing,
+            post_process_started.elapsed(),
+        );
+    }
 
     // Print completion after all processing is done
     if !quiet {
-        println!(" Done.");
+        app::status_line(" Done.");
     }
 
-    // Phase 4: Output (print, file, type to window, or clipboard)
-    let output_mode = if config.print {
-        pipeline::OutputMode::Print
-    } else if let Some(path) = config.output_path {
-        pipeline::OutputMode::File(path)
-    } else {
-        pipeline::OutputMode::Clipboard
-    };
-    pipeline::output(processed_result, output_mode, config.format, quiet)?;
+    // Phase 4: Output (print, subtitles, file, type to window, or clipboard)
+    let output_mode =
+        pipeline::OutputMode::new(config.print, config.output_path.clone(), config.format);
+    pipeline::output(processed_result, output_mode, audio_duration, quiet)?;
+
+    if config.metadata
+        && let Some(path) = &config.output_path
+    {
+        let sidecar = metadata::write(
+            path,
+            config.input.as_ref(),
+            Some(audio_duration),
+            detected_language.as_deref(),
+            will_post_process,
+            &transcription_config,
+            &timings,
+        )?;
+        if !quiet {
+            eprintln!("Metadata written to {}", sidecar.display());
+        }
+    }
+
+    if whis_core::verbose::is_verbose() {
+        print_latency_breakdown(&timings, started.elapsed());
+    }
 
     Ok(())
 }
 
+/// Print where time went, per pipeline phase (verbose mode)
+///
+/// In microphone mode chunks are encoded and transcribed while recording,
+/// so the line that matters for perceived latency is "after recording".
+fn print_latency_breakdown(timings: &StageTimings, total: std::time::Duration) {
+    whis_core::verbose!("Latency breakdown:");
+    for line in timings.breakdown().lines() {
+        whis_core::verbose!("{line}");
+    }
+
+    let recording = timings.get(TranscriptionStage::Recording);
+    let (label, elapsed) = if recording.is_zero() {
+        ("total", total)
+    } else {
+        ("after recording", total.saturating_sub(recording))
+    };
+    whis_core::verbose!("  {label:<16}{:>7.2}s", elapsed.as_secs_f64());
+}
+
 /// Progressive recording + transcription (combines recording and transcription phases)
 ///
 /// This function overlaps recording and transcription using the progressive
@@ -126,6 +257,7 @@ pub fn run(config: RecordConfig) -> Result<()> {
 async fn progressive_record_and_transcribe(
     mic_config: modes::MicrophoneConfig,
     transcription_config: &app::TranscriptionConfig,
+    timings: &StageTimings,
     quiet: bool,
 ) -> Result<types::TranscriptionResult> {
     use tokio::sync::mpsc;
@@ -133,7 +265,7 @@ async fn progressive_record_and_transcribe(
     use whis_core::progressive_transcribe_local;
     use whis_core::{
         AudioRecorder, ChunkerConfig, ProgressiveChunker, Settings, TranscriptionProvider,
-        WarmupConfig, progressive_transcribe_cloud, warmup_configured,
+        WarmupConfig, progressive_transcribe_cloud_with_timings, warmup_configured,
     };
 
     // Check if this is a realtime provider (for branching later)
@@ -191,6 +323,7 @@ async fn progressive_record_and_transcribe(
     let device_name = settings.ui.microphone_device.clone();
     let mut audio_rx_bounded =
         recorder.start_recording_streaming_with_device(device_name.as_deref())?;
+    let recording_started = Instant::now();
 
     // Create unbounded channel for chunker (adapter pattern)
     let (audio_tx_unbounded, audio_rx_unbounded) = mpsc::unbounded_channel();
@@ -204,10 +337,22 @@ async fn progressive_record_and_transcribe(
         }
     });
 
+    // Show chunk transcripts as they complete (chunked path only)
+    let live = if is_realtime {
+        None
+    } else {
+        live::LiveTranscript::new(quiet)
+    };
+
+    // Keep the clipboard up to date chunk by chunk (not when autotyping)
+    let copy_partials = mic_config.copy_as_you_go
+        && !is_realtime
+        && settings.ui.output_method != whis_core::OutputMethod::Autotype;
+
     // Branch based on provider type: realtime streaming vs chunked progressive
     let (transcription_task, chunker_task): (
-        tokio::task::JoinHandle<anyhow::Result<String>>,
-        Option<tokio::task::JoinHandle<anyhow::Result<()>>>,
+        tokio::task::JoinHandle<anyhow::Result<types::TranscriptionResult>>,
+        Option<tokio::task::JoinHandle<anyhow::Result<usize>>>,
     ) = if is_realtime {
         // REALTIME PATH: Stream audio directly to WebSocket (no chunking)
         #[cfg(feature = "realtime")]
@@ -220,6 +365,7 @@ async fn progressive_record_and_transcribe(
                 realtime_backend
                     .transcribe_stream(&api_key, audio_rx_unbounded, language)
                     .await
+                    .map(types::TranscriptionResult::from_text)
             });
 
             (task, None) // No chunker task for realtime
@@ -237,13 +383,8 @@ async fn progressive_record_and_transcribe(
         let (chunk_tx, chunk_rx) = mpsc::unbounded_channel();
 
         // Create chunker config from settings
-        let target = settings.ui.chunk_duration_secs;
-        let chunker_config = ChunkerConfig {
-            target_duration_secs: target,
-            min_duration_secs: target * 2 / 3,
-            max_duration_secs: target * 4 / 3,
-            vad_aware: vad_enabled,
-        };
+        let chunker_config =
+            ChunkerConfig::from_target_secs(settings.ui.chunk_duration_secs, vad_enabled);
 
         // Spawn chunker task
         let mut chunker = ProgressiveChunker::new(chunker_config, chunk_tx);
@@ -251,7 +392,8 @@ async fn progressive_record_and_transcribe(
             chunker
                 .consume_stream(audio_rx_unbounded, None)
                 .await
-                .map_err(|e| anyhow::anyhow!(e))
+                .map_err(|e| anyhow::anyhow!(e))?;
+            Ok(chunker.chunks_sent())
         });
 
         // Spawn transcription task based on provider
@@ -259,6 +401,18 @@ async fn progressive_record_and_transcribe(
             let provider = transcription_config.provider.clone();
             let api_key = transcription_config.api_key.clone();
             let language = transcription_config.language.clone();
+            let timings = timings.clone();
+            let encoder = upload_encoder(mic_config.save_recording.as_ref());
+            let show = live.as_ref().map(live::LiveTranscript::partial_callback);
+            let copy = copy_partials
+                .then(|| copy_as_you_go::partial_callback(settings.ui.clipboard_backend.clone()));
+            let on_partial: Option<whis_core::PartialTranscriptCallback> = match (show, copy) {
+                (Some(show), Some(copy)) => Some(Box::new(move |sequence, merged| {
+                    show(sequence, merged);
+                    copy(sequence, merged);
+                })),
+                (show, copy) => show.or(copy),
+            };
 
             tokio::spawn(async move {
                 #[cfg(feature = "local-transcription")]
@@ -269,70 +423,140 @@ async fn progressive_record_and_transcribe(
                         .parakeet_model_path()
                         .ok_or_else(|| anyhow::anyhow!("Parakeet model path not configured"))?;
 
-                    return progressive_transcribe_local(&model_path, chunk_rx, None).await;
+                    return progressive_transcribe_local(&model_path, chunk_rx, None, None)
+                        .await
+                        .map(types::TranscriptionResult::from_text);
                 }
 
                 // Cloud provider progressive transcription
-                progressive_transcribe_cloud(
+                progressive_transcribe_cloud_with_timings(
                     &provider,
                     &api_key,
                     language.as_deref(),
                     chunk_rx,
+                    encoder,
+                    timings,
+                    on_partial,
                     None,
                 )
                 .await
+                .map(|result| types::TranscriptionResult {
+                    text: result.text,
+                    confidence: result.confidence,
+                    segments: Vec::new(),
+                    language: result.language,
+                    duration: std::time::Duration::ZERO,
+                })
             })
         };
 
         (transcription_task, Some(chunker_task))
     };
 
-    // Wait for recording to complete (user input or duration)
-    if let Some(dur) = mic_config.duration {
-        // Timed recording
-        if !quiet {
-            if whis_core::verbose::is_verbose() {
-                println!("Recording for {} seconds...", dur.as_secs());
-            } else {
-                print!("Recording for {} seconds...", dur.as_secs());
-                use std::io::Write;
-                std::io::stdout().flush()?;
-            }
-        }
-        tokio::time::sleep(dur).await;
-    } else {
-        // Interactive mode
-        if !quiet {
-            println!("Press Enter to stop");
-            if whis_core::verbose::is_verbose() {
-                println!("Recording...");
-            } else {
-                print!("Recording...");
-                use std::io::Write;
-                std::io::stdout().flush()?;
-            }
+    // Recording status: the live display's spinner, or a plain status line
+    let recording_status = match mic_config.duration {
+        Some(dur) => format!("Recording for {} seconds...", dur.as_secs()),
+        None => "Recording...".to_string(),
+    };
+    if mic_config.duration.is_none() && !quiet {
+        app::status_line("Press Enter to stop");
+    }
+    if let Some(live) = &live {
+        live.start(recording_status);
+    } else if !quiet {
+        if whis_core::verbose::is_verbose() {
+            app::status_line(&recording_status);
+        } else {
+            app::status_inline(&recording_status);
         }
+    }
 
+    // Wait for recording to complete (user input or duration)
+    match mic_config.duration {
+        Some(dur) => tokio::time::sleep(dur).await,
         // Wait for user to stop (blocking operation)
-        tokio::task::spawn_blocking(app::wait_for_stop).await??;
+        None => tokio::task::spawn_blocking(app::wait_for_stop).await??,
     }
 
     // Stop recording (closes audio stream, signals chunker/realtime to finish)
-    recorder.stop_recording()?;
+    let recording = recorder.stop_recording()?;
+    timings.add(TranscriptionStage::Recording, recording_started.elapsed());
 
-    // Wait for chunker to finish (only for non-realtime path)
-    if let Some(chunker_task) = chunker_task {
-        chunker_task.await??;
+    let samples = recording.finalize_raw();
+
+    // Keep the audio for `whis reprocess` (failure here shouldn't lose the transcript)
+    if mic_config.save_last
+        && let Err(e) = last_recording::save(&samples)
+    {
+        eprintln!("Warning: Could not save last recording: {e}");
     }
 
-    // Wait for transcription to finish
-    if !quiet {
+    // Wait for chunker to finish (only for non-realtime path)
+    let total_chunks = match chunker_task {
+        Some(chunker_task) => Some(chunker_task.await??),
+        None => None,
+    };
+
+    // Wait for transcription to finish (the live display shows chunk progress)
+    if let (Some(live), Some(total)) = (&live, total_chunks) {
+        live.set_total(total);
+    } else if !quiet {
         app::print_status(" Transcribing...", Some(&transcription_config.provider));
     }
 
-    let text = transcription_task.await??;
+    let result = transcription_task.await?;
+    if let Some(live) = &live {
+        live.finish();
+    }
+    if let Some(dump) = &mic_config.save_recording {
+        finish_save_recording(dump, &samples, quiet);
+    }
+    result.map(|result| types::TranscriptionResult {
+        duration: audio_duration(&samples),
+        ..result
+    })
+}
 
-    Ok(types::TranscriptionResult { text })
+/// Length of 16kHz mono `samples`
+fn audio_duration(samples: &[f32]) -> std::time::Duration {
+    std::time::Duration::from_secs_f64(
+        samples.len() as f64 / whis_core::resample::WHISPER_SAMPLE_RATE as f64,
+    )
+}
+
+/// Encoder for cloud uploads, copying its output to `dump` when given
+fn upload_encoder(
+    dump: Option<&Arc<whis_core::audio::EncodedAudioDump>>,
+) -> Arc<dyn whis_core::AudioEncoder> {
+    whis_core::RecorderConfig {
+        encoded_audio_dump: dump.cloned(),
+        ..Default::default()
+    }
+    .encoder()
+}
+
+/// Finish `--save-recording` once the provider has the audio
+///
+/// Uploads already copied the encoded audio to the dump file. Local and
+/// streaming providers get raw samples instead, so those are saved as WAV.
+fn finish_save_recording(dump: &whis_core::audio::EncodedAudioDump, samples: &[f32], quiet: bool) {
+    let path = dump.path();
+    let saved = if dump.written() {
+        Ok("as uploaded")
+    } else {
+        last_recording::write_wav(path, samples).map(|()| "16kHz mono WAV")
+    };
+
+    match saved {
+        Ok(format) if !quiet => {
+            eprintln!(
+                "Saved the audio sent to the provider ({format}) to {}",
+                path.display()
+            )
+        }
+        Ok(_) => {}
+        Err(e) => eprintln!("Warning: Could not save recording: {e}"),
+    }
 }
 
 /// Preload models in background to reduce latency (extracted from MicrophoneMode)
@@ -366,26 +590,34 @@ fn preload_models(config: &modes::MicrophoneConfig) {
     }
 }
 
-/// Transcribe an audio file
+/// Transcribe an audio file (local or downloaded)
 async fn transcribe_file(
-    input_file: &std::path::Path,
+    input: &types::AudioInput,
+    input_format: Option<&str>,
+    save_recording: Option<&Arc<whis_core::audio::EncodedAudioDump>>,
+    timed: bool,
     transcription_config: &app::TranscriptionConfig,
+    timings: &StageTimings,
     quiet: bool,
 ) -> Result<types::TranscriptionResult> {
-    use whis_core::{TranscriptionProvider, http::get_http_client, provider::TranscriptionRequest};
+    use whis_core::{
+        ResponseFormat, TranscriptionProvider, http::get_http_client,
+        provider::TranscriptionRequest,
+    };
 
-    if !quiet {
-        eprintln!(
-            "Transcribing {}...",
-            input_file.file_name().unwrap_or_default().to_string_lossy()
-        );
-    }
+    // Read audio and convert to 16kHz mono samples
+    let samples = modes::read_input(input, input_format, quiet).await?;
+    let saved_samples = save_recording.map(|_| samples.clone());
+    let duration = audio_duration(&samples);
 
-    // Read audio file and convert to 16kHz mono samples
-    let samples = modes::file::read_audio_file(input_file)?;
+    let progress = if quiet {
+        Progress::hidden()
+    } else {
+        Progress::spinner(format!("Transcribing {}...", input.name()))
+    };
 
     // Handle local vs cloud providers differently
-    let text = match &transcription_config.provider {
+    let result = match &transcription_config.provider {
         #[cfg(feature = "local-transcription")]
         TranscriptionProvider::LocalParakeet => {
             let model_path = whis_core::Settings::load()
@@ -393,27 +625,32 @@ async fn transcribe_file(
                 .parakeet_model_path()
                 .ok_or_else(|| anyhow::anyhow!("Parakeet model path not configured"))?;
 
-            tokio::task::spawn_blocking(move || {
+            timings.enter(TranscriptionStage::Transcribing);
+            let result = tokio::task::spawn_blocking(move || {
                 whis_core::provider::transcribe_raw_parakeet(&model_path, samples)
             })
-            .await??
-            .text
+            .await??;
+            timings.finish();
+            result
         }
 
         #[cfg(feature = "local-transcription")]
         TranscriptionProvider::LocalWhisper => {
             let model_path = transcription_config.api_key.clone();
             let language = transcription_config.language.clone();
-            tokio::task::spawn_blocking(move || {
+            timings.enter(TranscriptionStage::Transcribing);
+            let result = tokio::task::spawn_blocking(move || {
                 whis_core::provider::transcribe_raw(&model_path, &samples, language.as_deref())
             })
-            .await??
-            .text
+            .await??;
+            timings.finish();
+            result
         }
 
         _ => {
             // Cloud providers: encode to MP3 and send
-            let encoder = whis_core::audio::create_encoder();
+            timings.enter(TranscriptionStage::Encoding);
+            let encoder = upload_encoder(save_recording);
             let mp3_data =
                 encoder.encode_samples(&samples, whis_core::resample::WHISPER_SAMPLE_RATE)?;
 
@@ -421,27 +658,43 @@ async fn transcribe_file(
             let provider =
                 whis_core::provider::registry().get_by_kind(&transcription_config.provider)?;
 
-            let request = TranscriptionRequest {
-                audio_data: mp3_data,
-                language: transcription_config.language.clone(),
-                filename: format!(
-                    "{}.mp3",
-                    input_file.file_stem().unwrap_or_default().to_string_lossy()
-                ),
-                mime_type: "audio/mpeg".to_string(),
-                progress: None,
-            };
-
-            provider
-                .transcribe_async(client, &transcription_config.api_key, request)
-                .await?
-                .text
+            let request = TranscriptionRequest::builder()
+                .audio_data(mp3_data)
+                .language(transcription_config.language.clone())
+                .filename(
+                    std::path::Path::new(&input.name())
+                        .file_stem()
+                        .unwrap_or_default()
+                        .to_string_lossy(),
+                )
+                .format(encoder.format())
+                .response_format(if timed {
+                    ResponseFormat::VerboseJson
+                } else {
+                    ResponseFormat::Json
+                })
+                .progress(Some(timings.progress_callback()))
+                .build();
+
+            let result = provider
+                .transcribe_async(&client, &transcription_config.api_key, request)
+                .await?;
+            timings.finish();
+            result
         }
     };
 
-    if !quiet {
-        eprintln!("Done.");
+    progress.finish(Some("Done."));
+
+    if let (Some(dump), Some(samples)) = (save_recording, &saved_samples) {
+        finish_save_recording(dump, samples, quiet);
     }
 
-    Ok(types::TranscriptionResult { text })
+    Ok(types::TranscriptionResult {
+        text: result.text,
+        confidence: result.confidence,
+        segments: result.segments,
+        language: result.language,
+        duration,
+    })
 }
@@ -1,53 +1,43 @@
 //! File transcription mode
 //!
-//! Reads audio from a file and transcribes it.
+//! Reads audio from a file, or from stdin with `-f -`, and transcribes it.
+//! Decoding lives in whis-core (WAV only, see `whis_core::audio::read_audio_file`).
+//!
+//! Files are detected by extension and stdin by its leading bytes, unless
+//! `--input-format` names the format (e.g. a temp file without extension
+//! handed over by another tool).
 
 use anyhow::{Context, Result};
+use std::io::{IsTerminal, Read};
 use std::path::Path;
-use whis_core::resample::resample_to_16k;
-
-/// Read a WAV file and return 16kHz mono f32 samples
-pub fn read_audio_file(path: &Path) -> Result<Vec<f32>> {
-    let extension = path
-        .extension()
-        .and_then(|e| e.to_str())
-        .map(|e| e.to_lowercase());
+use whis_core::audio::{decode_audio_as, decode_audio_bytes};
 
-    match extension.as_deref() {
-        Some("wav") => read_wav(path),
-        Some(ext) => anyhow::bail!(
-            "Unsupported audio format: .{}\nCurrently supported: WAV",
-            ext
-        ),
-        None => anyhow::bail!("File has no extension. Please provide a WAV file."),
-    }
+/// Read `path` as 16kHz mono samples, as `format` if given
+pub fn read_audio_file(path: &Path, format: Option<&str>) -> Result<Vec<f32>> {
+    let Some(format) = format else {
+        return whis_core::audio::read_audio_file(path);
+    };
+    let data = std::fs::read(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    decode_audio_as(&data, format)
 }
 
-/// Read a WAV file and resample to 16kHz mono
-fn read_wav(path: &Path) -> Result<Vec<f32>> {
-    let mut reader = hound::WavReader::open(path).context("Failed to open WAV file")?;
-
-    let spec = reader.spec();
-    let sample_rate = spec.sample_rate;
-    let channels = spec.channels;
+/// Read all of stdin as 16kHz mono samples, as `format` if given
+pub fn read_audio_stdin(format: Option<&str>) -> Result<Vec<f32>> {
+    let mut stdin = std::io::stdin().lock();
+    if stdin.is_terminal() {
+        anyhow::bail!("No audio piped to stdin\nUsage: whis -f - < recording.wav");
+    }
 
-    // Read samples based on format
-    let samples: Vec<f32> = match spec.sample_format {
-        hound::SampleFormat::Float => reader
-            .samples::<f32>()
-            .collect::<Result<Vec<_>, _>>()
-            .context("Failed to read float samples")?,
-        hound::SampleFormat::Int => {
-            let bits = spec.bits_per_sample;
-            let max_val = (1u32 << (bits - 1)) as f32;
-            reader
-                .samples::<i32>()
-                .map(|s| s.map(|v| v as f32 / max_val))
-                .collect::<Result<Vec<_>, _>>()
-                .context("Failed to read int samples")?
-        }
-    };
+    let mut data = Vec::new();
+    stdin
+        .read_to_end(&mut data)
+        .context("Failed to read audio from stdin")?;
+    if data.is_empty() {
+        anyhow::bail!("No audio data on stdin");
+    }
 
-    // Resample to 16kHz mono if needed
-    resample_to_16k(&samples, sample_rate, channels)
+    match format {
+        Some(format) => decode_audio_as(&data, format),
+        None => decode_audio_bytes(&data),
+    }
 }
@@ -14,6 +14,12 @@ pub struct MicrophoneConfig {
     pub provider: TranscriptionProvider,
     /// Whether post-processing will be used (for preloading)
     pub will_post_process: bool,
+    /// Keep the recorded audio for `whis reprocess`
+    pub save_last: bool,
+    /// Copy the audio sent to the provider here (`--save-recording`)
+    pub save_recording: Option<std::sync::Arc<whis_core::audio::EncodedAudioDump>>,
+    /// Copy the transcript so far after every chunk (raw clipboard output only)
+    pub copy_as_you_go: bool,
 }
 
 // Note: MicrophoneMode has been removed as microphone recording now exclusively
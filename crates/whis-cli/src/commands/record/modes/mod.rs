@@ -1,8 +1,24 @@
 //! Recording mode strategies
 //!
-//! Supports microphone recording and file transcription.
+//! Supports microphone recording, file (or stdin) and URL transcription.
 
 pub mod file;
 pub mod microphone;
+pub mod url;
 
 pub use microphone::MicrophoneConfig;
+
+use anyhow::Result;
+
+use super::types::AudioInput;
+
+/// Load a file, stdin or URL input as 16kHz mono samples
+///
+/// `format` overrides format detection (`--input-format`).
+pub async fn read_input(input: &AudioInput, format: Option<&str>, quiet: bool) -> Result<Vec<f32>> {
+    match input {
+        AudioInput::File(path) => file::read_audio_file(path, format),
+        AudioInput::Stdin => file::read_audio_stdin(format),
+        AudioInput::Url(url) => url::read_audio_url(url, format, quiet).await,
+    }
+}
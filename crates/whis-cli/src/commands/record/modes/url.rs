@@ -0,0 +1,26 @@
+//! URL transcription mode
+//!
+//! Downloads a remote audio file into memory and decodes it like a local
+//! file. The format is detected from the data, not the URL, unless
+//! `--input-format` names it.
+
+use crate::progress::Progress;
+use anyhow::Result;
+use whis_core::audio::{
+    MAX_AUDIO_DOWNLOAD_BYTES, decode_audio_as, decode_audio_bytes, download_audio,
+};
+
+/// Download audio from `url` and return 16kHz mono samples
+pub async fn read_audio_url(url: &str, format: Option<&str>, quiet: bool) -> Result<Vec<f32>> {
+    let progress = if quiet {
+        Progress::hidden()
+    } else {
+        Progress::spinner(format!("Downloading {url}..."))
+    };
+    let data = download_audio(url, MAX_AUDIO_DOWNLOAD_BYTES).await?;
+    progress.finish(None);
+    match format {
+        Some(format) => decode_audio_as(&data, format),
+        None => decode_audio_bytes(&data),
+    }
+}
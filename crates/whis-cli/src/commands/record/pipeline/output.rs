@@ -4,64 +4,121 @@ use anyhow::Result;
 use std::fs;
 use std::io::{self, IsTerminal};
 use std::path::PathBuf;
-use whis_core::{OutputMethod, Settings, autotype_text, copy_to_clipboard};
+use std::time::Duration;
+use whis_core::{OutputMethod, Settings, TranscriptSegment, autotype_text, copy_to_clipboard};
 
 use crate::args::OutputFormat;
 
 use super::super::types::ProcessedResult;
 
 /// Output mode configuration
+///
+/// Each mode carries its own subtitle format, so where the text goes and
+/// how it's formatted are decided in one place (`OutputMode::new`).
+#[derive(Debug, PartialEq, Eq)]
 pub enum OutputMode {
     /// Print to stdout
     Print,
     /// Copy to clipboard (or autotype to window, based on settings)
-    Clipboard,
+    Clipboard { subtitles: Option<SubtitleFormat> },
     /// Write to file
-    File(PathBuf),
+    File {
+        path: PathBuf,
+        subtitles: Option<SubtitleFormat>,
+    },
+    /// Print subtitles to stdout (`--srt`, `--vtt`)
+    Subtitle { format: SubtitleFormat },
 }
 
-// Subtitle timing constants
-const CHARS_PER_SECOND: f64 = 15.0;
-const SUBTITLE_GAP_SECS: f64 = 0.5;
+impl OutputMode {
+    /// Mode for `--print` / `--output` and the resolved output format
+    pub fn new(print: bool, output_path: Option<PathBuf>, format: OutputFormat) -> Self {
+        let subtitles = SubtitleFormat::from_output_format(format);
+        match (print, output_path, subtitles) {
+            (true, _, Some(format)) => Self::Subtitle { format },
+            (true, _, None) => Self::Print,
+            (false, Some(path), subtitles) => Self::File { path, subtitles },
+            (false, None, subtitles) => Self::Clipboard { subtitles },
+        }
+    }
+
+    /// Subtitle format of the output, None for plain text
+    fn subtitles(&self) -> Option<SubtitleFormat> {
+        match self {
+            Self::Print => None,
+            Self::Clipboard { subtitles } | Self::File { subtitles, .. } => *subtitles,
+            Self::Subtitle { format } => Some(*format),
+        }
+    }
+}
+
+/// Subtitle file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubtitleFormat {
+    /// SubRip: numbered cues, `HH:MM:SS,mmm` timing
+    Srt,
+    /// WebVTT: `WEBVTT` header, `HH:MM:SS.mmm` timing
+    Vtt,
+}
 
-/// A text segment with calculated start/end times
+impl SubtitleFormat {
+    /// Subtitle format for `--format`, None for plain text
+    pub fn from_output_format(format: OutputFormat) -> Option<Self> {
+        match format {
+            OutputFormat::Txt => None,
+            OutputFormat::Srt => Some(Self::Srt),
+            OutputFormat::Vtt => Some(Self::Vtt),
+        }
+    }
+}
+
+/// A text segment with start/end times in seconds
 struct TimedSegment<'a> {
     text: &'a str,
     start: f64,
     end: f64,
 }
 
-/// Split text into timed segments for subtitle generation
-fn split_into_timed_segments(text: &str) -> Vec<TimedSegment<'_>> {
-    let segments: Vec<&str> = text
-        .split(['.', '!', '?'])
-        .map(|s| s.trim())
-        .filter(|s| !s.is_empty())
-        .collect();
-
-    let mut result = Vec::with_capacity(segments.len());
-    let mut time_offset = 0.0f64;
-
-    for segment in segments {
-        let duration = (segment.len() as f64 / CHARS_PER_SECOND).max(1.0);
-        result.push(TimedSegment {
-            text: segment,
-            start: time_offset,
-            end: time_offset + duration,
-        });
-        time_offset += duration + SUBTITLE_GAP_SECS;
+/// Timed segments for subtitles: the provider's when it returned them,
+/// otherwise a single cue spanning the whole recording
+fn timed_segments<'a>(
+    text: &'a str,
+    segments: &'a [TranscriptSegment],
+    duration: Duration,
+) -> Vec<TimedSegment<'a>> {
+    if segments.is_empty() {
+        if text.is_empty() {
+            return Vec::new();
+        }
+        return vec![TimedSegment {
+            text,
+            start: 0.0,
+            end: duration.as_secs_f64(),
+        }];
     }
-
-    result
+    segments
+        .iter()
+        .filter(|segment| !segment.text.is_empty())
+        .map(|segment| TimedSegment {
+            text: &segment.text,
+            start: segment.start,
+            end: segment.end,
+        })
+        .collect()
 }
 
 /// Decompose seconds into (hours, minutes, seconds, milliseconds)
-fn decompose_time(seconds: f64) -> (u32, u32, u32, u32) {
-    let hours = (seconds / 3600.0) as u32;
-    let minutes = ((seconds % 3600.0) / 60.0) as u32;
-    let secs = (seconds % 60.0) as u32;
-    let millis = ((seconds % 1.0) * 1000.0) as u32;
-    (hours, minutes, secs, millis)
+///
+/// Rounds to whole milliseconds first, so 59.9996s becomes 00:01:00.000
+/// rather than losing a millisecond to float error.
+fn decompose_time(seconds: f64) -> (u64, u64, u64, u64) {
+    let millis = (seconds.max(0.0) * 1000.0).round() as u64;
+    (
+        millis / 3_600_000,
+        millis / 60_000 % 60,
+        millis / 1000 % 60,
+        millis % 1000,
+    )
 }
 
 /// Format time as SRT timestamp (HH:MM:SS,mmm)
@@ -76,9 +133,8 @@ fn format_vtt_time(seconds: f64) -> String {
     format!("{h:02}:{m:02}:{s:02}.{ms:03}")
 }
 
-/// Format text as SRT subtitle
-fn format_srt(text: &str) -> String {
-    let segments = split_into_timed_segments(text);
+/// Format segments as SRT subtitle
+fn format_srt(segments: &[TimedSegment]) -> String {
     if segments.is_empty() {
         return String::new();
     }
@@ -96,15 +152,14 @@ fn format_srt(text: &str) -> String {
     output.trim_end().to_string()
 }
 
-/// Format text as WebVTT subtitle
-fn format_vtt(text: &str) -> String {
-    let segments = split_into_timed_segments(text);
+/// Format segments as WebVTT subtitle
+fn format_vtt(segments: &[TimedSegment]) -> String {
     if segments.is_empty() {
         return "WEBVTT\n".to_string();
     }
 
     let mut output = String::from("WEBVTT\n\n");
-    for seg in &segments {
+    for seg in segments {
         output.push_str(&format!(
             "{} --> {}\n{}\n\n",
             format_vtt_time(seg.start),
@@ -115,12 +170,20 @@ fn format_vtt(text: &str) -> String {
     output.trim_end().to_string()
 }
 
-/// Format text according to the specified output format
-pub fn format_text(text: &str, format: OutputFormat) -> String {
+/// Format text as subtitles
+///
+/// Cue timings come from `segments` when there are any, otherwise one cue
+/// covers the whole `duration` of the recording.
+pub fn format_subtitles(
+    text: &str,
+    segments: &[TranscriptSegment],
+    duration: Duration,
+    format: SubtitleFormat,
+) -> String {
+    let timed = timed_segments(text, segments, duration);
     match format {
-        OutputFormat::Txt => text.to_string(),
-        OutputFormat::Srt => format_srt(text),
-        OutputFormat::Vtt => format_vtt(text),
+        SubtitleFormat::Srt => format_srt(&timed),
+        SubtitleFormat::Vtt => format_vtt(&timed),
     }
 }
 
@@ -128,29 +191,38 @@ pub fn format_text(text: &str, format: OutputFormat) -> String {
 pub fn output(
     result: ProcessedResult,
     mode: OutputMode,
-    format: OutputFormat,
+    duration: Duration,
     quiet: bool,
 ) -> Result<()> {
     let text = result.text.trim();
-    let formatted = format_text(text, format);
+    let formatted = match mode.subtitles() {
+        Some(format) => format_subtitles(text, &result.segments, duration, format),
+        None => text.to_string(),
+    };
 
     match mode {
-        OutputMode::Print => {
+        OutputMode::Print | OutputMode::Subtitle { .. } => {
             println!("{}", formatted);
         }
-        OutputMode::File(path) => {
+        OutputMode::File { path, .. } => {
             fs::write(&path, &formatted)?;
             if !quiet && io::stdout().is_terminal() {
-                println!("Saved to {}", path.display());
+                crate::app::status_line(&format!("Saved to {}", path.display()));
             }
         }
-        OutputMode::Clipboard => {
+        OutputMode::Clipboard { .. } => {
             let settings = Settings::load();
 
             // Handle output based on configured method
             match settings.ui.output_method {
                 OutputMethod::Clipboard => {
-                    copy_to_clipboard(&formatted, settings.ui.clipboard_backend)?;
+                    // Don't lose the transcript when the clipboard is unreachable
+                    if let Err(e) = copy_to_clipboard(&formatted, settings.ui.clipboard_backend) {
+                        eprintln!("Warning: {e:#}");
+                        eprintln!("Printing the transcript to stdout instead.");
+                        println!("{}", formatted);
+                        return Ok(());
+                    }
                 }
                 OutputMethod::Autotype => {
                     autotype_text(
@@ -183,3 +255,89 @@ pub fn output(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(start: f64, end: f64, text: &str) -> TranscriptSegment {
+        TranscriptSegment {
+            start,
+            end,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_timestamps_roll_over() {
+        assert_eq!(format_srt_time(0.0), "00:00:00,000");
+        assert_eq!(format_srt_time(59.999), "00:00:59,999");
+        assert_eq!(format_srt_time(59.9996), "00:01:00,000");
+        assert_eq!(format_srt_time(61.5), "00:01:01,500");
+        assert_eq!(format_srt_time(3599.25), "00:59:59,250");
+        assert_eq!(format_srt_time(3725.042), "01:02:05,042");
+        assert_eq!(format_vtt_time(3725.042), "01:02:05.042");
+    }
+
+    #[test]
+    fn test_srt_numbers_cues() {
+        let segments = [
+            segment(0.0, 2.5, "Hello there."),
+            segment(2.5, 2.5, ""),
+            segment(3.0, 61.25, "General Kenobi."),
+        ];
+        let srt = format_subtitles("", &segments, Duration::ZERO, SubtitleFormat::Srt);
+        assert_eq!(
+            srt,
+            "1\n00:00:00,000 --> 00:00:02,500\nHello there.\n\n\
+             2\n00:00:03,000 --> 00:01:01,250\nGeneral Kenobi."
+        );
+    }
+
+    #[test]
+    fn test_vtt_has_header_and_no_numbers() {
+        let segments = [segment(1.0, 2.0, "One."), segment(2.0, 3.0, "Two.")];
+        let vtt = format_subtitles("", &segments, Duration::ZERO, SubtitleFormat::Vtt);
+        assert_eq!(
+            vtt,
+            "WEBVTT\n\n00:00:01.000 --> 00:00:02.000\nOne.\n\n\
+             00:00:02.000 --> 00:00:03.000\nTwo."
+        );
+    }
+
+    #[test]
+    fn test_fallback_cue_spans_the_recording() {
+        let duration = Duration::from_millis(83_500);
+        let srt = format_subtitles("Whole talk.", &[], duration, SubtitleFormat::Srt);
+        assert_eq!(srt, "1\n00:00:00,000 --> 00:01:23,500\nWhole talk.");
+
+        let vtt = format_subtitles("", &[], duration, SubtitleFormat::Vtt);
+        assert_eq!(vtt, "WEBVTT\n");
+    }
+
+    #[test]
+    fn test_output_mode_carries_the_format() {
+        assert_eq!(
+            OutputMode::new(true, None, OutputFormat::Txt),
+            OutputMode::Print
+        );
+        assert_eq!(
+            OutputMode::new(true, None, OutputFormat::Vtt),
+            OutputMode::Subtitle {
+                format: SubtitleFormat::Vtt
+            }
+        );
+        assert_eq!(
+            OutputMode::new(false, Some(PathBuf::from("talk.srt")), OutputFormat::Srt),
+            OutputMode::File {
+                path: PathBuf::from("talk.srt"),
+                subtitles: Some(SubtitleFormat::Srt)
+            }
+        );
+        assert_eq!(
+            OutputMode::new(false, None, OutputFormat::Txt),
+            OutputMode::Clipboard { subtitles: None }
+        );
+        assert_eq!(OutputMode::Print.subtitles(), None);
+    }
+}
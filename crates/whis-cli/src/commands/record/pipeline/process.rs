@@ -1,7 +1,11 @@
 //! Post-processing pipeline phase
 
-use anyhow::Result;
-use whis_core::{PostProcessor, Preset, Settings, post_process, resolve_post_processor_config};
+use anyhow::{Context, Result};
+use whis_core::{
+    PostProcessor, Preset, PromptVariables, Settings, WhisError, clipboard::read_from_clipboard,
+    format_spoken_numbers, normalize_transcript, post_process, render_prompt,
+    resolve_post_processor_config, strip_context, with_clipboard_context,
+};
 
 use super::super::types::{ProcessedResult, TranscriptionResult};
 use crate::app;
@@ -10,35 +14,144 @@ use crate::app;
 pub struct ProcessingConfig {
     pub enabled: bool,
     pub preset: Option<Preset>,
+    /// One-off prompt for this run (overrides preset and stored prompt)
+    pub instruction: Option<String>,
+    /// Pass clipboard text to the LLM as context
+    pub with_clipboard: bool,
+    /// Transcription language, for the `{{language}}` prompt variable
+    pub language: Option<String>,
+    /// Write spoken numbers as digits (providers that don't do it themselves)
+    pub format_numbers: bool,
+    /// Fail instead of falling back to the raw transcript
+    pub strict: bool,
 }
 
 /// Execute post-processing phase
+///
+/// Post-processing is best-effort: if any step fails, a warning naming the
+/// step is printed and the raw transcript is returned. With `strict`, the
+/// error is propagated instead.
+///
+/// A blank transcript (silence, whitespace, `[BLANK_AUDIO]`) fails with
+/// `WhisError::NoSpeech` before anything is post-processed or output.
+/// Confidence below `transcription.confidence_warn` prints a warning (or
+/// fails when `strict`); the transcript is still output.
 pub async fn process(
     transcription: TranscriptionResult,
     config: &ProcessingConfig,
     quiet: bool,
 ) -> Result<ProcessedResult> {
-    let mut text = transcription.text;
+    let mut raw = normalize_transcript(&transcription.text).ok_or(WhisError::NoSpeech)?;
+    let mut segments = transcription.segments;
+    if config.format_numbers {
+        raw = format_spoken_numbers(&raw);
+        for segment in &mut segments {
+            segment.text = format_spoken_numbers(&segment.text);
+        }
+    }
+    check_confidence(transcription.confidence, config.strict)?;
 
     // If post-processing is enabled OR a preset is provided, apply LLM processing
-    if config.enabled || config.preset.is_some() {
-        let settings = Settings::load();
-        let (processor, api_key, model, prompt) =
-            resolve_post_processor_config(&config.preset, &settings)?;
-
-        // Re-warm Ollama model (in case it unloaded during long recording > keep_alive timeout)
-        if processor == PostProcessor::Ollama && model.is_some() {
-            settings.services.ollama.preload();
-            // Brief pause to allow warmup to complete (runs in background thread)
-            tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+    if !(config.enabled
+        || config.preset.is_some()
+        || config.instruction.is_some()
+        || config.with_clipboard)
+    {
+        return Ok(ProcessedResult {
+            text: raw,
+            segments,
+        });
+    }
+
+    match polish(&raw, config, quiet).await {
+        // The rewritten text no longer lines up with the segment timings
+        Ok(text) => Ok(ProcessedResult {
+            text,
+            segments: Vec::new(),
+        }),
+        Err(e) if config.strict => Err(e),
+        Err(e) => {
+            eprintln!("Warning: {e:#}");
+            eprintln!("Using the raw transcript instead (pass --strict to fail).");
+            Ok(ProcessedResult {
+                text: raw,
+                segments,
+            })
         }
+    }
+}
 
-        if !quiet {
-            app::print_status(" Post-processing...", None);
+/// Warn (or fail when `strict`) if confidence is below the configured threshold
+fn check_confidence(confidence: Option<f32>, strict: bool) -> Result<()> {
+    let Some(threshold) = Settings::load().transcription.confidence_warn else {
+        return Ok(());
+    };
+    let Some(confidence) = confidence.filter(|c| *c < threshold) else {
+        return Ok(());
+    };
+
+    let problem = format!("Low transcription confidence ({confidence:.2} < {threshold:.2})");
+    if strict {
+        anyhow::bail!(problem);
+    }
+    eprintln!("Warning: {problem}. Check the transcript before using it.");
+    Ok(())
+}
+
+/// Run the LLM post-processing steps, tagging errors with the failed step
+async fn polish(text: &str, config: &ProcessingConfig, quiet: bool) -> Result<String> {
+    let settings = Settings::load();
+    let (processor, api_key, model, prompt) =
+        resolve_post_processor_config(&config.preset, &settings)
+            .context("Post-processing configuration failed")?;
+    let prompt = config.instruction.clone().unwrap_or(prompt);
+    let prompt = render_prompt(
+        &prompt,
+        &PromptVariables::for_prompt(&prompt, config.language.clone()),
+    );
+
+    // Re-warm Ollama model (in case it unloaded during long recording > keep_alive timeout)
+    if processor == PostProcessor::Ollama && model.is_some() {
+        settings.services.ollama.preload();
+        // Brief pause to allow warmup to complete (runs in background thread)
+        tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+    }
+
+    // Read clipboard context before the output phase overwrites it
+    let clipboard = if config.with_clipboard {
+        match read_from_clipboard(settings.ui.clipboard_backend.clone()) {
+            Ok(text) => Some(text),
+            Err(e) => {
+                eprintln!("Warning: Could not read clipboard context: {e}");
+                None
+            }
         }
+    } else {
+        None
+    };
+    let prompt = match &clipboard {
+        Some(context) => with_clipboard_context(&prompt, context),
+        None => prompt,
+    };
+
+    if !quiet {
+        app::print_status(" Post-processing...", None);
+    }
 
-        text = post_process(&text, &processor, &api_key, &prompt, model.as_deref()).await?;
+    let mut text = post_process(
+        text,
+        &processor,
+        &api_key,
+        &prompt,
+        model.as_deref(),
+        Some(&settings.services.ollama),
+        (&settings.post_processing).into(),
+    )
+    .await
+    .with_context(|| format!("Post-processing with {processor} failed"))?;
+    if let Some(context) = &clipboard {
+        text = strip_context(&text, context);
     }
 
-    Ok(ProcessedResult { text })
+    Ok(text)
 }
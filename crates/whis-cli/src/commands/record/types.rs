@@ -13,12 +13,12 @@
 //! └─────────────────┘
 //!     ↓
 //! ┌─────────────────┐
-//! │  Progressive    │  → TranscriptionResult { text }
+//! │  Progressive    │  → TranscriptionResult { text, confidence }
 //! │  Transcription  │
 //! └─────────────────┘
 //!     ↓
 //! ┌─────────────────┐
-//! │  Process Phase  │  → ProcessedResult { text }
+//! │  Process Phase  │  → ProcessedResult { text, segments }
 //! └─────────────────┘
 //!     ↓
 //! ┌─────────────────┐
@@ -33,33 +33,89 @@
 //! - `ProcessedResult`: Final processed text after LLM cleanup/preset transform
 
 use anyhow::Result;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
-use whis_core::Preset;
+use whis_core::settings::DefaultOutput;
+use whis_core::{
+    OutputMethod, Preset, Settings, TranscriptSegment, TranscriptionProvider,
+    is_clipboard_available,
+};
 
 use crate::args::{InputOptions, OutputFormat, OutputOptions, ProcessingOptions};
 
+/// Audio source other than the microphone
+#[derive(Debug, Clone)]
+pub enum AudioInput {
+    /// Local audio file
+    File(PathBuf),
+    /// Audio piped to stdin (`-f -`)
+    Stdin,
+    /// Remote audio file, downloaded into memory
+    Url(String),
+}
+
+impl AudioInput {
+    /// Name shown in status messages and sent as the upload filename
+    pub fn name(&self) -> String {
+        match self {
+            AudioInput::File(path) => path
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .into(),
+            AudioInput::Stdin => "stdin".to_string(),
+            AudioInput::Url(url) => url
+                .split(['?', '#'])
+                .next()
+                .and_then(|path| path.trim_end_matches('/').rsplit('/').next())
+                .filter(|name| !name.is_empty())
+                .unwrap_or("audio")
+                .to_string(),
+        }
+    }
+}
+
 /// Configuration for the record command
 #[derive(Debug, Clone)]
 pub struct RecordConfig {
-    /// Input file path (None = record from microphone)
-    pub input_file: Option<PathBuf>,
+    /// Input file, stdin or URL (None = record from microphone)
+    pub input: Option<AudioInput>,
+    /// Format of the input, overriding detection (e.g. "wav")
+    pub input_format: Option<String>,
+    /// Keep the recorded audio for `whis reprocess`
+    pub save_last: bool,
+    /// Write the audio sent to the provider here (`--save-recording`)
+    pub save_recording: Option<PathBuf>,
+    /// Provider override (None = use configured provider)
+    pub provider: Option<TranscriptionProvider>,
     /// Whether to enable post-processing
     pub post_process: bool,
     /// Preset to apply to output
     pub preset: Option<Preset>,
+    /// One-off prompt overriding the stored/preset prompt
+    pub instruction: Option<String>,
+    /// Whether to pass clipboard text to the LLM as context
+    pub with_clipboard: bool,
     /// Whether to print to stdout instead of clipboard
     pub print: bool,
     /// Output file path (None = clipboard)
     pub output_path: Option<PathBuf>,
     /// Output format (txt, srt, vtt)
     pub format: OutputFormat,
+    /// Write a metadata sidecar next to `output_path`
+    pub metadata: bool,
     /// Recording duration (None = until silence/manual stop)
     pub duration: Option<Duration>,
     /// Disable Voice Activity Detection
     pub no_vad: bool,
     /// Language override (None = use configured language)
     pub language: Option<String>,
+    /// Format numbers even if `format-numbers` is off
+    pub numerals: bool,
+    /// Error (instead of warn) on an unsupported language or failed post-processing
+    pub strict: bool,
+    /// Only report the pipeline plan, don't transcribe
+    pub dry_run: bool,
 }
 
 impl RecordConfig {
@@ -69,41 +125,99 @@ impl RecordConfig {
         processing: &ProcessingOptions,
         output: &OutputOptions,
     ) -> Result<Self> {
-        // Load preset if provided
-        let preset = if let Some(name) = &processing.preset {
-            let (p, _source) = Preset::load(name).map_err(|e| anyhow::anyhow!("{}", e))?;
-            Some(p)
-        } else {
-            None
-        };
+        let settings = Settings::load();
+
+        // Load preset if provided, falling back to the active preset
+        let preset = crate::app::resolve_preset(processing.preset.as_deref(), &settings)?;
+
+        let (print, output_path) = resolve_output(output, &settings)?;
 
-        // Auto-detect format from file extension if not explicitly set
-        let format = if output.format == OutputFormat::Txt {
-            output
-                .output
-                .as_ref()
-                .and_then(|p| OutputFormat::from_extension(p))
-                .unwrap_or(output.format)
-        } else {
-            output.format
+        let format = resolve_format(output, output_path.as_deref());
+
+        let audio_input = match (&input.file, &input.url) {
+            (Some(path), _) if path.as_os_str() == "-" => Some(AudioInput::Stdin),
+            (Some(path), _) => Some(AudioInput::File(path.clone())),
+            (None, Some(url)) => Some(AudioInput::Url(url.clone())),
+            (None, None) => None,
         };
+        if input.input_format.is_some() && audio_input.is_none() {
+            anyhow::bail!("--input-format needs audio to read (--file or --url)");
+        }
 
         Ok(Self {
-            input_file: input.file.clone(),
+            input: audio_input,
+            input_format: input.input_format.clone(),
+            save_last: input.save_last,
+            save_recording: input.save_recording.clone(),
+            provider: None,
             post_process: processing.post_process,
             preset,
-            print: output.print,
-            output_path: output.output.clone(),
+            instruction: processing.instruction.clone(),
+            with_clipboard: processing.with_clipboard,
+            print,
+            output_path,
             format,
+            metadata: output.metadata,
             duration: processing.duration,
             no_vad: processing.no_vad,
             language: processing.language.clone(),
+            numerals: processing.numerals,
+            strict: processing.strict,
+            dry_run: processing.dry_run,
         })
     }
 
-    /// Check if output should be quiet (for clean stdout)
+    /// Check if output should be quiet: stdout is reserved for the
+    /// transcript (`--print`) or status output is off (`--quiet`)
     pub fn is_quiet(&self) -> bool {
-        self.print
+        self.print || crate::app::is_quiet()
+    }
+}
+
+/// Output format from the flags, else from the output file's extension
+/// (`-o talk.srt` writes SRT)
+pub fn resolve_format(output: &OutputOptions, output_path: Option<&Path>) -> OutputFormat {
+    match output.requested_format() {
+        OutputFormat::Txt => output_path
+            .and_then(OutputFormat::from_extension)
+            .unwrap_or(OutputFormat::Txt),
+        requested => requested,
+    }
+}
+
+/// Resolve `(print, output_path)` from the flags, else the `default-output` setting
+///
+/// The clipboard default falls back to stdout (with a warning) when there is
+/// no clipboard, e.g. over SSH, instead of failing after transcription.
+pub fn resolve_output(
+    output: &OutputOptions,
+    settings: &Settings,
+) -> Result<(bool, Option<PathBuf>)> {
+    if output.print || output.output.is_some() {
+        return Ok((output.print, output.output.clone()));
+    }
+    // Subtitle shorthands are for redirecting: `whis -f talk.wav --srt > talk.srt`
+    if output.srt || output.vtt {
+        return Ok((true, None));
+    }
+
+    match settings.ui.default_output {
+        DefaultOutput::Stdout => Ok((true, None)),
+        DefaultOutput::File => match &settings.ui.default_output_path {
+            Some(path) => Ok((false, Some(path.clone()))),
+            None => anyhow::bail!(
+                "default-output is 'file' but no path is set\n\
+                 Set one with: whis config default-output-path <path>"
+            ),
+        },
+        DefaultOutput::Clipboard => {
+            if settings.ui.output_method != OutputMethod::Autotype && !is_clipboard_available() {
+                eprintln!("Warning: No clipboard available, printing the transcript to stdout.");
+                eprintln!("Run 'whis config default-output stdout' to make this the default.");
+                return Ok((true, None));
+            }
+            Ok((false, None))
+        }
     }
 }
 
@@ -112,6 +226,29 @@ impl RecordConfig {
 pub struct TranscriptionResult {
     /// The transcribed text
     pub text: String,
+    /// Provider-reported confidence (0.0-1.0), if available
+    pub confidence: Option<f32>,
+    /// Timed segments from the provider (file transcription with subtitle output)
+    pub segments: Vec<TranscriptSegment>,
+    /// Language the provider detected (ISO-639-1), if it reports one
+    pub language: Option<String>,
+    /// Length of the transcribed audio (from the recorded or decoded samples)
+    pub duration: Duration,
+}
+
+impl TranscriptionResult {
+    /// Result from a backend that doesn't report confidence
+    ///
+    /// `duration` is filled in by the caller, which has the samples.
+    pub fn from_text(text: String) -> Self {
+        Self {
+            text,
+            confidence: None,
+            segments: Vec::new(),
+            language: None,
+            duration: Duration::ZERO,
+        }
+    }
 }
 
 /// Result of post-processing phase
@@ -119,4 +256,6 @@ pub struct TranscriptionResult {
 pub struct ProcessedResult {
     /// The processed text
     pub text: String,
+    /// Timed segments matching `text` (empty if unknown or the LLM rewrote it)
+    pub segments: Vec<TranscriptSegment>,
 }
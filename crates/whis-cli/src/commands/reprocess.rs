@@ -0,0 +1,55 @@
+use anyhow::{Result, anyhow};
+use whis_core::{Settings, TranscriptionProvider};
+
+use crate::app;
+use crate::args::OutputOptions;
+use crate::commands::record::{self, AudioInput, RecordConfig, last_recording};
+
+pub fn run(
+    preset_name: Option<String>,
+    provider: Option<String>,
+    post_process: bool,
+    language: Option<String>,
+    output: OutputOptions,
+) -> Result<()> {
+    let input_file = last_recording::path();
+    if !input_file.exists() {
+        anyhow::bail!(
+            "No saved recording found at {}\nRecord with 'whis --save-last' first.",
+            input_file.display()
+        );
+    }
+
+    let provider = provider
+        .map(|p| p.parse::<TranscriptionProvider>())
+        .transpose()
+        .map_err(|e| anyhow!(e))?;
+
+    let settings = Settings::load();
+    let preset = app::resolve_preset(preset_name.as_deref(), &settings)?;
+    let (print, output_path) = record::resolve_output(&output, &settings)?;
+
+    let config = RecordConfig {
+        input: Some(AudioInput::File(input_file)),
+        input_format: None,
+        save_last: false,
+        save_recording: None,
+        provider,
+        post_process,
+        preset,
+        instruction: None,
+        with_clipboard: false,
+        print,
+        format: record::resolve_format(&output, output_path.as_deref()),
+        output_path,
+        metadata: output.metadata,
+        duration: None,
+        no_vad: false,
+        language,
+        numerals: false,
+        strict: false,
+        dry_run: false,
+    };
+
+    record::run(config)
+}
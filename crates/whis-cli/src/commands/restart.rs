@@ -1,7 +1,7 @@
 use crate::ipc;
 use anyhow::Result;
 
-pub fn run(autotype: bool, preset_name: Option<String>) -> Result<()> {
+pub fn run(autotype: bool, preset_name: Option<String>, allow_short: bool) -> Result<()> {
     // Stop the service if running
     if ipc::is_service_running() {
         let mut client = ipc::IpcClient::connect()?;
@@ -13,5 +13,5 @@ pub fn run(autotype: bool, preset_name: Option<String>) -> Result<()> {
     }
 
     // Start the service with optional preset and autotype override
-    crate::commands::start::run(autotype, preset_name)
+    crate::commands::start::run(autotype, preset_name, allow_short)
 }
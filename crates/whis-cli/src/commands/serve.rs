@@ -0,0 +1,176 @@
+//! Serve Command - live captions over a local WebSocket
+//!
+//! Listens like `whis listen --auto` and broadcasts each transcript to every
+//! connected WebSocket client as one JSON text message:
+//!
+//! ```text
+//! {"type":"interim","id":3,"text":"so the next"}
+//! {"type":"final","id":3,"start":12.48,"end":15.02,"text":"So the next slide."}
+//! ```
+//!
+//! Interims of utterance `id` are replaced by later ones and then by its
+//! final. Connect from a browser overlay (e.g. an OBS browser source) with
+//! `new WebSocket("ws://127.0.0.1:8080")`. Clients only receive; messages
+//! they send are ignored. Bound to localhost unless `--host` says otherwise.
+
+use anyhow::{Context, Result};
+use futures_util::{SinkExt, StreamExt};
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast;
+use tokio_tungstenite::tungstenite::Message;
+use whis_core::{ListenEvent, SegmenterConfig, Settings, TranscribeOptions, listen_with_events};
+
+use crate::app;
+
+/// Messages buffered per client before a slow one starts missing captions
+const CLIENT_BUFFER: usize = 64;
+
+pub fn run(
+    host: IpAddr,
+    port: u16,
+    interim: u64,
+    pause: Option<u64>,
+    language: Option<String>,
+) -> Result<()> {
+    let runtime = tokio::runtime::Runtime::new()?;
+
+    let transcription_config = app::load_transcription_config_with_overrides(None, language, None)?;
+    let settings = Settings::load();
+    let mut segmenter = SegmenterConfig {
+        threshold: settings.ui.vad.threshold,
+        ..SegmenterConfig::default()
+    };
+    if let Some(pause) = pause {
+        segmenter.pause = Duration::from_millis(pause);
+    }
+    let mut opts = TranscribeOptions::new(
+        transcription_config.provider.clone(),
+        transcription_config.api_key.clone(),
+    )
+    .format_numbers(settings.transcription.format_numbers)
+    .segmenter(segmenter);
+    if let Some(language) = transcription_config.language.clone() {
+        opts = opts.language(language);
+    }
+    if let Some(device) = settings.ui.microphone_device.clone() {
+        opts = opts.microphone_device(device);
+    }
+
+    let interim = (interim > 0).then(|| Duration::from_millis(interim));
+    runtime.block_on(serve(SocketAddr::new(host, port), opts, interim))
+}
+
+async fn serve(addr: SocketAddr, opts: TranscribeOptions, interim: Option<Duration>) -> Result<()> {
+    let listener = TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Could not listen on {addr}"))?;
+
+    let (captions_tx, _) = broadcast::channel::<String>(CLIENT_BUFFER);
+    let session = {
+        let captions_tx = captions_tx.clone();
+        listen_with_events(&opts, interim, move |event| {
+            if let ListenEvent::Final { result, .. } = &event {
+                whis_core::verbose!("Caption: {}", result.text);
+            }
+            // No receivers (nobody connected) is fine
+            let _ = captions_tx.send(caption_json(&event));
+        })?
+    };
+
+    if !addr.ip().is_loopback() {
+        eprintln!("Warning: Captions are reachable from other machines on {addr}");
+    }
+    if !app::is_quiet() {
+        eprintln!("Serving captions on ws://{addr} (Ctrl+C to quit)");
+    }
+
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+    let mut health = tokio::time::interval(Duration::from_secs(1));
+
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok((stream, peer)) => {
+                    whis_core::verbose!("Caption client connected: {peer}");
+                    tokio::spawn(serve_client(stream, captions_tx.subscribe()));
+                }
+                Err(e) => whis_core::verbose!("Failed to accept connection: {e}"),
+            },
+            _ = &mut ctrl_c => break,
+            // Listening only ends on its own after a fatal error (rejected key)
+            _ = health.tick() => {
+                if session.is_finished() {
+                    break;
+                }
+            }
+        }
+    }
+
+    Ok(session.stop().await?)
+}
+
+/// Forward captions to one WebSocket client until it disconnects
+async fn serve_client(stream: TcpStream, mut captions: broadcast::Receiver<String>) {
+    let ws = match tokio_tungstenite::accept_async(stream).await {
+        Ok(ws) => ws,
+        Err(e) => {
+            whis_core::verbose!("WebSocket handshake failed: {e}");
+            return;
+        }
+    };
+    let (mut write, mut read) = ws.split();
+
+    loop {
+        tokio::select! {
+            caption = captions.recv() => match caption {
+                Ok(json) => {
+                    if write.send(Message::text(json)).await.is_err() {
+                        break;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(missed)) => {
+                    whis_core::verbose!("Slow caption client missed {missed} messages");
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            // Incoming messages are ignored; pings are answered by tungstenite
+            message = read.next() => match message {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+    let _ = write.close().await;
+}
+
+/// JSON message sent to clients for `event`
+fn caption_json(event: &ListenEvent) -> String {
+    match event {
+        ListenEvent::Interim { id, text } => serde_json::json!({
+            "type": "interim",
+            "id": id,
+            "text": text,
+        }),
+        ListenEvent::Final {
+            id,
+            start,
+            end,
+            result,
+        } => serde_json::json!({
+            "type": "final",
+            "id": id,
+            "start": round_secs(*start),
+            "end": round_secs(*end),
+            "text": result.text,
+        }),
+    }
+    .to_string()
+}
+
+/// Seconds rounded to centiseconds
+fn round_secs(duration: Duration) -> f64 {
+    (duration.as_secs_f64() * 100.0).round() / 100.0
+}
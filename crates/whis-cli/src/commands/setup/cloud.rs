@@ -7,17 +7,23 @@
 //!
 //! # Flow
 //!
-//! 1. Select provider (with [configured] markers for existing keys)
+//! 1. Select provider (with [configured] markers for existing keys and
+//!    [current] for the one in use)
 //! 2. Choose method (standard vs streaming) for OpenAI/Deepgram
 //! 3. Enter/confirm API key with format validation
 //! 4. Save to settings
 
-use anyhow::{Result, anyhow};
+use anyhow::Result;
 use whis_core::{Settings, TranscriptionProvider};
 
 use super::interactive;
 use super::provider_helpers::{api_key_url, cloud_providers, provider_description};
 
+/// Whether `current` is `provider` or its realtime variant
+fn is_same_or_realtime(provider: &TranscriptionProvider, current: &TranscriptionProvider) -> bool {
+    provider == current || provider.realtime_variant().as_ref() == Some(current)
+}
+
 /// Prompt for streaming method selection (Standard vs Streaming)
 ///
 /// Returns the realtime variant if streaming selected, otherwise the base provider.
@@ -26,45 +32,49 @@ fn select_streaming_method(
     realtime: TranscriptionProvider,
     current: &TranscriptionProvider,
 ) -> Result<TranscriptionProvider> {
-    let methods = vec!["Standard - Progressive", "Streaming - Real-time"];
-    let default_method = if *current == realtime { 1 } else { 0 };
-    let choice = interactive::select("Which method?", &methods, Some(default_method))?;
+    let methods = ["Standard - Progressive", "Streaming - Real-time"];
+    let current_method = if *current == realtime {
+        Some(1)
+    } else if *current == base {
+        Some(0)
+    } else {
+        None
+    };
+    let choice = interactive::select_current("Which method?", &methods, current_method, 0)?;
     Ok(if choice == 1 { realtime } else { base })
 }
 
+/// Replace an API key the provider rejected
+///
+/// Offered right after a 401/403, so a mistyped key gets fixed instead of
+/// retried.
+pub fn reenter_api_key(provider: &TranscriptionProvider) -> Result<()> {
+    let replace = interactive::confirm(
+        &format!("Enter a new {} API key now?", provider.display_name()),
+        true,
+    )?;
+    if !replace {
+        return Ok(());
+    }
+
+    interactive::info(&format!("Get your API key from: {}", api_key_url(provider)));
+    let api_key = prompt_and_validate_key(provider)?;
+
+    let mut settings = Settings::load();
+    settings.transcription.set_api_key(provider, api_key);
+    settings.save()?;
+    interactive::info("API key saved to settings");
+    Ok(())
+}
+
 /// Prompt for and validate an API key
 pub fn prompt_and_validate_key(provider: &TranscriptionProvider) -> Result<String> {
     // Validation loop with secure password input
     loop {
         let api_key = interactive::password(&format!("{} API key", provider.display_name()))?;
 
-        // Validate key format
-        let validation_result = match provider {
-            TranscriptionProvider::OpenAI | TranscriptionProvider::OpenAIRealtime => {
-                if !api_key.starts_with("sk-") {
-                    Err(anyhow!("Invalid OpenAI key format. Keys start with 'sk-'"))
-                } else {
-                    Ok(())
-                }
-            }
-            TranscriptionProvider::Groq => {
-                if !api_key.starts_with("gsk_") {
-                    Err(anyhow!("Invalid Groq key format. Keys start with 'gsk_'"))
-                } else {
-                    Ok(())
-                }
-            }
-            _ => {
-                if api_key.len() < 20 {
-                    Err(anyhow!("API key seems too short"))
-                } else {
-                    Ok(())
-                }
-            }
-        };
-
-        match validation_result {
-            Ok(_) => return Ok(api_key),
+        match provider.validate_key_format(&api_key) {
+            Ok(()) => return Ok(api_key.trim().to_string()),
             Err(e) => interactive::error(&e.to_string()),
         }
     }
@@ -87,10 +97,7 @@ pub fn setup_transcription_cloud() -> Result<()> {
             );
             // Check if this provider or its realtime variant is configured
             let marker = if settings.transcription.has_configured_api_key(provider)
-                || (*provider == TranscriptionProvider::OpenAI
-                    && settings.transcription.provider == TranscriptionProvider::OpenAIRealtime)
-                || (*provider == TranscriptionProvider::Deepgram
-                    && settings.transcription.provider == TranscriptionProvider::DeepgramRealtime)
+                || provider.realtime_variant().as_ref() == Some(&settings.transcription.provider)
             {
                 " [configured]"
             } else if settings.transcription.api_key_for(provider).is_some() {
@@ -98,8 +105,12 @@ pub fn setup_transcription_cloud() -> Result<()> {
             } else {
                 ""
             };
+            let current = interactive::current_marker(is_same_or_realtime(
+                provider,
+                &settings.transcription.provider,
+            ));
             (
-                format!("{}{}", display, marker),
+                format!("{}{}{}", display, marker, current),
                 provider.display_name().to_string(),
             )
         })
@@ -107,13 +118,9 @@ pub fn setup_transcription_cloud() -> Result<()> {
 
     // Default to current provider if configured
     // Treat realtime variants same as base provider for default selection
-    let default = providers.iter().position(|p| {
-        *p == settings.transcription.provider
-            || (*p == TranscriptionProvider::OpenAI
-                && settings.transcription.provider == TranscriptionProvider::OpenAIRealtime)
-            || (*p == TranscriptionProvider::Deepgram
-                && settings.transcription.provider == TranscriptionProvider::DeepgramRealtime)
-    });
+    let default = providers
+        .iter()
+        .position(|p| is_same_or_realtime(p, &settings.transcription.provider));
 
     // Fallback: if on local provider, find first cloud provider with configured API key
     let default = default.or_else(|| {
@@ -125,26 +132,21 @@ pub fn setup_transcription_cloud() -> Result<()> {
     let choice = interactive::select_clean("Which provider?", &items, &clean_items, default)?;
     let mut provider = providers[choice].clone();
 
-    // If OpenAI or Deepgram selected, ask for method (Standard vs Streaming)
-    provider = match provider {
-        TranscriptionProvider::OpenAI => select_streaming_method(
-            TranscriptionProvider::OpenAI,
-            TranscriptionProvider::OpenAIRealtime,
-            &settings.transcription.provider,
-        )?,
-        TranscriptionProvider::Deepgram => select_streaming_method(
-            TranscriptionProvider::Deepgram,
-            TranscriptionProvider::DeepgramRealtime,
-            &settings.transcription.provider,
-        )?,
-        _ => provider,
-    };
+    // If the provider can stream, ask for method (Standard vs Streaming)
+    if let Some(realtime) = provider.realtime_variant() {
+        provider = select_streaming_method(provider, realtime, &settings.transcription.provider)?;
+    }
 
     // Check if API key already exists for this provider
     if let Some(existing_key) = settings.transcription.api_key_for(&provider) {
         let is_configured = settings.transcription.has_configured_api_key(&provider);
 
-        let keep = interactive::select("Keep current key?", &["Yes", "No"], Some(0))? == 0;
+        let source = if is_configured {
+            whis_core::settings::mask_api_key(&existing_key)
+        } else {
+            format!("from ${}", provider.api_key_env_var())
+        };
+        let keep = interactive::confirm(&format!("Keep current key ({source})?"), true)?;
 
         if keep {
             // If key is env-only, save it to settings
@@ -0,0 +1,144 @@
+//! Downloads queued by the setup wizard
+//!
+//! Local transcription (Whisper/Parakeet model) and Ollama post-processing
+//! (model pull) can each need a large download. The wizard asks all its
+//! questions first and then runs the downloads side by side, so the waits
+//! overlap instead of adding up:
+//!
+//! ```text
+//! [i] parakeet-v3 [=====     ] 52%  ministral:3b [==        ] 23%
+//! ```
+//!
+//! A failed download doesn't stop the other; both outcomes are reported at
+//! the end. Settings that depend on a download (provider, model path, Ollama
+//! model) are only saved once it succeeded.
+
+use anyhow::{Result, anyhow};
+use std::path::PathBuf;
+use whis_core::{PostProcessor, Settings, TranscriptionProvider, model, ollama};
+
+#[cfg(feature = "local-transcription")]
+use whis_core::model::{ParakeetModel, WhisperModel};
+
+use super::interactive;
+use crate::progress::Progress;
+
+/// A download the wizard still has to run
+pub enum Download {
+    /// Model for local transcription (`LocalParakeet` or `LocalWhisper`)
+    LocalModel {
+        provider: TranscriptionProvider,
+        name: &'static str,
+        path: PathBuf,
+    },
+    /// Ollama model for post-processing
+    OllamaModel { url: String, model: String },
+}
+
+impl Download {
+    /// Name shown next to the progress bar
+    fn label(&self) -> &str {
+        match self {
+            Self::LocalModel { name, .. } => name,
+            Self::OllamaModel { model, .. } => model,
+        }
+    }
+
+    fn run(&self, on_progress: impl Fn(u64, u64)) -> Result<()> {
+        match self {
+            Self::LocalModel {
+                provider: TranscriptionProvider::LocalParakeet,
+                name,
+                path,
+            } => model::download::download_with_progress(&ParakeetModel, name, path, on_progress),
+            Self::LocalModel { name, path, .. } => {
+                model::download::download_with_progress(&WhisperModel, name, path, on_progress)
+            }
+            Self::OllamaModel { url, model } => {
+                ollama::pull_model_with_progress(url, model, on_progress)?;
+                // The pull can end without the model (e.g. a typo in the name)
+                if !ollama::has_model(url, model)? {
+                    return Err(anyhow!("Ollama doesn't list the model after pulling it"));
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Save the settings that needed this download
+    fn apply(&self, settings: &mut Settings) {
+        match self {
+            Self::LocalModel { provider, path, .. } => {
+                let path = Some(path.to_string_lossy().to_string());
+                if *provider == TranscriptionProvider::LocalParakeet {
+                    settings.transcription.local_models.parakeet_path = path;
+                } else {
+                    settings.transcription.local_models.whisper_path = path;
+                }
+                settings.transcription.provider = provider.clone();
+            }
+            Self::OllamaModel { url, model } => {
+                settings.post_processing.processor = PostProcessor::Ollama;
+                settings.services.ollama.url = Some(url.clone());
+                settings.services.ollama.model = Some(model.clone());
+            }
+        }
+    }
+}
+
+/// Run `downloads` concurrently, then save the settings of those that succeeded
+///
+/// Fails if any download failed, after reporting each one.
+pub fn run_all(downloads: Vec<Download>) -> Result<()> {
+    if downloads.is_empty() {
+        return Ok(());
+    }
+
+    let names: Vec<&str> = downloads.iter().map(Download::label).collect();
+    interactive::info(&format!("Downloading {}...", names.join(" and ")));
+
+    let progress = Progress::bars(&names);
+    let results: Vec<Result<()>> = std::thread::scope(|scope| {
+        let handles: Vec<_> = downloads
+            .iter()
+            .enumerate()
+            .map(|(i, download)| {
+                let progress = &progress;
+                scope.spawn(move || {
+                    download.run(|downloaded, total| progress.update(i, downloaded, total))
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|_| Err(anyhow!("Download thread panicked")))
+            })
+            .collect()
+    });
+    progress.finish(None);
+
+    let mut settings = Settings::load();
+    let mut failed = 0;
+    for (download, result) in downloads.iter().zip(&results) {
+        match result {
+            Ok(()) => {
+                download.apply(&mut settings);
+                interactive::info(&format!("{} ready!", download.label()));
+            }
+            Err(e) => {
+                failed += 1;
+                interactive::error(&format!("Failed to download {}: {e}", download.label()));
+            }
+        }
+    }
+    settings.save()?;
+
+    if failed > 0 {
+        interactive::info("Run 'whis setup' again to retry.");
+        return Err(anyhow!("{failed} of {} downloads failed", downloads.len()));
+    }
+    Ok(())
+}
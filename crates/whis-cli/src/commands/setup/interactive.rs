@@ -1,6 +1,8 @@
 //! Interactive prompt helpers using dialoguer
 //!
-//! Provides themed, consistent prompts for the setup wizard.
+//! Provides themed, consistent prompts for the setup wizard. Every choice is
+//! an arrow-key list built by `select_clean`; the current setting is marked
+//! `[current]` and preselected (`select_current`).
 
 use anyhow::Result;
 use dialoguer::{
@@ -46,20 +48,40 @@ pub fn theme() -> ColorfulTheme {
     }
 }
 
+/// Marker appended to the list item matching the current setting
+const CURRENT_MARKER: &str = " [current]";
+
+/// `CURRENT_MARKER` if `is_current`, else nothing
+pub fn current_marker(is_current: bool) -> &'static str {
+    if is_current { CURRENT_MARKER } else { "" }
+}
+
 /// Select from a list of options with arrow keys
-pub fn select<T: std::fmt::Display>(
+pub fn select(prompt: &str, items: &[impl AsRef<str>], default: Option<usize>) -> Result<usize> {
+    select_clean(prompt, items, items, default)
+}
+
+/// Select with the item at `current` marked `[current]` and preselected
+///
+/// Without a current item, `fallback` is preselected instead.
+pub fn select_current(
     prompt: &str,
-    items: &[T],
-    default: Option<usize>,
+    items: &[impl AsRef<str>],
+    current: Option<usize>,
+    fallback: usize,
 ) -> Result<usize> {
-    let theme = theme();
-    let mut select = Select::with_theme(&theme).with_prompt(prompt).items(items);
-
-    if let Some(idx) = default {
-        select = select.default(idx);
-    }
+    let marked: Vec<String> = items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| format!("{}{}", item.as_ref(), current_marker(current == Some(i))))
+        .collect();
+    select_clean(prompt, &marked, items, Some(current.unwrap_or(fallback)))
+}
 
-    Ok(select.interact()?)
+/// Yes/No question, answered with arrow keys like every other choice
+pub fn confirm(prompt: &str, default: bool) -> Result<bool> {
+    let default = if default { 0 } else { 1 };
+    Ok(select(prompt, &["Yes", "No"], Some(default))? == 0)
 }
 
 /// Select with separate display and confirmation labels.
@@ -8,12 +8,13 @@
 //!
 //! 1. Select engine (Parakeet/Whisper) with [current] marker
 //! 2. Select model variant with [installed]/[current] markers
-//! 3. Download model if not present
-//! 4. Save to settings
+//! 3. Queue the model download if not present (run by `downloads`)
+//! 4. Save to settings (after the download, if one is needed)
 
 use anyhow::Result;
-use whis_core::{Settings, TranscriptionProvider, model};
+use whis_core::{Settings, TranscriptionProvider};
 
+use super::downloads::Download;
 use super::interactive;
 
 #[cfg(feature = "local-transcription")]
@@ -21,53 +22,28 @@ use whis_core::model::{ModelType, ParakeetModel, WhisperModel};
 
 /// Streamlined local transcription setup (no post-processing config)
 /// Used by the unified wizard
-pub fn setup_transcription_local() -> Result<()> {
+///
+/// Returns the model download still needed, if any; settings for it are
+/// saved once it completes.
+pub fn setup_transcription_local() -> Result<Option<Download>> {
     let mut settings = Settings::load();
 
-    // Determine current engine and show with [current] marker during selection
+    // Current engine, marked [current] during selection
     let current_engine = match settings.transcription.provider {
-        TranscriptionProvider::LocalParakeet => Some(1),
-        TranscriptionProvider::LocalWhisper => Some(2),
+        TranscriptionProvider::LocalParakeet => Some(0),
+        TranscriptionProvider::LocalWhisper => Some(1),
         _ => None,
     };
 
-    let (items, clean_items): (Vec<String>, Vec<String>) = vec![
-        (
-            format!(
-                "Parakeet{}",
-                if current_engine == Some(1) {
-                    " [current]"
-                } else {
-                    ""
-                }
-            ),
-            "Parakeet".to_string(),
-        ),
-        (
-            format!(
-                "Whisper{}",
-                if current_engine == Some(2) {
-                    " [current]"
-                } else {
-                    ""
-                }
-            ),
-            "Whisper".to_string(),
-        ),
-    ]
-    .into_iter()
-    .unzip();
-
-    let default = current_engine.map(|e| e - 1).unwrap_or(0);
-    let engine_choice = interactive::select_clean(
+    let engine_choice = interactive::select_current(
         "Which transcription engine?",
-        &items,
-        &clean_items,
-        Some(default),
-    )? + 1;
+        &["Parakeet", "Whisper"],
+        current_engine,
+        0,
+    )?;
 
-    let (provider, model_path) = match engine_choice {
-        1 => {
+    let (provider, model_name, model_path, installed) = match engine_choice {
+        0 => {
             // Parakeet - show model options (matching Whisper pattern)
 
             // Get current model if any
@@ -95,11 +71,7 @@ pub fn setup_transcription_local() -> Result<()> {
                     } else {
                         ""
                     };
-                    let current = if current_model == Some(model.name) {
-                        " [current]"
-                    } else {
-                        ""
-                    };
+                    let current = interactive::current_marker(current_model == Some(model.name));
                     (
                         format!("{}{}{}", model.name, installed, current),
                         model.name.to_string(),
@@ -121,13 +93,15 @@ pub fn setup_transcription_local() -> Result<()> {
             let model = &ParakeetModel.models()[model_choice];
 
             let path = ParakeetModel.default_path(model.name);
-            if !ParakeetModel.verify(&path) {
-                model::download::download(&ParakeetModel, model.name, &path)?;
-            }
-
-            (TranscriptionProvider::LocalParakeet, path)
+            let installed = ParakeetModel.verify(&path);
+            (
+                TranscriptionProvider::LocalParakeet,
+                model.name,
+                path,
+                installed,
+            )
         }
-        2 => {
+        1 => {
             // Whisper - show model options
             // Get current model if any
             let current_model = settings
@@ -154,11 +128,7 @@ pub fn setup_transcription_local() -> Result<()> {
                     } else {
                         ""
                     };
-                    let current = if current_model == Some(model.name) {
-                        " [current]"
-                    } else {
-                        ""
-                    };
+                    let current = interactive::current_marker(current_model == Some(model.name));
                     (
                         format!(
                             "{} - {}{}{}",
@@ -183,16 +153,25 @@ pub fn setup_transcription_local() -> Result<()> {
             let model = &WhisperModel.models()[model_choice];
 
             let path = WhisperModel.default_path(model.name);
-            if !WhisperModel.verify(&path) {
-                interactive::info(&format!("Downloading {}...", model.name));
-                model::download::download(&WhisperModel, model.name, &path)?;
-            }
-
-            (TranscriptionProvider::LocalWhisper, path)
+            let installed = WhisperModel.verify(&path);
+            (
+                TranscriptionProvider::LocalWhisper,
+                model.name,
+                path,
+                installed,
+            )
         }
         _ => unreachable!(),
     };
 
+    if !installed {
+        return Ok(Some(Download::LocalModel {
+            provider,
+            name: model_name,
+            path: model_path,
+        }));
+    }
+
     // Save transcription config
     settings.transcription.provider = provider.clone();
     match &provider {
@@ -208,5 +187,5 @@ pub fn setup_transcription_local() -> Result<()> {
     }
     settings.save()?;
 
-    Ok(())
+    Ok(None)
 }
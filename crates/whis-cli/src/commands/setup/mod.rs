@@ -21,6 +21,11 @@
 //! ┌─────────────────────────┐
 //! │ Audio device + Shortcut │
 //! │ (this module)           │
+//! └───────────┬─────────────┘
+//!             ▼
+//! ┌─────────────────────────┐
+//! │ Model downloads, side   │
+//! │ by side ──► downloads.rs│
 //! └─────────────────────────┘
 //! ```
 //!
@@ -29,10 +34,12 @@
 //! - `cloud` - Cloud provider API key setup
 //! - `local` - Local model (Whisper/Parakeet) selection
 //! - `post_processing` - Ollama or cloud LLM configuration
+//! - `downloads` - Concurrent model downloads queued by the steps above
 //! - `interactive` - UI helpers (prompts, selection menus)
 //! - `provider_helpers` - Provider metadata (URLs, descriptions)
 
 mod cloud;
+mod downloads;
 mod interactive;
 mod local;
 mod post_processing;
@@ -48,18 +55,29 @@ pub fn run() -> Result<()> {
     setup_wizard()
 }
 
+pub use cloud::reenter_api_key;
+
 /// Unified setup wizard - guides user through all configuration
 fn setup_wizard() -> Result<()> {
     let settings = Settings::load();
 
-    // Default to current provider type (Local if using local, else Cloud)
-    let default = match settings.transcription.provider {
+    // Re-running setup: show what's configured before changing it
+    if Settings::path().exists() {
+        show_current_setup(&settings);
+    }
+
+    // Current provider type (Local if using local, else Cloud)
+    let current = match settings.transcription.provider {
         TranscriptionProvider::LocalParakeet | TranscriptionProvider::LocalWhisper => 1,
         _ => 0,
     };
 
-    let items = vec!["Cloud", "Local"];
-    let choice = interactive::select("How do you want to transcribe?", &items, Some(default))?;
+    let items = ["Cloud", "Local"];
+    let choice =
+        interactive::select_current("How do you want to transcribe?", &items, Some(current), 0)?;
+
+    // Downloads run after the last question, concurrently
+    let mut downloads = Vec::new();
 
     let is_cloud = match choice {
         0 => {
@@ -67,13 +85,13 @@ fn setup_wizard() -> Result<()> {
             true
         }
         1 => {
-            local::setup_transcription_local()?;
+            downloads.extend(local::setup_transcription_local()?);
             false
         }
         _ => unreachable!(),
     };
 
-    post_processing::setup_post_processing_step(is_cloud)?;
+    downloads.extend(post_processing::setup_post_processing_step(is_cloud)?);
 
     // Audio device selection step
     setup_audio_device_step()?;
@@ -81,22 +99,31 @@ fn setup_wizard() -> Result<()> {
     // Shortcut setup step
     setup_shortcut_step()?;
 
+    downloads::run_all(downloads)?;
+
     interactive::info("Configuration saved! Run 'whis' to record and transcribe.");
 
     Ok(())
 }
 
+/// Print the active configuration (same summary as `whis status --config`)
+fn show_current_setup(settings: &Settings) {
+    interactive::info("Current setup:");
+    super::status::print_config_summary(settings, "    ");
+    println!();
+}
+
 /// Setup shortcut mode (system or direct)
 fn setup_shortcut_step() -> Result<()> {
     let mut settings = Settings::load();
 
-    let items = vec!["System shortcut", "Direct capture"];
-    let default = if settings.shortcuts.cli_mode == CliShortcutMode::Direct {
+    let items = ["System shortcut", "Direct capture"];
+    let current = if settings.shortcuts.cli_mode == CliShortcutMode::Direct {
         1
     } else {
         0
     };
-    let choice = interactive::select("Recording trigger?", &items, Some(default))?;
+    let choice = interactive::select_current("Recording trigger?", &items, Some(current), 0)?;
 
     match choice {
         0 => {
@@ -170,17 +197,17 @@ fn setup_audio_device_step() -> Result<()> {
         items.push(name.to_string());
     }
 
-    // Find current selection for default highlight
+    // Current selection (a saved device that's unplugged matches nothing)
     let mut settings = Settings::load();
-    let default_idx = settings
-        .ui
-        .microphone_device
-        .as_ref()
-        .and_then(|current| devices.iter().position(|d| &d.name == current))
-        .map(|i| i + 1)
-        .unwrap_or(0);
-
-    let choice = interactive::select("Microphone?", &items, Some(default_idx))?;
+    let current = match &settings.ui.microphone_device {
+        None => Some(0),
+        Some(current) => devices
+            .iter()
+            .position(|d| &d.name == current)
+            .map(|i| i + 1),
+    };
+
+    let choice = interactive::select_current("Microphone?", &items, current, 0)?;
 
     settings.ui.microphone_device = if choice == 0 {
         None
@@ -1,39 +1,20 @@
 //! Post-processing setup (Ollama, OpenAI, Mistral)
 
 use anyhow::{Result, anyhow};
-use std::io::Write;
 use whis_core::{PostProcessor, Settings, TranscriptionProvider, ollama};
 
 use super::cloud::prompt_and_validate_key;
+use super::downloads::Download;
 use super::interactive;
 use super::provider_helpers::{PP_PROVIDERS, api_key_url};
-
-/// Display a progress bar for ollama model pulls (with bracket notation prefix)
-fn display_progress(downloaded: u64, total: u64) {
-    let progress = if total > 0 {
-        (downloaded * 100 / total) as usize
-    } else {
-        0
-    };
-
-    let bar_width = 20;
-    let filled = (bar_width * progress) / 100;
-
-    eprint!("\r[i] [");
-    for i in 0..bar_width {
-        if i < filled {
-            eprint!("=");
-        } else {
-            eprint!(" ");
-        }
-    }
-    eprint!("] {}%", progress);
-
-    std::io::stderr().flush().ok();
-}
+use crate::progress::Progress;
 
 /// Interactive Ollama model selection
 /// Shows installed models + recommended options, allows pulling new models
+///
+/// Recommended models that aren't installed are returned without pulling
+/// (the wizard queues the pull); custom names are pulled right away, since
+/// that's how a typo is caught.
 pub fn select_ollama_model(url: &str, current_model: Option<&str>) -> Result<String> {
     // Get installed models from Ollama
     let installed = ollama::list_models(url).unwrap_or_default();
@@ -60,14 +41,13 @@ pub fn select_ollama_model(url: &str, current_model: Option<&str>) -> Result<Str
                 String::new()
             };
 
-            let markers = if is_current {
-                " [current]".to_string()
-            } else {
-                String::new()
-            };
-
             // Use [Installed] prefix instead of separator
-            items.push(format!("[Installed] {}{}{}", model.name, size, markers));
+            items.push(format!(
+                "[Installed] {}{}{}",
+                model.name,
+                size,
+                interactive::current_marker(is_current)
+            ));
             clean_items.push(model.name.clone());
             model_data.push(Some((model.name.clone(), false)));
         }
@@ -143,25 +123,8 @@ pub fn select_ollama_model(url: &str, current_model: Option<&str>) -> Result<Str
 
     // Handle selection
     match &model_data[choice] {
-        Some((model_name, needs_download)) => {
-            // Selected a model from the list
-            if *needs_download {
-                interactive::info(&format!("Pulling model '{}'...", model_name));
-                ollama::pull_model_with_progress(url, model_name, display_progress)?;
-
-                // Verify pull succeeded
-                if !ollama::has_model(url, model_name)? {
-                    return Err(anyhow!(
-                        "Failed to pull model '{}'. Check your connection.",
-                        model_name
-                    ));
-                }
-
-                eprintln!(); // Newline after progress bar (only on success)
-                interactive::info(&format!("Model '{}' ready!", model_name));
-            }
-            Ok(model_name.clone())
-        }
+        // Selected a model from the list (pulled later if needed)
+        Some((model_name, _needs_download)) => Ok(model_name.clone()),
         None => {
             // Either separator or custom model
             if items[choice].contains("custom") {
@@ -178,7 +141,11 @@ pub fn select_ollama_model(url: &str, current_model: Option<&str>) -> Result<Str
                     // Check if model exists, pull if needed
                     if !ollama::has_model(url, &model_name)? {
                         interactive::info(&format!("Pulling model '{}'...", model_name));
-                        ollama::pull_model_with_progress(url, &model_name, display_progress)?;
+                        let progress = Progress::bar(&model_name);
+                        ollama::pull_model_with_progress(url, &model_name, |done, total| {
+                            progress.update(0, done, total)
+                        })?;
+                        progress.finish(None);
 
                         // Verify pull succeeded
                         if !ollama::has_model(url, &model_name)? {
@@ -189,7 +156,6 @@ pub fn select_ollama_model(url: &str, current_model: Option<&str>) -> Result<Str
                             continue;
                         }
 
-                        eprintln!(); // Newline after progress bar (only on success)
                         interactive::info(&format!("Model '{}' ready!", model_name));
                     }
 
@@ -204,18 +170,29 @@ pub fn select_ollama_model(url: &str, current_model: Option<&str>) -> Result<Str
 }
 
 /// Independent post-processing step (called after transcription setup in wizard)
-pub fn setup_post_processing_step(_prefer_cloud: bool) -> Result<()> {
+///
+/// Returns the Ollama pull still needed, if any; Ollama is only selected as
+/// post-processor once it completes.
+pub fn setup_post_processing_step(_prefer_cloud: bool) -> Result<Option<Download>> {
     let mut settings = Settings::load();
 
-    // Default to current processor setting
-    let default = match settings.post_processing.processor {
+    // Independent of the transcription choice: local transcription can be
+    // polished by a cloud LLM and cloud transcription by Ollama
+    interactive::info(&format!(
+        "Post-processing cleans up transcripts with an LLM, cloud or local, whatever transcribes (now {})",
+        settings.transcription.provider.display_name()
+    ));
+
+    // Current processor setting
+    let current = match settings.post_processing.processor {
         PostProcessor::OpenAI | PostProcessor::Mistral => 0, // Cloud
         PostProcessor::Ollama => 1,
         PostProcessor::None => 2, // Skip
     };
 
-    let options = vec!["Cloud", "Ollama", "Skip"];
-    let choice = interactive::select("Configure post-processing?", &options, Some(default))?;
+    let options = ["Cloud", "Ollama", "Skip"];
+    let choice =
+        interactive::select_current("Configure post-processing?", &options, Some(current), 0)?;
 
     match choice {
         0 => setup_cloud_post_processing(&mut settings)?,
@@ -229,7 +206,7 @@ pub fn setup_post_processing_step(_prefer_cloud: bool) -> Result<()> {
                 interactive::info(
                     "You can run 'whis setup post-processing' later to configure Ollama.",
                 );
-                return Ok(());
+                return Ok(None);
             }
 
             // Start Ollama if not running
@@ -239,6 +216,14 @@ pub fn setup_post_processing_step(_prefer_cloud: bool) -> Result<()> {
             let current_model = settings.services.ollama.model.as_deref();
             let model = select_ollama_model(ollama_url, current_model)?;
 
+            if !ollama::has_model(ollama_url, &model)? {
+                settings.save()?;
+                return Ok(Some(Download::OllamaModel {
+                    url: ollama_url.to_string(),
+                    model,
+                }));
+            }
+
             settings.post_processing.processor = PostProcessor::Ollama;
             settings.services.ollama.url = Some(ollama_url.to_string());
             settings.services.ollama.model = Some(model);
@@ -250,7 +235,7 @@ pub fn setup_post_processing_step(_prefer_cloud: bool) -> Result<()> {
     }
 
     settings.save()?;
-    Ok(())
+    Ok(None)
 }
 
 /// Setup cloud post-processing (OpenAI or Mistral)
@@ -267,7 +252,15 @@ fn setup_cloud_post_processing(settings: &mut Settings) -> Result<()> {
             } else {
                 ""
             };
-            (format!("{}{}", base, marker), base)
+            let current = interactive::current_marker(
+                settings
+                    .post_processing
+                    .processor
+                    .api_key_provider()
+                    .as_ref()
+                    == Some(provider),
+            );
+            (format!("{}{}{}", base, marker, current), base)
         })
         .unzip();
 
@@ -285,11 +278,15 @@ fn setup_cloud_post_processing(settings: &mut Settings) -> Result<()> {
     let choice = interactive::select_clean("Which provider?", &items, &clean_items, default)?;
     let provider = PP_PROVIDERS[choice].clone();
 
+    if provider == settings.transcription.provider {
+        interactive::info("Using the same API key as for transcription");
+    }
+
     // Check if API key already exists
     if let Some(existing_key) = settings.transcription.api_key_for(&provider) {
         let is_configured = settings.transcription.has_configured_api_key(&provider);
 
-        let keep = interactive::select("Keep current key?", &["Yes", "No"], Some(0))? == 0;
+        let keep = interactive::confirm("Keep current key?", true)?;
 
         if keep {
             // If key is env-only, save it to settings
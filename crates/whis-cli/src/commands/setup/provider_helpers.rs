@@ -16,28 +16,16 @@ pub const PP_PROVIDERS: &[TranscriptionProvider] = &[
     TranscriptionProvider::Mistral,
 ];
 
-/// Provider descriptions for display
-pub fn provider_description(provider: &TranscriptionProvider) -> &'static str {
-    match provider {
-        TranscriptionProvider::OpenAIRealtime | TranscriptionProvider::DeepgramRealtime => {
-            "Streaming"
-        }
-        _ => "",
+/// Provider description for display: typical cost, and streaming support
+pub fn provider_description(provider: &TranscriptionProvider) -> String {
+    let capabilities = provider.capabilities();
+    match capabilities.realtime_variant {
+        Some(_) => format!("{}, streaming", capabilities.typical_cost),
+        None => capabilities.typical_cost.to_string(),
     }
 }
 
 /// Get the API key URL for a provider
 pub fn api_key_url(provider: &TranscriptionProvider) -> &'static str {
-    match provider {
-        TranscriptionProvider::OpenAI | TranscriptionProvider::OpenAIRealtime => {
-            "https://platform.openai.com/api-keys"
-        }
-        TranscriptionProvider::Mistral => "https://console.mistral.ai/api-keys",
-        TranscriptionProvider::Groq => "https://console.groq.com/keys",
-        TranscriptionProvider::Deepgram | TranscriptionProvider::DeepgramRealtime => {
-            "https://console.deepgram.com"
-        }
-        TranscriptionProvider::ElevenLabs => "https://elevenlabs.io/app/settings/api-keys",
-        _ => "",
-    }
+    provider.api_key_url().unwrap_or_default()
 }
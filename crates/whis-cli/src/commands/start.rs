@@ -1,11 +1,12 @@
 use crate::{app, hotkey, ipc, service};
 use anyhow::Result;
+use std::time::Duration;
 use whis_core::Settings;
 use whis_core::autotyping::OutputMethod;
 use whis_core::settings::CliShortcutMode;
 use whis_core::{Preset, resolve_post_processor_config};
 
-pub fn run(autotype: bool, preset_name: Option<String>) -> Result<()> {
+pub fn run(autotype: bool, preset_name: Option<String>, allow_short: bool) -> Result<()> {
     // Check if service is already running
     if ipc::is_service_running() {
         eprintln!("Error: whis service is already running.");
@@ -23,16 +24,32 @@ pub fn run(autotype: bool, preset_name: Option<String>) -> Result<()> {
         None
     };
     let config = app::load_transcription_config()?;
+    app::check_language_support(&config, false)?;
 
-    // Load preset if specified
+    // Minimum recording length (--allow-short keeps every recording)
+    let min_recording = if allow_short {
+        Duration::ZERO
+    } else {
+        Duration::from_millis(settings.ui.min_recording_ms)
+    };
+    let idle_shutdown = match settings.ui.service_idle_shutdown_minutes {
+        0 => None,
+        minutes => Some(Duration::from_secs(u64::from(minutes) * 60)),
+    };
+
+    // Load preset if specified (overrides the active preset for this service run)
     let preset = preset_name
         .map(|name| Preset::load(&name).map(|(p, _source)| p))
         .transpose()
         .map_err(|e| anyhow::anyhow!("{}", e))?;
 
     // Validate post-processing configuration early (catches missing Ollama model, etc.)
-    if preset.is_some() || settings.post_processing.enabled {
-        resolve_post_processor_config(&preset, &settings)?;
+    let effective_preset = match &preset {
+        Some(p) => Some(p.clone()),
+        None => app::resolve_preset(None, &settings)?,
+    };
+    if effective_preset.is_some() || settings.post_processing.enabled {
+        resolve_post_processor_config(&effective_preset, &settings)?;
     }
 
     // Create Tokio runtime
@@ -50,7 +67,9 @@ pub fn run(autotype: bool, preset_name: Option<String>) -> Result<()> {
             let output_method = output_method_override
                 .as_ref()
                 .unwrap_or(&settings.ui.output_method);
-            match hotkey::setup(shortcut) {
+            let debounce = Duration::from_millis(settings.shortcuts.hotkey_debounce_ms);
+            let backend = settings.shortcuts.cli_hotkey_backend;
+            match hotkey::setup(shortcut, debounce, backend) {
                 Ok((hotkey_rx, _guard)) => {
                     if push_to_talk {
                         println!(
@@ -65,15 +84,15 @@ pub fn run(autotype: bool, preset_name: Option<String>) -> Result<()> {
                     }
 
                     runtime.block_on(async {
-                        let service =
-                            service::Service::new(config, preset, output_method_override)?;
-                        tokio::select! {
-                            result = service.run(Some(hotkey_rx), push_to_talk) => result,
-                            _ = tokio::signal::ctrl_c() => {
-                                println!("\nShutting down...");
-                                Ok(())
-                            }
-                        }
+                        let service = service::Service::new(
+                            config,
+                            preset,
+                            output_method_override,
+                            min_recording,
+                            idle_shutdown,
+                        )?;
+                        // Handles Ctrl+C/SIGTERM itself so the socket gets cleaned up
+                        service.run(Some(hotkey_rx), push_to_talk).await
                     })
                 }
                 Err(e) => {
@@ -82,6 +101,12 @@ pub fn run(autotype: bool, preset_name: Option<String>) -> Result<()> {
                     eprintln!("To use direct hotkey capture, run:");
                     eprintln!("  sudo usermod -aG input $USER");
                     eprintln!("Then logout and login again.");
+                    #[cfg(target_os = "linux")]
+                    {
+                        eprintln!();
+                        eprintln!("Or listen through the desktop's shortcut portal (Wayland):");
+                        eprintln!("  whis config cli-hotkey-backend portal");
+                    }
                     eprintln!();
                     eprintln!("Or switch to system mode:");
                     eprintln!("  whis config cli-mode system");
@@ -100,14 +125,14 @@ pub fn run(autotype: bool, preset_name: Option<String>) -> Result<()> {
             );
 
             runtime.block_on(async {
-                let service = service::Service::new(config, preset, output_method_override)?;
-                tokio::select! {
-                    result = service.run(None, false) => result,
-                    _ = tokio::signal::ctrl_c() => {
-                        println!("\nShutting down...");
-                        Ok(())
-                    }
-                }
+                let service = service::Service::new(
+                    config,
+                    preset,
+                    output_method_override,
+                    min_recording,
+                    idle_shutdown,
+                )?;
+                service.run(None, false).await
             })
         }
     }
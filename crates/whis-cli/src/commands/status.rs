@@ -1,10 +1,25 @@
 use crate::ipc;
 use anyhow::Result;
+use std::path::Path;
+use whis_core::{PostProcessor, Settings, TranscriptionProvider};
 
-pub fn run() -> Result<()> {
+/// Print the service status, and with `config` the active configuration
+pub fn run(config: bool) -> Result<()> {
+    print_service_status()?;
+
+    if config {
+        println!();
+        println!("Configuration ({}):", Settings::path().display());
+        print_config_summary(&Settings::load(), "  ");
+    }
+
+    Ok(())
+}
+
+fn print_service_status() -> Result<()> {
     if !ipc::is_service_running() {
         println!("Status: Not running");
-        println!("Start with: whis start");
+        println!("Start with: {}", ipc::start_command());
         return Ok(());
     }
 
@@ -13,7 +28,11 @@ pub fn run() -> Result<()> {
 
     match response {
         ipc::IpcResponse::Idle => println!("Status: Running (idle)"),
-        ipc::IpcResponse::Recording => println!("Status: Running (recording)"),
+        ipc::IpcResponse::Recording(progress) => println!(
+            "Status: Running (recording, {}, {:.1}s of audio)",
+            format_elapsed(progress.elapsed_secs),
+            progress.audio_secs
+        ),
         ipc::IpcResponse::Transcribing => println!("Status: Running (transcribing)"),
         ipc::IpcResponse::Error(e) => {
             eprintln!("Error: {e}");
@@ -24,3 +43,187 @@ pub fn run() -> Result<()> {
 
     Ok(())
 }
+
+/// Active provider, model, language, post-processor and key state
+///
+/// Shared by `whis status --config` and the setup wizard.
+pub fn config_summary(settings: &Settings) -> Vec<(&'static str, String)> {
+    let provider = &settings.transcription.provider;
+    let mut summary = vec![
+        ("Provider", provider.display_name().to_string()),
+        (
+            "Model",
+            model_label(settings).unwrap_or_else(|| "(not set)".to_string()),
+        ),
+        (
+            "Language",
+            settings
+                .transcription
+                .language
+                .clone()
+                .unwrap_or_else(|| "auto".to_string()),
+        ),
+    ];
+    if provider.requires_api_key() {
+        summary.push(("API key", key_state(settings, provider)));
+    }
+
+    let processor = &settings.post_processing.processor;
+    let post_processing = if !settings.post_processing.enabled {
+        "off".to_string()
+    } else if let Some(missing) = settings.post_processor_missing(processor, None) {
+        format!("{processor} (not ready: {missing})")
+    } else if matches!(processor, PostProcessor::Ollama)
+        && let Some(model) = settings.services.ollama.model()
+    {
+        format!("{processor} ({model})")
+    } else {
+        processor.to_string()
+    };
+    summary.push(("Post-processing", post_processing));
+    summary
+}
+
+/// Print `config_summary` as aligned `label  value` lines, each after `indent`
+pub fn print_config_summary(settings: &Settings, indent: &str) {
+    let summary = config_summary(settings);
+    let width = summary
+        .iter()
+        .map(|(label, _)| label.len())
+        .max()
+        .unwrap_or(0);
+    for (label, value) in summary {
+        println!("{indent}{label:<width$}  {value}");
+    }
+}
+
+/// Model the provider transcribes with: the model file for local providers,
+/// the provider's default model otherwise
+fn model_label(settings: &Settings) -> Option<String> {
+    let provider = &settings.transcription.provider;
+    let model_path = match provider {
+        TranscriptionProvider::LocalWhisper => settings.transcription.whisper_model_path(),
+        TranscriptionProvider::LocalParakeet => settings.transcription.parakeet_model_path(),
+        _ => {
+            return whis_core::provider::registry()
+                .get_by_kind(provider)
+                .ok()?
+                .default_model()
+                .map(str::to_string);
+        }
+    };
+    model_path.map(|path| {
+        Path::new(&path)
+            .file_name()
+            .map_or(path.clone(), |name| name.to_string_lossy().into_owned())
+    })
+}
+
+/// Where the provider's API key comes from, if anywhere
+fn key_state(settings: &Settings, provider: &TranscriptionProvider) -> String {
+    describe_key(
+        settings.transcription.has_configured_api_key(provider),
+        settings.transcription.api_key_for(provider).is_some(),
+        provider.api_key_env_var(),
+    )
+}
+
+/// "set" for a key in the settings, else whether `env_var` provides one
+fn describe_key(in_settings: bool, available: bool, env_var: &str) -> String {
+    if in_settings {
+        "set".to_string()
+    } else if available {
+        format!("set (from ${env_var})")
+    } else {
+        "missing".to_string()
+    }
+}
+
+/// Format seconds as `M:SS`
+fn format_elapsed(secs: f64) -> String {
+    let secs = secs as u64;
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(summary: &[(&'static str, String)], label: &str) -> Option<String> {
+        summary
+            .iter()
+            .find(|(l, _)| *l == label)
+            .map(|(_, value)| value.clone())
+    }
+
+    fn openai_settings() -> Settings {
+        let mut settings = Settings::default();
+        settings.transcription.provider = TranscriptionProvider::OpenAI;
+        settings
+    }
+
+    #[test]
+    fn test_describe_key() {
+        assert_eq!(describe_key(true, true, "OPENAI_API_KEY"), "set");
+        assert_eq!(
+            describe_key(false, true, "OPENAI_API_KEY"),
+            "set (from $OPENAI_API_KEY)"
+        );
+        assert_eq!(describe_key(false, false, "OPENAI_API_KEY"), "missing");
+    }
+
+    #[test]
+    fn test_summary_key_state() {
+        let mut settings = openai_settings();
+        if std::env::var("OPENAI_API_KEY").is_err() {
+            assert_eq!(
+                value(&config_summary(&settings), "API key").as_deref(),
+                Some("missing")
+            );
+        }
+
+        settings.transcription.set_api_key(
+            &TranscriptionProvider::OpenAI,
+            "sk-test_key_1234567890".to_string(),
+        );
+        assert_eq!(
+            value(&config_summary(&settings), "API key").as_deref(),
+            Some("set")
+        );
+    }
+
+    #[test]
+    fn test_summary_skips_key_for_local_providers() {
+        let mut settings = Settings::default();
+        settings.transcription.provider = TranscriptionProvider::LocalWhisper;
+        assert_eq!(value(&config_summary(&settings), "API key"), None);
+    }
+
+    #[test]
+    fn test_summary_post_processor_readiness() {
+        let mut settings = openai_settings();
+        settings.post_processing.enabled = false;
+        assert_eq!(
+            value(&config_summary(&settings), "Post-processing").as_deref(),
+            Some("off")
+        );
+
+        settings.post_processing.enabled = true;
+        settings.post_processing.processor = PostProcessor::Ollama;
+        settings.services.ollama.url = Some("http://localhost:11434".to_string());
+        settings.services.ollama.model = Some("qwen2.5:1.5b".to_string());
+        assert_eq!(
+            value(&config_summary(&settings), "Post-processing").as_deref(),
+            Some("ollama (qwen2.5:1.5b)")
+        );
+
+        if std::env::var("OLLAMA_MODEL").is_err() {
+            settings.services.ollama.model = None;
+            let post_processing = value(&config_summary(&settings), "Post-processing").unwrap();
+            assert!(
+                post_processing.starts_with("ollama (not ready: Ollama model not configured"),
+                "{post_processing}"
+            );
+        }
+    }
+}
@@ -4,10 +4,18 @@ use anyhow::Result;
 pub fn run() -> Result<()> {
     let mut client = ipc::IpcClient::connect()?;
     match client.send_message(ipc::IpcMessage::Toggle)? {
-        ipc::IpcResponse::Recording => println!("Recording..."),
+        ipc::IpcResponse::Recording(_) => println!("Recording..."),
         ipc::IpcResponse::Idle => println!("Stopped"),
         ipc::IpcResponse::Transcribing => println!("Transcribing..."),
         ipc::IpcResponse::Success => println!("Done"),
+        ipc::IpcResponse::Stopped(reason) => {
+            whis_core::verbose!("Recording stopped ({reason})");
+            if reason == whis_core::StopReason::Cancelled {
+                println!("Discarded")
+            } else {
+                println!("Done")
+            }
+        }
         ipc::IpcResponse::Error(e) => anyhow::bail!(e),
     }
     Ok(())
@@ -0,0 +1,39 @@
+//! Environment file (`.env`) loading
+//!
+//! Off unless asked for, with `--env-file [PATH]` or the `env-file` setting,
+//! so a stray `.env` in some project directory never changes whis' behavior.
+//! Variables already in the environment are kept: the file only fills gaps.
+//!
+//! API key precedence (highest first):
+//! 1. Keys saved in settings (`whis setup`, `whis config`)
+//! 2. Exported environment variables
+//! 3. The environment file
+
+use anyhow::{Context, Result};
+use std::path::Path;
+use whis_core::Settings;
+
+/// Load the environment file from `--env-file`, else from the `env-file` setting
+///
+/// A missing file is an error when passed on the command line, but skipped
+/// for the setting, which applies in every working directory.
+pub fn load(flag: Option<&Path>) -> Result<()> {
+    if let Some(path) = flag {
+        return load_file(path);
+    }
+
+    if let Some(path) = Settings::load().ui.env_file {
+        if path.exists() {
+            return load_file(&path);
+        }
+        whis_core::verbose!("Environment file {} not found, skipping", path.display());
+    }
+    Ok(())
+}
+
+fn load_file(path: &Path) -> Result<()> {
+    dotenvy::from_path(path)
+        .with_context(|| format!("Failed to load environment file {}", path.display()))?;
+    whis_core::verbose!("Loaded environment file {}", path.display());
+    Ok(())
+}
@@ -3,7 +3,7 @@
 //! This module provides user-friendly error messages with contextual hints
 //! for resolving common issues.
 
-use whis_core::{AudioError, ProviderError, WhisError};
+use whis_core::{AudioError, ErrorKind, ProviderError, TranscriptionProvider, WhisError};
 
 /// Display an error to stderr with helpful hints
 ///
@@ -26,15 +26,21 @@ pub fn display_error(err: &WhisError) {
             eprintln!("Error: Invalid API key for {}", provider);
             eprintln!("Reason: {}", reason);
             eprintln!();
-            eprintln!("Hint: Check your API key at:");
-            match provider.as_str() {
-                "OpenAI" => eprintln!("  https://platform.openai.com/api-keys"),
-                "Mistral" => eprintln!("  https://console.mistral.ai/api-keys/"),
-                "Groq" => eprintln!("  https://console.groq.com/keys"),
-                "Deepgram" => eprintln!("  https://console.deepgram.com/"),
-                "ElevenLabs" => eprintln!("  https://elevenlabs.io/app/settings/api-keys"),
-                _ => eprintln!("  (Check your provider's console)"),
-            }
+            print_api_key_hint(provider);
+        }
+
+        WhisError::Provider(ProviderError::Unauthorized { provider, message }) => {
+            eprintln!("Error: {} rejected the API key", provider);
+            eprintln!("{}", message);
+            eprintln!();
+            eprintln!("Retrying won't help: the key is wrong, revoked or lacks access.");
+            print_api_key_hint(provider);
+            eprintln!();
+            eprintln!("Then enter the new key:");
+            eprintln!("  whis setup            # Interactive setup wizard");
+            let key_name = TranscriptionProvider::from_display_name(provider)
+                .map_or_else(|| provider.to_lowercase(), |p| p.api_key_name().to_string());
+            eprintln!("  whis config {key_name}-api-key <key>");
         }
 
         WhisError::Provider(ProviderError::TranscriptionFailed(msg)) => {
@@ -71,6 +77,15 @@ pub fn display_error(err: &WhisError) {
             eprintln!("  whis models           # List available models");
         }
 
+        WhisError::Provider(ProviderError::ModelNotFound(msg)) => {
+            eprintln!("Error: Model not found");
+            eprintln!("{}", msg);
+            eprintln!();
+            eprintln!("Hint: Download a model or fix the configured path:");
+            eprintln!("  whis setup local      # Download and configure local models");
+            eprintln!("  whis models           # List available models");
+        }
+
         // Audio errors with helpful hints
         WhisError::Audio(AudioError::DeviceNotFound(device)) => {
             eprintln!("Error: Audio device not found: {}", device);
@@ -81,6 +96,13 @@ pub fn display_error(err: &WhisError) {
             eprintln!("  whis setup            # Run setup wizard again");
         }
 
+        WhisError::Audio(AudioError::PermissionDenied(msg)) => {
+            eprintln!("Error: Microphone access denied");
+            eprintln!("{}", msg);
+            eprintln!();
+            eprintln!("Hint: {}", whis_core::microphone_permission_hint());
+        }
+
         WhisError::Audio(AudioError::RecordingFailed(msg)) => {
             eprintln!("Error: Recording failed");
             eprintln!("{}", msg);
@@ -165,6 +187,11 @@ pub fn display_error(err: &WhisError) {
             eprintln!("  whis --help");
         }
 
+        // Not a failure as such: scripts detect it through the exit code
+        WhisError::NoSpeech => {
+            eprintln!("No speech detected");
+        }
+
         // Catch-all for other variants
         _ => {
             eprintln!("Error: {}", err);
@@ -175,24 +202,138 @@ pub fn display_error(err: &WhisError) {
     }
 }
 
-/// Display an error and exit with code 1
+/// Where to check the API key for `provider` (a display name)
+fn print_api_key_hint(provider: &str) {
+    eprintln!("Hint: Check your API key at:");
+    match TranscriptionProvider::from_display_name(provider).and_then(|p| p.api_key_url()) {
+        Some(url) => eprintln!("  {}", url),
+        None => eprintln!("  (Check your provider's console)"),
+    }
+}
+
+/// Display an error and exit with its exit code
 ///
 /// This is a convenience function for direct error handling.
 #[allow(dead_code)]
 pub fn display_error_and_exit(err: &WhisError) -> ! {
     display_error(err);
-    std::process::exit(1);
+    std::process::exit(kind_exit_code(err.kind()));
+}
+
+// Exit codes, so scripts can react without parsing stderr. These are part
+// of the CLI's interface (listed in the README): don't renumber them.
+// 2 is left to clap for invalid arguments.
+
+/// Any failure not covered below
+pub const EXIT_FAILURE: i32 = 1;
+
+/// Exit code when a transcript came back blank
+pub const EXIT_NO_SPEECH: i32 = 3;
+
+/// API key missing, malformed or rejected
+pub const EXIT_AUTH: i32 = 4;
+
+/// Connection failure, timeout or rate limiting (worth retrying later)
+pub const EXIT_NETWORK: i32 = 5;
+
+/// Invalid or incomplete configuration (including a missing local model)
+pub const EXIT_CONFIG: i32 = 6;
+
+/// Process exit code for `err`
+pub fn exit_code(err: &anyhow::Error) -> i32 {
+    kind_exit_code(ErrorKind::of(err))
+}
+
+/// Process exit code for an error of `kind`
+fn kind_exit_code(kind: ErrorKind) -> i32 {
+    match kind {
+        ErrorKind::NoSpeech => EXIT_NO_SPEECH,
+        ErrorKind::Auth => EXIT_AUTH,
+        ErrorKind::Network | ErrorKind::RateLimited | ErrorKind::TimedOut => EXIT_NETWORK,
+        ErrorKind::Config | ErrorKind::ModelNotFound => EXIT_CONFIG,
+        _ => EXIT_FAILURE,
+    }
+}
+
+/// Provider whose API key was rejected (HTTP 401/403), if that's what failed
+pub fn rejected_key_provider(err: &anyhow::Error) -> Option<TranscriptionProvider> {
+    match err.downcast_ref::<WhisError>() {
+        Some(WhisError::Provider(err)) => err.rejected_key_provider(),
+        Some(_) => None,
+        None => err
+            .downcast_ref::<ProviderError>()
+            .and_then(ProviderError::rejected_key_provider),
+    }
+}
+
+/// OS guidance for granting microphone access, if `err` is a denied permission
+pub fn microphone_hint(err: &anyhow::Error) -> Option<&'static str> {
+    match err.downcast_ref::<WhisError>() {
+        Some(WhisError::Audio(AudioError::PermissionDenied(_))) => {
+            Some(whis_core::microphone_permission_hint())
+        }
+        _ => None,
+    }
 }
 
 /// Convert anyhow::Error to WhisError and display
 ///
-/// This is a bridge function for gradual migration from anyhow.
+/// Typed errors anywhere in the chain (provider, audio, HTTP, I/O) get their
+/// hints; anything else is printed with its context.
 pub fn display_anyhow_error(err: anyhow::Error) {
-    // Try to downcast to WhisError first
-    if let Some(whis_err) = err.downcast_ref::<WhisError>() {
-        display_error(whis_err);
-    } else {
-        // Fall back to generic error display
-        eprintln!("Error: {:#}", err);
+    match WhisError::from(err) {
+        WhisError::Other(msg) => eprintln!("Error: {}", msg),
+        err => display_error(&err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_codes_are_stable() {
+        // Documented in the README: scripts depend on these numbers
+        assert_eq!(EXIT_FAILURE, 1);
+        assert_eq!(EXIT_NO_SPEECH, 3);
+        assert_eq!(EXIT_AUTH, 4);
+        assert_eq!(EXIT_NETWORK, 5);
+        assert_eq!(EXIT_CONFIG, 6);
+    }
+
+    #[test]
+    fn test_kind_exit_code() {
+        for (kind, code) in [
+            (ErrorKind::NoSpeech, 3),
+            (ErrorKind::Auth, 4),
+            (ErrorKind::Network, 5),
+            (ErrorKind::RateLimited, 5),
+            (ErrorKind::TimedOut, 5),
+            (ErrorKind::Config, 6),
+            (ErrorKind::ModelNotFound, 6),
+            (ErrorKind::Audio, 1),
+            (ErrorKind::Transcription, 1),
+            (ErrorKind::Model, 1),
+            (ErrorKind::Cancelled, 1),
+            (ErrorKind::Io, 1),
+            (ErrorKind::Other, 1),
+        ] {
+            assert_eq!(kind_exit_code(kind), code, "{kind:?}");
+        }
+    }
+
+    #[test]
+    fn test_exit_code_of_errors() {
+        assert_eq!(exit_code(&WhisError::NoSpeech.into()), 3);
+        let rejected = ProviderError::Unauthorized {
+            provider: "OpenAI".into(),
+            message: "(401) invalid key".into(),
+        };
+        assert_eq!(
+            exit_code(&anyhow::Error::from(rejected).context("Transcribing")),
+            4
+        );
+        assert_eq!(exit_code(&WhisError::config("no provider").into()), 6);
+        assert_eq!(exit_code(&anyhow::anyhow!("something else")), 1);
     }
 }
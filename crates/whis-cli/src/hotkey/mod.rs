@@ -1,13 +1,18 @@
 //! Cross-platform hotkey support (push-to-talk mode)
 //!
 //! - Linux/macOS: Uses rdev for keyboard grab (supports X11, Wayland, and macOS)
+//! - Linux: Optionally the XDG GlobalShortcuts portal (no input group needed)
 //! - Windows: Uses global-hotkey crate (Tauri-maintained)
 //!
 //! Push-to-talk: Recording starts when hotkey is pressed, stops when released.
 
 use anyhow::Result;
+use std::time::Duration;
 use tokio::sync::mpsc::UnboundedReceiver;
+use whis_core::settings::CliHotkeyBackend;
 
+#[cfg(target_os = "linux")]
+mod portal;
 #[cfg(any(target_os = "linux", target_os = "macos"))]
 mod unix_like;
 #[cfg(any(target_os = "linux", target_os = "macos"))]
@@ -33,24 +38,42 @@ pub struct HotkeyGuard(platform::HotkeyGuard);
 
 /// Setup the hotkey listener for push-to-talk mode.
 /// Returns a receiver for hotkey press/release events and a guard that must be kept alive.
-pub fn setup(hotkey_str: &str) -> Result<(UnboundedReceiver<HotkeyEvent>, HotkeyGuard)> {
-    let (rx, guard) = platform::setup(hotkey_str)?;
+///
+/// Key repeat never fires twice; releases are debounced by `debounce`
+/// (Linux/macOS) so switch bounce doesn't stop and restart a recording.
+/// `backend` picks between the keyboard grab and the portal (Linux only).
+pub fn setup(
+    hotkey_str: &str,
+    debounce: Duration,
+    backend: CliHotkeyBackend,
+) -> Result<(UnboundedReceiver<HotkeyEvent>, HotkeyGuard)> {
+    #[cfg(target_os = "linux")]
+    if portal::should_use(backend) {
+        let rx = portal::setup(hotkey_str)?;
+        return Ok((rx, HotkeyGuard(platform::HotkeyGuard)));
+    }
+    #[cfg(not(target_os = "linux"))]
+    let _ = backend;
+
+    let (rx, guard) = platform::setup(hotkey_str, debounce)?;
     Ok((rx, HotkeyGuard(guard)))
 }
 
 /// Validate a hotkey string and return normalized form if valid
 ///
-/// Examples of valid hotkeys: "ctrl+alt+w", "super+shift+r", "cmd+option+w"
+/// Parsing goes through the shared grammar in `whis_core::hotkey`, so the
+/// accepted syntax, errors and normalized form are the same on every platform.
+///
+/// Examples of valid hotkeys: "ctrl+alt+w", "super+shift+r", "cmd+option+w",
+/// "mouse4" (Linux/macOS), "mediaplaypause" (Linux/Windows)
 pub fn validate(hotkey_str: &str) -> Result<String> {
-    #[cfg(any(target_os = "linux", target_os = "macos"))]
-    {
-        use whis_core::hotkey::Hotkey;
-        let hotkey = Hotkey::parse(hotkey_str).map_err(|e| anyhow::anyhow!(e))?;
-        Ok(hotkey.to_normalized_string())
-    }
+    use whis_core::hotkey::Hotkey;
+    let hotkey = Hotkey::parse(hotkey_str).map_err(|e| anyhow::anyhow!(e))?;
+    hotkey.check_supported().map_err(|e| anyhow::anyhow!(e))?;
+
+    // Windows: also make sure global-hotkey can register the combination
     #[cfg(target_os = "windows")]
-    {
-        // Windows validation via global-hotkey
-        windows::validate(hotkey_str)
-    }
+    windows::check_registrable(&hotkey)?;
+
+    Ok(hotkey.to_normalized_string())
 }
@@ -0,0 +1,184 @@
+//! Push-to-talk through the XDG GlobalShortcuts portal (Linux)
+//!
+//! Alternative to the keyboard grab that needs no `input` group membership:
+//! the desktop environment owns the binding and tells us when the shortcut is
+//! activated (pressed) and deactivated (released). Works on Wayland with
+//! GNOME 48+, KDE and Hyprland.
+//!
+//! Tradeoffs compared to the grab:
+//! - whis only listens; it can't consume the key, so whether other apps also
+//!   see it is up to the compositor
+//! - The desktop may ask the user to confirm (or change) the binding, so the
+//!   active trigger can differ from `cli-key`
+//! - Mouse buttons can't be bound, and the debounce setting doesn't apply
+
+use anyhow::{Context, Result, anyhow, bail};
+use ashpd::desktop::global_shortcuts::{GlobalShortcuts, NewShortcut};
+use futures_util::StreamExt;
+use std::sync::mpsc;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+use whis_core::hotkey::Hotkey;
+use whis_core::platform::{Platform, detect_platform};
+use whis_core::settings::CliHotkeyBackend;
+
+use super::HotkeyEvent;
+
+/// Portal shortcut id (the desktop app binds "toggle-recording")
+const SHORTCUT_ID: &str = "cli-push-to-talk";
+
+/// App id registered with the portal (see whis-desktop's portal registry)
+const APP_ID: &str = "ink.whis.Whis";
+
+/// Whether to use the portal instead of the keyboard grab
+///
+/// `auto` keeps the grab when input devices are accessible, and only uses the
+/// portal on Wayland sessions where it's available.
+pub fn should_use(backend: CliHotkeyBackend) -> bool {
+    match backend {
+        CliHotkeyBackend::Grab => false,
+        CliHotkeyBackend::Portal => true,
+        CliHotkeyBackend::Auto => {
+            if can_grab() {
+                return false;
+            }
+            let platform = detect_platform();
+            platform.platform == Platform::LinuxWayland && platform.portal_version >= 1
+        }
+    }
+}
+
+/// Whether the keyboard grab has the device access it needs
+/// (reading `/dev/input/event*` and writing `/dev/uinput`)
+fn can_grab() -> bool {
+    let readable_input = std::fs::read_dir("/dev/input").is_ok_and(|entries| {
+        entries.flatten().any(|entry| {
+            entry.file_name().to_string_lossy().starts_with("event")
+                && std::fs::File::open(entry.path()).is_ok()
+        })
+    });
+    readable_input
+        && std::fs::OpenOptions::new()
+            .write(true)
+            .open("/dev/uinput")
+            .is_ok()
+}
+
+/// Bind the hotkey through the portal and forward press/release events
+///
+/// Blocks until the binding is done, which can include a desktop dialog.
+pub fn setup(hotkey_str: &str) -> Result<UnboundedReceiver<HotkeyEvent>> {
+    let hotkey = Hotkey::parse(hotkey_str).map_err(|e| anyhow!(e))?;
+    let trigger = hotkey.to_xdg_trigger().ok_or_else(|| {
+        anyhow!(
+            "The GlobalShortcuts portal can't bind mouse buttons.\n\
+             Use a key, or: whis config cli-hotkey-backend grab"
+        )
+    })?;
+
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+    let (startup_tx, startup_rx) = mpsc::channel::<Result<String>>();
+
+    std::thread::spawn(move || {
+        let result = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .context("Failed to start portal runtime")
+            .and_then(|runtime| runtime.block_on(listen(&trigger, tx, &startup_tx)));
+
+        // Before startup the error goes to setup(), afterwards nobody's waiting
+        if let Err(e) = result
+            && let Err(mpsc::SendError(Err(e))) = startup_tx.send(Err(e))
+        {
+            eprintln!("Hotkey error: {e}");
+        }
+    });
+
+    let bound = startup_rx
+        .recv()
+        .map_err(|_| anyhow!("Portal hotkey thread terminated unexpectedly"))??;
+    println!("Portal shortcut bound: {bound} (not consumed from other apps)");
+    Ok(rx)
+}
+
+async fn listen(
+    trigger: &str,
+    tx: UnboundedSender<HotkeyEvent>,
+    startup_tx: &mpsc::Sender<Result<String>>,
+) -> Result<()> {
+    register_app().await;
+
+    let shortcuts = GlobalShortcuts::new()
+        .await
+        .context("GlobalShortcuts portal is not available")?;
+    let session = shortcuts.create_session().await?;
+
+    let shortcut =
+        NewShortcut::new(SHORTCUT_ID, "Push-to-talk (whis CLI)").preferred_trigger(Some(trigger));
+    let response = shortcuts
+        .bind_shortcuts(&session, &[shortcut], None)
+        .await
+        .context("Portal request failed")?
+        .response()
+        .context("Portal bind failed; the shortcut may conflict with an existing binding")?;
+    let bound = response
+        .shortcuts()
+        .iter()
+        .find(|s| s.id() == SHORTCUT_ID)
+        .map(|s| s.trigger_description().to_string())
+        .filter(|t| !t.is_empty())
+        .unwrap_or_else(|| trigger.to_string());
+
+    // Subscribe before reporting success so no activation is missed
+    let mut activated = shortcuts.receive_activated().await?;
+    let mut deactivated = shortcuts.receive_deactivated().await?;
+    let _ = startup_tx.send(Ok(bound));
+
+    // Only forward transitions, in case the portal repeats activations
+    let mut held = false;
+    loop {
+        let event = tokio::select! {
+            Some(event) = activated.next() => {
+                (event.shortcut_id() == SHORTCUT_ID && !held).then_some(HotkeyEvent::Pressed)
+            }
+            Some(event) = deactivated.next() => {
+                (event.shortcut_id() == SHORTCUT_ID && held).then_some(HotkeyEvent::Released)
+            }
+            else => bail!("Portal shortcut signals ended"),
+        };
+        if let Some(event) = event {
+            held = event == HotkeyEvent::Pressed;
+            if tx.send(event).is_err() {
+                // Receiver dropped: the listener is shutting down
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Register the app id with the portal Registry
+///
+/// Native apps need this before other portal calls, otherwise the portal
+/// falls back to cgroup detection, which fails when run from a terminal.
+/// Older portals don't have the Registry, which is fine.
+async fn register_app() {
+    use std::collections::HashMap;
+
+    let result = async {
+        let connection = zbus::Connection::session().await?;
+        connection
+            .call_method(
+                Some("org.freedesktop.portal.Desktop"),
+                "/org/freedesktop/portal/desktop",
+                Some("org.freedesktop.host.portal.Registry"),
+                "Register",
+                &(APP_ID, HashMap::<String, zbus::zvariant::Value>::new()),
+            )
+            .await
+            .map(|_| ())
+    }
+    .await;
+
+    if let Err(e) = result {
+        whis_core::verbose!("Portal Registry registration failed (may be unavailable): {e}");
+    }
+}
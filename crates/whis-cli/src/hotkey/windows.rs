@@ -2,7 +2,9 @@
 
 use anyhow::Result;
 use global_hotkey::{GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState, hotkey::HotKey};
+use std::time::Duration;
 use tokio::sync::mpsc::UnboundedReceiver;
+use whis_core::hotkey::Hotkey;
 
 use super::HotkeyEvent;
 
@@ -10,11 +12,15 @@ pub struct HotkeyGuard {
     _manager: GlobalHotKeyManager,
 }
 
-pub fn setup(hotkey_str: &str) -> Result<(UnboundedReceiver<HotkeyEvent>, HotkeyGuard)> {
-    let converted = convert_to_global_hotkey_format(hotkey_str)?;
-    let hotkey: HotKey = converted
-        .parse()
-        .map_err(|e| anyhow::anyhow!("Invalid hotkey '{}': {:?}", hotkey_str, e))?;
+/// `debounce` is unused: global-hotkey reports one event per transition
+/// (repeats are filtered below), bounce filtering is left to the OS.
+pub fn setup(
+    hotkey_str: &str,
+    _debounce: Duration,
+) -> Result<(UnboundedReceiver<HotkeyEvent>, HotkeyGuard)> {
+    let parsed = Hotkey::parse(hotkey_str).map_err(|e| anyhow::anyhow!(e))?;
+    parsed.check_supported().map_err(|e| anyhow::anyhow!(e))?;
+    let hotkey = to_global_hotkey(&parsed)?;
 
     let manager = GlobalHotKeyManager::new()
         .map_err(|e| anyhow::anyhow!("Failed to create hotkey manager: {:?}", e))?;
@@ -33,13 +39,17 @@ pub fn setup(hotkey_str: &str) -> Result<(UnboundedReceiver<HotkeyEvent>, Hotkey
     let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
 
     std::thread::spawn(move || {
+        // Only forward transitions, so key repeat can't double-fire
+        let mut held = false;
         loop {
             if let Ok(event) = receiver.recv() {
                 if event.id() == hotkey_id {
-                    let hotkey_event = match event.state() {
-                        HotKeyState::Pressed => HotkeyEvent::Pressed,
-                        HotKeyState::Released => HotkeyEvent::Released,
+                    let hotkey_event = match (event.state(), held) {
+                        (HotKeyState::Pressed, false) => HotkeyEvent::Pressed,
+                        (HotKeyState::Released, true) => HotkeyEvent::Released,
+                        _ => continue,
                     };
+                    held = hotkey_event == HotkeyEvent::Pressed;
                     let _ = tx.send(hotkey_event);
                 }
             }
@@ -49,175 +59,19 @@ pub fn setup(hotkey_str: &str) -> Result<(UnboundedReceiver<HotkeyEvent>, Hotkey
     Ok((rx, HotkeyGuard { _manager: manager }))
 }
 
-/// Convert our hotkey format to global-hotkey format
-///
-/// Input: "ctrl+alt+w" (our format)
-/// Output: "Ctrl+Alt+KeyW" (global-hotkey format)
-fn convert_to_global_hotkey_format(s: &str) -> Result<String> {
-    let parts: Vec<&str> = s.split('+').map(|p| p.trim()).collect();
-
-    if parts.is_empty() {
-        anyhow::bail!("Empty hotkey string");
-    }
-
-    let mut result = Vec::new();
-    let mut has_main_key = false;
-
-    for part in parts {
-        let lower = part.to_lowercase();
-        let converted = match lower.as_str() {
-            // Modifiers
-            "ctrl" | "control" => "Ctrl".to_string(),
-            "shift" => "Shift".to_string(),
-            "alt" | "option" => "Alt".to_string(),
-            "super" | "meta" | "win" | "cmd" => "Super".to_string(),
-
-            // Single letters -> KeyX format
-            key if key.len() == 1 && key.chars().next().unwrap().is_ascii_alphabetic() => {
-                has_main_key = true;
-                format!("Key{}", key.to_uppercase())
-            }
-
-            // Numbers -> DigitX format
-            key if key.len() == 1 && key.chars().next().unwrap().is_ascii_digit() => {
-                has_main_key = true;
-                format!("Digit{}", key)
-            }
-
-            // Function keys
-            "f1" => {
-                has_main_key = true;
-                "F1".to_string()
-            }
-            "f2" => {
-                has_main_key = true;
-                "F2".to_string()
-            }
-            "f3" => {
-                has_main_key = true;
-                "F3".to_string()
-            }
-            "f4" => {
-                has_main_key = true;
-                "F4".to_string()
-            }
-            "f5" => {
-                has_main_key = true;
-                "F5".to_string()
-            }
-            "f6" => {
-                has_main_key = true;
-                "F6".to_string()
-            }
-            "f7" => {
-                has_main_key = true;
-                "F7".to_string()
-            }
-            "f8" => {
-                has_main_key = true;
-                "F8".to_string()
-            }
-            "f9" => {
-                has_main_key = true;
-                "F9".to_string()
-            }
-            "f10" => {
-                has_main_key = true;
-                "F10".to_string()
-            }
-            "f11" => {
-                has_main_key = true;
-                "F11".to_string()
-            }
-            "f12" => {
-                has_main_key = true;
-                "F12".to_string()
-            }
-
-            // Special keys
-            "space" => {
-                has_main_key = true;
-                "Space".to_string()
-            }
-            "enter" | "return" => {
-                has_main_key = true;
-                "Enter".to_string()
-            }
-            "escape" | "esc" => {
-                has_main_key = true;
-                "Escape".to_string()
-            }
-            "tab" => {
-                has_main_key = true;
-                "Tab".to_string()
-            }
-            "backspace" => {
-                has_main_key = true;
-                "Backspace".to_string()
-            }
-            "delete" | "del" => {
-                has_main_key = true;
-                "Delete".to_string()
-            }
-            "insert" | "ins" => {
-                has_main_key = true;
-                "Insert".to_string()
-            }
-            "home" => {
-                has_main_key = true;
-                "Home".to_string()
-            }
-            "end" => {
-                has_main_key = true;
-                "End".to_string()
-            }
-            "pageup" | "pgup" => {
-                has_main_key = true;
-                "PageUp".to_string()
-            }
-            "pagedown" | "pgdn" => {
-                has_main_key = true;
-                "PageDown".to_string()
-            }
-            "up" => {
-                has_main_key = true;
-                "ArrowUp".to_string()
-            }
-            "down" => {
-                has_main_key = true;
-                "ArrowDown".to_string()
-            }
-            "left" => {
-                has_main_key = true;
-                "ArrowLeft".to_string()
-            }
-            "right" => {
-                has_main_key = true;
-                "ArrowRight".to_string()
-            }
-
-            _ => anyhow::bail!("Unknown key: {}", part),
-        };
-        result.push(converted);
-    }
-
-    if !has_main_key {
-        anyhow::bail!("No main key specified in hotkey");
-    }
-
-    Ok(result.join("+"))
+/// Convert a parsed hotkey to a global-hotkey `HotKey`
+fn to_global_hotkey(hotkey: &Hotkey) -> Result<HotKey> {
+    let code = hotkey.to_code_string();
+    code.parse().map_err(|e| {
+        anyhow::anyhow!(
+            "Hotkey '{}' is not supported on Windows: {:?}",
+            hotkey.to_normalized_string(),
+            e
+        )
+    })
 }
 
-/// Validate a hotkey string and return normalized form if valid
-pub fn validate(hotkey_str: &str) -> Result<String> {
-    // Try to convert to global-hotkey format (validates syntax)
-    let converted = convert_to_global_hotkey_format(hotkey_str)?;
-
-    // Try to parse as HotKey to validate it's a valid combination
-    let _: HotKey = converted
-        .parse()
-        .map_err(|e| anyhow::anyhow!("Invalid hotkey '{}': {:?}", hotkey_str, e))?;
-
-    // Return a normalized form
-    Ok(converted)
+/// Check that global-hotkey accepts an (already validated) hotkey
+pub fn check_registrable(hotkey: &Hotkey) -> Result<()> {
+    to_global_hotkey(hotkey).map(|_| ())
 }
@@ -8,11 +8,16 @@
 //! - Unix: Domain socket at `$XDG_RUNTIME_DIR/whis.sock` (fallback: `/tmp/whis.sock`)
 //! - Windows: Named pipe `whis`
 //!
+//! A named instance (`--instance <name>` or `WHIS_SOCKET=<name>`) uses
+//! `whis-<name>.sock` / `whis-<name>` instead, so several services can run
+//! side by side.
+//!
 //! # Messages
 //!
 //! - `Stop` → Terminate the service
-//! - `Status` → Query recording state (Idle/Recording/Transcribing)
-//! - `Toggle` → Start/stop recording
+//! - `Status` → Query recording state (Idle/Recording/Transcribing), with
+//!   elapsed time and captured audio while recording
+//! - `Toggle` → Start/stop recording (`Stopped` carries why the recording stopped)
 //!
 //! # Components
 //!
@@ -25,7 +30,9 @@ use interprocess::local_socket::{GenericFilePath, ListenerOptions, ToFsName, pre
 use serde::{Deserialize, Serialize};
 use std::io::{BufRead, BufReader, Write};
 use std::path::PathBuf;
+use std::sync::OnceLock;
 use tokio::sync::mpsc;
+use whis_core::StopReason;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub enum IpcMessage {
@@ -37,23 +44,73 @@ pub enum IpcMessage {
 #[derive(Debug, Serialize, Deserialize)]
 pub enum IpcResponse {
     Success,
-    Recording,
+    /// A recording stopped (and was transcribed) in response to the message
+    Stopped(StopReason),
+    Recording(RecordingProgress),
     Idle,
     Transcribing,
     Error(String),
 }
 
+/// Progress of the current recording
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct RecordingProgress {
+    /// Wall-clock seconds since recording started
+    pub elapsed_secs: f64,
+    /// Seconds of audio captured (less than elapsed when VAD drops silence)
+    pub audio_secs: f64,
+}
+
+/// Instance name selected at startup (None = default instance)
+static INSTANCE: OnceLock<String> = OnceLock::new();
+
+/// Select a named instance so its socket/pipe doesn't collide with others
+///
+/// Names are restricted to ASCII letters, digits, `-` and `_` since they
+/// become part of a file or pipe name.
+pub fn set_instance(name: &str) -> Result<()> {
+    let name = name.trim();
+    if name.is_empty() {
+        anyhow::bail!("Instance name cannot be empty");
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        anyhow::bail!("Invalid instance name '{name}'. Use only letters, digits, '-' and '_'");
+    }
+    let _ = INSTANCE.set(name.to_string());
+    Ok(())
+}
+
+/// Base name of the socket/pipe (`whis` or `whis-<instance>`)
+fn base_name() -> String {
+    match INSTANCE.get() {
+        Some(instance) => format!("whis-{instance}"),
+        None => "whis".to_string(),
+    }
+}
+
+/// Command that starts the service for the selected instance
+pub fn start_command() -> String {
+    match INSTANCE.get() {
+        Some(instance) => format!("whis --instance {instance} start"),
+        None => "whis start".to_string(),
+    }
+}
+
 /// Get the socket name for IPC communication
 #[cfg(unix)]
 fn socket_name() -> String {
+    let base = base_name();
     std::env::var("XDG_RUNTIME_DIR")
-        .map(|dir| format!("{dir}/whis.sock"))
-        .unwrap_or_else(|_| "/tmp/whis.sock".to_string())
+        .map(|dir| format!("{dir}/{base}.sock"))
+        .unwrap_or_else(|_| format!("/tmp/{base}.sock"))
 }
 
 #[cfg(windows)]
 fn socket_name() -> String {
-    "whis".to_string()
+    base_name()
 }
 
 /// IPC Server for the background service
@@ -174,7 +231,8 @@ impl IpcClient {
             if !path.exists() {
                 anyhow::bail!(
                     "whis service is not running.\n\
-                    Start it with: whis start"
+                    Start it with: {}",
+                    start_command()
                 );
             }
         }
@@ -186,15 +244,22 @@ impl IpcClient {
         let stream = LocalSocketStream::connect(name).with_context(|| {
             #[cfg(unix)]
             {
-                "Failed to connect to whis service.\n\
-                The service may have crashed. Try removing stale files:\n\
-                  rm -f $XDG_RUNTIME_DIR/whis.*\n\
-                Then start the service again with: whis start"
+                format!(
+                    "Failed to connect to whis service.\n\
+                    The service may have crashed. Try removing the stale socket:\n\
+                      rm -f {}\n\
+                    Then start the service again with: {}",
+                    socket_name(),
+                    start_command()
+                )
             }
             #[cfg(windows)]
             {
-                "Failed to connect to whis service.\n\
-                The service may not be running. Start it with: whis start"
+                format!(
+                    "Failed to connect to whis service.\n\
+                    The service may not be running. Start it with: {}",
+                    start_command()
+                )
             }
         })?;
 
@@ -1,20 +1,35 @@
 mod app;
 mod args;
 mod commands;
+mod env_file;
 mod error;
 mod hotkey;
 mod ipc;
+mod progress;
 mod service;
-mod ui;
 
 use anyhow::Result;
 use clap::Parser;
+use std::io::IsTerminal;
 
 fn main() -> Result<()> {
     // Run CLI and handle errors with helpful messages
     if let Err(err) = run() {
+        let code = error::exit_code(&err);
+        let rejected_key = error::rejected_key_provider(&err);
         error::display_anyhow_error(err);
-        std::process::exit(1);
+
+        // A rejected key won't work on retry: offer to replace it right away
+        if let Some(provider) = rejected_key
+            && std::io::stdin().is_terminal()
+            && !app::is_quiet()
+        {
+            eprintln!();
+            if let Err(e) = commands::setup::reenter_api_key(&provider) {
+                eprintln!("Error: {e:#}");
+            }
+        }
+        std::process::exit(code);
     }
     Ok(())
 }
@@ -25,23 +40,89 @@ fn run() -> Result<()> {
     // Enable verbose logging if requested
     whis_core::set_verbose(cli.verbose);
 
+    // Quiet mode: stdout only carries results, everything else goes to stderr
+    if cli.quiet {
+        app::set_quiet(true);
+        whis_core::verbose::set_verbose_stderr(true);
+    }
+
+    // Fill in variables (API keys, WHIS_*) from an environment file, if enabled
+    env_file::load(cli.env_file.as_deref())?;
+
+    // Select a named service instance (separate IPC socket/pipe)
+    if let Some(instance) = cli
+        .instance
+        .clone()
+        .or_else(|| std::env::var("WHIS_SOCKET").ok())
+    {
+        ipc::set_instance(&instance)?;
+    }
+
     match cli.command {
-        Some(args::Commands::Start { autotype, preset }) => commands::start::run(autotype, preset),
+        Some(args::Commands::Start {
+            autotype,
+            preset,
+            allow_short,
+        }) => commands::start::run(autotype, preset, allow_short),
         Some(args::Commands::Stop) => commands::stop::run(),
-        Some(args::Commands::Restart { autotype, preset }) => {
-            commands::restart::run(autotype, preset)
-        }
-        Some(args::Commands::Status) => commands::status::run(),
+        Some(args::Commands::Restart {
+            autotype,
+            preset,
+            allow_short,
+        }) => commands::restart::run(autotype, preset, allow_short),
+        Some(args::Commands::Status { config }) => commands::status::run(config),
         Some(args::Commands::Toggle) => commands::toggle::run(),
         Some(args::Commands::Config {
+            show_effective: true,
+            ..
+        }) => commands::config::show_effective(&cli.processing),
+        Some(args::Commands::Config {
+            set_language: Some(language),
+            ..
+        }) => commands::config::run(
+            None,
+            Some("language".to_string()),
+            Some(language),
+            false,
+            false,
+            false,
+            false,
+        ),
+        Some(args::Commands::Config {
+            action,
             key,
             value,
             list,
+            all,
             path,
-        }) => commands::config::run(key, value, list, path),
+            reveal,
+            show_effective: _,
+            set_language: None,
+        }) => commands::config::run(action, key, value, list, all, path, reveal),
+        Some(args::Commands::Listen {
+            auto,
+            pause,
+            json,
+            language,
+        }) => commands::listen::run(auto, pause, json, language),
+        Some(args::Commands::Serve {
+            port,
+            host,
+            interim,
+            pause,
+            language,
+        }) => commands::serve::run(host, port, interim, pause, language),
+        Some(args::Commands::Reprocess {
+            preset,
+            provider,
+            post_process,
+            language,
+            output,
+        }) => commands::reprocess::run(preset, provider, post_process, language, output),
         Some(args::Commands::Preset { action }) => commands::preset::run(action),
         Some(args::Commands::Setup) => commands::setup::run(),
         Some(args::Commands::Model { action }) => commands::model::run(action),
+        Some(args::Commands::Ollama { action }) => commands::ollama::run(action),
         None => {
             // Microphone recording or file transcription
             let config =
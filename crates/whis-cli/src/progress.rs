@@ -0,0 +1,324 @@
+//! Progress feedback for long operations
+//!
+//! One abstraction for everything that makes the user wait: a spinner when
+//! the length is unknown (transcribing a file, downloading a URL, chunked
+//! transcription) and bars when it's known (model downloads, Ollama pulls).
+//!
+//! - **Terminal**: drawn in place on stderr, the spinner animated on its own
+//!   thread
+//! - **Not a terminal, or `--verbose`**: plain log lines instead (messages as
+//!   they change, bars in 25% steps) so logs and pipes stay readable
+//! - **`--quiet`**: nothing
+
+use std::io::{IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::app;
+
+/// Width of each progress bar
+const BAR_WIDTH: usize = 10;
+
+/// Spinner animation frames
+const SPINNER_FRAMES: [char; 4] = ['|', '/', '-', '\\'];
+
+/// Delay between spinner frames
+const TICK: Duration = Duration::from_millis(100);
+
+/// Bars are logged in steps of this many percent in plain mode
+const PLAIN_STEP: u64 = 25;
+
+/// Clear the current terminal line
+const CLEAR_LINE: &str = "\r\x1b[K";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Hidden,
+    Plain,
+    Live,
+}
+
+impl Style {
+    fn detect() -> Self {
+        if app::is_quiet() {
+            Style::Hidden
+        } else if std::io::stderr().is_terminal() && !whis_core::verbose::is_verbose() {
+            Style::Live
+        } else {
+            Style::Plain
+        }
+    }
+}
+
+struct Bar {
+    label: String,
+    done: u64,
+    total: u64,
+    /// Last percent step logged (plain mode)
+    logged: u64,
+}
+
+impl Bar {
+    fn percent(&self) -> u64 {
+        if self.total > 0 {
+            (self.done * 100 / self.total).min(100)
+        } else {
+            0
+        }
+    }
+
+    /// Percent step to log in plain mode, if a new one was reached
+    fn next_plain_step(&mut self) -> Option<u64> {
+        let step = self.percent() / PLAIN_STEP * PLAIN_STEP;
+        (step > self.logged).then(|| {
+            self.logged = step;
+            step
+        })
+    }
+
+    fn render(&self) -> String {
+        let percent = self.percent();
+        let filled = BAR_WIDTH * percent as usize / 100;
+        format!(
+            "{} [{}{}] {percent}%",
+            self.label,
+            "=".repeat(filled),
+            " ".repeat(BAR_WIDTH - filled)
+        )
+    }
+}
+
+struct State {
+    /// Spinner text (empty for bars)
+    message: String,
+    bars: Vec<Bar>,
+    frame: usize,
+    /// The line is drawn and has to be cleared before printing
+    drawn: bool,
+}
+
+impl State {
+    fn line(&self) -> String {
+        if self.bars.is_empty() {
+            format!("{} {}", SPINNER_FRAMES[self.frame], self.message)
+        } else {
+            let bars: Vec<String> = self.bars.iter().map(Bar::render).collect();
+            format!("[i] {}", bars.join("  "))
+        }
+    }
+
+    fn draw(&mut self) {
+        eprint!("{CLEAR_LINE}{}", self.line());
+        std::io::stderr().flush().ok();
+        self.drawn = true;
+    }
+
+    fn clear(&mut self) {
+        if self.drawn {
+            eprint!("{CLEAR_LINE}");
+            std::io::stderr().flush().ok();
+            self.drawn = false;
+        }
+    }
+}
+
+/// Spinner or progress bars for one long operation
+///
+/// Cleared when finished or dropped.
+pub struct Progress {
+    style: Style,
+    state: Arc<Mutex<State>>,
+    stopped: Arc<AtomicBool>,
+    ticker: Option<JoinHandle<()>>,
+}
+
+impl Progress {
+    /// Spinner for work of unknown length
+    pub fn spinner(message: impl Into<String>) -> Self {
+        let mut progress = Self::new(Style::detect(), message.into(), Vec::new());
+        match progress.style {
+            Style::Live => {
+                progress.state.lock().unwrap().draw();
+                progress.start_ticker();
+            }
+            Style::Plain => eprintln!("{}", progress.state.lock().unwrap().message),
+            Style::Hidden => {}
+        }
+        progress
+    }
+
+    /// One bar per label, side by side on a line (e.g. concurrent downloads)
+    pub fn bars(labels: &[&str]) -> Self {
+        let bars = labels
+            .iter()
+            .map(|label| Bar {
+                label: label.to_string(),
+                done: 0,
+                total: 0,
+                logged: 0,
+            })
+            .collect();
+        Self::new(Style::detect(), String::new(), bars)
+    }
+
+    /// Single progress bar
+    pub fn bar(label: &str) -> Self {
+        Self::bars(&[label])
+    }
+
+    /// Show nothing (e.g. when stdout and stderr are reserved for results)
+    pub fn hidden() -> Self {
+        Self::new(Style::Hidden, String::new(), Vec::new())
+    }
+
+    fn new(style: Style, message: String, bars: Vec<Bar>) -> Self {
+        Self {
+            style,
+            state: Arc::new(Mutex::new(State {
+                message,
+                bars,
+                frame: 0,
+                drawn: false,
+            })),
+            stopped: Arc::new(AtomicBool::new(false)),
+            ticker: None,
+        }
+    }
+
+    fn start_ticker(&mut self) {
+        let state = Arc::clone(&self.state);
+        let stopped = Arc::clone(&self.stopped);
+        self.ticker = Some(std::thread::spawn(move || {
+            while !stopped.load(Ordering::Relaxed) {
+                {
+                    let mut state = state.lock().unwrap();
+                    state.frame = (state.frame + 1) % SPINNER_FRAMES.len();
+                    state.draw();
+                }
+                std::thread::sleep(TICK);
+            }
+        }));
+    }
+
+    /// Change the spinner text
+    pub fn set_message(&self, message: impl Into<String>) {
+        let message = message.into();
+        let mut state = self.state.lock().unwrap();
+        if state.message == message {
+            return;
+        }
+        state.message = message;
+        match self.style {
+            Style::Live => state.draw(),
+            Style::Plain => eprintln!("{}", state.message),
+            Style::Hidden => {}
+        }
+    }
+
+    /// Report `done` of `total` for the bar at `index`
+    pub fn update(&self, index: usize, done: u64, total: u64) {
+        let mut state = self.state.lock().unwrap();
+        let Some(bar) = state.bars.get_mut(index) else {
+            return;
+        };
+        bar.done = done;
+        bar.total = total;
+        match self.style {
+            Style::Live => state.draw(),
+            Style::Plain => {
+                if let Some(step) = bar.next_plain_step() {
+                    eprintln!("{}: {step}%", bar.label);
+                }
+            }
+            Style::Hidden => {}
+        }
+    }
+
+    /// Print a line of output above the progress line
+    pub fn println(&self, text: &str) {
+        let mut state = self.state.lock().unwrap();
+        let redraw = state.drawn;
+        state.clear();
+        app::status_line(text);
+        if redraw {
+            state.draw();
+        }
+    }
+
+    /// Clear the progress line and print `message` in its place
+    pub fn finish(mut self, message: Option<&str>) {
+        self.stop();
+        if let Some(message) = message
+            && self.style != Style::Hidden
+        {
+            eprintln!("{message}");
+        }
+    }
+
+    fn stop(&mut self) {
+        self.stopped.store(true, Ordering::Relaxed);
+        if let Some(ticker) = self.ticker.take() {
+            let _ = ticker.join();
+        }
+        self.state.lock().unwrap().clear();
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bar(done: u64, total: u64) -> Bar {
+        Bar {
+            label: "model".to_string(),
+            done,
+            total,
+            logged: 0,
+        }
+    }
+
+    #[test]
+    fn test_bar_percent() {
+        assert_eq!(bar(0, 0).percent(), 0);
+        assert_eq!(bar(5, 0).percent(), 0);
+        assert_eq!(bar(0, 200).percent(), 0);
+        assert_eq!(bar(99, 200).percent(), 49);
+        assert_eq!(bar(200, 200).percent(), 100);
+        assert_eq!(bar(300, 200).percent(), 100);
+    }
+
+    #[test]
+    fn test_bar_render() {
+        assert_eq!(bar(0, 0).render(), "model [          ] 0%");
+        assert_eq!(bar(1, 2).render(), "model [=====     ] 50%");
+        assert_eq!(bar(3, 2).render(), "model [==========] 100%");
+    }
+
+    #[test]
+    fn test_plain_steps_log_each_quarter_once() {
+        let mut bar = bar(0, 100);
+        let mut logged = Vec::new();
+        for done in [0, 10, 24, 25, 26, 49, 60, 75, 75, 99, 100, 120] {
+            bar.done = done;
+            logged.extend(bar.next_plain_step());
+        }
+        assert_eq!(logged, [25, 50, 75, 100]);
+    }
+
+    #[test]
+    fn test_plain_steps_skip_to_the_reached_step() {
+        let mut bar = bar(80, 100);
+        assert_eq!(bar.next_plain_step(), Some(75));
+        bar.done = 100;
+        assert_eq!(bar.next_plain_step(), Some(100));
+        assert_eq!(bar.next_plain_step(), None);
+    }
+}
@@ -30,18 +30,27 @@
 //! - Event-driven loop using `tokio::select!` (no polling, zero CPU when idle)
 //! - Progressive transcription: audio chunks sent during recording
 //! - Post-processing and clipboard copy on completion
+//! - SIGTERM/SIGINT (Ctrl-C/close events on Windows) and IPC `Stop` share one
+//!   shutdown path, so the socket is removed even when the service is killed
+//! - Optional idle shutdown (`service-idle-shutdown`) takes the same path after
+//!   a stretch without recordings
 
 use anyhow::{Context, Result};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc::UnboundedReceiver;
 
 use crate::app::TranscriptionConfig;
 use crate::hotkey::HotkeyEvent;
-use crate::ipc::{IpcMessage, IpcResponse, IpcServer};
+use crate::ipc::{IpcMessage, IpcResponse, IpcServer, RecordingProgress};
 use whis_core::{
-    AudioRecorder, OutputMethod, PostProcessor, Preset, Settings, TranscriptionProvider,
-    autotype_text, copy_to_clipboard, post_process, resolve_post_processor_config,
+    AudioRecorder, CancellationToken, Cue, OutputMethod, PostProcessor, Preset, PromptVariables,
+    Settings, StopReason, TranscriptionProvider, WhisError, autotype_text, copy_to_clipboard,
+    format_spoken_numbers, formats_numbers_locally, normalize_transcript, ollama, play_cue,
+    play_cue_and_wait, post_process, render_prompt, resolve_post_processor_config,
 };
+#[cfg(feature = "local-transcription")]
+use whis_core::{unload_parakeet, whisper_unload_model};
 
 // Type aliases to reduce complexity warnings
 type TaskHandle<T> = Arc<Mutex<Option<tokio::task::JoinHandle<T>>>>;
@@ -56,16 +65,28 @@ enum ServiceState {
 pub struct Service {
     state: Arc<Mutex<ServiceState>>,
     recorder: Arc<Mutex<Option<AudioRecorder>>>,
+    /// When the current recording started (for status reporting)
+    recording_started: Arc<Mutex<Option<Instant>>>,
     // Store handles for background tasks (progressive transcription)
     chunker_handle: TaskHandle<Result<(), String>>,
     transcription_handle: TaskHandle<Result<String>>,
+    /// Cancels in-flight transcription requests when the service stops
+    cancel_token: Arc<Mutex<Option<CancellationToken>>>,
     provider: TranscriptionProvider,
     api_key: String,
     language: Option<String>,
     recording_counter: Arc<Mutex<u32>>,
+    /// Explicit preset from `--preset`; when unset, the active preset is
+    /// re-read from settings for every recording
     preset: Option<Preset>,
     /// CLI override for output method (e.g., --autotype flag)
     output_method_override: Option<OutputMethod>,
+    /// Recordings shorter than this are discarded (zero with `--allow-short`)
+    min_recording: Duration,
+    /// Exit after this long without a recording (None = run until stopped)
+    idle_shutdown: Option<Duration>,
+    /// Sound cue settings, read once when the service starts
+    cues: whis_core::settings::CueSettings,
 }
 
 impl Service {
@@ -73,18 +94,25 @@ impl Service {
         config: TranscriptionConfig,
         preset: Option<Preset>,
         output_method_override: Option<OutputMethod>,
+        min_recording: Duration,
+        idle_shutdown: Option<Duration>,
     ) -> Result<Self> {
         Ok(Self {
             state: Arc::new(Mutex::new(ServiceState::Idle)),
             recorder: Arc::new(Mutex::new(None)),
+            recording_started: Arc::new(Mutex::new(None)),
             chunker_handle: Arc::new(Mutex::new(None)),
             transcription_handle: Arc::new(Mutex::new(None)),
+            cancel_token: Arc::new(Mutex::new(None)),
             provider: config.provider,
             api_key: config.api_key,
             language: config.language,
             recording_counter: Arc::new(Mutex::new(0)),
             preset,
             output_method_override,
+            min_recording,
+            idle_shutdown,
+            cues: Settings::load().ui.cues,
         })
     }
 
@@ -109,14 +137,29 @@ impl Service {
             self.provider.set_keep_loaded(keep_loaded);
         }
 
+        let shutdown = shutdown_signal();
+        tokio::pin!(shutdown);
+
+        // Recordings (start, stop or toggle) reset the idle timer; status
+        // queries don't
+        let mut last_activity = Instant::now();
+
         loop {
+            let idle_deadline = self.idle_shutdown.map(|idle| last_activity + idle);
             tokio::select! {
                 // Wait for IPC connection
                 Some(mut conn) = ipc_server.accept() => {
                     match conn.receive() {
                         Ok(message) => {
+                            let stop = matches!(message, IpcMessage::Stop);
+                            if matches!(message, IpcMessage::Toggle) {
+                                last_activity = Instant::now();
+                            }
                             let response = self.handle_message(message).await;
                             let _ = conn.send(response);
+                            if stop {
+                                break;
+                            }
                         }
                         Err(e) => {
                             eprintln!("Error receiving message: {e}");
@@ -132,6 +175,7 @@ impl Service {
                         None => std::future::pending().await,
                     }
                 } => {
+                    last_activity = Instant::now();
                     if push_to_talk {
                         // Push-to-talk mode: press starts, release stops
                         match event {
@@ -149,8 +193,92 @@ impl Service {
                         }
                     }
                 }
+
+                // Wait for SIGTERM/SIGINT (Ctrl-C/close on Windows)
+                _ = &mut shutdown => {
+                    println!("\nShutting down...");
+                    break;
+                }
+
+                // Stop after a stretch without recordings (if configured)
+                _ = async {
+                    match idle_deadline {
+                        Some(deadline) => tokio::time::sleep_until(deadline.into()).await,
+                        None => std::future::pending().await,
+                    }
+                } => {
+                    // A toggle-mode recording can outlast the timeout
+                    if *self.state.lock().unwrap() != ServiceState::Idle {
+                        last_activity = Instant::now();
+                        continue;
+                    }
+                    let minutes = self.idle_shutdown.unwrap_or_default().as_secs() / 60;
+                    println!("No recordings for {minutes} minutes, shutting down...");
+                    break;
+                }
             }
         }
+
+        // Dropping the server removes the socket file
+        drop(ipc_server);
+        self.shutdown();
+        Ok(())
+    }
+
+    /// Release everything the service holds before exiting
+    ///
+    /// Cancels in-flight transcription, stops an active recording and unloads
+    /// a cached local model.
+    fn shutdown(&self) {
+        self.abort_recording();
+
+        #[cfg(feature = "local-transcription")]
+        match self.provider {
+            TranscriptionProvider::LocalWhisper => whisper_unload_model(),
+            TranscriptionProvider::LocalParakeet => unload_parakeet(),
+            _ => {}
+        }
+    }
+
+    /// Stop an active recording and drop its audio without transcribing
+    fn abort_recording(&self) {
+        if let Some(token) = self.cancel_token.lock().unwrap().take() {
+            token.cancel();
+        }
+        if let Some(mut recorder) = self.recorder.lock().unwrap().take() {
+            let _ = recorder.stop_recording();
+            whis_core::verbose!("Recording stopped ({})", StopReason::Cancelled);
+        }
+        self.recording_started.lock().unwrap().take();
+        if let Some(handle) = self.chunker_handle.lock().unwrap().take() {
+            handle.abort();
+        }
+        if let Some(handle) = self.transcription_handle.lock().unwrap().take() {
+            handle.abort();
+        }
+    }
+
+    /// Discard the current recording if it's shorter than `min_recording`
+    ///
+    /// Returns true if it was discarded (the service is back to idle).
+    fn discard_if_too_short(&self, count: u32) -> bool {
+        let Some(elapsed) = self
+            .recording_started
+            .lock()
+            .unwrap()
+            .map(|started| started.elapsed())
+        else {
+            return false;
+        };
+        if elapsed >= self.min_recording {
+            return false;
+        }
+
+        self.abort_recording();
+        *self.state.lock().unwrap() = ServiceState::Idle;
+        println!("#{count} Too short ({}ms), discarded", elapsed.as_millis());
+        println!();
+        true
     }
 
     /// Handle an IPC message
@@ -158,25 +286,43 @@ impl Service {
         match message {
             IpcMessage::Toggle => self.handle_toggle().await,
             IpcMessage::Stop => {
+                // The main loop shuts down after the response is sent
                 println!("Stop signal received");
-                // Return Ok response before exiting
-                tokio::spawn(async {
-                    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
-                    std::process::exit(0);
-                });
                 IpcResponse::Success
             }
             IpcMessage::Status => {
                 let state = *self.state.lock().unwrap();
                 match state {
                     ServiceState::Idle => IpcResponse::Idle,
-                    ServiceState::Recording => IpcResponse::Recording,
+                    ServiceState::Recording => IpcResponse::Recording(self.recording_progress()),
                     ServiceState::Transcribing => IpcResponse::Transcribing,
                 }
             }
         }
     }
 
+    /// Elapsed time and captured audio of the current recording
+    fn recording_progress(&self) -> RecordingProgress {
+        let elapsed_secs = self
+            .recording_started
+            .lock()
+            .unwrap()
+            .map(|started| started.elapsed().as_secs_f64())
+            .unwrap_or_default();
+        let audio_secs = self
+            .recorder
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|r| r.buffered_secs() as f64)
+            .unwrap_or_default();
+
+        RecordingProgress {
+            elapsed_secs,
+            audio_secs,
+        }
+    }
+
     /// Handle toggle command (start/stop recording)
     async fn handle_toggle(&self) -> IpcResponse {
         let current_state = *self.state.lock().unwrap();
@@ -192,30 +338,40 @@ impl Service {
                 match self.start_recording().await {
                     Ok(_) => {
                         println!("#{count} Recording...");
-                        IpcResponse::Recording
+                        IpcResponse::Recording(RecordingProgress::default())
                     }
                     Err(e) => {
                         println!("#{count} error: {e}");
+                        if let Some(hint) = crate::error::microphone_hint(&e) {
+                            println!("#{count} {hint}");
+                        }
                         IpcResponse::Error(e.to_string())
                     }
                 }
             }
             ServiceState::Recording => {
+                let count = *self.recording_counter.lock().unwrap();
+                if self.discard_if_too_short(count) {
+                    return IpcResponse::Stopped(StopReason::Cancelled);
+                }
+
                 // Stop recording and transcribe
                 *self.state.lock().unwrap() = ServiceState::Transcribing;
-                let count = *self.recording_counter.lock().unwrap();
 
                 println!("#{count} Transcribing...");
 
-                match self.stop_and_transcribe(count).await {
+                match self.stop_and_transcribe(count, StopReason::Manual).await {
                     Ok(_) => {
                         *self.state.lock().unwrap() = ServiceState::Idle;
                         println!(); // blank line between transcriptions
-                        IpcResponse::Success
+                        IpcResponse::Stopped(StopReason::Manual)
                     }
                     Err(e) => {
                         *self.state.lock().unwrap() = ServiceState::Idle;
                         println!("#{count} error: {e}");
+                        if let Some(hint) = crate::error::microphone_hint(&e) {
+                            println!("#{count} {hint}");
+                        }
                         println!();
                         IpcResponse::Error(e.to_string())
                     }
@@ -248,6 +404,9 @@ impl Service {
             }
             Err(e) => {
                 println!("#{count} error: {e}");
+                if let Some(hint) = crate::error::microphone_hint(&e) {
+                    println!("#{count} {hint}");
+                }
             }
         }
     }
@@ -260,13 +419,17 @@ impl Service {
             return; // Only stop if currently recording
         }
 
+        let count = *self.recording_counter.lock().unwrap();
+        if self.discard_if_too_short(count) {
+            return;
+        }
+
         // Stop recording and transcribe
         *self.state.lock().unwrap() = ServiceState::Transcribing;
-        let count = *self.recording_counter.lock().unwrap();
 
         println!("#{count} Transcribing...");
 
-        match self.stop_and_transcribe(count).await {
+        match self.stop_and_transcribe(count, StopReason::Manual).await {
             Ok(_) => {
                 *self.state.lock().unwrap() = ServiceState::Idle;
                 println!(); // blank line between transcriptions
@@ -274,11 +437,21 @@ impl Service {
             Err(e) => {
                 *self.state.lock().unwrap() = ServiceState::Idle;
                 println!("#{count} error: {e}");
+                if let Some(hint) = crate::error::microphone_hint(&e) {
+                    println!("#{count} {hint}");
+                }
                 println!();
             }
         }
     }
 
+    /// Play `cue` if sound cues are enabled
+    fn sound_cue(&self, cue: Cue) {
+        if self.cues.sound {
+            play_cue(cue, self.cues.volume);
+        }
+    }
+
     /// Start recording audio with progressive transcription
     async fn start_recording(&self) -> Result<()> {
         use tokio::sync::mpsc;
@@ -293,6 +466,14 @@ impl Service {
             recorder.set_vad(settings.ui.vad.enabled, settings.ui.vad.threshold);
         }
 
+        // The start cue finishes before capture begins, so it isn't recorded
+        if self.cues.sound {
+            let volume = self.cues.volume;
+            tokio::task::spawn_blocking(move || play_cue_and_wait(Cue::RecordingStarted, volume))
+                .await
+                .ok();
+        }
+
         // Start streaming recording with configured device
         let device_name = settings.ui.microphone_device.clone();
         let mut audio_rx_bounded =
@@ -315,13 +496,8 @@ impl Service {
 
         // Create chunker config from settings
         let vad_enabled = settings.ui.vad.enabled;
-        let target = settings.ui.chunk_duration_secs;
-        let chunker_config = ChunkerConfig {
-            target_duration_secs: target,
-            min_duration_secs: target * 2 / 3,
-            max_duration_secs: target * 4 / 3,
-            vad_aware: vad_enabled,
-        };
+        let chunker_config =
+            ChunkerConfig::from_target_secs(settings.ui.chunk_duration_secs, vad_enabled);
 
         // Spawn chunker task
         let mut chunker = ProgressiveChunker::new(chunker_config, chunk_tx);
@@ -336,6 +512,8 @@ impl Service {
         let provider = self.provider.clone();
         let api_key = self.api_key.clone();
         let language = self.language.clone();
+        let cancel_token = CancellationToken::new();
+        let cancel = cancel_token.clone();
 
         let transcription_handle = tokio::spawn(async move {
             #[cfg(feature = "local-transcription")]
@@ -346,7 +524,13 @@ impl Service {
                     .parakeet_model_path()
                     .ok_or_else(|| anyhow::anyhow!("Parakeet model path not configured"))?;
 
-                return whis_core::progressive_transcribe_local(&model_path, chunk_rx, None).await;
+                return whis_core::progressive_transcribe_local(
+                    &model_path,
+                    chunk_rx,
+                    None,
+                    Some(cancel),
+                )
+                .await;
             }
 
             // Cloud provider progressive transcription
@@ -356,6 +540,7 @@ impl Service {
                 language.as_deref(),
                 chunk_rx,
                 None,
+                Some(cancel),
             )
             .await
         });
@@ -380,15 +565,17 @@ impl Service {
 
         // Store recorder and task handles
         *self.recorder.lock().unwrap() = Some(recorder);
+        *self.recording_started.lock().unwrap() = Some(Instant::now());
         *self.chunker_handle.lock().unwrap() = Some(chunker_handle);
         *self.transcription_handle.lock().unwrap() = Some(transcription_handle);
+        *self.cancel_token.lock().unwrap() = Some(cancel_token);
         *self.state.lock().unwrap() = ServiceState::Recording;
 
         Ok(())
     }
 
     /// Stop recording and await progressive transcription completion
-    async fn stop_and_transcribe(&self, count: u32) -> Result<()> {
+    async fn stop_and_transcribe(&self, count: u32, reason: StopReason) -> Result<()> {
         // Get the recorder
         let mut recorder = self
             .recorder
@@ -396,9 +583,12 @@ impl Service {
             .unwrap()
             .take()
             .context("No active recording")?;
+        self.recording_started.lock().unwrap().take();
 
         // Stop recording (closes audio stream, signals chunker to finish)
         recorder.stop_recording()?;
+        whis_core::verbose!("#{count} Recording stopped ({reason})");
+        self.sound_cue(Cue::RecordingStopped);
 
         // Get task handles
         let chunker_handle = self
@@ -425,12 +615,47 @@ impl Service {
         let transcription = transcription_handle
             .await
             .context("Failed to join transcription task")??;
+        self.cancel_token.lock().unwrap().take();
+        let mut transcription = normalize_transcript(&transcription).ok_or(WhisError::NoSpeech)?;
 
         // Apply post-processing if enabled or preset is provided
         let settings = Settings::load();
-        let final_text = if settings.post_processing.enabled || self.preset.is_some() {
-            match resolve_post_processor_config(&self.preset, &settings) {
+        if settings.transcription.format_numbers
+            && formats_numbers_locally(&self.provider, self.language.as_deref())
+        {
+            transcription = format_spoken_numbers(&transcription);
+        }
+        let preset = match &self.preset {
+            Some(preset) => Some(preset.clone()),
+            None => crate::app::resolve_preset(None, &settings)?,
+        };
+        let final_text = if settings.post_processing.enabled || preset.is_some() {
+            match resolve_post_processor_config(&preset, &settings) {
                 Ok((processor, api_key, model, prompt)) => {
+                    let prompt = render_prompt(
+                        &prompt,
+                        &PromptVariables::for_prompt(&prompt, self.language.clone()),
+                    );
+                    // Pull a missing Ollama model over HTTP (no ollama CLI required)
+                    if processor == PostProcessor::Ollama
+                        && let Some(model_name) = model.clone()
+                    {
+                        let url = api_key.clone();
+                        let pull_result = tokio::task::spawn_blocking(move || {
+                            let announce = std::sync::Once::new();
+                            ollama::ensure_ollama_ready_with_progress(&url, &model_name, |_, _| {
+                                announce.call_once(|| {
+                                    println!("#{count} Pulling Ollama model '{model_name}'...")
+                                });
+                            })
+                        })
+                        .await
+                        .context("Failed to join task")?;
+                        if let Err(e) = pull_result {
+                            eprintln!("#{count} Ollama model pull failed: {e}");
+                        }
+                    }
+
                     // Re-warm Ollama model if needed
                     if processor == PostProcessor::Ollama && model.is_some() {
                         settings.services.ollama.preload();
@@ -445,6 +670,8 @@ impl Service {
                         &api_key,
                         &prompt,
                         model.as_deref(),
+                        Some(&settings.services.ollama),
+                        (&settings.post_processing).into(),
                     )
                     .await
                     {
@@ -497,7 +724,46 @@ impl Service {
         })
         .await
         .context("Failed to join task")??;
+        self.sound_cue(Cue::TranscriptionDone);
 
         Ok(())
     }
 }
+
+/// Resolve when the process is asked to terminate
+#[cfg(unix)]
+async fn shutdown_signal() {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    match signal(SignalKind::terminate()) {
+        Ok(mut sigterm) => {
+            tokio::select! {
+                _ = sigterm.recv() => {}
+                _ = tokio::signal::ctrl_c() => {}
+            }
+        }
+        Err(e) => {
+            eprintln!("Warning: Could not install SIGTERM handler: {e}");
+            let _ = tokio::signal::ctrl_c().await;
+        }
+    }
+}
+
+/// Resolve when the process is asked to terminate
+#[cfg(windows)]
+async fn shutdown_signal() {
+    use tokio::signal::windows::ctrl_close;
+
+    match ctrl_close() {
+        Ok(mut close) => {
+            tokio::select! {
+                _ = close.recv() => {}
+                _ = tokio::signal::ctrl_c() => {}
+            }
+        }
+        Err(e) => {
+            eprintln!("Warning: Could not install close handler: {e}");
+            let _ = tokio::signal::ctrl_c().await;
+        }
+    }
+}
@@ -4,7 +4,7 @@
 //! enabling transcription to begin before recording completes.
 //!
 //! ## Features
-//! - Fixed duration chunking (90s default)
+//! - Fixed duration chunking (90s default, `chunk_duration_secs` setting)
 //! - VAD-aware chunking (chunks at silence near target duration)
 //! - 2-second overlap between chunks for better accuracy
 //!
@@ -24,6 +24,9 @@
 use std::collections::VecDeque;
 use tokio::sync::mpsc;
 
+use crate::configuration::{
+    DEFAULT_CHUNK_DURATION_SECS, MAX_CHUNK_DURATION_SECS, MIN_CHUNK_DURATION_SECS,
+};
 use crate::resample::WHISPER_SAMPLE_RATE;
 
 use super::vad::VadState;
@@ -58,17 +61,28 @@ pub struct ChunkerConfig {
     pub vad_aware: bool,
 }
 
-impl Default for ChunkerConfig {
-    fn default() -> Self {
+impl ChunkerConfig {
+    /// Chunk around `target_secs` (clamped to 10-300s)
+    ///
+    /// VAD-aware chunking may cut anywhere between 2/3 and 4/3 of the target.
+    /// This is what `chunk_duration_secs` / `whis config chunk-size` feeds.
+    pub fn from_target_secs(target_secs: u64, vad_aware: bool) -> Self {
+        let target = target_secs.clamp(MIN_CHUNK_DURATION_SECS, MAX_CHUNK_DURATION_SECS);
         Self {
-            target_duration_secs: 90,
-            min_duration_secs: 60,
-            max_duration_secs: 120,
-            vad_aware: true,
+            target_duration_secs: target,
+            min_duration_secs: target * 2 / 3,
+            max_duration_secs: target * 4 / 3,
+            vad_aware,
         }
     }
 }
 
+impl Default for ChunkerConfig {
+    fn default() -> Self {
+        Self::from_target_secs(DEFAULT_CHUNK_DURATION_SECS, true)
+    }
+}
+
 /// Buffer for accumulating audio samples with overlap management
 struct ChunkBuffer {
     /// Current chunk being accumulated
@@ -143,6 +157,7 @@ pub struct ProgressiveChunker {
     config: ChunkerConfig,
     buffer: ChunkBuffer,
     chunk_tx: mpsc::UnboundedSender<AudioChunk>,
+    chunks_sent: usize,
 }
 
 impl ProgressiveChunker {
@@ -152,9 +167,15 @@ impl ProgressiveChunker {
             config,
             buffer: ChunkBuffer::new(),
             chunk_tx,
+            chunks_sent: 0,
         }
     }
 
+    /// Number of chunks sent so far (the total once `consume_stream` returns)
+    pub fn chunks_sent(&self) -> usize {
+        self.chunks_sent
+    }
+
     /// Check if we should create a chunk
     ///
     /// Decision logic:
@@ -210,6 +231,7 @@ impl ProgressiveChunker {
                             chunk.samples.len() as f32 / WHISPER_SAMPLE_RATE as f32
                         );
                         self.chunk_tx.send(chunk).map_err(|e| e.to_string())?;
+                        self.chunks_sent += 1;
                     }
                 }
 
@@ -232,6 +254,7 @@ impl ProgressiveChunker {
                             final_chunk.samples.len() as f32 / WHISPER_SAMPLE_RATE as f32
                         );
                         self.chunk_tx.send(final_chunk).map_err(|e| e.to_string())?;
+                        self.chunks_sent += 1;
                     }
                     break;
                 }
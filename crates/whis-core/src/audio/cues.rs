@@ -0,0 +1,158 @@
+//! Audible feedback on recording state changes.
+//!
+//! Short synthesized beeps, played on the default output device: a rising
+//! pair when recording starts, a falling pair when it stops and a single
+//! high tone when the transcript is ready. Playback runs on its own thread
+//! and never fails loudly; a missing or busy output device only logs.
+//!
+//! The start cue is played with [`play_cue_and_wait`] before the microphone
+//! opens, so the beep doesn't end up in the recording.
+
+use std::time::Duration;
+
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+
+/// Recording state change to give feedback on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cue {
+    RecordingStarted,
+    RecordingStopped,
+    TranscriptionDone,
+}
+
+/// Length of the fade in and out of each tone, against clicks
+const FADE: Duration = Duration::from_millis(8);
+
+/// Silence between the tones of a cue
+const GAP: Duration = Duration::from_millis(40);
+
+impl Cue {
+    /// Tones of the cue as (frequency in Hz, duration)
+    fn tones(self) -> &'static [(f32, Duration)] {
+        const SHORT: Duration = Duration::from_millis(70);
+        match self {
+            Cue::RecordingStarted => &[(660.0, SHORT), (880.0, SHORT)],
+            Cue::RecordingStopped => &[(880.0, SHORT), (660.0, SHORT)],
+            Cue::TranscriptionDone => &[(1046.5, Duration::from_millis(120))],
+        }
+    }
+}
+
+/// Play `cue` at `volume` (0.0-1.0) without blocking
+///
+/// Does nothing at volume 0.
+pub fn play_cue(cue: Cue, volume: f32) {
+    let volume = volume.clamp(0.0, 1.0);
+    if volume == 0.0 {
+        return;
+    }
+    // The stream is built and dropped on this thread (cpal streams aren't
+    // Send on every platform)
+    let spawned = std::thread::Builder::new()
+        .name("whis-cue".into())
+        .spawn(move || {
+            if let Err(e) = play_blocking(cue, volume) {
+                crate::verbose!("Couldn't play sound cue: {e}");
+            }
+        });
+    if let Err(e) = spawned {
+        crate::verbose!("Couldn't start sound cue thread: {e}");
+    }
+}
+
+/// Play `cue` at `volume` (0.0-1.0) and return once it has finished
+///
+/// For the start cue, which must be over before capture begins. Does
+/// nothing at volume 0.
+pub fn play_cue_and_wait(cue: Cue, volume: f32) {
+    let volume = volume.clamp(0.0, 1.0);
+    if volume == 0.0 {
+        return;
+    }
+    if let Err(e) = play_blocking(cue, volume) {
+        crate::verbose!("Couldn't play sound cue: {e}");
+    }
+}
+
+/// Play `cue` on the default output device and wait until it's done
+fn play_blocking(cue: Cue, volume: f32) -> anyhow::Result<()> {
+    let device = cpal::default_host()
+        .default_output_device()
+        .ok_or_else(|| anyhow::anyhow!("no output device"))?;
+    let config = device.default_output_config()?;
+    let stream_config: cpal::StreamConfig = config.clone().into();
+    let samples = cue_samples(cue, stream_config.sample_rate, volume);
+    let duration = Duration::from_secs_f64(samples.len() as f64 / stream_config.sample_rate as f64);
+
+    let stream = match config.sample_format() {
+        cpal::SampleFormat::F32 => build_stream::<f32>(&device, &stream_config, samples)?,
+        cpal::SampleFormat::I16 => build_stream::<i16>(&device, &stream_config, samples)?,
+        cpal::SampleFormat::I32 => build_stream::<i32>(&device, &stream_config, samples)?,
+        cpal::SampleFormat::U16 => build_stream::<u16>(&device, &stream_config, samples)?,
+        format => anyhow::bail!("unsupported output format {format}"),
+    };
+    stream.play()?;
+    // Let the device drain its buffer before the stream is dropped
+    std::thread::sleep(duration + Duration::from_millis(100));
+    Ok(())
+}
+
+/// Output stream writing `samples` to every channel, then silence
+fn build_stream<T>(
+    device: &cpal::Device,
+    config: &cpal::StreamConfig,
+    samples: Vec<f32>,
+) -> anyhow::Result<cpal::Stream>
+where
+    T: cpal::SizedSample + cpal::FromSample<f32>,
+{
+    let channels = config.channels as usize;
+    let mut samples = samples.into_iter();
+    let stream = device.build_output_stream(
+        config,
+        move |data: &mut [T], _: &cpal::OutputCallbackInfo| {
+            for frame in data.chunks_mut(channels) {
+                let value = T::from_sample(samples.next().unwrap_or(0.0));
+                frame.fill(value);
+            }
+        },
+        |err| crate::verbose!("Sound cue stream error: {err}"),
+        None,
+    )?;
+    Ok(stream)
+}
+
+/// Mono samples of `cue` at `sample_rate`, scaled by `volume`
+fn cue_samples(cue: Cue, sample_rate: u32, volume: f32) -> Vec<f32> {
+    let rate = sample_rate as f32;
+    let fade = (FADE.as_secs_f32() * rate) as usize;
+    let gap = (GAP.as_secs_f32() * rate) as usize;
+    let mut samples = Vec::new();
+    for (i, &(freq, duration)) in cue.tones().iter().enumerate() {
+        if i > 0 {
+            samples.extend(std::iter::repeat_n(0.0, gap));
+        }
+        let len = (duration.as_secs_f32() * rate) as usize;
+        samples.extend((0..len).map(|n| {
+            let envelope = (n.min(len - 1 - n) as f32 / fade.max(1) as f32).min(1.0);
+            let phase = 2.0 * std::f32::consts::PI * freq * n as f32 / rate;
+            phase.sin() * envelope * volume
+        }));
+    }
+    samples
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cue_samples_fade_and_scale() {
+        let samples = cue_samples(Cue::RecordingStarted, 48_000, 0.5);
+        // Two 70ms tones and a 40ms gap
+        assert_eq!(samples.len(), 3360 + 1920 + 3360);
+        assert_eq!(samples[0], 0.0);
+        assert!(samples.last().unwrap().abs() < 0.01);
+        assert!(samples.iter().all(|s| s.abs() <= 0.5));
+    }
+}
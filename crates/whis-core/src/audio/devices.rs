@@ -41,13 +41,24 @@ mod alsa_suppress {
 
     static INIT: Once = Once::new();
 
+    /// Set to `1` to keep ALSA's diagnostics (for debugging device problems)
+    const VERBOSE_ENV: &str = "WHIS_ALSA_VERBOSE";
+
     /// Initialize ALSA error suppression.
     ///
     /// NOTE: This function can be safely removed without affecting functionality.
     /// It only suppresses noisy log output about unavailable PCM plugins (pulse, jack, oss).
     /// The unsafe FFI code here is purely cosmetic - audio works fine without it.
+    ///
+    /// Skipped with `WHIS_ALSA_VERBOSE=1`, since the same handler also hides
+    /// real device errors.
     pub fn init() {
         INIT.call_once(|| {
+            if std::env::var(VERBOSE_ENV).is_ok_and(|v| v == "1") {
+                crate::verbose!("{VERBOSE_ENV}=1: showing ALSA diagnostics");
+                return;
+            }
+
             // SAFETY: We provide a valid no-op error handler function.
             // This suppresses ALSA's error messages about unavailable PCM plugins.
             unsafe {
@@ -217,19 +228,29 @@ fn list_cpal_devices() -> Result<Vec<AudioDeviceInfo>> {
             let raw_name = desc.to_string();
 
             // Filter out virtual/null devices that aren't real microphones
-            if is_virtual_device(&raw_name) {
+            if is_virtual_device(&raw_name)
+                || desc.device_type() == cpal::DeviceType::Virtual
+                || desc.interface_type() == cpal::InterfaceType::Virtual
+            {
                 continue;
             }
 
-            let display_name = clean_device_name(&raw_name);
+            // Some hosts (e.g. AAudio on Android) report device and connection
+            // type; most desktop hosts leave these as Unknown
+            let form_factor = (desc.device_type() != cpal::DeviceType::Unknown)
+                .then(|| desc.device_type().to_string().to_lowercase());
+            let bus = (desc.interface_type() != cpal::InterfaceType::Unknown)
+                .then(|| desc.interface_type().to_string().to_lowercase());
+
+            let display_name =
+                typed_display_name(&desc).unwrap_or_else(|| clean_device_name(&raw_name));
 
             devices.push(AudioDeviceInfo {
                 name: raw_name.clone(),
                 display_name: Some(display_name),
                 is_default: default_device_name.as_ref() == Some(&raw_name),
-                // cpal doesn't provide rich metadata
-                form_factor: None,
-                bus: None,
+                form_factor,
+                bus,
                 is_monitor: false,
             });
         }
@@ -267,6 +288,28 @@ fn is_virtual_device(name: &str) -> bool {
     false
 }
 
+/// Build an Android display name from the device and connection type.
+///
+/// On Android every built-in input reports the phone model as its name, so
+/// the type is what tells "Built-in microphone" apart from a wired or
+/// Bluetooth headset. Desktop hosts keep their cleaned-up names.
+fn typed_display_name(desc: &cpal::DeviceDescription) -> Option<String> {
+    if !cfg!(target_os = "android") {
+        return None;
+    }
+
+    let kind = match (desc.interface_type(), desc.device_type()) {
+        (cpal::InterfaceType::Unknown, cpal::DeviceType::Unknown) => return None,
+        (cpal::InterfaceType::Unknown, device_type) => device_type.to_string(),
+        (interface, cpal::DeviceType::Unknown) => format!("{interface} input"),
+        (interface, device_type) => {
+            format!("{interface} {}", device_type.to_string().to_lowercase())
+        }
+    };
+
+    Some(format!("{kind} ({})", desc.name()))
+}
+
 /// Clean up a device name for display.
 fn clean_device_name(name: &str) -> String {
     let mut cleaned = name.to_string();
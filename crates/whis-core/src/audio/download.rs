@@ -0,0 +1,180 @@
+//! Downloading remote audio files into memory
+//!
+//! Uses the shared HTTP client, so the usual `HTTPS_PROXY`/`HTTP_PROXY`/
+//! `NO_PROXY` environment variables apply, and retries transient failures
+//! with the same backoff as transcription requests (downloads are idempotent,
+//! so any transient network error is retried). The response is checked
+//! before and while reading it:
+//! - The size is capped (from `Content-Length` up front, and while streaming
+//!   for servers that don't send one)
+//! - Obvious non-audio content types (HTML error pages, JSON) are rejected
+//!
+//! The bytes are returned as-is; decoding sniffs the actual format.
+
+use anyhow::{Context, Result, bail};
+use futures_util::StreamExt;
+use reqwest::Url;
+
+use crate::http::{get_http_client, request_timeouts};
+use crate::provider::retry::{
+    RetryConfig, is_rate_limited, is_retryable_idempotent_error, is_retryable_status,
+};
+
+/// Largest audio download accepted (256 MiB, about 25 minutes of CD-quality WAV)
+pub const MAX_AUDIO_DOWNLOAD_BYTES: u64 = 256 * 1024 * 1024;
+
+/// Download an audio file over HTTP(S), up to `max_bytes`
+pub async fn download_audio(url: &str, max_bytes: u64) -> Result<Vec<u8>> {
+    let url = Url::parse(url).with_context(|| format!("Invalid URL: {url}"))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        bail!(
+            "Unsupported URL scheme '{}' (use http or https)",
+            url.scheme()
+        );
+    }
+
+    let client = get_http_client()?;
+    let config = RetryConfig::default();
+    let mut attempt = 0;
+
+    let response = loop {
+        let result = client
+            .get(url.clone())
+            .timeout(request_timeouts().request())
+            .send()
+            .await;
+
+        match result {
+            Ok(response) if response.status().is_success() => break response,
+            Ok(response) => {
+                let status = response.status();
+                if is_retryable_status(status) && attempt < config.max_retries {
+                    let delay = config.delay_for_attempt(attempt, is_rate_limited(status));
+                    crate::verbose!(
+                        "Download failed with {} (attempt {}/{}), retrying in {:?}",
+                        status,
+                        attempt + 1,
+                        config.max_retries,
+                        delay
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                    continue;
+                }
+                bail!("Download failed ({status}): {url}");
+            }
+            Err(err) => {
+                if is_retryable_idempotent_error(&err) && attempt < config.max_retries {
+                    let delay = config.delay_for_attempt(attempt, false);
+                    crate::verbose!(
+                        "Download failed with network error (attempt {}/{}), retrying in {:?}: {}",
+                        attempt + 1,
+                        config.max_retries,
+                        delay,
+                        err
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                    continue;
+                }
+                return Err(err).with_context(|| format!("Failed to download {url}"));
+            }
+        }
+    };
+
+    if let Some(content_type) = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        && !is_audio_content_type(content_type)
+    {
+        bail!("URL doesn't point to an audio file (content type: {content_type})");
+    }
+
+    if let Some(length) = response.content_length()
+        && length > max_bytes
+    {
+        bail!(
+            "Audio file is too large ({} MB, limit {} MB)",
+            length / (1024 * 1024),
+            max_bytes / (1024 * 1024)
+        );
+    }
+
+    let mut data = Vec::new();
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.context("Download interrupted")?;
+        if (data.len() + chunk.len()) as u64 > max_bytes {
+            bail!(
+                "Audio file is too large (over the {} MB limit)",
+                max_bytes / (1024 * 1024)
+            );
+        }
+        data.extend_from_slice(&chunk);
+    }
+
+    crate::verbose!("Downloaded {} bytes from {}", data.len(), url);
+    Ok(data)
+}
+
+/// Whether a content type may be audio
+///
+/// Lenient on purpose: many servers send audio as `application/octet-stream`
+/// or `binary/octet-stream`. Only types that are clearly something else
+/// (pages, JSON/XML error bodies, images) are rejected.
+fn is_audio_content_type(content_type: &str) -> bool {
+    let mime = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_lowercase();
+    !(mime.starts_with("text/")
+        || mime.starts_with("image/")
+        || mime.ends_with("json")
+        || mime.ends_with("xml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_audio_content_type_accepts_audio_and_binary() {
+        for content_type in [
+            "audio/mpeg",
+            "audio/wav; charset=binary",
+            "audio/ogg;codecs=opus",
+            "application/octet-stream",
+            "binary/octet-stream",
+            "video/mp4",
+            "",
+        ] {
+            assert!(is_audio_content_type(content_type), "{content_type}");
+        }
+    }
+
+    #[test]
+    fn test_is_audio_content_type_rejects_pages_and_error_bodies() {
+        for content_type in [
+            "text/html",
+            "text/html; charset=utf-8",
+            "application/json",
+            "application/problem+json",
+            "application/xml",
+            "application/xhtml+xml",
+            "image/png",
+        ] {
+            assert!(!is_audio_content_type(content_type), "{content_type}");
+        }
+    }
+
+    #[test]
+    fn test_is_audio_content_type_ignores_case_and_whitespace() {
+        assert!(is_audio_content_type("Audio/MPEG"));
+        assert!(is_audio_content_type("  APPLICATION/Octet-Stream ; x=1"));
+        assert!(!is_audio_content_type("TEXT/HTML; Charset=UTF-8"));
+        assert!(!is_audio_content_type(" Application/JSON"));
+    }
+}
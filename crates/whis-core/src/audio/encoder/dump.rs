@@ -0,0 +1,148 @@
+//! Copy of the encoded upload audio, for diagnostics
+//!
+//! An encoder wrapped by an `EncodedAudioDump` (see
+//! `RecorderConfig::with_encoded_audio_dump`) also writes every buffer it
+//! produces to the dump file, so users can listen to exactly what the
+//! provider received. Chunked uploads are appended in the order they were
+//! encoded; concatenated MP3 files still play as one stream (including the
+//! overlap between chunks).
+
+use anyhow::Result;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use super::AudioEncoder;
+use crate::provider::AudioFormat;
+
+/// File that receives a copy of everything the wrapped encoders produce
+///
+/// The file is replaced on the first write, and left alone if nothing gets
+/// encoded (local and streaming providers upload raw samples).
+#[derive(Debug)]
+pub struct EncodedAudioDump {
+    path: PathBuf,
+    /// Whether anything has been written yet (later writes append)
+    written: Mutex<bool>,
+}
+
+impl EncodedAudioDump {
+    /// Dump encoded audio to `path`
+    pub fn new(path: impl Into<PathBuf>) -> Arc<Self> {
+        Arc::new(Self {
+            path: path.into(),
+            written: Mutex::new(false),
+        })
+    }
+
+    /// The dump file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Whether any encoded audio was written to the dump file
+    pub fn written(&self) -> bool {
+        *self.written.lock().unwrap()
+    }
+
+    /// Wrap `encoder` so its output is also written to the dump file
+    pub fn wrap(self: &Arc<Self>, encoder: Arc<dyn AudioEncoder>) -> Arc<dyn AudioEncoder> {
+        Arc::new(DumpingEncoder {
+            inner: encoder,
+            dump: self.clone(),
+        })
+    }
+
+    /// Append `data` to the dump file (a failed write only warns)
+    fn write(&self, data: &[u8]) {
+        let mut written = self.written.lock().unwrap();
+        let result = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(*written)
+            .truncate(!*written)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(data));
+        match result {
+            Ok(()) => *written = true,
+            Err(e) => crate::warn!(
+                "Could not write encoded audio to {}: {e}",
+                self.path.display()
+            ),
+        }
+    }
+}
+
+struct DumpingEncoder {
+    inner: Arc<dyn AudioEncoder>,
+    dump: Arc<EncodedAudioDump>,
+}
+
+impl AudioEncoder for DumpingEncoder {
+    fn encode_samples(&self, samples: &[f32], sample_rate: u32) -> Result<Vec<u8>> {
+        let data = self.inner.encode_samples(samples, sample_rate)?;
+        self.dump.write(&data);
+        Ok(data)
+    }
+
+    fn format(&self) -> AudioFormat {
+        self.inner.format()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encoder whose output is the number of samples, as bytes
+    struct LengthEncoder;
+
+    impl AudioEncoder for LengthEncoder {
+        fn encode_samples(&self, samples: &[f32], _sample_rate: u32) -> Result<Vec<u8>> {
+            Ok(vec![samples.len() as u8; samples.len()])
+        }
+    }
+
+    #[test]
+    fn test_dump_truncates_then_appends() {
+        let path = std::env::temp_dir().join(format!("whis-dump-test-{}.bin", std::process::id()));
+        std::fs::write(&path, b"stale contents from an earlier run").unwrap();
+
+        let dump = EncodedAudioDump::new(&path);
+        assert!(!dump.written());
+
+        let encoder = dump.wrap(Arc::new(LengthEncoder));
+        assert_eq!(encoder.encode_samples(&[0.0; 2], 16000).unwrap(), [2, 2]);
+        assert!(dump.written());
+        assert_eq!(std::fs::read(&path).unwrap(), [2, 2]);
+
+        encoder.encode_samples(&[0.0; 3], 16000).unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), [2, 2, 3, 3, 3]);
+
+        // A new dump replaces the file again
+        let dump = EncodedAudioDump::new(&path);
+        dump.wrap(Arc::new(LengthEncoder))
+            .encode_samples(&[0.0; 1], 16000)
+            .unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), [1]);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_dump_untouched_without_output() {
+        let path = std::env::temp_dir().join(format!(
+            "whis-dump-test-untouched-{}.bin",
+            std::process::id()
+        ));
+        std::fs::write(&path, b"keep").unwrap();
+
+        let dump = EncodedAudioDump::new(&path);
+        let _encoder = dump.wrap(Arc::new(LengthEncoder));
+        assert!(!dump.written());
+        assert_eq!(std::fs::read(&path).unwrap(), b"keep");
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -14,6 +14,8 @@ use super::AudioEncoder;
 /// Uses mp3lame-encoder crate for high-quality MP3 encoding without external dependencies.
 pub struct EmbeddedEncoder {
     channels: u16,
+    /// Bitrate in kbps, one of `MP3_BITRATES`
+    bitrate: u32,
 }
 
 impl EmbeddedEncoder {
@@ -21,7 +23,15 @@ impl EmbeddedEncoder {
     ///
     /// Always configured for mono (1 channel) output.
     pub fn new() -> Self {
-        Self { channels: 1 }
+        Self::with_bitrate(super::DEFAULT_MP3_BITRATE)
+    }
+
+    /// Create an encoder with the given bitrate (kbps, one of `MP3_BITRATES`).
+    pub fn with_bitrate(bitrate: u32) -> Self {
+        Self {
+            channels: 1,
+            bitrate,
+        }
     }
 
     /// Convert f32 samples to i16 PCM format.
@@ -48,7 +58,7 @@ impl EmbeddedEncoder {
             .map_err(|e| anyhow::anyhow!("Failed to set sample rate: {:?}", e))?;
 
         builder
-            .set_brate(mp3lame_encoder::Bitrate::Kbps128)
+            .set_brate(lame_bitrate(self.bitrate))
             .map_err(|e| anyhow::anyhow!("Failed to set bitrate: {:?}", e))?;
 
         builder
@@ -106,6 +116,26 @@ impl EmbeddedEncoder {
     }
 }
 
+/// LAME bitrate for `kbps` (one of `MP3_BITRATES`; others fall back to 128)
+fn lame_bitrate(kbps: u32) -> mp3lame_encoder::Bitrate {
+    use mp3lame_encoder::Bitrate;
+
+    match kbps {
+        8 => Bitrate::Kbps8,
+        16 => Bitrate::Kbps16,
+        24 => Bitrate::Kbps24,
+        32 => Bitrate::Kbps32,
+        40 => Bitrate::Kbps40,
+        48 => Bitrate::Kbps48,
+        64 => Bitrate::Kbps64,
+        80 => Bitrate::Kbps80,
+        96 => Bitrate::Kbps96,
+        112 => Bitrate::Kbps112,
+        160 => Bitrate::Kbps160,
+        _ => Bitrate::Kbps128,
+    }
+}
+
 impl Default for EmbeddedEncoder {
     fn default() -> Self {
         Self::new()
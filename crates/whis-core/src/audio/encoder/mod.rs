@@ -1,35 +1,137 @@
 //! Audio encoding module providing MP3 encoding via embedded LAME encoder.
+//!
+//! `AudioEncoder` is public so library users can plug in their own codec.
+//!
+//! # MP3 bitrate
+//!
+//! Recordings are 16kHz mono speech, which needs far fewer bits than music.
+//! The default of 128 kbps is transparent; 32-64 kbps cuts the upload to a
+//! quarter or half with no measurable effect on transcription accuracy for
+//! clear speech, which helps on slow or metered connections. Below 24 kbps
+//! LAME starts dropping high frequencies, and accuracy on quiet or accented
+//! speech suffers first. Set with `set_mp3_bitrate` (process-wide) or
+//! `RecorderConfig::with_mp3_bitrate`.
+//!
+//! An `EncodedAudioDump` keeps a copy of everything encoded, to check what
+//! the provider actually heard.
 
+mod dump;
 #[cfg(feature = "embedded-encoder")]
 mod embedded;
 
+pub use dump::EncodedAudioDump;
+
 use anyhow::Result;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use crate::provider::AudioFormat;
 
-/// Trait for encoding raw audio samples to compressed formats.
+/// Trait for encoding raw audio samples for upload to a transcription provider.
+///
+/// This is the extension point for custom codecs: implement it and pass the
+/// encoder via `RecorderConfig::with_encoder` (or
+/// `TranscribeOptions::recorder`). The default is MP3 (see `create_encoder`).
+///
+/// # Contract
+///
+/// - Input is f32 PCM in `-1.0..=1.0`, mono, at `sample_rate` (always 16kHz
+///   for whis recordings and decoded files).
+/// - Output is a complete file in the encoder's container format, ready to be
+///   uploaded as-is. `format()` names that container; its extension and MIME
+///   type are used for the upload's filename and `Content-Type`.
+/// - Encoders are shared across threads and may be called concurrently for
+///   different chunks, so keep per-call state local to `encode_samples`.
 pub trait AudioEncoder: Send + Sync {
-    /// Encode raw f32 PCM samples to MP3.
+    /// Encode raw f32 PCM samples.
     ///
     /// # Parameters
     /// - `samples`: Raw audio samples (f32 PCM, expected to be 16kHz mono)
     /// - `sample_rate`: Sample rate of the input audio
     ///
     /// # Returns
-    /// Encoded MP3 data as bytes
+    /// Encoded audio data as bytes
     fn encode_samples(&self, samples: &[f32], sample_rate: u32) -> Result<Vec<u8>>;
+
+    /// Container format of the encoded data (sets the upload's filename and MIME type)
+    fn format(&self) -> AudioFormat {
+        AudioFormat::Mp3
+    }
+}
+
+/// Default MP3 bitrate (kbps)
+pub const DEFAULT_MP3_BITRATE: u32 = 128;
+
+/// MP3 bitrates (kbps) LAME can encode at 16kHz (MPEG-2 Layer III)
+pub const MP3_BITRATES: &[u32] = &[8, 16, 24, 32, 40, 48, 64, 80, 96, 112, 128, 160];
+
+/// Bitrate used by `create_encoder` (`transcription.mp3_bitrate`)
+static MP3_BITRATE: AtomicU32 = AtomicU32::new(DEFAULT_MP3_BITRATE);
+
+/// Nearest valid MP3 bitrate to `kbps` (see `MP3_BITRATES`)
+///
+/// Rounds down between two values, so the upload never gets bigger than asked.
+pub fn clamp_mp3_bitrate(kbps: u32) -> u32 {
+    MP3_BITRATES
+        .iter()
+        .rev()
+        .find(|&&valid| valid <= kbps)
+        .copied()
+        .unwrap_or(MP3_BITRATES[0])
+}
+
+/// Set the bitrate of encoders from `create_encoder` (clamped to a valid value)
+pub fn set_mp3_bitrate(kbps: u32) {
+    MP3_BITRATE.store(clamp_mp3_bitrate(kbps), Ordering::Relaxed);
 }
 
 /// Create the audio encoder using embedded LAME library.
 ///
 /// Uses mp3lame-encoder crate which wraps the same LAME library as FFmpeg's libmp3lame,
 /// so audio quality is identical while eliminating the FFmpeg runtime dependency.
+/// Encodes at the bitrate from `set_mp3_bitrate` (128 kbps by default).
 pub fn create_encoder() -> Box<dyn AudioEncoder> {
+    create_mp3_encoder(MP3_BITRATE.load(Ordering::Relaxed))
+}
+
+/// Create an MP3 encoder with the given bitrate (kbps, clamped to a valid value)
+pub fn create_mp3_encoder(kbps: u32) -> Box<dyn AudioEncoder> {
     #[cfg(feature = "embedded-encoder")]
-    {
-        Box::new(embedded::EmbeddedEncoder::new())
-    }
+    let encoder: Box<dyn AudioEncoder> = Box::new(embedded::EmbeddedEncoder::with_bitrate(
+        clamp_mp3_bitrate(kbps),
+    ));
 
     #[cfg(not(feature = "embedded-encoder"))]
-    {
-        panic!("No audio encoder available. Enable the 'embedded-encoder' feature.");
+    let encoder: Box<dyn AudioEncoder> = {
+        let _ = kbps;
+        Box::new(UnavailableEncoder)
+    };
+
+    encoder
+}
+
+/// Placeholder used when built without `embedded-encoder`.
+///
+/// Fails at encode time instead of panicking, so paths that never upload
+/// MP3 (local transcription, realtime streaming) keep working.
+#[cfg(not(feature = "embedded-encoder"))]
+struct UnavailableEncoder;
+
+#[cfg(not(feature = "embedded-encoder"))]
+impl AudioEncoder for UnavailableEncoder {
+    fn encode_samples(&self, _samples: &[f32], _sample_rate: u32) -> Result<Vec<u8>> {
+        anyhow::bail!("No audio encoder available. Rebuild with the 'embedded-encoder' feature.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamp_mp3_bitrate() {
+        assert_eq!(clamp_mp3_bitrate(128), 128);
+        assert_eq!(clamp_mp3_bitrate(56), 48);
+        assert_eq!(clamp_mp3_bitrate(320), 160);
+        assert_eq!(clamp_mp3_bitrate(0), 8);
     }
 }
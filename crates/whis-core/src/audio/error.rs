@@ -9,6 +9,10 @@ pub enum AudioError {
     #[error("Audio device not found: {0}")]
     DeviceNotFound(String),
 
+    /// The OS denied access to the microphone
+    #[error("Microphone access denied: {0}")]
+    PermissionDenied(String),
+
     /// Failed to initialize or start recording
     #[error("Recording failed: {0}")]
     RecordingFailed(String),
@@ -57,18 +61,74 @@ impl AudioError {
     pub fn encoding_failed(msg: impl fmt::Display) -> Self {
         Self::EncodingFailed(msg.to_string())
     }
+
+    /// Create a recording failed error, or `PermissionDenied` if the
+    /// backend message says microphone access was refused
+    pub fn from_backend(msg: impl fmt::Display) -> Self {
+        let msg = msg.to_string();
+        if is_permission_message(&msg) {
+            Self::PermissionDenied(msg)
+        } else {
+            Self::RecordingFailed(msg)
+        }
+    }
+}
+
+/// Whether a backend error message means the OS refused microphone access
+///
+/// cpal reports these as backend-specific strings: CoreAudio/WASAPI
+/// (`E_ACCESSDENIED`, 0x80070005), ALSA (`EACCES`) and PulseAudio/PipeWire
+/// inside a Flatpak sandbox.
+fn is_permission_message(msg: &str) -> bool {
+    let msg = msg.to_lowercase();
+    [
+        "permission denied",
+        "access denied",
+        "access is denied",
+        "not authorized",
+        "not permitted",
+        "e_accessdenied",
+        "0x80070005",
+        "eacces",
+    ]
+    .iter()
+    .any(|needle| msg.contains(needle))
+}
+
+/// OS-specific steps for granting microphone access
+pub fn microphone_permission_hint() -> &'static str {
+    #[cfg(target_os = "macos")]
+    {
+        "Open System Settings → Privacy & Security → Microphone, enable your terminal app \
+         (or Whis), then restart it completely (Cmd+Q, then reopen)."
+    }
+    #[cfg(target_os = "windows")]
+    {
+        "Open Settings → Privacy & security → Microphone and turn on \
+         \"Let desktop apps access your microphone\"."
+    }
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    {
+        if std::env::var_os("FLATPAK_ID").is_some() {
+            "Allow microphone access in Settings → Privacy → Microphone, and make sure the \
+             Flatpak has audio access (e.g. flatpak override --user --socket=pulseaudio ink.whis.Whis)."
+        } else {
+            "Check that your user may access audio devices (e.g. the 'audio' group) and \
+             that the microphone isn't blocked in your desktop's privacy settings."
+        }
+    }
 }
 
 // Allow converting from cpal errors
 impl From<cpal::BuildStreamError> for AudioError {
     fn from(err: cpal::BuildStreamError) -> Self {
-        AudioError::RecordingFailed(err.to_string())
+        AudioError::from_backend(err)
     }
 }
 
 impl From<cpal::PlayStreamError> for AudioError {
     fn from(err: cpal::PlayStreamError) -> Self {
-        AudioError::RecordingFailed(err.to_string())
+        AudioError::from_backend(err)
     }
 }
 
@@ -77,3 +137,50 @@ impl From<cpal::DevicesError> for AudioError {
         AudioError::DeviceNotFound(err.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_permission_message() {
+        let cases = [
+            // CoreAudio / WASAPI
+            (
+                "The requested device is no longer available: E_ACCESSDENIED",
+                true,
+            ),
+            ("A backend-specific error has occurred: 0x80070005", true),
+            ("Access is denied. (os error 5)", true),
+            // ALSA
+            (
+                "ALSA function 'snd_pcm_open' failed with error 'EACCES: Permission denied'",
+                true,
+            ),
+            // PulseAudio / PipeWire in a sandbox
+            ("Connection refused: Access denied", true),
+            ("Operation not permitted", true),
+            ("Client is not authorized", true),
+            // Unrelated failures
+            ("The requested device is no longer available", false),
+            ("The requested stream configuration is not supported", false),
+            ("Device or resource busy", false),
+            ("", false),
+        ];
+        for (msg, expected) in cases {
+            assert_eq!(is_permission_message(msg), expected, "{msg}");
+        }
+    }
+
+    #[test]
+    fn test_from_backend_maps_permission_errors() {
+        assert!(matches!(
+            AudioError::from_backend("Permission denied"),
+            AudioError::PermissionDenied(_)
+        ));
+        assert!(matches!(
+            AudioError::from_backend("Device or resource busy"),
+            AudioError::RecordingFailed(_)
+        ));
+    }
+}
@@ -0,0 +1,170 @@
+//! Audio file decoding
+//!
+//! Only WAV is decoded natively; whis never invokes ffmpeg itself. Other
+//! formats have to be converted by the user first (the error says how).
+//!
+//! Files are detected by extension; in-memory data (e.g. downloads) by its
+//! leading bytes, see [`decode_audio_bytes`]. Callers that know better can
+//! name the format instead, see [`decode_audio_as`].
+
+use anyhow::{Context, Result};
+use std::io::Read;
+use std::path::Path;
+
+use crate::resample::resample_to_16k;
+
+/// Read an audio file and return 16kHz mono f32 samples
+pub fn read_audio_file(path: &Path) -> Result<Vec<f32>> {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase());
+
+    match extension.as_deref() {
+        Some("wav") => read_wav(path),
+        Some(ext) => anyhow::bail!(
+            "Unsupported audio format: .{}\nCurrently supported: WAV\n\
+             Convert first, e.g.: ffmpeg -i {} -ar 16000 -ac 1 audio.wav",
+            ext,
+            path.display()
+        ),
+        None => anyhow::bail!("File has no extension. Please provide a WAV file."),
+    }
+}
+
+/// Decode in-memory audio and return 16kHz mono f32 samples
+///
+/// The format is sniffed from the data itself, since downloads don't
+/// reliably carry a file extension or content type.
+pub fn decode_audio_bytes(data: &[u8]) -> Result<Vec<f32>> {
+    match sniff_format(data) {
+        Some("WAV") => decode_wav_bytes(data),
+        Some(format) => Err(unsupported_format(format)),
+        None => anyhow::bail!("Unrecognized audio data (expected a WAV file)"),
+    }
+}
+
+/// Decode in-memory audio of a given `format` and return 16kHz mono f32 samples
+///
+/// For data the sniffer misses or that arrives without a name, like audio
+/// piped in by another tool. `format` is case-insensitive and may be written
+/// as an extension (`wav`, `.wav`).
+pub fn decode_audio_as(data: &[u8], format: &str) -> Result<Vec<f32>> {
+    match format_from_name(format) {
+        Some("WAV") => decode_wav_bytes(data),
+        Some(format) => Err(unsupported_format(format)),
+        None => anyhow::bail!("Unknown audio format: {format}\nCurrently supported: WAV"),
+    }
+}
+
+/// Error for a recognized format that can't be decoded
+fn unsupported_format(format: &str) -> anyhow::Error {
+    anyhow::anyhow!(
+        "Unsupported audio format: {format}\nCurrently supported: WAV\n\
+         Convert first, e.g.: ffmpeg -i <input> -ar 16000 -ac 1 audio.wav"
+    )
+}
+
+/// Container format for a user-given name or extension (as named by `sniff_format`)
+fn format_from_name(name: &str) -> Option<&'static str> {
+    match name.trim().trim_start_matches('.').to_lowercase().as_str() {
+        "wav" | "wave" => Some("WAV"),
+        "mp3" => Some("MP3"),
+        "aac" => Some("AAC"),
+        "ogg" | "oga" | "opus" => Some("Ogg"),
+        "flac" => Some("FLAC"),
+        "mp4" | "m4a" => Some("MP4/M4A"),
+        "webm" | "mkv" => Some("WebM/Matroska"),
+        _ => None,
+    }
+}
+
+/// Name of the container format, from its magic bytes
+fn sniff_format(data: &[u8]) -> Option<&'static str> {
+    match data {
+        [
+            b'R',
+            b'I',
+            b'F',
+            b'F',
+            _,
+            _,
+            _,
+            _,
+            b'W',
+            b'A',
+            b'V',
+            b'E',
+            ..,
+        ] => Some("WAV"),
+        [b'I', b'D', b'3', ..] => Some("MP3"),
+        [0xFF, second, ..] if second & 0xF6 == 0xF0 => Some("AAC"),
+        [0xFF, second, ..] if second & 0xE0 == 0xE0 => Some("MP3"),
+        [b'O', b'g', b'g', b'S', ..] => Some("Ogg"),
+        [b'f', b'L', b'a', b'C', ..] => Some("FLAC"),
+        [_, _, _, _, b'f', b't', b'y', b'p', ..] => Some("MP4/M4A"),
+        [0x1A, 0x45, 0xDF, 0xA3, ..] => Some("WebM/Matroska"),
+        _ => None,
+    }
+}
+
+/// Read a WAV file and resample to 16kHz mono
+fn read_wav(path: &Path) -> Result<Vec<f32>> {
+    let reader = hound::WavReader::open(path).context("Failed to open WAV file")?;
+    decode_wav(reader)
+}
+
+/// Parse in-memory WAV data and resample to 16kHz mono
+fn decode_wav_bytes(data: &[u8]) -> Result<Vec<f32>> {
+    let reader =
+        hound::WavReader::new(std::io::Cursor::new(data)).context("Failed to parse WAV data")?;
+    decode_wav(reader)
+}
+
+/// Decode WAV samples and resample to 16kHz mono
+fn decode_wav<R: Read>(mut reader: hound::WavReader<R>) -> Result<Vec<f32>> {
+    let spec = reader.spec();
+    let sample_rate = spec.sample_rate;
+    let channels = spec.channels;
+
+    // Read samples based on format
+    let samples: Vec<f32> = match spec.sample_format {
+        hound::SampleFormat::Float => reader
+            .samples::<f32>()
+            .collect::<Result<Vec<_>, _>>()
+            .context("Failed to read float samples")?,
+        hound::SampleFormat::Int => {
+            let bits = spec.bits_per_sample;
+            let max_val = (1u32 << (bits - 1)) as f32;
+            reader
+                .samples::<i32>()
+                .map(|s| s.map(|v| v as f32 / max_val))
+                .collect::<Result<Vec<_>, _>>()
+                .context("Failed to read int samples")?
+        }
+    };
+
+    // Resample to 16kHz mono if needed
+    resample_to_16k(&samples, sample_rate, channels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_from_name_accepts_extensions() {
+        assert_eq!(format_from_name("wav"), Some("WAV"));
+        assert_eq!(format_from_name(".WAV"), Some("WAV"));
+        assert_eq!(format_from_name("m4a"), Some("MP4/M4A"));
+        assert_eq!(format_from_name("xyz"), None);
+    }
+
+    #[test]
+    fn test_decode_audio_as_reports_unsupported_formats() {
+        let error = decode_audio_as(b"", "mp3").unwrap_err().to_string();
+        assert!(error.starts_with("Unsupported audio format: MP3"));
+        let error = decode_audio_as(b"", "xyz").unwrap_err().to_string();
+        assert!(error.starts_with("Unknown audio format: xyz"));
+    }
+}
@@ -4,6 +4,10 @@
 //! - Real-time resampling to 16kHz mono
 //! - Voice Activity Detection (optional, via `vad` feature)
 //! - MP3 encoding via embedded encoder
+//! - WAV file decoding (via `wav` feature)
+//! - Downloading remote audio files
+//! - Splitting a continuous stream into utterances (listen mode)
+//! - Sound cues on recording state changes
 //!
 //! # Architecture
 //!
@@ -34,10 +38,15 @@
 //! - **Linux**: ALSA stderr suppression via safe FFI wrapper
 
 pub mod chunker;
+mod cues;
 mod devices;
+mod download;
 mod encoder;
 pub mod error;
+#[cfg(feature = "wav")]
+mod file;
 mod recorder;
+mod segmenter;
 mod types;
 mod vad;
 
@@ -47,10 +56,21 @@ mod pulse;
 
 // Re-export public types
 pub use chunker::{AudioChunk as ProgressiveChunk, ChunkerConfig, ProgressiveChunker};
+pub use cues::{Cue, play_cue, play_cue_and_wait};
 pub use devices::list_audio_devices;
-pub use encoder::{AudioEncoder, create_encoder};
-pub use error::AudioError;
-pub use recorder::{AudioRecorder, AudioStreamSender, RecorderConfig, RecordingData};
+pub use download::{MAX_AUDIO_DOWNLOAD_BYTES, download_audio};
+pub use encoder::{
+    AudioEncoder, DEFAULT_MP3_BITRATE, EncodedAudioDump, MP3_BITRATES, clamp_mp3_bitrate,
+    create_encoder, create_mp3_encoder, set_mp3_bitrate,
+};
+pub use error::{AudioError, microphone_permission_hint};
+#[cfg(feature = "wav")]
+pub use file::{decode_audio_as, decode_audio_bytes, read_audio_file};
+pub use recorder::{
+    AudioRecorder, AudioStreamSender, LevelCallback, RecorderConfig, RecordingData,
+    SILENCE_PEAK_THRESHOLD,
+};
+pub use segmenter::{SegmenterConfig, Utterance, UtteranceSegmenter};
 pub use types::AudioDeviceInfo;
 
 // Re-export VAD types (always available - no-op when feature disabled)
@@ -2,6 +2,8 @@
 //!
 //! Uses libpulse-binding to access device properties like form-factor,
 //! bus type, and monitor status for reliable device classification.
+//! [`find_source`] also resolves a device name to the source (and its
+//! native sample spec) that native capture records from.
 
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -12,15 +14,77 @@ use libpulse_binding::{
     context::{self, Context as PulseContext, FlagSet, introspect::SourceInfo},
     mainloop::standard::{IterateResult, Mainloop},
     proplist::Proplist,
+    sample::Spec,
 };
 
+use super::devices::fuzzy_device_match;
 use super::types::AudioDeviceInfo;
 
+/// A PulseAudio source to record from
+#[derive(Debug, Clone)]
+pub struct PulseSource {
+    /// Technical name (e.g. `alsa_input.usb-...`)
+    pub name: String,
+    /// Human-readable description
+    pub description: Option<String>,
+    /// Native sample spec (rate and channels are recorded as-is)
+    pub spec: Spec,
+}
+
 /// Get audio input devices with PulseAudio metadata.
 ///
 /// Returns devices with form_factor, bus, and is_monitor populated.
 /// Filters out monitor sources automatically.
 pub fn list_pulse_devices() -> Result<Vec<AudioDeviceInfo>> {
+    let (devices, _) = query_sources(source_info_to_device)?;
+
+    if devices.is_empty() {
+        anyhow::bail!("No audio input devices found via PulseAudio");
+    }
+
+    Ok(devices)
+}
+
+/// Find the source for a stored device name (None = the default source)
+///
+/// Names are matched against the source's technical name and description,
+/// so both PulseAudio and CPAL-style names resolve. Monitor sources are
+/// never returned.
+pub fn find_source(device_name: Option<&str>) -> Result<Option<PulseSource>> {
+    let (sources, default_source) = query_sources(|info, _| {
+        if info.monitor_of_sink.is_some() {
+            return None;
+        }
+        Some(PulseSource {
+            name: info.name.as_ref()?.to_string(),
+            description: info.description.as_ref().map(|d| d.to_string()),
+            spec: info.sample_spec,
+        })
+    })?;
+
+    Ok(select_source(&sources, device_name.or(default_source.as_deref())).cloned())
+}
+
+/// The source `wanted` names: an exact name or description first, then a
+/// fuzzy description match (CPAL-style names)
+fn select_source<'a>(sources: &'a [PulseSource], wanted: Option<&str>) -> Option<&'a PulseSource> {
+    let wanted = wanted?;
+    let matches = |source: &&PulseSource, fuzzy: bool| {
+        source.name == wanted
+            || source.description.as_deref().is_some_and(|description| {
+                description == wanted || (fuzzy && fuzzy_device_match(wanted, description))
+            })
+    };
+    sources
+        .iter()
+        .find(|s| matches(s, false))
+        .or_else(|| sources.iter().find(|s| matches(s, true)))
+}
+
+/// Map every source with `f` and return the results and the default source name
+fn query_sources<T: 'static>(
+    f: impl Fn(&SourceInfo, &Option<String>) -> Option<T> + 'static,
+) -> Result<(Vec<T>, Option<String>)> {
     // Create mainloop
     let mainloop = Rc::new(RefCell::new(
         Mainloop::new().context("Failed to create PulseAudio mainloop")?,
@@ -57,7 +121,7 @@ pub fn list_pulse_devices() -> Result<Vec<AudioDeviceInfo>> {
     }
 
     // Collect devices
-    let devices: Rc<RefCell<Vec<AudioDeviceInfo>>> = Rc::new(RefCell::new(Vec::new()));
+    let devices: Rc<RefCell<Vec<T>>> = Rc::new(RefCell::new(Vec::new()));
     let done = Rc::new(RefCell::new(false));
 
     // Get default source name for marking default device
@@ -97,7 +161,7 @@ pub fn list_pulse_devices() -> Result<Vec<AudioDeviceInfo>> {
         let introspector = context.borrow().introspect();
         introspector.get_source_info_list(move |result| match result {
             ListResult::Item(info) => {
-                if let Some(device) = source_info_to_device(info, &default_source_clone.borrow()) {
+                if let Some(device) = f(info, &default_source_clone.borrow()) {
                     devices_clone.borrow_mut().push(device);
                 }
             }
@@ -123,12 +187,9 @@ pub fn list_pulse_devices() -> Result<Vec<AudioDeviceInfo>> {
     let result = Rc::try_unwrap(devices)
         .map_err(|_| anyhow::anyhow!("Failed to unwrap devices"))?
         .into_inner();
+    let default_source = default_source.borrow_mut().take();
 
-    if result.is_empty() {
-        anyhow::bail!("No audio input devices found via PulseAudio");
-    }
-
-    Ok(result)
+    Ok((result, default_source))
 }
 
 /// Convert PulseAudio SourceInfo to our AudioDeviceInfo.
@@ -177,3 +238,74 @@ fn extract_properties(proplist: &Proplist) -> (Option<String>, Option<String>) {
 
     (form_factor, bus)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libpulse_binding::sample::Format;
+
+    const BUILT_IN: &str = "alsa_input.pci-0000_00_1f.3.analog-stereo";
+    const YETI: &str = "alsa_input.usb-Blue_Microphones_Yeti_Stereo_Microphone-00.analog-stereo";
+
+    fn source(name: &str, description: &str) -> PulseSource {
+        PulseSource {
+            name: name.to_string(),
+            description: Some(description.to_string()),
+            spec: Spec {
+                format: Format::S16le,
+                rate: 48000,
+                channels: 2,
+            },
+        }
+    }
+
+    fn sources() -> Vec<PulseSource> {
+        vec![
+            source(BUILT_IN, "Built-in Audio Analog Stereo"),
+            source(YETI, "Yeti Stereo Microphone Analog Stereo"),
+        ]
+    }
+
+    fn selected(sources: &[PulseSource], wanted: Option<&str>) -> Option<String> {
+        select_source(sources, wanted).map(|s| s.name.clone())
+    }
+
+    #[test]
+    fn test_select_source_by_name_or_description() {
+        let sources = sources();
+        assert_eq!(selected(&sources, Some(YETI)).as_deref(), Some(YETI));
+        assert_eq!(
+            selected(&sources, Some("Built-in Audio Analog Stereo")).as_deref(),
+            Some(BUILT_IN)
+        );
+    }
+
+    #[test]
+    fn test_select_source_fuzzy_cpal_name() {
+        let sources = sources();
+        assert_eq!(
+            selected(&sources, Some("Yeti Stereo Microphone")).as_deref(),
+            Some(YETI)
+        );
+    }
+
+    #[test]
+    fn test_select_source_prefers_exact_over_fuzzy() {
+        let sources = vec![
+            source("usb-yeti-analog", "Yeti Microphone Analog Stereo"),
+            source("usb-yeti", "Yeti Microphone"),
+        ];
+        assert_eq!(
+            selected(&sources, Some("Yeti Microphone")).as_deref(),
+            Some("usb-yeti")
+        );
+    }
+
+    #[test]
+    fn test_select_source_without_match() {
+        let sources = sources();
+        assert_eq!(selected(&sources, Some("Scarlett 2i2")), None);
+        assert_eq!(selected(&sources, None), None);
+        assert_eq!(selected(&[], Some(YETI)), None);
+    }
+}
@@ -1,15 +1,42 @@
 //! Audio recorder configuration.
 
+use std::fmt;
+use std::sync::Arc;
+
+use super::super::encoder::{AudioEncoder, EncodedAudioDump, create_encoder, create_mp3_encoder};
 use super::super::vad::VadConfig;
 
 /// Configuration for the audio recorder.
-#[derive(Debug, Clone, Default)]
+#[derive(Clone, Default)]
 pub struct RecorderConfig {
     /// Device name to use (None = system default)
     pub device_name: Option<String>,
 
     /// Voice Activity Detection configuration (no-op when vad feature disabled)
     pub vad: VadConfig,
+
+    /// Encoder for uploading recordings (None = MP3 via `create_encoder`)
+    pub encoder: Option<Arc<dyn AudioEncoder>>,
+
+    /// Bitrate of the default MP3 encoder in kbps (None = `set_mp3_bitrate`'s)
+    ///
+    /// Ignored when a custom `encoder` is set.
+    pub mp3_bitrate: Option<u32>,
+
+    /// Copy everything `encoder()` encodes to this dump (None = no copy)
+    pub encoded_audio_dump: Option<Arc<EncodedAudioDump>>,
+}
+
+impl fmt::Debug for RecorderConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecorderConfig")
+            .field("device_name", &self.device_name)
+            .field("vad", &self.vad)
+            .field("encoder", &self.encoder.as_ref().map(|e| e.format()))
+            .field("mp3_bitrate", &self.mp3_bitrate)
+            .field("encoded_audio_dump", &self.encoded_audio_dump)
+            .finish()
+    }
 }
 
 impl RecorderConfig {
@@ -35,4 +62,40 @@ impl RecorderConfig {
         self.vad = VadConfig::disabled();
         self
     }
+
+    /// Use a custom encoder instead of the default MP3 encoder.
+    pub fn with_encoder(mut self, encoder: Arc<dyn AudioEncoder>) -> Self {
+        self.encoder = Some(encoder);
+        self
+    }
+
+    /// Set the bitrate of the default MP3 encoder (kbps, clamped to a valid value).
+    ///
+    /// Lower bitrates mean smaller uploads; see the `encoder` module docs for
+    /// the effect on accuracy.
+    pub fn with_mp3_bitrate(mut self, kbps: u32) -> Self {
+        self.mp3_bitrate = Some(kbps);
+        self
+    }
+
+    /// Write a copy of the encoded upload audio to `dump`.
+    pub fn with_encoded_audio_dump(mut self, dump: Arc<EncodedAudioDump>) -> Self {
+        self.encoded_audio_dump = Some(dump);
+        self
+    }
+
+    /// The configured encoder, or the default MP3 encoder.
+    ///
+    /// Wrapped to copy its output when an encoded audio dump is set.
+    pub fn encoder(&self) -> Arc<dyn AudioEncoder> {
+        let encoder = match (&self.encoder, self.mp3_bitrate) {
+            (Some(encoder), _) => encoder.clone(),
+            (None, Some(kbps)) => create_mp3_encoder(kbps).into(),
+            (None, None) => create_encoder().into(),
+        };
+        match &self.encoded_audio_dump {
+            Some(dump) => dump.wrap(encoder),
+            None => encoder,
+        }
+    }
 }
@@ -0,0 +1,250 @@
+//! Input stream config selection.
+//!
+//! The device's default config is used when its sample format is one the
+//! recorder converts to f32. Some interfaces (pro audio, unusual USB mics)
+//! default to formats like 24-bit packed or 64-bit float; for those the
+//! supported configs are searched for the best convertible one instead of
+//! failing to record.
+//!
+//! Devices that capture 16kHz mono natively (some USB and conferencing mics)
+//! are recorded that way, so no resampling is needed. Only configs listed at
+//! exactly 16kHz count: a continuous rate range means a software layer (e.g.
+//! the PulseAudio ALSA plugin) converts whatever is asked for, which is no
+//! better than resampling ourselves.
+
+use anyhow::Result;
+use cpal::traits::DeviceTrait;
+use cpal::{SampleFormat, SupportedStreamConfig, SupportedStreamConfigRange};
+
+use super::super::error::AudioError;
+use crate::error::WhisError;
+use crate::resample::WHISPER_SAMPLE_RATE;
+
+/// Sample formats the recorder converts to f32, best first
+///
+/// Float and common integer formats come first; exotic ones are a last resort.
+pub(super) const SUPPORTED_FORMATS: &[SampleFormat] = &[
+    SampleFormat::F32,
+    SampleFormat::I16,
+    SampleFormat::I32,
+    SampleFormat::I24,
+    SampleFormat::U16,
+    SampleFormat::U8,
+    SampleFormat::I8,
+    SampleFormat::F64,
+    SampleFormat::U32,
+    SampleFormat::U24,
+    SampleFormat::I64,
+    SampleFormat::U64,
+];
+
+/// Sample rate to aim for when the default config can't be used
+const FALLBACK_SAMPLE_RATE: u32 = 48_000;
+
+/// Preference of a sample format (lower is better), None if unsupported
+fn format_rank(format: SampleFormat) -> Option<usize> {
+    SUPPORTED_FORMATS.iter().position(|&f| f == format)
+}
+
+/// Pick the config to record from `device` with
+pub(super) fn input_config(device: &cpal::Device) -> Result<SupportedStreamConfig> {
+    let ranges: Option<Vec<_>> = device
+        .supported_input_configs()
+        .ok()
+        .map(|ranges| ranges.collect());
+    let default = device
+        .default_input_config()
+        .map_err(AudioError::from_backend);
+    select_config(default, ranges.as_deref())
+}
+
+/// Choose between the device's `default` config and the `ranges` it supports
+///
+/// In order: a native 16kHz mono config, the default if its format is
+/// convertible, then the best convertible range near the default's rate.
+/// `ranges` is None when the device couldn't list its configs.
+fn select_config(
+    default: std::result::Result<SupportedStreamConfig, AudioError>,
+    ranges: Option<&[SupportedStreamConfigRange]>,
+) -> Result<SupportedStreamConfig> {
+    if let Some(config) = ranges.and_then(native_whisper_config) {
+        return Ok(config);
+    }
+
+    let default = match default {
+        Ok(config) if format_rank(config.sample_format()).is_some() => return Ok(config),
+        Ok(config) => {
+            crate::verbose!(
+                "Default input format {} isn't supported, looking for another",
+                config.sample_format()
+            );
+            Ok(config)
+        }
+        Err(err @ AudioError::PermissionDenied(_)) => return Err(WhisError::from(err).into()),
+        Err(err) => Err(err),
+    };
+
+    // Nothing to choose from: report why the default didn't work
+    let Some(ranges) = ranges else {
+        return Err(match default {
+            Ok(config) => unsupported_error(&[config.sample_format()]),
+            Err(err) => anyhow::anyhow!("Failed to get default input config: {err}"),
+        });
+    };
+
+    let target_rate = default
+        .as_ref()
+        .map_or(FALLBACK_SAMPLE_RATE, |config| config.sample_rate());
+    let best = ranges
+        .iter()
+        .filter_map(|range| format_rank(range.sample_format()).map(|rank| (rank, range)))
+        .min_by_key(|(rank, range)| {
+            let covers_rate =
+                (range.min_sample_rate()..=range.max_sample_rate()).contains(&target_rate);
+            (*rank, !covers_rate, range.channels())
+        });
+
+    match best {
+        Some((_, range)) => {
+            let rate = target_rate.clamp(range.min_sample_rate(), range.max_sample_rate());
+            let config = range.clone().with_sample_rate(rate);
+            crate::verbose!(
+                "Recording as {} at {} Hz, {} channel(s)",
+                config.sample_format(),
+                config.sample_rate(),
+                config.channels()
+            );
+            Ok(config)
+        }
+        None => {
+            let offered: Vec<_> = ranges.iter().map(|range| range.sample_format()).collect();
+            Err(unsupported_error(&offered))
+        }
+    }
+}
+
+/// A config capturing 16kHz mono in hardware, if `ranges` offers one
+fn native_whisper_config(ranges: &[SupportedStreamConfigRange]) -> Option<SupportedStreamConfig> {
+    let (_, range) = ranges
+        .iter()
+        .filter(|range| {
+            range.channels() == 1
+                && range.min_sample_rate() == WHISPER_SAMPLE_RATE
+                && range.max_sample_rate() == WHISPER_SAMPLE_RATE
+        })
+        .filter_map(|range| format_rank(range.sample_format()).map(|rank| (rank, range)))
+        .min_by_key(|(rank, _)| *rank)?;
+    Some(range.clone().with_sample_rate(WHISPER_SAMPLE_RATE))
+}
+
+/// Error for a device that only offers `formats`, none of them usable
+fn unsupported_error(formats: &[SampleFormat]) -> anyhow::Error {
+    let mut names: Vec<String> = formats.iter().map(|f| f.to_string()).collect();
+    names.dedup();
+    WhisError::from(AudioError::InvalidConfig(format!(
+        "the input device only offers sample formats whis can't record ({}). \
+         Choose another microphone.",
+        names.join(", ")
+    )))
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_rank_prefers_float_and_common_formats() {
+        assert_eq!(format_rank(SampleFormat::F32), Some(0));
+        assert!(format_rank(SampleFormat::I16) < format_rank(SampleFormat::I24));
+        assert!(format_rank(SampleFormat::I24) < format_rank(SampleFormat::U8));
+        assert!(format_rank(SampleFormat::U8).is_some());
+    }
+
+    fn range(
+        channels: u16,
+        min_rate: u32,
+        max_rate: u32,
+        format: SampleFormat,
+    ) -> SupportedStreamConfigRange {
+        SupportedStreamConfigRange::new(
+            channels,
+            min_rate,
+            max_rate,
+            cpal::SupportedBufferSize::Unknown,
+            format,
+        )
+    }
+
+    fn config(channels: u16, rate: u32, format: SampleFormat) -> SupportedStreamConfig {
+        SupportedStreamConfig::new(channels, rate, cpal::SupportedBufferSize::Unknown, format)
+    }
+
+    #[test]
+    fn test_select_config_prefers_native_whisper_config() {
+        let ranges = [
+            range(2, 48_000, 48_000, SampleFormat::F32),
+            range(1, 16_000, 16_000, SampleFormat::I16),
+        ];
+        let picked =
+            select_config(Ok(config(2, 48_000, SampleFormat::F32)), Some(&ranges)).unwrap();
+        assert_eq!(picked, config(1, 16_000, SampleFormat::I16));
+    }
+
+    #[test]
+    fn test_select_config_ignores_rate_range_covering_16khz() {
+        // A continuous range means software resampling, not native capture
+        let ranges = [range(1, 8_000, 96_000, SampleFormat::F32)];
+        let default = config(2, 44_100, SampleFormat::F32);
+        let picked = select_config(Ok(default.clone()), Some(&ranges)).unwrap();
+        assert_eq!(picked, default);
+    }
+
+    #[test]
+    fn test_select_config_uses_convertible_default() {
+        let default = config(2, 44_100, SampleFormat::I16);
+        let picked = select_config(Ok(default.clone()), None).unwrap();
+        assert_eq!(picked, default);
+    }
+
+    fn no_default() -> std::result::Result<SupportedStreamConfig, AudioError> {
+        Err(AudioError::Other("no default".into()))
+    }
+
+    #[test]
+    fn test_select_config_falls_back_to_best_range() {
+        let ranges = [
+            range(2, 44_100, 48_000, SampleFormat::U8),
+            range(2, 8_000, 22_050, SampleFormat::I16),
+            range(1, 44_100, 96_000, SampleFormat::I16),
+            range(2, 44_100, 96_000, SampleFormat::I16),
+        ];
+        let picked = select_config(no_default(), Some(&ranges)).unwrap();
+        // Best format, then covering the target rate, then fewest channels
+        assert_eq!(picked, config(1, FALLBACK_SAMPLE_RATE, SampleFormat::I16));
+    }
+
+    #[test]
+    fn test_select_config_clamps_rate_to_range() {
+        let ranges = [range(1, 8_000, 22_050, SampleFormat::I16)];
+        let picked = select_config(no_default(), Some(&ranges)).unwrap();
+        assert_eq!(picked, config(1, 22_050, SampleFormat::I16));
+    }
+
+    #[test]
+    fn test_select_config_reports_permission_denied() {
+        let ranges = [range(2, 8_000, 96_000, SampleFormat::F32)];
+        let default = Err(AudioError::PermissionDenied("blocked".into()));
+        let err = select_config(default, Some(&ranges)).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<WhisError>(),
+            Some(WhisError::Audio(AudioError::PermissionDenied(_)))
+        ));
+    }
+
+    #[test]
+    fn test_select_config_errors_without_default_or_ranges() {
+        assert!(select_config(no_default(), None).is_err());
+        assert!(select_config(no_default(), Some(&[])).is_err());
+    }
+}
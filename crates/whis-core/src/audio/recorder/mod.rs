@@ -1,7 +1,10 @@
 //! Audio recording with real-time resampling and optional VAD.
 
 mod config;
+mod format;
 mod processor;
+#[cfg(all(target_os = "linux", feature = "pulse-capture"))]
+mod pulse;
 mod stream;
 
 pub use config::RecorderConfig;
@@ -12,19 +15,41 @@ use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 use std::sync::{Arc, Mutex};
 
 use super::devices;
+use super::error::AudioError;
 use super::vad::{VadConfig, VadProcessor};
+use crate::error::WhisError;
 use crate::resample::{FrameResampler, WHISPER_SAMPLE_RATE};
 
 use processor::SampleProcessor;
+use stream::InputSink;
+
+/// Raw samples of exact zeros (about half a second at 48 kHz) before a
+/// recording is treated as a denied microphone permission (macOS)
+const SILENT_PERMISSION_MIN_SAMPLES: u64 = 24_000;
+
+/// Whether a stream of `raw_samples` looks like a denied microphone
+/// permission: long enough to judge, and not a single non-zero sample
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn is_denied_permission_silence(raw_samples: u64, heard_signal: bool) -> bool {
+    raw_samples >= SILENT_PERMISSION_MIN_SAMPLES && !heard_signal
+}
 
 /// Sender type for streaming audio samples during recording
 pub type AudioStreamSender = tokio::sync::mpsc::Sender<Vec<f32>>;
 
+/// Callback receiving the input level (RMS, 0.0-1.0) of each device buffer
+///
+/// Called on the audio thread, typically 50-100 times per second: keep it
+/// cheap and throttle before doing anything expensive (like IPC).
+pub type LevelCallback = Arc<dyn Fn(f32) + Send + Sync>;
+
 /// Audio recorder with real-time resampling to 16kHz mono.
 ///
 /// # Platform Notes
 /// - **macOS**: Contains unsafe Send impl due to cpal::Stream limitations
-/// - **Linux**: ALSA error suppression is automatically initialized
+/// - **Linux**: ALSA error suppression is automatically initialized. With the
+///   `pulse-capture` feature, PulseAudio sources are recorded natively and
+///   cpal is only the fallback
 pub struct AudioRecorder {
     samples: Arc<Mutex<Vec<f32>>>,
     /// Output sample rate (always 16kHz after resampling)
@@ -32,6 +57,9 @@ pub struct AudioRecorder {
     /// Output channels (always 1/mono after resampling)
     channels: u16,
     stream: Option<cpal::Stream>,
+    /// Native PulseAudio capture, used instead of `stream` when available
+    #[cfg(all(target_os = "linux", feature = "pulse-capture"))]
+    pulse_capture: Option<pulse::PulseCapture>,
     /// Real-time resampler (converts device rate to 16kHz mono)
     /// Created when recording starts (needs device sample rate)
     resampler: Option<Arc<Mutex<FrameResampler>>>,
@@ -43,6 +71,10 @@ pub struct AudioRecorder {
     vad_config: VadConfig,
     /// Optional sender for streaming samples during recording
     stream_tx: Option<Arc<AudioStreamSender>>,
+    /// Optional input level callback (raw input, before VAD)
+    level_callback: Option<LevelCallback>,
+    /// Whether samples are kept for `stop_recording` (off for streaming-only use)
+    keep_samples: bool,
 }
 
 // SAFETY: AudioRecorder is always used behind a Mutex in AppState, ensuring
@@ -66,11 +98,15 @@ impl AudioRecorder {
             sample_rate: WHISPER_SAMPLE_RATE, // Output is always 16kHz
             channels: 1,                      // Output is always mono
             stream: None,
+            #[cfg(all(target_os = "linux", feature = "pulse-capture"))]
+            pulse_capture: None,
             resampler: None,
             processor: None,
             vad: None,
             vad_config: VadConfig::default(),
             stream_tx: None,
+            level_callback: None,
+            keep_samples: true,
         })
     }
 
@@ -83,6 +119,23 @@ impl AudioRecorder {
         };
     }
 
+    /// Report the input level to `callback` during the next recording.
+    ///
+    /// Levels are measured on the raw input, so silence dropped by VAD
+    /// still shows up (useful for mic meters).
+    pub fn set_level_callback(&mut self, callback: Option<LevelCallback>) {
+        self.level_callback = callback;
+    }
+
+    /// Keep recorded samples for `stop_recording` (default: true).
+    ///
+    /// Turn off for open-ended streaming (e.g. `whis listen`), where the
+    /// samples are consumed from the streaming channel and keeping a copy
+    /// would grow memory without bound. `stop_recording` then returns no samples.
+    pub fn set_keep_samples(&mut self, keep: bool) {
+        self.keep_samples = keep;
+    }
+
     /// Start recording with the default input device.
     pub fn start_recording(&mut self) -> Result<()> {
         self.start_recording_with_device(None)
@@ -97,6 +150,15 @@ impl AudioRecorder {
         reset_stream_error_count();
 
         devices::init_platform();
+
+        // Record from the PulseAudio source directly when there is one
+        #[cfg(all(target_os = "linux", feature = "pulse-capture"))]
+        match self.start_pulse_capture(device_name) {
+            Ok(true) => return Ok(()),
+            Ok(false) => {}
+            Err(e) => crate::verbose!("PulseAudio capture unavailable, using cpal: {e}"),
+        }
+
         let host = cpal::default_host();
 
         let device = if let Some(name) = device_name {
@@ -118,7 +180,7 @@ impl AudioRecorder {
                             .map(|desc| devices::fuzzy_device_match(name, &desc.to_string()))
                             .unwrap_or(false)
                     })
-                    .with_context(|| format!("Audio device '{}' not found", name))?
+                    .ok_or_else(|| WhisError::from(AudioError::device_not_found(name)))?
             }
         } else {
             // Use default device
@@ -132,9 +194,7 @@ impl AudioRecorder {
             .unwrap_or_else(|_| "<unknown>".to_string());
         crate::verbose!("Audio device: {}", actual_device_name);
 
-        let config = device
-            .default_input_config()
-            .context("Failed to get default input config")?;
+        let config = format::input_config(&device)?;
 
         // Force mono on Android - emulators and some devices don't support stereo input
         #[cfg(target_os = "android")]
@@ -142,14 +202,90 @@ impl AudioRecorder {
         #[cfg(not(target_os = "android"))]
         let device_channels = config.channels();
 
-        let device_sample_rate = config.sample_rate();
+        self.prepare_processing(config.sample_rate(), device_channels)?;
+
+        let stream_config = cpal::StreamConfig {
+            channels: device_channels,
+            sample_rate: config.sample_rate(),
+            buffer_size: cpal::BufferSize::Default,
+        };
+
+        // Build stream using unified builder; every format in
+        // `format::SUPPORTED_FORMATS` is converted to f32 here
+        let stream = match config.sample_format() {
+            cpal::SampleFormat::F32 => self.build_stream_typed::<f32>(&device, &stream_config)?,
+            cpal::SampleFormat::F64 => self.build_stream_typed::<f64>(&device, &stream_config)?,
+            cpal::SampleFormat::I8 => self.build_stream_typed::<i8>(&device, &stream_config)?,
+            cpal::SampleFormat::I16 => self.build_stream_typed::<i16>(&device, &stream_config)?,
+            cpal::SampleFormat::I24 => {
+                self.build_stream_typed::<cpal::I24>(&device, &stream_config)?
+            }
+            cpal::SampleFormat::I32 => self.build_stream_typed::<i32>(&device, &stream_config)?,
+            cpal::SampleFormat::I64 => self.build_stream_typed::<i64>(&device, &stream_config)?,
+            cpal::SampleFormat::U8 => self.build_stream_typed::<u8>(&device, &stream_config)?,
+            cpal::SampleFormat::U16 => self.build_stream_typed::<u16>(&device, &stream_config)?,
+            cpal::SampleFormat::U24 => {
+                self.build_stream_typed::<cpal::U24>(&device, &stream_config)?
+            }
+            cpal::SampleFormat::U32 => self.build_stream_typed::<u32>(&device, &stream_config)?,
+            cpal::SampleFormat::U64 => self.build_stream_typed::<u64>(&device, &stream_config)?,
+            // `format::input_config` only returns the formats above
+            format => {
+                return Err(WhisError::from(AudioError::InvalidConfig(format!(
+                    "unsupported sample format {format}"
+                )))
+                .into());
+            }
+        };
+
+        stream
+            .play()
+            .map_err(|e| WhisError::from(AudioError::from(e)))?;
+
+        // Store stream to keep it alive; dropping it will release the microphone
+        self.stream = Some(stream);
+
+        Ok(())
+    }
+
+    /// Start native PulseAudio capture, if `device_name` is a PulseAudio source
+    ///
+    /// Returns false when there is no matching source (e.g. a raw ALSA
+    /// device), leaving cpal to handle it.
+    #[cfg(all(target_os = "linux", feature = "pulse-capture"))]
+    fn start_pulse_capture(&mut self, device_name: Option<&str>) -> Result<bool> {
+        let Some(source) = super::pulse::find_source(device_name)? else {
+            crate::verbose!("No matching PulseAudio source, using cpal");
+            return Ok(false);
+        };
 
         crate::verbose!(
-            "Audio device: {} Hz, {} channel(s) -> resampling to {} Hz mono",
-            device_sample_rate,
-            device_channels,
-            WHISPER_SAMPLE_RATE
+            "Audio device: {} (PulseAudio {})",
+            source.description.as_deref().unwrap_or(&source.name),
+            source.name
         );
+        self.prepare_processing(source.spec.rate, source.spec.channels.into())?;
+        self.pulse_capture = Some(pulse::PulseCapture::start(&source, self.input_sink())?);
+
+        Ok(true)
+    }
+
+    /// Set up resampling (and VAD) for input at the device's rate and
+    /// channel count, and clear samples from the last recording
+    fn prepare_processing(&mut self, device_sample_rate: u32, device_channels: u16) -> Result<()> {
+        if device_sample_rate == WHISPER_SAMPLE_RATE && device_channels == 1 {
+            crate::verbose!(
+                "Audio device: {} Hz mono natively, no resampling",
+                WHISPER_SAMPLE_RATE
+            );
+        } else {
+            crate::verbose!(
+                "Audio device: {} Hz, {} channel(s) -> resampling to {} Hz mono",
+                device_sample_rate,
+                device_channels,
+                WHISPER_SAMPLE_RATE
+            );
+        }
 
         // Create real-time resampler (device rate -> 16kHz mono)
         let resampler = FrameResampler::new(device_sample_rate, device_channels)
@@ -165,35 +301,21 @@ impl AudioRecorder {
         self.sample_rate = WHISPER_SAMPLE_RATE;
         self.channels = 1;
 
-        let stream_config = cpal::StreamConfig {
-            channels: device_channels,
-            sample_rate: config.sample_rate(),
-            buffer_size: cpal::BufferSize::Default,
-        };
-
-        let samples = self.samples.clone();
-        samples.lock().unwrap().clear();
-
-        // Build stream using unified builder (no duplication!)
-        let stream = match config.sample_format() {
-            cpal::SampleFormat::F32 => {
-                self.build_stream_typed::<f32>(&device, &stream_config, samples)?
-            }
-            cpal::SampleFormat::I16 => {
-                self.build_stream_typed::<i16>(&device, &stream_config, samples)?
-            }
-            cpal::SampleFormat::U16 => {
-                self.build_stream_typed::<u16>(&device, &stream_config, samples)?
-            }
-            _ => anyhow::bail!("Unsupported sample format"),
-        };
-
-        stream.play()?;
+        self.samples.lock().unwrap().clear();
+        Ok(())
+    }
 
-        // Store stream to keep it alive; dropping it will release the microphone
-        self.stream = Some(stream);
+    /// Where the next stream's raw input goes
+    fn input_sink(&self) -> InputSink {
+        // Get the processor - clone it since it's shared with self
+        let processor = self.processor.as_ref().unwrap().lock().unwrap().clone();
 
-        Ok(())
+        InputSink {
+            samples: self.keep_samples.then(|| self.samples.clone()),
+            processor: Arc::new(Mutex::new(processor)),
+            stream_tx: self.stream_tx.clone(),
+            level_callback: self.level_callback.clone(),
+        }
     }
 
     /// Create a sample processor with the appropriate VAD configuration.
@@ -219,16 +341,12 @@ impl AudioRecorder {
         &self,
         device: &cpal::Device,
         config: &cpal::StreamConfig,
-        samples: Arc<Mutex<Vec<f32>>>,
     ) -> Result<cpal::Stream>
     where
         T: cpal::Sample + cpal::SizedSample,
         f32: cpal::FromSample<T>,
     {
-        // Get the processor - clone it since it's shared with self
-        let processor = self.processor.as_ref().unwrap().lock().unwrap().clone();
-
-        stream::build_stream::<T>(device, config, samples, processor, self.stream_tx.clone())
+        stream::build_stream::<T>(device, config, self.input_sink())
     }
 
     /// Start recording and stream samples to a channel for real-time processing.
@@ -256,11 +374,25 @@ impl AudioRecorder {
         Ok(rx)
     }
 
+    /// Seconds of audio captured so far in the current recording.
+    ///
+    /// Samples are stored at 16kHz mono, so this is the sample count divided
+    /// by the output rate. With VAD enabled, silence is not counted.
+    pub fn buffered_secs(&self) -> f32 {
+        self.samples.lock().unwrap().len() as f32 / self.sample_rate as f32
+    }
+
     /// Stop recording and return the recording data.
     /// The stream is dropped here, making the returned RecordingData Send-safe.
     pub fn stop_recording(&mut self) -> Result<RecordingData> {
         // Drop the stream first to release the microphone
         self.stream = None;
+        // Stopping native capture joins its thread, so no input arrives after this
+        #[cfg(all(target_os = "linux", feature = "pulse-capture"))]
+        let capture_result = match self.pulse_capture.take() {
+            Some(capture) => capture.finish(),
+            None => Ok(()),
+        };
 
         // Drop the streaming sender to signal end of audio to receivers
         self.stream_tx = None;
@@ -285,6 +417,30 @@ impl AudioRecorder {
         };
         samples.extend_from_slice(&flushed_samples);
 
+        // A capture that broke off mid-recording lost the rest of the audio
+        #[cfg(all(target_os = "linux", feature = "pulse-capture"))]
+        capture_result.map_err(|e| WhisError::from(AudioError::recording_failed(e)))?;
+
+        // macOS hands out an input stream of pure zeros when microphone
+        // access is denied instead of failing to open it
+        #[cfg(target_os = "macos")]
+        {
+            let (raw_samples, heard_signal) = stream::stream_signal_stats();
+            if is_denied_permission_silence(raw_samples, heard_signal) {
+                crate::verbose!("Input stream delivered {raw_samples} samples of digital silence");
+                return Err(WhisError::from(AudioError::PermissionDenied(
+                    "the microphone delivered only silence".to_string(),
+                ))
+                .into());
+            }
+        }
+
+        if !self.keep_samples {
+            return Ok(RecordingData {
+                samples: Vec::new(),
+            });
+        }
+
         if samples.is_empty() {
             crate::verbose!("No audio samples captured");
             anyhow::bail!("No audio data recorded");
@@ -318,6 +474,9 @@ impl Default for AudioRecorder {
     }
 }
 
+/// Peak amplitude below which a recording counts as silent (about -40 dBFS)
+pub const SILENCE_PEAK_THRESHOLD: f32 = 0.01;
+
 /// Recording data extracted from AudioRecorder after stopping.
 /// This struct is Send-safe (unlike AudioRecorder on macOS where cpal::Stream isn't Send).
 /// Contains f32 samples at 16kHz mono, ready for progressive transcription.
@@ -326,6 +485,27 @@ pub struct RecordingData {
 }
 
 impl RecordingData {
+    /// Recorded samples (16kHz mono f32)
+    pub fn samples(&self) -> &[f32] {
+        &self.samples
+    }
+
+    /// Length of the recording
+    pub fn duration(&self) -> std::time::Duration {
+        std::time::Duration::from_secs_f64(self.samples.len() as f64 / WHISPER_SAMPLE_RATE as f64)
+    }
+
+    /// Largest absolute sample value (0.0 to 1.0 for in-range audio)
+    pub fn peak_amplitude(&self) -> f32 {
+        self.samples.iter().fold(0.0, |peak, s| peak.max(s.abs()))
+    }
+
+    /// Whether the recording never rises above `SILENCE_PEAK_THRESHOLD`
+    /// (muted or disconnected microphone)
+    pub fn is_silent(&self) -> bool {
+        self.peak_amplitude() < SILENCE_PEAK_THRESHOLD
+    }
+
     /// Finalize the recording and return raw f32 samples (16kHz mono).
     ///
     /// The samples are already resampled to 16kHz mono during recording.
@@ -334,3 +514,57 @@ impl RecordingData {
         self.samples
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recording(samples: &[f32]) -> RecordingData {
+        RecordingData {
+            samples: samples.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_is_denied_permission_silence() {
+        let cases = [
+            // Too short to tell a denied stream from a slow start
+            (0, false, false),
+            (SILENT_PERMISSION_MIN_SAMPLES - 1, false, false),
+            // Long run of exact zeros
+            (SILENT_PERMISSION_MIN_SAMPLES, false, true),
+            (SILENT_PERMISSION_MIN_SAMPLES * 20, false, true),
+            // Any real signal means access was granted
+            (SILENT_PERMISSION_MIN_SAMPLES, true, false),
+            (SILENT_PERMISSION_MIN_SAMPLES * 20, true, false),
+        ];
+        for (raw_samples, heard_signal, expected) in cases {
+            assert_eq!(
+                is_denied_permission_silence(raw_samples, heard_signal),
+                expected,
+                "{raw_samples} samples, heard signal: {heard_signal}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_silent() {
+        let cases: &[(&[f32], bool)] = &[
+            (&[], true),
+            (&[0.0; 16], true),
+            (&[0.001, -0.005, 0.009], true),
+            (&[0.0, SILENCE_PEAK_THRESHOLD, 0.0], false),
+            (&[0.0, -0.02, 0.0], false),
+            (&[0.5, -1.0], false),
+        ];
+        for (samples, silent) in cases {
+            assert_eq!(recording(samples).is_silent(), *silent, "{samples:?}");
+        }
+    }
+
+    #[test]
+    fn test_peak_amplitude_uses_absolute_value() {
+        assert_eq!(recording(&[]).peak_amplitude(), 0.0);
+        assert_eq!(recording(&[0.25, -0.75, 0.5]).peak_amplitude(), 0.75);
+    }
+}
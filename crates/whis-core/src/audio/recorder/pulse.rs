@@ -0,0 +1,148 @@
+//! Native PulseAudio capture (Linux, `pulse-capture` feature).
+//!
+//! Records straight from a PulseAudio source instead of going through cpal's
+//! ALSA layer and the PulseAudio/PipeWire ALSA plugin. The source is the one
+//! the device metadata came from, so selection matches what the user picked,
+//! and ALSA quirks (plugin probing, buffer timing errors) don't apply.
+//!
+//! Samples are requested as f32 at the source's native rate and channel
+//! count, so the server only converts the sample format; resampling to 16kHz
+//! mono happens in whis like for cpal input.
+
+use anyhow::{Context, Result, anyhow};
+use libpulse_binding::def::BufferAttr;
+use libpulse_binding::sample::{Format, Spec};
+use libpulse_binding::stream::Direction;
+use libpulse_simple_binding::Simple;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::JoinHandle;
+
+use super::stream::InputSink;
+use crate::audio::pulse::PulseSource;
+
+/// Audio per read, and the fragment size asked of the server
+const READ_MS: usize = 20;
+
+/// Bytes per f32 sample
+const SAMPLE_BYTES: usize = 4;
+
+/// A running capture; dropping it stops recording
+pub(super) struct PulseCapture {
+    stop: Arc<AtomicBool>,
+    /// Ends with the read error that stopped the capture early, if any
+    thread: Option<JoinHandle<Result<()>>>,
+}
+
+impl PulseCapture {
+    /// Start recording `source` into `sink`
+    ///
+    /// Returns once the source is open, so a failure here can still fall
+    /// back to cpal.
+    pub fn start(source: &PulseSource, sink: InputSink) -> Result<Self> {
+        let spec = Spec {
+            format: Format::FLOAT32NE,
+            channels: source.spec.channels,
+            rate: source.spec.rate,
+        };
+        if !spec.is_valid() {
+            anyhow::bail!(
+                "Unusable sample spec for {}: {} Hz, {} channel(s)",
+                source.name,
+                spec.rate,
+                spec.channels
+            );
+        }
+
+        let frames_per_read = spec.rate as usize * READ_MS / 1000;
+        let read_bytes = frames_per_read * spec.channels as usize * SAMPLE_BYTES;
+        let attr = BufferAttr {
+            // u32::MAX = server default
+            maxlength: u32::MAX,
+            tlength: u32::MAX,
+            prebuf: u32::MAX,
+            minreq: u32::MAX,
+            fragsize: read_bytes as u32,
+        };
+
+        let (ready_tx, ready_rx) = std::sync::mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let source_name = source.name.clone();
+        let thread = std::thread::Builder::new()
+            .name("whis-pulse-capture".into())
+            .spawn({
+                let stop = stop.clone();
+                move || {
+                    let simple = match Simple::new(
+                        None,
+                        "whis",
+                        Direction::Record,
+                        Some(&source_name),
+                        "Recording",
+                        &spec,
+                        None,
+                        Some(&attr),
+                    ) {
+                        Ok(simple) => {
+                            let _ = ready_tx.send(Ok(()));
+                            simple
+                        }
+                        Err(e) => {
+                            let _ = ready_tx.send(Err(anyhow!(
+                                "Failed to open PulseAudio source {source_name}: {e}"
+                            )));
+                            return Ok(());
+                        }
+                    };
+
+                    let mut buffer = vec![0u8; read_bytes];
+                    let mut samples = Vec::with_capacity(read_bytes / SAMPLE_BYTES);
+                    while !stop.load(Ordering::Relaxed) {
+                        // Blocks for about READ_MS, so stopping is quick
+                        if let Err(e) = simple.read(&mut buffer) {
+                            anyhow::bail!("PulseAudio capture from {source_name} stopped: {e}");
+                        }
+                        samples.clear();
+                        samples.extend(buffer.chunks_exact(SAMPLE_BYTES).map(|bytes| {
+                            f32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+                        }));
+                        sink.push(&samples);
+                    }
+                    Ok(())
+                }
+            })
+            .context("Failed to start PulseAudio capture thread")?;
+
+        ready_rx
+            .recv()
+            .map_err(|_| anyhow!("PulseAudio capture thread exited unexpectedly"))??;
+
+        Ok(Self {
+            stop,
+            thread: Some(thread),
+        })
+    }
+}
+
+impl PulseCapture {
+    /// Stop recording, returning the read error that ended it early, if any
+    pub fn finish(mut self) -> Result<()> {
+        self.stop_thread()
+    }
+
+    fn stop_thread(&mut self) -> Result<()> {
+        self.stop.store(true, Ordering::Relaxed);
+        match self.thread.take() {
+            Some(thread) => thread
+                .join()
+                .map_err(|_| anyhow!("PulseAudio capture thread panicked"))?,
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for PulseCapture {
+    fn drop(&mut self) {
+        let _ = self.stop_thread();
+    }
+}
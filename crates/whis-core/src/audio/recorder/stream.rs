@@ -3,19 +3,29 @@
 use anyhow::Result;
 use cpal::traits::DeviceTrait;
 use cpal::{Device, Stream, StreamConfig};
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 
-use super::AudioStreamSender;
 use super::processor::SampleProcessor;
+use super::{AudioStreamSender, LevelCallback};
+use crate::audio::AudioError;
+use crate::error::WhisError;
 
 /// Global counter for stream errors (reset per recording session)
 /// Used to provide rate-limited, user-friendly error reporting
 static STREAM_ERROR_COUNT: AtomicU64 = AtomicU64::new(0);
 
+/// Raw input samples received and whether any of them was non-zero
+/// (reset per recording session). A long run of exact zeros is how a denied
+/// microphone permission shows up on macOS.
+static STREAM_SAMPLE_COUNT: AtomicU64 = AtomicU64::new(0);
+static STREAM_HEARD_SIGNAL: AtomicBool = AtomicBool::new(false);
+
 /// Reset the stream error counter (call at start of new recording)
 pub fn reset_stream_error_count() {
     STREAM_ERROR_COUNT.store(0, Ordering::Relaxed);
+    STREAM_SAMPLE_COUNT.store(0, Ordering::Relaxed);
+    STREAM_HEARD_SIGNAL.store(false, Ordering::Relaxed);
 }
 
 /// Get total stream errors from last recording session
@@ -23,6 +33,86 @@ pub fn get_stream_error_count() -> u64 {
     STREAM_ERROR_COUNT.load(Ordering::Relaxed)
 }
 
+/// Raw samples received from the device and whether any carried signal
+#[cfg(target_os = "macos")]
+pub(super) fn stream_signal_stats() -> (u64, bool) {
+    (
+        STREAM_SAMPLE_COUNT.load(Ordering::Relaxed),
+        STREAM_HEARD_SIGNAL.load(Ordering::Relaxed),
+    )
+}
+
+/// Root mean square of a buffer (0.0 for an empty one)
+fn rms(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let sum: f32 = samples.iter().map(|s| s * s).sum();
+    (sum / samples.len() as f32).sqrt().min(1.0)
+}
+
+/// Where raw input goes: level metering, resampling/VAD, storage, streaming
+///
+/// Shared by the cpal stream and native PulseAudio capture, which both hand
+/// it f32 samples in the device's rate and channel layout.
+pub(super) struct InputSink {
+    pub samples: Option<Arc<Mutex<Vec<f32>>>>,
+    pub processor: Arc<Mutex<SampleProcessor>>,
+    pub stream_tx: Option<Arc<AudioStreamSender>>,
+    pub level_callback: Option<LevelCallback>,
+}
+
+impl InputSink {
+    /// Handle one buffer of raw input
+    pub fn push(&self, f32_samples: &[f32]) {
+        STREAM_SAMPLE_COUNT.fetch_add(f32_samples.len() as u64, Ordering::Relaxed);
+        if f32_samples.iter().any(|&s| s != 0.0) {
+            STREAM_HEARD_SIGNAL.store(true, Ordering::Relaxed);
+        }
+
+        if let Some(ref on_level) = self.level_callback {
+            on_level(rms(f32_samples));
+        }
+
+        // Process through resampler and VAD (if enabled)
+        let processed_samples = self.processor.lock().unwrap().process(f32_samples);
+
+        // Store processed samples (speech only if VAD enabled)
+        if !processed_samples.is_empty() {
+            if let Some(ref samples) = self.samples {
+                samples
+                    .lock()
+                    .unwrap()
+                    .extend_from_slice(&processed_samples);
+            }
+
+            // Stream samples if channel is configured (for real-time transcription)
+            if let Some(ref tx) = self.stream_tx {
+                // Use try_send to avoid blocking the audio thread
+                let _ = tx.try_send(processed_samples);
+            }
+        }
+    }
+}
+
+/// Count a non-fatal stream error, logging the first and every 1000th
+pub(super) fn report_stream_error(err: impl std::fmt::Display) {
+    let count = STREAM_ERROR_COUNT.fetch_add(1, Ordering::Relaxed);
+
+    // Log first error with helpful explanation
+    if count == 0 {
+        crate::verbose!(
+            "Audio stream error (common on Linux, non-fatal): {err}\n\
+             This is usually caused by audio buffer timing and doesn't affect recording quality.\n\
+             Subsequent similar errors will be suppressed."
+        );
+    }
+    // Log periodically to show it's ongoing (every 1000 errors)
+    else if count.is_multiple_of(1000) {
+        crate::verbose!("Audio stream: {count} non-fatal errors (recording continues normally)");
+    }
+}
+
 /// Build a unified audio input stream that works with or without VAD.
 ///
 /// This function eliminates the code duplication between VAD and non-VAD builds
@@ -30,65 +120,30 @@ pub fn get_stream_error_count() -> u64 {
 pub(super) fn build_stream<T>(
     device: &Device,
     config: &StreamConfig,
-    samples: Arc<Mutex<Vec<f32>>>,
-    processor: SampleProcessor,
-    stream_tx: Option<Arc<AudioStreamSender>>,
+    sink: InputSink,
 ) -> Result<Stream>
 where
     T: cpal::Sample + cpal::SizedSample,
     f32: cpal::FromSample<T>,
 {
-    // Wrap processor in Arc<Mutex> for sharing with audio callback
-    let processor = Arc::new(Mutex::new(processor));
-
     // Rate-limited error handler for ALSA stream errors
     // These are common on Linux (especially with USB audio) and non-fatal
-    let err_fn = |err| {
-        let count = STREAM_ERROR_COUNT.fetch_add(1, Ordering::Relaxed);
-
-        // Log first error with helpful explanation
-        if count == 0 {
-            crate::verbose!(
-                "Audio stream error (common on Linux, non-fatal): {err}\n\
-                 This is usually caused by audio buffer timing and doesn't affect recording quality.\n\
-                 Subsequent similar errors will be suppressed."
-            );
-        }
-        // Log periodically to show it's ongoing (every 1000 errors)
-        else if count.is_multiple_of(1000) {
-            crate::verbose!(
-                "Audio stream: {count} non-fatal errors (recording continues normally)"
-            );
-        }
-    };
+    let err_fn = |err: cpal::StreamError| report_stream_error(err);
 
-    let stream = device.build_input_stream(
+    let result = device.build_input_stream(
         config,
         move |data: &[T], _: &cpal::InputCallbackInfo| {
             // Convert to f32
             let f32_samples: Vec<f32> =
                 data.iter().map(|&s| cpal::Sample::from_sample(s)).collect();
 
-            // Process through resampler and VAD (if enabled)
-            let processed_samples = processor.lock().unwrap().process(&f32_samples);
-
-            // Store processed samples (speech only if VAD enabled)
-            if !processed_samples.is_empty() {
-                samples
-                    .lock()
-                    .unwrap()
-                    .extend_from_slice(&processed_samples);
-
-                // Stream samples if channel is configured (for real-time transcription)
-                if let Some(ref tx) = stream_tx {
-                    // Use try_send to avoid blocking the audio thread
-                    let _ = tx.try_send(processed_samples);
-                }
-            }
+            sink.push(&f32_samples);
         },
         err_fn,
         None,
-    )?;
+    );
+    // Surface a denied microphone permission as `AudioError::PermissionDenied`
+    let stream = result.map_err(|e| WhisError::from(AudioError::from(e)))?;
 
     Ok(stream)
 }
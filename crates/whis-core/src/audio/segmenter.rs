@@ -0,0 +1,190 @@
+//! Splitting a continuous audio stream into utterances
+//!
+//! Used by always-listening mode (`whis listen`): the microphone stays open
+//! and each stretch of speech is cut out on the pause after it, so it can be
+//! transcribed on its own.
+//!
+//! ## How it cuts
+//! ```text
+//! silence ─ speech ─ hangover ─ pause ─┬─ speech ─ hangover ─ pause ─┬─ ...
+//! [pre-roll|       utterance 1         ] [         utterance 2        ]
+//! ```
+//! - All audio since the previous cut goes into the next utterance, so
+//!   back-to-back utterances share their boundary and nothing in between is lost
+//! - While nobody speaks, only the last `pre_roll` of silence is kept
+//! - Speech running past `max_utterance` is cut mid-speech to bound latency
+//! - Blips with less than `min_speech` of speech (clicks, coughs) are dropped
+//!
+//! Speech detection uses the Silero VAD, so this needs the `vad` feature.
+
+use std::time::Duration;
+
+use anyhow::{Result, bail};
+
+use crate::configuration::{
+    DEFAULT_LISTEN_PAUSE_MS, DEFAULT_VAD_THRESHOLD, MAX_LISTEN_UTTERANCE_SECS,
+};
+use crate::resample::WHISPER_SAMPLE_RATE;
+
+use super::vad::{VAD_CHUNK_SIZE, VadProcessor};
+
+/// Configuration for utterance segmentation
+#[derive(Debug, Clone)]
+pub struct SegmenterConfig {
+    /// VAD speech probability threshold (0.0-1.0)
+    pub threshold: f32,
+    /// Silence after speech (past the VAD hangover) that ends an utterance
+    pub pause: Duration,
+    /// Silence kept before speech starts (captures word beginnings)
+    pub pre_roll: Duration,
+    /// Speech needed for an utterance to be emitted
+    pub min_speech: Duration,
+    /// Longest utterance before it's cut mid-speech
+    pub max_utterance: Duration,
+}
+
+impl Default for SegmenterConfig {
+    fn default() -> Self {
+        Self {
+            threshold: DEFAULT_VAD_THRESHOLD,
+            pause: Duration::from_millis(DEFAULT_LISTEN_PAUSE_MS),
+            pre_roll: Duration::from_millis(500),
+            min_speech: Duration::from_millis(250),
+            max_utterance: Duration::from_secs(MAX_LISTEN_UTTERANCE_SECS),
+        }
+    }
+}
+
+/// One stretch of speech cut from the stream
+#[derive(Debug, Clone)]
+pub struct Utterance {
+    /// Audio samples (16kHz mono f32), including surrounding silence
+    pub samples: Vec<f32>,
+    /// Offset of the first sample from the start of the stream
+    pub start: Duration,
+    /// Offset just past the last sample
+    pub end: Duration,
+}
+
+/// Cuts a continuous 16kHz mono stream into utterances at pauses
+pub struct UtteranceSegmenter {
+    vad: VadProcessor,
+    /// Audio since the last cut (or the pre-roll while idle)
+    pending: Vec<f32>,
+    /// Stream offset of `pending[0]`, in samples
+    pending_start: u64,
+    /// Whether `pending` contains speech
+    has_speech: bool,
+    /// Speech in `pending`, in samples
+    speech_samples: usize,
+    /// Silence since the speech in `pending` ended, in samples
+    silence_samples: usize,
+    pause_samples: usize,
+    pre_roll_samples: usize,
+    min_speech_samples: usize,
+    max_samples: usize,
+}
+
+impl UtteranceSegmenter {
+    /// Create a segmenter (fails without the `vad` feature)
+    pub fn new(config: SegmenterConfig) -> Result<Self> {
+        let vad = VadProcessor::new(true, config.threshold)?;
+        if !vad.is_enabled() {
+            bail!("Splitting speech into utterances requires the 'vad' feature");
+        }
+
+        Ok(Self {
+            vad,
+            pending: Vec::new(),
+            pending_start: 0,
+            has_speech: false,
+            speech_samples: 0,
+            silence_samples: 0,
+            pause_samples: duration_to_samples(config.pause),
+            pre_roll_samples: duration_to_samples(config.pre_roll),
+            min_speech_samples: duration_to_samples(config.min_speech),
+            max_samples: duration_to_samples(config.max_utterance).max(VAD_CHUNK_SIZE),
+        })
+    }
+
+    /// Feed samples (16kHz mono) and return the utterances they complete
+    pub fn push(&mut self, samples: &[f32]) -> Vec<Utterance> {
+        let mut utterances = Vec::new();
+
+        // Step in VAD frames so a cut lands right where the pause is reached
+        for frame in samples.chunks(VAD_CHUNK_SIZE) {
+            self.pending.extend_from_slice(frame);
+            self.vad.process(frame);
+
+            if self.vad.state().is_speaking {
+                self.has_speech = true;
+                self.speech_samples += frame.len();
+                self.silence_samples = 0;
+            } else if self.has_speech {
+                self.silence_samples += frame.len();
+            }
+
+            if !self.has_speech {
+                self.trim_pre_roll();
+            } else if self.silence_samples >= self.pause_samples
+                || self.pending.len() >= self.max_samples
+            {
+                utterances.extend(self.cut());
+            }
+        }
+
+        utterances
+    }
+
+    /// Audio of the utterance in progress, once speech has started
+    pub fn in_progress(&self) -> Option<&[f32]> {
+        self.has_speech.then_some(self.pending.as_slice())
+    }
+
+    /// End the current utterance now (e.g. on a key press or at the end
+    /// of the stream)
+    ///
+    /// Returns `None` when it holds too little speech.
+    pub fn cut(&mut self) -> Option<Utterance> {
+        let samples = std::mem::take(&mut self.pending);
+        let start = self.pending_start;
+        self.pending_start += samples.len() as u64;
+
+        let enough_speech = self.speech_samples >= self.min_speech_samples;
+        // Speech still going on (forced cut) carries over into the next utterance
+        self.has_speech = self.vad.state().is_speaking;
+        self.speech_samples = 0;
+        self.silence_samples = 0;
+
+        if !enough_speech {
+            crate::verbose!(
+                "Dropping {:.1}s of audio with too little speech",
+                samples.len() as f32 / WHISPER_SAMPLE_RATE as f32
+            );
+            return None;
+        }
+
+        Some(Utterance {
+            start: samples_to_duration(start),
+            end: samples_to_duration(start + samples.len() as u64),
+            samples,
+        })
+    }
+
+    /// Drop silence older than the pre-roll while nobody is speaking
+    fn trim_pre_roll(&mut self) {
+        if self.pending.len() > self.pre_roll_samples {
+            let excess = self.pending.len() - self.pre_roll_samples;
+            self.pending.drain(..excess);
+            self.pending_start += excess as u64;
+        }
+    }
+}
+
+fn duration_to_samples(duration: Duration) -> usize {
+    (duration.as_secs_f64() * WHISPER_SAMPLE_RATE as f64) as usize
+}
+
+fn samples_to_duration(samples: u64) -> Duration {
+    Duration::from_secs_f64(samples as f64 / WHISPER_SAMPLE_RATE as f64)
+}
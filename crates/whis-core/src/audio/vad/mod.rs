@@ -17,10 +17,10 @@ mod processor_noop;
 
 // Re-export the appropriate implementation
 #[cfg(feature = "vad")]
-pub use processor::{VadProcessor, VadState};
+pub use processor::{VAD_CHUNK_SIZE, VadProcessor, VadState};
 
 #[cfg(not(feature = "vad"))]
-pub use processor_noop::{VadProcessor, VadState};
+pub use processor_noop::{VAD_CHUNK_SIZE, VadProcessor, VadState};
 
 // VadConfig is always available (not feature-gated)
 
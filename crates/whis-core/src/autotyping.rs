@@ -87,6 +87,7 @@ pub fn get_autotype_tool_status() -> AutotypeToolStatus {
                     platform_info.compositor,
                     crate::platform::Compositor::Sway
                         | crate::platform::Compositor::Hyprland
+                        | crate::platform::Compositor::River
                         | crate::platform::Compositor::Wlroots
                 );
 
@@ -137,6 +137,69 @@ fn copy_via_arboard(text: &str) -> Result<()> {
     Ok(())
 }
 
+/// Read clipboard text by running a command and capturing stdout
+fn read_via_command(program: &str, args: &[&str]) -> Result<String> {
+    crate::verbose!("Using {} to read clipboard", program);
+
+    let output = Command::new(program)
+        .args(args)
+        .stderr(Stdio::null())
+        .output()
+        .with_context(|| format!("Failed to spawn {}", program))?;
+
+    if !output.status.success() {
+        anyhow::bail!("{} exited with non-zero status", program);
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Read clipboard text using arboard (cross-platform)
+fn read_via_arboard() -> Result<String> {
+    crate::verbose!("Using arboard to read clipboard");
+
+    let mut clipboard = Clipboard::new().context("Failed to access clipboard")?;
+    clipboard
+        .get_text()
+        .context("Failed to read text from clipboard")
+}
+
+/// Whether a clipboard can be reached at all
+///
+/// On Linux the clipboard belongs to the display server, so a session without
+/// `DISPLAY` or `WAYLAND_DISPLAY` (SSH, CI, containers) has none. macOS and
+/// Windows always have one.
+pub fn is_clipboard_available() -> bool {
+    if !cfg!(target_os = "linux") || is_flatpak() {
+        return true;
+    }
+    ["DISPLAY", "WAYLAND_DISPLAY"]
+        .iter()
+        .any(|var| std::env::var_os(var).is_some_and(|v| !v.is_empty()))
+}
+
+/// Read text from the clipboard using the specified method
+///
+/// Uses the same backend selection as [`copy_to_clipboard`].
+pub fn read_from_clipboard(method: ClipboardMethod) -> Result<String> {
+    match method {
+        ClipboardMethod::Auto => {
+            if is_flatpak() {
+                return read_via_command("wl-paste", &["--no-newline"]);
+            }
+
+            if session_type() == "x11" {
+                return read_via_command("xclip", &["-selection", "clipboard", "-o"]);
+            }
+
+            read_via_arboard()
+        }
+        ClipboardMethod::Xclip => read_via_command("xclip", &["-selection", "clipboard", "-o"]),
+        ClipboardMethod::WlCopy => read_via_command("wl-paste", &["--no-newline"]),
+        ClipboardMethod::Arboard => read_via_arboard(),
+    }
+}
+
 /// Copy text to clipboard using the specified method
 pub fn copy_to_clipboard(text: &str, method: ClipboardMethod) -> Result<()> {
     crate::verbose!("Copying {} chars to clipboard", text.len());
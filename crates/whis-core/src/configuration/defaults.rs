@@ -67,6 +67,14 @@ pub const DEFAULT_LANGUAGE: Option<&str> = None;
 /// `post_processing.rs` as `DEFAULT_POST_PROCESSING_PROMPT`.
 pub const DEFAULT_POST_PROCESSOR: PostProcessor = PostProcessor::OpenAI;
 
+/// Default transcript length (characters) above which post-processing is chunked
+///
+/// About 2,000 tokens, roughly ten minutes of dictation: a chunk and its
+/// cleaned-up version fit in Ollama's default 4,096-token context, and
+/// models rewrite shorter inputs more faithfully. Adjust via
+/// `whis config post-processing-chunk-chars <n>` (0 never chunks).
+pub const DEFAULT_POST_PROCESSING_CHUNK_CHARS: usize = 8_000;
+
 // =============================================================================
 // UI DEFAULTS
 // =============================================================================
@@ -85,6 +93,13 @@ pub const DEFAULT_SHORTCUT_MODE: &str = "system";
 /// Users can customize this via `whis config shortcut <your-shortcut>`.
 pub const DEFAULT_SHORTCUT: &str = "Ctrl+Alt+W";
 
+/// Default hotkey debounce window (milliseconds)
+///
+/// A trigger release only counts once the trigger stayed up this long, so
+/// switch bounce (release + press within a few ms) doesn't stop and restart
+/// a recording. Adjust via `whis config hotkey-debounce <ms>` (0 disables).
+pub const DEFAULT_HOTKEY_DEBOUNCE_MS: u64 = 30;
+
 /// Default VAD (Voice Activity Detection) enabled state
 ///
 /// VAD is disabled by default to ensure all audio is captured.
@@ -97,6 +112,18 @@ pub const DEFAULT_VAD_ENABLED: bool = false;
 /// and capturing soft speech. Adjust via `whis config vad-threshold <value>`.
 pub const DEFAULT_VAD_THRESHOLD: f32 = 0.5;
 
+/// Default minimum recording length (milliseconds)
+///
+/// Shorter recordings (accidental taps) are discarded without being
+/// transcribed. Adjust via `whis config min-recording <ms>` (0 disables).
+pub const DEFAULT_MIN_RECORDING_MS: u64 = 300;
+
+/// Default sound cue volume (0.0-1.0)
+///
+/// Quiet enough not to be picked up loudly by the microphone right after
+/// recording starts. Adjust via `whis config cue-volume <value>`.
+pub const DEFAULT_CUE_VOLUME: f32 = 0.3;
+
 /// Default chunk duration for progressive transcription (seconds)
 ///
 /// 90 seconds provides a good balance between transcription quality
@@ -104,6 +131,43 @@ pub const DEFAULT_VAD_THRESHOLD: f32 = 0.5;
 /// Smaller values (30s) feel more real-time, larger values (120s) improve accuracy.
 pub const DEFAULT_CHUNK_DURATION_SECS: u64 = 90;
 
+/// Shortest allowed chunk duration (seconds)
+///
+/// Below this, per-request overhead and the 2s overlap dominate.
+pub const MIN_CHUNK_DURATION_SECS: u64 = 10;
+
+/// Longest allowed chunk duration (seconds)
+///
+/// Keeps chunks well under provider upload limits.
+pub const MAX_CHUNK_DURATION_SECS: u64 = 300;
+
+/// Default pause that ends an utterance in `whis listen --auto` (milliseconds)
+///
+/// Counted after the VAD's own ~0.5s trailing hangover, so the gap in speech
+/// is about a second. Shorter splits sentences at breaths, longer merges
+/// separate remarks. Adjust per run with `whis listen --auto --pause <ms>`.
+pub const DEFAULT_LISTEN_PAUSE_MS: u64 = 500;
+
+/// Longest utterance in listen mode before it's cut mid-speech (seconds)
+///
+/// Keeps latency bounded when someone talks without pausing.
+pub const MAX_LISTEN_UTTERANCE_SECS: u64 = 60;
+
+// =============================================================================
+// NETWORK DEFAULTS
+// =============================================================================
+
+/// Default time to establish a connection to a provider (seconds)
+///
+/// Adjust via `whis config connect-timeout <seconds>`.
+pub const DEFAULT_CONNECT_TIMEOUT_SECS: u64 = 10;
+
+/// Default time allowed to upload the audio of one request (seconds)
+///
+/// A 90s MP3 chunk is ~1.5 MB, so this leaves room for slow links.
+/// Raise it for large files via `whis config upload-timeout <seconds>`.
+pub const DEFAULT_UPLOAD_TIMEOUT_SECS: u64 = 120;
+
 // =============================================================================
 // SERVICE DEFAULTS
 // =============================================================================
@@ -133,6 +197,16 @@ pub const DEFAULT_OLLAMA_MODEL: &str = "qwen2.5:1.5b";
 /// - "-1": Keep loaded forever (until Ollama restarts)
 pub const DEFAULT_OLLAMA_KEEP_ALIVE: &str = "5m";
 
+/// Default Ollama system prompt for post-processing
+///
+/// Sets a stable role for the model. The per-run instruction (preset or
+/// post-processing prompt) is sent together with the transcript in the
+/// user message. Configure via `whis config ollama-system-prompt <text>`.
+pub const DEFAULT_OLLAMA_SYSTEM_PROMPT: &str = "You are a transcript editor. \
+You receive an instruction and a voice transcript. \
+Apply the instruction to the transcript and output only the resulting text, \
+without explanations or commentary.";
+
 // =============================================================================
 // MODEL MEMORY DEFAULTS
 // =============================================================================
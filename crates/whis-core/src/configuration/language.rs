@@ -0,0 +1,326 @@
+//! Language code normalization.
+//!
+//! Users type `English`, `EN`, `en-US` or `eng`, while transcription
+//! providers expect ISO-639-1 codes (`en`). [`normalize_language`] maps
+//! these variants to the canonical code; [`parse_language`] also handles
+//! `auto` and produces a helpful error for unknown input.
+
+use anyhow::{Result, anyhow};
+
+/// Supported languages: (code, English name, native name)
+///
+/// The full Whisper language set. Codes are ISO-639-1 except where Whisper
+/// uses its own (`haw`, `jw`, `yue`).
+pub const LANGUAGES: &[(&str, &str, &str)] = &[
+    ("af", "afrikaans", "afrikaans"),
+    ("am", "amharic", "አማርኛ"),
+    ("ar", "arabic", "العربية"),
+    ("as", "assamese", "অসমীয়া"),
+    ("az", "azerbaijani", "azərbaycan"),
+    ("ba", "bashkir", "башҡорт"),
+    ("be", "belarusian", "беларуская"),
+    ("bg", "bulgarian", "български"),
+    ("bn", "bengali", "বাংলা"),
+    ("bo", "tibetan", "བོད་སྐད"),
+    ("br", "breton", "brezhoneg"),
+    ("bs", "bosnian", "bosanski"),
+    ("ca", "catalan", "català"),
+    ("cs", "czech", "čeština"),
+    ("cy", "welsh", "cymraeg"),
+    ("da", "danish", "dansk"),
+    ("de", "german", "deutsch"),
+    ("el", "greek", "ελληνικά"),
+    ("en", "english", "english"),
+    ("es", "spanish", "español"),
+    ("et", "estonian", "eesti"),
+    ("eu", "basque", "euskara"),
+    ("fa", "persian", "فارسی"),
+    ("fi", "finnish", "suomi"),
+    ("fo", "faroese", "føroyskt"),
+    ("fr", "french", "français"),
+    ("ga", "irish", "gaeilge"),
+    ("gl", "galician", "galego"),
+    ("gu", "gujarati", "ગુજરાતી"),
+    ("ha", "hausa", "hausa"),
+    ("haw", "hawaiian", "ʻōlelo hawaiʻi"),
+    ("he", "hebrew", "עברית"),
+    ("hi", "hindi", "हिन्दी"),
+    ("hr", "croatian", "hrvatski"),
+    ("ht", "haitian creole", "kreyòl ayisyen"),
+    ("hu", "hungarian", "magyar"),
+    ("hy", "armenian", "հայերեն"),
+    ("id", "indonesian", "bahasa indonesia"),
+    ("is", "icelandic", "íslenska"),
+    ("it", "italian", "italiano"),
+    ("ja", "japanese", "日本語"),
+    ("jw", "javanese", "basa jawa"),
+    ("ka", "georgian", "ქართული"),
+    ("kk", "kazakh", "қазақ"),
+    ("km", "khmer", "ខ្មែរ"),
+    ("kn", "kannada", "ಕನ್ನಡ"),
+    ("ko", "korean", "한국어"),
+    ("la", "latin", "latina"),
+    ("lb", "luxembourgish", "lëtzebuergesch"),
+    ("ln", "lingala", "lingála"),
+    ("lo", "lao", "ລາວ"),
+    ("lt", "lithuanian", "lietuvių"),
+    ("lv", "latvian", "latviešu"),
+    ("mg", "malagasy", "malagasy"),
+    ("mi", "maori", "māori"),
+    ("mk", "macedonian", "македонски"),
+    ("ml", "malayalam", "മലയാളം"),
+    ("mn", "mongolian", "монгол"),
+    ("mr", "marathi", "मराठी"),
+    ("ms", "malay", "bahasa melayu"),
+    ("mt", "maltese", "malti"),
+    ("my", "burmese", "မြန်မာ"),
+    ("ne", "nepali", "नेपाली"),
+    ("nl", "dutch", "nederlands"),
+    ("no", "norwegian", "norsk"),
+    ("oc", "occitan", "occitan"),
+    ("pa", "punjabi", "ਪੰਜਾਬੀ"),
+    ("pl", "polish", "polski"),
+    ("ps", "pashto", "پښتو"),
+    ("pt", "portuguese", "português"),
+    ("ro", "romanian", "română"),
+    ("ru", "russian", "русский"),
+    ("sa", "sanskrit", "संस्कृतम्"),
+    ("sd", "sindhi", "سنڌي"),
+    ("si", "sinhala", "සිංහල"),
+    ("sk", "slovak", "slovenčina"),
+    ("sl", "slovenian", "slovenščina"),
+    ("sn", "shona", "chishona"),
+    ("so", "somali", "soomaali"),
+    ("sq", "albanian", "shqip"),
+    ("sr", "serbian", "српски"),
+    ("su", "sundanese", "basa sunda"),
+    ("sv", "swedish", "svenska"),
+    ("sw", "swahili", "kiswahili"),
+    ("ta", "tamil", "தமிழ்"),
+    ("te", "telugu", "తెలుగు"),
+    ("tg", "tajik", "тоҷикӣ"),
+    ("th", "thai", "ไทย"),
+    ("tk", "turkmen", "türkmen"),
+    ("tl", "tagalog", "tagalog"),
+    ("tr", "turkish", "türkçe"),
+    ("tt", "tatar", "татар"),
+    ("uk", "ukrainian", "українська"),
+    ("ur", "urdu", "اردو"),
+    ("uz", "uzbek", "oʻzbek"),
+    ("vi", "vietnamese", "tiếng việt"),
+    ("yi", "yiddish", "ייִדיש"),
+    ("yo", "yoruba", "yorùbá"),
+    ("yue", "cantonese", "粵語"),
+    ("zh", "chinese", "中文"),
+];
+
+/// Common ISO-639-2/3 codes and aliases that map to an ISO-639-1 code
+const ALIASES: &[(&str, &str)] = &[
+    ("eng", "en"),
+    ("deu", "de"),
+    ("ger", "de"),
+    ("fra", "fr"),
+    ("fre", "fr"),
+    ("spa", "es"),
+    ("ita", "it"),
+    ("por", "pt"),
+    ("nld", "nl"),
+    ("dut", "nl"),
+    ("pol", "pl"),
+    ("rus", "ru"),
+    ("ukr", "uk"),
+    ("jpn", "ja"),
+    ("kor", "ko"),
+    ("zho", "zh"),
+    ("chi", "zh"),
+    ("swe", "sv"),
+    ("dan", "da"),
+    ("nor", "no"),
+    ("nob", "no"),
+    ("nb", "no"),
+    ("nn", "no"),
+    ("fin", "fi"),
+    ("tur", "tr"),
+    ("ara", "ar"),
+    ("hin", "hi"),
+    ("ces", "cs"),
+    ("cze", "cs"),
+    ("ell", "el"),
+    ("gre", "el"),
+    ("heb", "he"),
+    ("iw", "he"),
+    ("jv", "jw"),
+    ("fil", "tl"),
+    ("filipino", "tl"),
+    ("farsi", "fa"),
+    ("mandarin", "zh"),
+    ("flemish", "nl"),
+];
+
+/// Normalize user input to an ISO-639-1 language code.
+///
+/// Accepts codes in any case (`EN`), region/script variants (`en-US`,
+/// `pt_BR`, `zh-Hans`), ISO-639-2/3 codes (`eng`, `deu`), and English or
+/// native language names (`English`, `Deutsch`). Returns `None` if the input
+/// is not recognized. `auto` is not a language; see [`parse_language`].
+pub fn normalize_language(input: &str) -> Option<String> {
+    let lower = input.trim().to_lowercase();
+    if lower.is_empty() {
+        return None;
+    }
+
+    // Names first, so multi-word names ("bahasa indonesia") aren't split
+    if let Some((code, _, _)) = LANGUAGES
+        .iter()
+        .find(|(_, english, native)| *english == lower || *native == lower)
+    {
+        return Some(code.to_string());
+    }
+
+    // Strip region/script subtags: en-US, pt_BR, zh-Hans
+    let base = lower.split(['-', '_']).next().unwrap_or(&lower);
+
+    if let Some((code, _, _)) = LANGUAGES.iter().find(|(code, _, _)| *code == base) {
+        return Some(code.to_string());
+    }
+
+    ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == base || *alias == lower)
+        .map(|(_, code)| code.to_string())
+}
+
+/// Parse a language setting: `auto` (or empty) means auto-detect (`None`).
+///
+/// Fails with a message listing examples if the input isn't recognized.
+pub fn parse_language(input: &str) -> Result<Option<String>> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() || trimmed.eq_ignore_ascii_case("auto") {
+        return Ok(None);
+    }
+
+    if let Some(code) = normalize_language(trimmed) {
+        return Ok(Some(code));
+    }
+    // Codes outside the table are passed on for the provider to judge
+    if trimmed.len() == 2 && trimmed.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Ok(Some(trimmed.to_ascii_lowercase()));
+    }
+
+    Err(anyhow!(
+        "Unknown language '{}'. Use an ISO-639-1 code or name \
+         (e.g. 'en', 'de', 'fr', 'English', 'pt-BR') or 'auto'",
+        trimmed
+    ))
+}
+
+/// Pick the transcription language for one run.
+///
+/// Precedence: `flag` (`--language`) > `preset` (the preset's `language`) >
+/// `setting` (`whis config language`) > `None` (auto-detect). The first
+/// level that is set wins, and `auto` there means auto-detect, so a flag of
+/// `auto` also overrides a preset or setting. Flag and preset values are
+/// validated; the stored setting was validated when it was set and is only
+/// normalized (an unrecognized one is passed through as-is).
+pub fn resolve_language(
+    flag: Option<&str>,
+    preset: Option<&str>,
+    setting: Option<&str>,
+) -> Result<Option<String>> {
+    if let Some(lang) = flag.or(preset) {
+        return parse_language(lang);
+    }
+    Ok(setting.and_then(|lang| parse_language(lang).unwrap_or_else(|_| Some(lang.to_string()))))
+}
+
+// Documented language support per provider model. Providers not listed here
+// (Groq, ElevenLabs, local Whisper) accept every Whisper language.
+
+/// OpenAI `whisper-1` / `gpt-4o-transcribe` (57 languages)
+pub(super) const OPENAI_LANGUAGES: &[&str] = &[
+    "af", "ar", "hy", "az", "be", "bs", "bg", "ca", "zh", "hr", "cs", "da", "nl", "en", "et", "fi",
+    "fr", "gl", "de", "el", "he", "hi", "hu", "is", "id", "it", "ja", "kn", "kk", "ko", "lv", "lt",
+    "mk", "ms", "mr", "mi", "ne", "no", "fa", "pl", "pt", "ro", "ru", "sr", "sk", "sl", "es", "sw",
+    "sv", "tl", "ta", "th", "tr", "uk", "ur", "vi", "cy",
+];
+
+/// Mistral Voxtral
+pub(super) const MISTRAL_LANGUAGES: &[&str] = &["en", "es", "fr", "pt", "hi", "de", "nl", "it"];
+
+/// Deepgram Nova
+pub(super) const DEEPGRAM_LANGUAGES: &[&str] = &[
+    "bg", "ca", "zh", "cs", "da", "nl", "en", "et", "fi", "fr", "de", "el", "hi", "hu", "id", "it",
+    "ja", "ko", "lv", "lt", "ms", "no", "pl", "pt", "ro", "ru", "sk", "es", "sv", "th", "tr", "uk",
+    "vi",
+];
+
+/// NVIDIA Parakeet TDT v3 (25 European languages)
+pub(super) const PARAKEET_LANGUAGES: &[&str] = &[
+    "bg", "hr", "cs", "da", "nl", "en", "et", "fi", "fr", "de", "el", "hu", "it", "lv", "lt", "mt",
+    "pl", "pt", "ro", "sk", "sl", "es", "sv", "ru", "uk",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_language_precedence() {
+        let en = Some("en".to_string());
+        assert_eq!(
+            resolve_language(Some("en"), Some("de"), Some("fr")).unwrap(),
+            en
+        );
+        assert_eq!(
+            resolve_language(None, Some("English"), Some("fr")).unwrap(),
+            en
+        );
+        assert_eq!(resolve_language(None, None, Some("en-US")).unwrap(), en);
+        assert_eq!(resolve_language(None, None, None).unwrap(), None);
+    }
+
+    #[test]
+    fn test_resolve_language_auto_stops_the_lookup() {
+        assert_eq!(
+            resolve_language(Some("auto"), Some("de"), Some("fr")).unwrap(),
+            None
+        );
+        assert_eq!(
+            resolve_language(None, Some("auto"), Some("fr")).unwrap(),
+            None
+        );
+        assert_eq!(resolve_language(None, None, Some("auto")).unwrap(), None);
+    }
+
+    #[test]
+    fn test_resolve_language_validates_overrides_only() {
+        assert!(resolve_language(Some("klingon"), None, None).is_err());
+        assert!(resolve_language(None, Some("klingon"), None).is_err());
+        assert_eq!(
+            resolve_language(None, None, Some("klingon")).unwrap(),
+            Some("klingon".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_language_accepts_whisper_languages() {
+        for (input, code) in [
+            ("hy", "hy"),
+            ("AZ", "az"),
+            ("Kazakh", "kk"),
+            ("मराठी", "mr"),
+            ("ne-NP", "ne"),
+            ("haw", "haw"),
+            ("jv", "jw"),
+        ] {
+            assert_eq!(parse_language(input).unwrap(), Some(code.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_parse_language_passes_on_unlisted_codes() {
+        assert_eq!(parse_language("Xy").unwrap(), Some("xy".to_string()));
+        assert!(parse_language("x1").is_err());
+        assert!(parse_language("xyz").is_err());
+    }
+}
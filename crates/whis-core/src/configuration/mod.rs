@@ -3,12 +3,15 @@
 //! This module contains:
 //! - `TranscriptionProvider` enum (provider selection)
 //! - Default values for settings
+//! - Language code normalization
 //! - Preset system for post-processing
 
 mod defaults;
+mod language;
 mod preset;
 mod provider;
 
 pub use defaults::*;
+pub use language::{LANGUAGES, normalize_language, parse_language, resolve_language};
 pub use preset::{Preset, PresetSource};
-pub use provider::TranscriptionProvider;
+pub use provider::{KeyFormatError, ProviderCapabilities, TranscriptionProvider};
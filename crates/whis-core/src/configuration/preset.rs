@@ -21,7 +21,8 @@
 //!   "description": "What this preset does",
 //!   "prompt": "System prompt for the LLM",
 //!   "post_processor": "openai",  // optional override
-//!   "model": "gpt-4"             // optional override
+//!   "model": "gpt-4",            // optional override
+//!   "language": "de"             // optional transcription language
 //! }
 //! ```
 //!
@@ -63,6 +64,11 @@ pub struct Preset {
     /// Optional: Override the model for this preset
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub model: Option<String>,
+
+    /// Optional: Transcription language for this preset ("auto" = auto-detect).
+    /// A `--language` flag beats it; it beats the `language` setting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
 }
 
 /// Where a preset was loaded from
@@ -105,6 +111,7 @@ impl Preset {
                     .to_string(),
                 post_processor: None,
                 model: None,
+                language: None,
             },
             Preset {
                 name: "email".to_string(),
@@ -117,6 +124,7 @@ impl Preset {
                     .to_string(),
                 post_processor: None,
                 model: None,
+                language: None,
             },
             Preset {
                 name: "default".to_string(),
@@ -129,6 +137,7 @@ impl Preset {
                     .to_string(),
                 post_processor: None,
                 model: None,
+                language: None,
             },
         ]
     }
@@ -237,6 +246,7 @@ impl Preset {
             prompt: "Your system prompt here".to_string(),
             post_processor: None,
             model: None,
+            language: None,
         }
     }
 
@@ -18,6 +18,11 @@
 //! - **LocalWhisper** - Run Whisper locally via ONNX
 //! - **LocalParakeet** - NVIDIA's Parakeet model locally
 //!
+//! # Testing
+//!
+//! - **Mock** - Returns `$WHIS_MOCK_TRANSCRIPT` without a network call.
+//!   Not listed by `all()`, so it never shows up in setup or settings.
+//!
 //! # Helper Methods
 //!
 //! - `as_str()` - Get provider identifier string
@@ -25,10 +30,66 @@
 //! - `api_key_env_var()` - Environment variable for API key
 //! - `requires_api_key()` - Whether cloud API key is needed
 //! - `is_local()` - Whether provider runs locally
+//! - `validate_key_format()` - Offline sanity check of an API key
+//! - `capabilities()` - Everything a provider picker needs to show
+//! - `supports_language()` - Whether a language code is supported
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+/// Minimum length of a cloud API key; real keys are all well above this
+const MIN_API_KEY_LENGTH: usize = 20;
+
+/// Why an API key can't be right for its provider
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum KeyFormatError {
+    /// Nothing entered
+    #[error("Invalid {provider} API key: cannot be empty")]
+    Empty { provider: &'static str },
+
+    /// Key doesn't start with the provider's prefix (e.g. `sk-`)
+    #[error("Invalid key format. {provider} keys start with '{prefix}'")]
+    WrongPrefix {
+        provider: &'static str,
+        prefix: &'static str,
+    },
+
+    /// Key is shorter than any real key
+    #[error("Invalid {provider} API key: key appears too short")]
+    TooShort {
+        provider: &'static str,
+        min_length: usize,
+    },
+}
+
+/// What a provider offers, for rendering provider pickers
+///
+/// Built by [`TranscriptionProvider::capabilities`] so the CLI, desktop and
+/// mobile apps list providers from one place. Feature flags describe the
+/// provider's API, not what whis uses of it yet.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProviderCapabilities {
+    /// Identifier, as in the settings file (`openai`, `local-whisper`, ...)
+    pub id: &'static str,
+    pub display_name: &'static str,
+    pub requires_api_key: bool,
+    pub is_local: bool,
+    /// Where to create an API key
+    pub api_key_url: Option<&'static str>,
+    /// Placeholder for the API key input (e.g. `sk-...`)
+    pub api_key_placeholder: &'static str,
+    /// Id of the streaming variant, if the provider has one
+    pub realtime_variant: Option<&'static str>,
+    /// Can label who is speaking
+    pub diarization: bool,
+    /// Can return word or segment timestamps
+    pub timestamps: bool,
+    /// Can translate speech to English
+    pub translation: bool,
+    /// Rough list price, for display only (from `cost_per_minute_usd`)
+    pub typical_cost: String,
+}
+
 /// Available transcription providers
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -46,6 +107,7 @@ pub enum TranscriptionProvider {
     LocalWhisper,
     #[serde(rename = "local-parakeet")]
     LocalParakeet,
+    Mock,
 }
 
 impl Default for TranscriptionProvider {
@@ -67,6 +129,7 @@ impl TranscriptionProvider {
             TranscriptionProvider::ElevenLabs => "elevenlabs",
             TranscriptionProvider::LocalWhisper => "local-whisper",
             TranscriptionProvider::LocalParakeet => "local-parakeet",
+            TranscriptionProvider::Mock => "mock",
         }
     }
 
@@ -84,6 +147,7 @@ impl TranscriptionProvider {
             TranscriptionProvider::ElevenLabs => "ELEVENLABS_API_KEY",
             TranscriptionProvider::LocalWhisper => "LOCAL_WHISPER_MODEL_PATH",
             TranscriptionProvider::LocalParakeet => "LOCAL_PARAKEET_MODEL_PATH",
+            TranscriptionProvider::Mock => "WHIS_MOCK_TRANSCRIPT",
         }
     }
 
@@ -91,6 +155,7 @@ impl TranscriptionProvider {
     ///
     /// Ordering: Deepgram first (generous free tier, real-time optimized),
     /// then OpenAI (widely used), followed by other cloud options.
+    /// `Mock` is left out: it's for tests, not for users to pick.
     pub fn all() -> &'static [TranscriptionProvider] {
         &[
             TranscriptionProvider::Deepgram,
@@ -127,9 +192,46 @@ impl TranscriptionProvider {
             TranscriptionProvider::ElevenLabs => "ElevenLabs",
             TranscriptionProvider::LocalWhisper => "Local Whisper",
             TranscriptionProvider::LocalParakeet => "Local Parakeet",
+            TranscriptionProvider::Mock => "Mock",
         }
     }
 
+    /// Provider named in an error message: its display name ("OpenAI
+    /// Realtime") or its config name ("openai-realtime")
+    pub fn from_display_name(name: &str) -> Option<Self> {
+        Self::all()
+            .iter()
+            .find(|provider| provider.display_name().eq_ignore_ascii_case(name))
+            .cloned()
+            .or_else(|| name.parse().ok())
+    }
+
+    /// List price per minute of audio in US dollars, for cost estimates
+    ///
+    /// Also shown as `ProviderCapabilities::typical_cost`; zero for local providers.
+    pub fn cost_per_minute_usd(&self) -> f64 {
+        match self {
+            Self::OpenAI | Self::OpenAIRealtime => 0.006,
+            Self::Mistral => 0.001,
+            Self::Groq => 0.04 / 60.0,
+            Self::Deepgram => 0.0043,
+            Self::DeepgramRealtime => 0.0077,
+            Self::ElevenLabs => 0.40 / 60.0,
+            Self::LocalWhisper | Self::LocalParakeet | Self::Mock => 0.0,
+        }
+    }
+
+    /// Whether the provider returns segment timestamps (for subtitles)
+    ///
+    /// These are the providers that take OpenAI's `response_format`; the
+    /// OpenAI-compatible client asks every other provider for plain JSON.
+    pub fn supports_timestamps(&self) -> bool {
+        matches!(
+            self,
+            TranscriptionProvider::OpenAI | TranscriptionProvider::Groq
+        )
+    }
+
     /// Whether this provider requires an API key (vs path/URL for local/remote)
     pub fn requires_api_key(&self) -> bool {
         !matches!(
@@ -146,6 +248,124 @@ impl TranscriptionProvider {
         )
     }
 
+    /// Whether the provider writes spoken numbers as digits itself
+    ///
+    /// With `transcription.format_numbers` on, other providers' transcripts
+    /// get the local `format_spoken_numbers` pass instead.
+    pub fn formats_numerals(&self) -> bool {
+        matches!(self, Self::Deepgram | Self::DeepgramRealtime)
+    }
+
+    /// Streaming variant of this provider, if it has one
+    pub fn realtime_variant(&self) -> Option<TranscriptionProvider> {
+        match self {
+            Self::OpenAI => Some(Self::OpenAIRealtime),
+            Self::Deepgram => Some(Self::DeepgramRealtime),
+            _ => None,
+        }
+    }
+
+    /// Display name, key requirements, features and cost of this provider
+    pub fn capabilities(&self) -> ProviderCapabilities {
+        let (diarization, timestamps, translation) = match self {
+            Self::OpenAI | Self::LocalWhisper => (false, true, true),
+            Self::Groq | Self::Mistral | Self::LocalParakeet => (false, true, false),
+            Self::Deepgram | Self::DeepgramRealtime | Self::ElevenLabs => (true, true, false),
+            Self::OpenAIRealtime | Self::Mock => (false, false, false),
+        };
+        let api_key_placeholder = match self.api_key_prefix() {
+            Some("sk-") => "sk-...",
+            Some("gsk_") => "gsk_...",
+            _ if self.requires_api_key() => "...",
+            _ => "",
+        };
+
+        ProviderCapabilities {
+            id: self.as_str(),
+            display_name: self.display_name(),
+            requires_api_key: self.requires_api_key(),
+            is_local: self.is_local(),
+            api_key_url: self.api_key_url(),
+            api_key_placeholder,
+            realtime_variant: self.realtime_variant().map(|p| p.as_str()),
+            diarization,
+            timestamps,
+            translation,
+            typical_cost: self.typical_cost(),
+        }
+    }
+
+    /// List price for display: per minute, or per hour for prices quoted
+    /// that way (those don't come out even at four decimals per minute)
+    fn typical_cost(&self) -> String {
+        let per_minute = self.cost_per_minute_usd();
+        if per_minute == 0.0 {
+            return "Free (runs on device)".to_string();
+        }
+
+        let hundredths_of_cent = per_minute * 10_000.0;
+        let price = if (hundredths_of_cent - hundredths_of_cent.round()).abs() < 1e-6 {
+            let amount = format!("{per_minute:.4}");
+            format!("${}/min", amount.trim_end_matches('0'))
+        } else {
+            format!("${:.2}/hour", per_minute * 60.0)
+        };
+        match self {
+            Self::Deepgram | Self::DeepgramRealtime => format!("{price}, free credit to start"),
+            _ => price,
+        }
+    }
+
+    /// Where to create or look up an API key for this provider
+    pub fn api_key_url(&self) -> Option<&'static str> {
+        match self {
+            Self::OpenAI | Self::OpenAIRealtime => Some("https://platform.openai.com/api-keys"),
+            Self::Mistral => Some("https://console.mistral.ai/api-keys"),
+            Self::Groq => Some("https://console.groq.com/keys"),
+            Self::Deepgram | Self::DeepgramRealtime => Some("https://console.deepgram.com"),
+            Self::ElevenLabs => Some("https://elevenlabs.io/app/settings/api-keys"),
+            Self::LocalWhisper | Self::LocalParakeet | Self::Mock => None,
+        }
+    }
+
+    /// Prefix every API key of this provider starts with, if any
+    pub fn api_key_prefix(&self) -> Option<&'static str> {
+        match self {
+            Self::OpenAI | Self::OpenAIRealtime => Some("sk-"),
+            Self::Groq => Some("gsk_"),
+            _ => None,
+        }
+    }
+
+    /// Check that `key` looks like an API key for this provider
+    ///
+    /// Offline check of prefix and length only, shared by the CLI, desktop
+    /// and mobile apps so they accept the same keys. Surrounding whitespace
+    /// is ignored. Providers without API keys accept anything.
+    pub fn validate_key_format(&self, key: &str) -> Result<(), KeyFormatError> {
+        if !self.requires_api_key() || *self == Self::Mock {
+            return Ok(());
+        }
+
+        let key = key.trim();
+        let provider = self.display_name();
+        if key.is_empty() {
+            return Err(KeyFormatError::Empty { provider });
+        }
+        if let Some(prefix) = self.api_key_prefix()
+            && !key.starts_with(prefix)
+        {
+            return Err(KeyFormatError::WrongPrefix { provider, prefix });
+        }
+        if key.len() < MIN_API_KEY_LENGTH {
+            return Err(KeyFormatError::TooShort {
+                provider,
+                min_length: MIN_API_KEY_LENGTH,
+            });
+        }
+        Ok(())
+    }
+
     /// Get the API key name for this provider.
     ///
     /// Realtime variants share API keys with their base providers:
@@ -159,6 +379,37 @@ impl TranscriptionProvider {
         }
     }
 
+    /// ISO-639-1 codes this provider supports (None = all Whisper languages)
+    pub fn supported_languages(&self) -> Option<&'static [&'static str]> {
+        match self {
+            Self::OpenAI | Self::OpenAIRealtime => Some(super::language::OPENAI_LANGUAGES),
+            Self::Mistral => Some(super::language::MISTRAL_LANGUAGES),
+            Self::Deepgram | Self::DeepgramRealtime => Some(super::language::DEEPGRAM_LANGUAGES),
+            Self::LocalParakeet => Some(super::language::PARAKEET_LANGUAGES),
+            Self::Groq | Self::ElevenLabs | Self::LocalWhisper | Self::Mock => None,
+        }
+    }
+
+    /// Whether this provider supports the given ISO-639-1 language code
+    pub fn supports_language(&self, code: &str) -> bool {
+        self.supported_languages()
+            .is_none_or(|languages| languages.contains(&code))
+    }
+
+    /// Whether this provider detects the language when none is set
+    ///
+    /// Deepgram assumes English unless a language is given.
+    pub fn supports_auto_detect(&self) -> bool {
+        !matches!(self, Self::Deepgram | Self::DeepgramRealtime)
+    }
+
+    /// Providers that support the given language code
+    pub fn supporting_language(code: &str) -> impl Iterator<Item = &'static TranscriptionProvider> {
+        Self::all()
+            .iter()
+            .filter(move |p| p.supports_language(code))
+    }
+
     /// Configure model memory behavior for local providers.
     ///
     /// When `keep` is true, the model stays loaded in memory between
@@ -200,6 +451,7 @@ impl std::str::FromStr for TranscriptionProvider {
             "local-parakeet" | "localparakeet" | "parakeet" => {
                 Ok(TranscriptionProvider::LocalParakeet)
             }
+            "mock" => Ok(TranscriptionProvider::Mock),
             _ => Err(format!(
                 "Unknown provider: {}. Available: openai, openai-realtime, mistral, groq, deepgram, deepgram-realtime, elevenlabs, local-whisper, local-parakeet",
                 s
@@ -207,3 +459,126 @@ impl std::str::FromStr for TranscriptionProvider {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typical_cost_matches_cost_per_minute() {
+        let cost = |provider: TranscriptionProvider| provider.capabilities().typical_cost;
+        assert_eq!(cost(TranscriptionProvider::OpenAI), "$0.006/min");
+        assert_eq!(cost(TranscriptionProvider::Mistral), "$0.001/min");
+        assert_eq!(cost(TranscriptionProvider::Groq), "$0.04/hour");
+        assert_eq!(
+            cost(TranscriptionProvider::Deepgram),
+            "$0.0043/min, free credit to start"
+        );
+        assert_eq!(
+            cost(TranscriptionProvider::DeepgramRealtime),
+            "$0.0077/min, free credit to start"
+        );
+        assert_eq!(cost(TranscriptionProvider::ElevenLabs), "$0.40/hour");
+        assert_eq!(
+            cost(TranscriptionProvider::LocalWhisper),
+            "Free (runs on device)"
+        );
+    }
+
+    /// A key of exactly the minimum length with `prefix`
+    fn key_with(prefix: &str) -> String {
+        format!("{prefix}{}", "x".repeat(MIN_API_KEY_LENGTH - prefix.len()))
+    }
+
+    #[test]
+    fn test_key_format_accepts_prefixed_keys() {
+        for provider in [
+            TranscriptionProvider::OpenAI,
+            TranscriptionProvider::OpenAIRealtime,
+        ] {
+            assert_eq!(provider.validate_key_format(&key_with("sk-")), Ok(()));
+            assert_eq!(provider.validate_key_format(&key_with("sk-proj-")), Ok(()));
+        }
+        assert_eq!(
+            TranscriptionProvider::Groq.validate_key_format(&key_with("gsk_")),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_key_format_accepts_unprefixed_providers() {
+        for provider in [
+            TranscriptionProvider::Mistral,
+            TranscriptionProvider::Deepgram,
+            TranscriptionProvider::DeepgramRealtime,
+            TranscriptionProvider::ElevenLabs,
+        ] {
+            assert_eq!(provider.validate_key_format(&key_with("")), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_key_format_ignores_surrounding_whitespace() {
+        let key = format!("  {}\n", key_with("sk-"));
+        assert_eq!(
+            TranscriptionProvider::OpenAI.validate_key_format(&key),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_key_format_rejects_empty() {
+        for provider in [
+            TranscriptionProvider::OpenAI,
+            TranscriptionProvider::Groq,
+            TranscriptionProvider::Mistral,
+        ] {
+            assert!(matches!(
+                provider.validate_key_format("   "),
+                Err(KeyFormatError::Empty { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_key_format_rejects_wrong_prefix() {
+        assert_eq!(
+            TranscriptionProvider::OpenAI.validate_key_format(&key_with("gsk_")),
+            Err(KeyFormatError::WrongPrefix {
+                provider: "OpenAI",
+                prefix: "sk-",
+            })
+        );
+        assert!(matches!(
+            TranscriptionProvider::Groq.validate_key_format(&key_with("sk-")),
+            Err(KeyFormatError::WrongPrefix { prefix: "gsk_", .. })
+        ));
+    }
+
+    #[test]
+    fn test_key_format_rejects_short_keys() {
+        let short = &key_with("sk-")[..MIN_API_KEY_LENGTH - 1];
+        assert!(matches!(
+            TranscriptionProvider::OpenAI.validate_key_format(short),
+            Err(KeyFormatError::TooShort {
+                min_length: MIN_API_KEY_LENGTH,
+                ..
+            })
+        ));
+        assert!(matches!(
+            TranscriptionProvider::Deepgram.validate_key_format("abc123"),
+            Err(KeyFormatError::TooShort { .. })
+        ));
+    }
+
+    #[test]
+    fn test_key_format_skips_providers_without_keys() {
+        for provider in [
+            TranscriptionProvider::LocalWhisper,
+            TranscriptionProvider::LocalParakeet,
+            TranscriptionProvider::Mock,
+        ] {
+            assert_eq!(provider.validate_key_format(""), Ok(()));
+        }
+    }
+}
@@ -14,26 +14,98 @@
 //!   └── Io(std::io::Error)      - Generic I/O errors
 //! ```
 //!
-//! # Migration Strategy
+//! # Matching on errors
 //!
-//! This error system is designed for gradual migration from `anyhow::Result`:
+//! The embedding API ([`transcribe_file`](crate::transcribe_file) and
+//! friends) returns `Result<T, WhisError>`. [`WhisError::kind`] sorts any
+//! error into a coarse [`ErrorKind`], so callers can react to, say, a
+//! rejected API key differently from a network timeout without knowing
+//! which provider produced it:
 //!
-//! 1. **Phase 1** (current): Error types defined, re-exported at crate root
-//! 2. **Phase 2**: Migrate audio and provider modules to use typed errors
-//! 3. **Phase 3**: Update public APIs to return `Result<T, WhisError>`
-//! 4. **Phase 4**: Remove `anyhow` dependency from whis-core
+//! ```rust,no_run
+//! use whis_core::{ErrorKind, TranscribeOptions, TranscriptionProvider, transcribe_file};
 //!
-//! # Backward Compatibility
+//! # async fn run() {
+//! let opts = TranscribeOptions::new(TranscriptionProvider::Groq, "gsk_...");
+//! match transcribe_file("meeting.wav", &opts).await {
+//!     Ok(result) => println!("{}", result.text),
+//!     Err(e) if e.kind() == ErrorKind::Auth => eprintln!("Check your API key: {e}"),
+//!     Err(e) if e.kind() == ErrorKind::Network => eprintln!("Offline? {e}"),
+//!     Err(e) => eprintln!("{e}"),
+//! }
+//! # }
+//! ```
+//!
+//! # anyhow interop
 //!
-//! During migration, both error systems coexist:
-//! - New code uses `WhisError`
-//! - Existing code continues using `anyhow::Error`
-//! - Conversions provided via `From` implementations
+//! Internally most code still uses `anyhow::Error`. Converting one into a
+//! `WhisError` recovers typed errors it carries (`WhisError`, provider,
+//! audio, HTTP and I/O errors); anything else becomes `Other` with the full
+//! context chain as message. `WhisError` converts into `anyhow::Error` as
+//! any std error does.
 
 // Re-export domain-specific error types for convenience
 pub use crate::audio::AudioError;
 pub use crate::provider::ProviderError;
 
+/// Coarse classification of a [`WhisError`], stable across providers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Recording, audio devices, decoding or encoding
+    Audio,
+    /// The provider failed to transcribe (bad request, server error, bad response)
+    Transcription,
+    /// API key missing, malformed or rejected by the provider
+    Auth,
+    /// Connection failures and timeouts
+    Network,
+    /// The provider is rate limiting requests
+    RateLimited,
+    /// A local model file/directory doesn't exist
+    ModelNotFound,
+    /// Model download or verification failed
+    Model,
+    /// Invalid configuration or settings
+    Config,
+    /// The recording contained no speech
+    NoSpeech,
+    /// The operation was cancelled by the caller
+    Cancelled,
+    /// The caller's overall deadline passed
+    /// ([`transcribe_samples_with_timeout`](crate::transcribe_samples_with_timeout))
+    TimedOut,
+    /// File system errors
+    Io,
+    /// Anything not classified above
+    Other,
+}
+
+impl ErrorKind {
+    /// Classify an `anyhow::Error` without consuming it
+    ///
+    /// Finds the same typed errors anywhere in the chain as converting it
+    /// into a [`WhisError`] would.
+    pub fn of(err: &anyhow::Error) -> Self {
+        if let Some(err) = err.downcast_ref::<WhisError>() {
+            return err.kind();
+        }
+        if let Some(err) = err.downcast_ref::<ProviderError>() {
+            return err.kind();
+        }
+        if err.downcast_ref::<AudioError>().is_some() {
+            return ErrorKind::Audio;
+        }
+        if let Some(http) = err.downcast_ref::<reqwest::Error>() {
+            return ProviderError::from_reqwest(http, String::new()).kind();
+        }
+        if err.downcast_ref::<std::io::Error>().is_some() {
+            return ErrorKind::Io;
+        }
+        ErrorKind::Other
+    }
+}
+
 /// Top-level error type for whis-core operations
 #[derive(Debug, thiserror::Error)]
 pub enum WhisError {
@@ -57,6 +129,14 @@ pub enum WhisError {
     #[error("Settings error: {0}")]
     Settings(String),
 
+    /// Transcription succeeded but contained no speech (silence, blank output)
+    #[error("No speech detected")]
+    NoSpeech,
+
+    /// The caller's overall deadline passed before transcription finished
+    #[error("Transcription timed out after {}s", .0.as_secs_f32())]
+    TimedOut(std::time::Duration),
+
     /// I/O errors
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
@@ -67,6 +147,20 @@ pub enum WhisError {
 }
 
 impl WhisError {
+    /// The kind of failure, for handling errors programmatically
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            WhisError::Audio(_) => ErrorKind::Audio,
+            WhisError::Provider(err) => err.kind(),
+            WhisError::Config(_) | WhisError::Settings(_) => ErrorKind::Config,
+            WhisError::Model(_) => ErrorKind::Model,
+            WhisError::NoSpeech => ErrorKind::NoSpeech,
+            WhisError::TimedOut(_) => ErrorKind::TimedOut,
+            WhisError::Io(_) => ErrorKind::Io,
+            WhisError::Other(_) => ErrorKind::Other,
+        }
+    }
+
     /// Create a configuration error
     pub fn config(msg: impl Into<String>) -> Self {
         Self::Config(msg.into())
@@ -91,12 +185,89 @@ impl WhisError {
 /// Convenience result type using WhisError
 pub type Result<T> = std::result::Result<T, WhisError>;
 
-// Allow converting from anyhow::Error during migration
+// Recover the typed error an anyhow::Error carries, if any
 impl From<anyhow::Error> for WhisError {
     fn from(err: anyhow::Error) -> Self {
-        WhisError::Other(err.to_string())
+        let err = match err.downcast::<WhisError>() {
+            Ok(err) => return err,
+            Err(err) => err,
+        };
+        let err = match err.downcast::<ProviderError>() {
+            Ok(err) => return err.into(),
+            Err(err) => err,
+        };
+        let err = match err.downcast::<AudioError>() {
+            Ok(err) => return err.into(),
+            Err(err) => err,
+        };
+        // HTTP and I/O errors keep the context chain in their message
+        if let Some(http) = err.downcast_ref::<reqwest::Error>() {
+            return ProviderError::from_reqwest(http, format!("{err:#}")).into();
+        }
+        if let Some(io) = err.downcast_ref::<std::io::Error>() {
+            return std::io::Error::new(io.kind(), format!("{err:#}")).into();
+        }
+        WhisError::Other(format!("{err:#}"))
     }
 }
 
 // Note: WhisError automatically implements Into<anyhow::Error>
 // because it implements std::error::Error via thiserror
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    fn unauthorized() -> ProviderError {
+        ProviderError::from_status("Groq", reqwest::StatusCode::UNAUTHORIZED, "invalid key")
+    }
+
+    #[test]
+    fn test_kind_of_finds_typed_errors_under_context() {
+        let auth = Err::<(), _>(unauthorized())
+            .context("Transcribing chunk 3")
+            .unwrap_err();
+        assert_eq!(ErrorKind::of(&auth), ErrorKind::Auth);
+
+        let no_speech = anyhow::Error::from(WhisError::NoSpeech).context("Recording");
+        assert_eq!(ErrorKind::of(&no_speech), ErrorKind::NoSpeech);
+
+        let io = anyhow::Error::from(std::io::Error::from(std::io::ErrorKind::NotFound));
+        assert_eq!(ErrorKind::of(&io), ErrorKind::Io);
+
+        let network = anyhow::Error::from(ProviderError::NetworkError("timed out".into()));
+        assert_eq!(ErrorKind::of(&network), ErrorKind::Network);
+
+        assert_eq!(ErrorKind::of(&anyhow::anyhow!("boom")), ErrorKind::Other);
+    }
+
+    #[test]
+    fn test_from_anyhow_recovers_typed_errors() {
+        let err = WhisError::from(anyhow::Error::from(unauthorized()));
+        assert!(matches!(
+            err,
+            WhisError::Provider(ProviderError::Unauthorized { ref provider, .. }) if provider == "Groq"
+        ));
+        assert_eq!(err.kind(), ErrorKind::Auth);
+
+        let err = WhisError::from(anyhow::Error::from(WhisError::config("no provider")));
+        assert_eq!(err.kind(), ErrorKind::Config);
+
+        let err = WhisError::from(
+            anyhow::Error::from(std::io::Error::from(std::io::ErrorKind::PermissionDenied))
+                .context("Writing transcript"),
+        );
+        assert!(
+            matches!(&err, WhisError::Io(io) if io.kind() == std::io::ErrorKind::PermissionDenied)
+        );
+        assert!(err.to_string().contains("Writing transcript"));
+    }
+
+    #[test]
+    fn test_from_anyhow_keeps_context_for_other_errors() {
+        let err = WhisError::from(anyhow::anyhow!("inner").context("outer"));
+        assert!(matches!(&err, WhisError::Other(msg) if msg == "outer: inner"));
+        assert_eq!(err.kind(), ErrorKind::Other);
+    }
+}
@@ -3,12 +3,38 @@
 //! This module provides a unified `Hotkey` struct and parsing logic used by both
 //! whis-cli and whis-desktop for global keyboard shortcuts.
 //!
+//! Grammar (case-insensitive, `+`-separated, any order, exactly one trigger):
+//!
+//! ```text
+//! modifier  = ctrl | control
+//!           | shift
+//!           | alt | option | opt
+//!           | super | meta | win | cmd | command
+//! trigger   = a-z | 0-9 | f1-f12 | space | enter | escape | tab | ...
+//!           | KeyW | Digit1 | ArrowUp   (W3C code names, as used by Tauri)
+//!           | mouse3 | mouse4 | mouse5  (middle, back and forward buttons)
+//!           | mediaplaypause | medianext | mediaprev | mediastop
+//!           | volumemute | volumedown | volumeup
+//! ```
+//!
+//! Every platform validates through [`Hotkey::parse`], so a hotkey accepted on
+//! one platform is accepted (and normalized) identically on the others.
+//! Not every listener can see every trigger, which
+//! [`Hotkey::check_supported`] reports:
+//!
+//! | Trigger       | Linux (grab) | macOS (listen) | Windows (global-hotkey) |
+//! |---------------|--------------|----------------|-------------------------|
+//! | keys          | yes          | yes            | yes                     |
+//! | mouse buttons | yes          | yes            | no                      |
+//! | media keys    | yes          | no             | yes                     |
+//!
 //! Note: AltGr (right Alt on international keyboards) is treated as a distinct key
 //! from Alt. Hotkeys configured with "Alt" will only match the left Alt key.
 
-use rdev::{Event, EventType, Key};
+use rdev::{Button, Event, EventType, Key};
 use std::collections::HashSet;
 use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
 /// Error type for hotkey parsing failures.
@@ -16,10 +42,20 @@ use thiserror::Error;
 pub enum HotkeyParseError {
     #[error("Empty hotkey string")]
     Empty,
+    #[error("Empty key in hotkey '{0}' (doubled or trailing '+')")]
+    EmptyToken(String),
     #[error("No main key specified in hotkey")]
     NoMainKey,
-    #[error("Unknown key: {0}")]
+    #[error("Unknown key '{0}'")]
     UnknownKey(String),
+    #[error("Hotkey has more than one main key: '{0}' and '{1}'")]
+    MultipleKeys(String, String),
+    #[error("'{trigger}' can't be used as a hotkey on {platform}: {reason}")]
+    Unsupported {
+        trigger: String,
+        platform: &'static str,
+        reason: &'static str,
+    },
 }
 
 /// Lock a mutex, recovering from poisoned state if needed.
@@ -32,20 +68,207 @@ pub fn lock_or_recover<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
         .unwrap_or_else(|poisoned| poisoned.into_inner())
 }
 
+/// A hotkey state change reported by [`HotkeyTracker`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HotkeyTransition {
+    /// All modifiers and the trigger are down (push-to-talk start)
+    Pressed,
+    /// The trigger was released (push-to-talk stop)
+    Released,
+}
+
+/// Tracks pressed keys and mouse buttons and reports hotkey transitions.
+///
+/// Shared by the rdev grab (Linux) and listen (macOS) paths:
+/// - Auto-repeat: press events while the hotkey is held don't fire again
+/// - Debounce: with a debounce window, a trigger release is only confirmed
+///   by [`HotkeyTracker::poll_release`] once the trigger stayed up for the
+///   window; pressing it again within the window is treated as switch bounce
+pub struct HotkeyTracker {
+    hotkey: Hotkey,
+    pressed_keys: HashSet<Key>,
+    pressed_buttons: HashSet<MouseButton>,
+    triggered: bool,
+    debounce: Duration,
+    /// When the trigger went up while triggered (release not yet confirmed)
+    release_pending: Option<Instant>,
+}
+
+impl HotkeyTracker {
+    pub fn new(hotkey: Hotkey) -> Self {
+        Self {
+            hotkey,
+            pressed_keys: HashSet::new(),
+            pressed_buttons: HashSet::new(),
+            triggered: false,
+            debounce: Duration::ZERO,
+            release_pending: None,
+        }
+    }
+
+    /// Set the debounce window (zero reports releases immediately)
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// The debounce window
+    pub fn debounce(&self) -> Duration {
+        self.debounce
+    }
+
+    /// Whether a release is waiting for [`HotkeyTracker::poll_release`]
+    pub fn release_pending(&self) -> bool {
+        self.release_pending.is_some()
+    }
+
+    /// Feed an input event, returning the transition it caused (if any)
+    pub fn handle(&mut self, event: &EventType) -> Option<HotkeyTransition> {
+        self.handle_at(event, Instant::now())
+    }
+
+    /// Confirm a pending release once the trigger stayed up for the debounce
+    /// window. Returns `Released` if it wasn't pressed again in the meantime.
+    pub fn poll_release(&mut self) -> Option<HotkeyTransition> {
+        self.poll_release_at(Instant::now())
+    }
+
+    fn handle_at(&mut self, event: &EventType, now: Instant) -> Option<HotkeyTransition> {
+        match *event {
+            EventType::KeyPress(key) => {
+                self.pressed_keys.insert(key);
+            }
+            EventType::ButtonPress(button) => {
+                self.pressed_buttons
+                    .insert(MouseButton::from_rdev(&button)?);
+            }
+            EventType::KeyRelease(key) => {
+                self.pressed_keys.remove(&key);
+                return self.trigger_up(self.hotkey.trigger.is_key(&key), now);
+            }
+            EventType::ButtonRelease(button) => {
+                let button = MouseButton::from_rdev(&button)?;
+                self.pressed_buttons.remove(&button);
+                return self.trigger_up(self.hotkey.trigger == Trigger::Mouse(button), now);
+            }
+            _ => return None,
+        }
+
+        if !self
+            .hotkey
+            .is_pressed(&self.pressed_keys, &self.pressed_buttons)
+        {
+            return None;
+        }
+
+        if let Some(since) = self.release_pending.take() {
+            if now.duration_since(since) < self.debounce {
+                // Bounce: the hotkey never really went up
+                return None;
+            }
+            // The release timer is late: report the release now
+            self.triggered = false;
+            return Some(HotkeyTransition::Released);
+        }
+
+        if self.triggered {
+            return None; // Auto-repeat
+        }
+        self.triggered = true;
+        Some(HotkeyTransition::Pressed)
+    }
+
+    fn trigger_up(&mut self, is_trigger: bool, now: Instant) -> Option<HotkeyTransition> {
+        if !is_trigger || !self.triggered || self.release_pending.is_some() {
+            return None;
+        }
+        if self.debounce.is_zero() {
+            self.triggered = false;
+            return Some(HotkeyTransition::Released);
+        }
+        self.release_pending = Some(now);
+        None
+    }
+
+    fn poll_release_at(&mut self, now: Instant) -> Option<HotkeyTransition> {
+        match self.release_pending {
+            Some(since) if now.duration_since(since) >= self.debounce => {
+                self.release_pending = None;
+                self.triggered = false;
+                Some(HotkeyTransition::Released)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Drive a tracker from input events and call the press/release callbacks.
+///
+/// Pending (debounced) releases are confirmed on a short-lived timer thread.
+/// Returns whether the event triggered the hotkey (grab consumes it).
+fn dispatcher<FPress, FRelease>(
+    hotkey: Hotkey,
+    debounce: Duration,
+    on_trigger: FPress,
+    on_release: FRelease,
+) -> impl Fn(&EventType) -> bool + Send + 'static
+where
+    FPress: Fn() + Send + 'static,
+    FRelease: Fn() + Send + Sync + 'static,
+{
+    let tracker = Arc::new(Mutex::new(
+        HotkeyTracker::new(hotkey).with_debounce(debounce),
+    ));
+    let on_release = Arc::new(on_release);
+
+    move |event: &EventType| -> bool {
+        let (transition, schedule_release) = {
+            let mut tracker = lock_or_recover(&tracker);
+            let was_pending = tracker.release_pending();
+            let transition = tracker.handle(event);
+            (transition, !was_pending && tracker.release_pending())
+        };
+
+        if schedule_release {
+            let tracker = Arc::clone(&tracker);
+            let on_release = Arc::clone(&on_release);
+            std::thread::spawn(move || {
+                std::thread::sleep(debounce);
+                let released = lock_or_recover(&tracker).poll_release().is_some();
+                if released {
+                    on_release();
+                }
+            });
+        }
+
+        match transition {
+            Some(HotkeyTransition::Pressed) => {
+                on_trigger();
+                true
+            }
+            Some(HotkeyTransition::Released) => {
+                on_release();
+                false
+            }
+            None => false,
+        }
+    }
+}
+
 /// Creates an rdev grab callback that tracks pressed keys and fires on hotkey match.
 ///
 /// This is the shared implementation used by both CLI and Desktop for direct keyboard
 /// capture on Linux. It handles:
-/// - Tracking currently pressed keys
+/// - Tracking currently pressed keys and mouse buttons
 /// - Detecting when the hotkey combination is pressed (push-to-talk start)
-/// - Detecting when the main key is released (push-to-talk stop)
-/// - Preventing double-fire on key repeat (via triggered flag)
+/// - Detecting when the trigger is released (push-to-talk stop), after `debounce`
+/// - Ignoring key repeat and press/release bounce
 ///
 /// Returns `None` to consume the event (hotkey was triggered), `Some(event)` to pass through.
 ///
 /// # Example
 /// ```ignore
-/// let callback = create_grab_callback(hotkey,
+/// let callback = create_grab_callback(hotkey, Duration::from_millis(30),
 ///     || println!("Hotkey pressed - start recording!"),
 ///     || println!("Hotkey released - stop recording!"),
 /// );
@@ -53,50 +276,162 @@ pub fn lock_or_recover<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
 /// ```
 pub fn create_grab_callback<FPress, FRelease>(
     hotkey: Hotkey,
+    debounce: Duration,
     on_trigger: FPress,
     on_release: FRelease,
 ) -> impl Fn(Event) -> Option<Event> + Send
 where
     FPress: Fn() + Send + 'static,
-    FRelease: Fn() + Send + 'static,
+    FRelease: Fn() + Send + Sync + 'static,
 {
-    let pressed_keys: Arc<Mutex<HashSet<Key>>> = Arc::new(Mutex::new(HashSet::new()));
-    let hotkey_triggered: Arc<Mutex<bool>> = Arc::new(Mutex::new(false));
-    let main_key = hotkey.key;
-
+    let dispatch = dispatcher(hotkey, debounce, on_trigger, on_release);
     move |event: Event| -> Option<Event> {
-        match event.event_type {
-            EventType::KeyPress(key) => {
-                let mut keys = lock_or_recover(&pressed_keys);
-                keys.insert(key);
+        if dispatch(&event.event_type) {
+            None // Consume event
+        } else {
+            Some(event)
+        }
+    }
+}
 
-                let mut triggered = lock_or_recover(&hotkey_triggered);
-                if *triggered {
-                    return Some(event); // Already triggered, pass through
-                }
+/// Creates an rdev listen callback (macOS) with the same handling as
+/// [`create_grab_callback`]; listen can't consume events.
+pub fn create_listen_callback<FPress, FRelease>(
+    hotkey: Hotkey,
+    debounce: Duration,
+    on_trigger: FPress,
+    on_release: FRelease,
+) -> impl FnMut(Event) + Send + 'static
+where
+    FPress: Fn() + Send + 'static,
+    FRelease: Fn() + Send + Sync + 'static,
+{
+    let dispatch = dispatcher(hotkey, debounce, on_trigger, on_release);
+    move |event: Event| {
+        dispatch(&event.event_type);
+    }
+}
 
-                if hotkey.is_pressed(&keys) {
-                    *triggered = true;
-                    on_trigger();
-                    return None; // Consume event
-                }
-                Some(event)
-            }
-            EventType::KeyRelease(key) => {
-                let mut keys = lock_or_recover(&pressed_keys);
-                keys.remove(&key);
-
-                if key == main_key {
-                    let mut triggered = lock_or_recover(&hotkey_triggered);
-                    if *triggered {
-                        *triggered = false;
-                        on_release();
-                    }
+/// Health of an rdev grab that is re-established after disruptions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GrabHealth {
+    /// The grab is receiving events again after being impaired
+    Active,
+    /// The grab keeps failing; hotkey presses may be missed while it retries
+    Impaired { failures: u32, last_error: String },
+}
+
+/// Exponential backoff for re-establishing a disrupted grab
+///
+/// The grab can be disrupted by autotyping tools (ydotool, enigo, ...).
+/// Retries start fast, since a single disruption usually recovers at once,
+/// and slow down while it keeps failing.
+#[derive(Debug, Default)]
+pub struct GrabBackoff {
+    failures: u32,
+}
+
+impl GrabBackoff {
+    /// Delay before the first retry
+    pub const INITIAL_DELAY: Duration = Duration::from_millis(300);
+    /// Upper bound for the delay between retries
+    pub const MAX_DELAY: Duration = Duration::from_secs(10);
+    /// Consecutive failures before the grab is reported as impaired
+    pub const WARN_AFTER: u32 = 5;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a failed attempt, returning how long to wait before retrying
+    pub fn failed(&mut self) -> Duration {
+        self.failures = self.failures.saturating_add(1);
+        let exponent = (self.failures - 1).min(16);
+        Self::INITIAL_DELAY
+            .saturating_mul(1 << exponent)
+            .min(Self::MAX_DELAY)
+    }
+
+    /// The grab worked again: start over from the initial delay
+    pub fn reset(&mut self) {
+        self.failures = 0;
+    }
+
+    /// Consecutive failures since the grab last worked
+    pub fn failures(&self) -> u32 {
+        self.failures
+    }
+
+    /// Whether the failures reached [`GrabBackoff::WARN_AFTER`]
+    pub fn impaired(&self) -> bool {
+        self.failures >= Self::WARN_AFTER
+    }
+}
+
+/// Run an rdev grab, re-establishing it with [`GrabBackoff`] when disrupted.
+///
+/// `make_callback` creates the grab callback for each attempt. `on_health`
+/// is called once the grab is impaired and again when it receives events
+/// after that. Only returns if the first attempt fails (typically missing
+/// permissions), which retrying won't fix.
+#[cfg(target_os = "linux")]
+pub fn grab_with_retry<M, C, H>(make_callback: M, on_health: H) -> rdev::GrabError
+where
+    M: Fn() -> C,
+    C: Fn(Event) -> Option<Event> + Send + 'static,
+    H: Fn(&GrabHealth) + Send + Sync + 'static,
+{
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    let on_health = Arc::new(on_health);
+    let impaired = Arc::new(AtomicBool::new(false));
+    let mut backoff = GrabBackoff::new();
+    let mut first_attempt = true;
+
+    loop {
+        let callback = make_callback();
+        let seen_event = Arc::new(AtomicBool::new(false));
+        let wrapped = {
+            let seen_event = Arc::clone(&seen_event);
+            let impaired = Arc::clone(&impaired);
+            let on_health = Arc::clone(&on_health);
+            move |event: Event| {
+                if !seen_event.swap(true, Ordering::Relaxed)
+                    && impaired.swap(false, Ordering::Relaxed)
+                {
+                    on_health(&GrabHealth::Active);
                 }
-                Some(event)
+                callback(event)
             }
-            _ => Some(event),
+        };
+
+        let error = match rdev::grab(wrapped) {
+            // grab() exited normally - shouldn't happen, but retry
+            Ok(()) => "grab exited".to_string(),
+            Err(e) if first_attempt && !seen_event.load(Ordering::Relaxed) => return e,
+            Err(e) => format!("{e:?}"),
+        };
+        first_attempt = false;
+
+        // A grab that delivered events was working until this disruption
+        if seen_event.load(Ordering::Relaxed) {
+            backoff.reset();
+        }
+        let delay = backoff.failed();
+        crate::verbose!(
+            "Keyboard grab lost ({error}), retry {} in {}ms",
+            backoff.failures(),
+            delay.as_millis()
+        );
+
+        if backoff.failures() == GrabBackoff::WARN_AFTER {
+            impaired.store(true, Ordering::Relaxed);
+            on_health(&GrabHealth::Impaired {
+                failures: backoff.failures(),
+                last_error: error,
+            });
         }
+        std::thread::sleep(delay);
     }
 }
 
@@ -120,14 +455,192 @@ macro_rules! key_to_str {
     };
 }
 
-/// Represents a hotkey combination (modifiers + main key)
+/// Mouse buttons usable as a trigger
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MouseButton {
+    /// Middle button / wheel click ("mouse3")
+    Middle,
+    /// Back side button ("mouse4")
+    Back,
+    /// Forward side button ("mouse5")
+    Forward,
+}
+
+/// Side button numbers as rdev reports them (`Button::Unknown`): X11/evdev
+/// numbering on Linux (8 = back, 9 = forward), CoreGraphics on macOS (3, 4)
+#[cfg(not(target_os = "macos"))]
+const SIDE_BUTTONS: (u8, u8) = (8, 9);
+#[cfg(target_os = "macos")]
+const SIDE_BUTTONS: (u8, u8) = (3, 4);
+
+impl MouseButton {
+    fn from_rdev(button: &Button) -> Option<Self> {
+        match *button {
+            Button::Middle => Some(Self::Middle),
+            Button::Unknown(n) if n == SIDE_BUTTONS.0 => Some(Self::Back),
+            Button::Unknown(n) if n == SIDE_BUTTONS.1 => Some(Self::Forward),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Middle => "Mouse3",
+            Self::Back => "Mouse4",
+            Self::Forward => "Mouse5",
+        }
+    }
+}
+
+/// Media keys usable as a trigger
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MediaKey {
+    PlayPause,
+    Next,
+    Previous,
+    Stop,
+    Mute,
+    VolumeDown,
+    VolumeUp,
+}
+
+impl MediaKey {
+    /// The key rdev reports: media keys have no named variant, so they
+    /// arrive as unknown keys carrying their X11 keycode (Linux)
+    fn rdev_key(self) -> Key {
+        Key::Unknown(match self {
+            Self::PlayPause => 172,
+            Self::Next => 171,
+            Self::Previous => 173,
+            Self::Stop => 174,
+            Self::Mute => 121,
+            Self::VolumeDown => 122,
+            Self::VolumeUp => 123,
+        })
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::PlayPause => "MediaPlayPause",
+            Self::Next => "MediaNext",
+            Self::Previous => "MediaPrev",
+            Self::Stop => "MediaStop",
+            Self::Mute => "VolumeMute",
+            Self::VolumeDown => "VolumeDown",
+            Self::VolumeUp => "VolumeUp",
+        }
+    }
+
+    /// W3C code name (global-hotkey on Windows)
+    fn code(self) -> &'static str {
+        match self {
+            Self::PlayPause => "MediaPlayPause",
+            Self::Next => "MediaTrackNext",
+            Self::Previous => "MediaTrackPrevious",
+            Self::Stop => "MediaStop",
+            Self::Mute => "AudioVolumeMute",
+            Self::VolumeDown => "AudioVolumeDown",
+            Self::VolumeUp => "AudioVolumeUp",
+        }
+    }
+
+    /// XKB keysym name (GlobalShortcuts portal)
+    fn keysym(self) -> &'static str {
+        match self {
+            Self::PlayPause => "XF86AudioPlay",
+            Self::Next => "XF86AudioNext",
+            Self::Previous => "XF86AudioPrev",
+            Self::Stop => "XF86AudioStop",
+            Self::Mute => "XF86AudioMute",
+            Self::VolumeDown => "XF86AudioLowerVolume",
+            Self::VolumeUp => "XF86AudioRaiseVolume",
+        }
+    }
+}
+
+/// The non-modifier part of a hotkey
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trigger {
+    Key(Key),
+    Mouse(MouseButton),
+    Media(MediaKey),
+}
+
+impl Trigger {
+    /// Parse a single (lowercase) trigger: mouse button, media key or key
+    fn parse(s: &str) -> Result<Self, HotkeyParseError> {
+        let trigger = match s {
+            "mouse3" | "mousemiddle" => Self::Mouse(MouseButton::Middle),
+            "mouse4" | "mouseback" => Self::Mouse(MouseButton::Back),
+            "mouse5" | "mouseforward" => Self::Mouse(MouseButton::Forward),
+            "mediaplaypause" | "playpause" => Self::Media(MediaKey::PlayPause),
+            "medianext" | "mediatracknext" => Self::Media(MediaKey::Next),
+            "mediaprev" | "mediaprevious" | "mediatrackprevious" => Self::Media(MediaKey::Previous),
+            "mediastop" => Self::Media(MediaKey::Stop),
+            "volumemute" | "audiovolumemute" | "mute" => Self::Media(MediaKey::Mute),
+            "volumedown" | "audiovolumedown" => Self::Media(MediaKey::VolumeDown),
+            "volumeup" | "audiovolumeup" => Self::Media(MediaKey::VolumeUp),
+            key => Self::Key(parse_key(key)?),
+        };
+        Ok(trigger)
+    }
+
+    /// Whether a key event is this trigger
+    fn is_key(&self, key: &Key) -> bool {
+        match self {
+            Self::Key(k) => k == key,
+            Self::Media(media) => media.rdev_key() == *key,
+            Self::Mouse(_) => false,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Key(key) => key_to_string(key),
+            Self::Mouse(button) => button.name(),
+            Self::Media(media) => media.name(),
+        }
+    }
+
+    /// W3C code name; mouse buttons (which have none) use their name
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Key(key) => key_to_code(key),
+            Self::Mouse(button) => button.name(),
+            Self::Media(media) => media.code(),
+        }
+    }
+
+    /// XKB keysym name; mouse buttons have none
+    fn keysym(&self) -> Option<String> {
+        match self {
+            Self::Key(key) => {
+                let keysym = match key_to_string(key) {
+                    "?" => return None,
+                    "Space" => "space",
+                    "Enter" => "Return",
+                    "Backspace" => "BackSpace",
+                    "PageUp" => "Page_Up",
+                    "PageDown" => "Page_Down",
+                    letter if letter.len() == 1 => return Some(letter.to_lowercase()),
+                    name => name,
+                };
+                Some(keysym.to_string())
+            }
+            Self::Mouse(_) => None,
+            Self::Media(media) => Some(media.keysym().to_string()),
+        }
+    }
+}
+
+/// Represents a hotkey combination (modifiers + trigger)
 #[derive(Debug, Clone)]
 pub struct Hotkey {
     pub ctrl: bool,
     pub shift: bool,
     pub alt: bool,
     pub super_key: bool,
-    pub key: Key,
+    pub trigger: Trigger,
 }
 
 impl Hotkey {
@@ -136,13 +649,12 @@ impl Hotkey {
     /// Supported modifier aliases:
     /// - ctrl, control
     /// - shift
-    /// - alt, option
-    /// - super, meta, win, cmd
+    /// - alt, option, opt
+    /// - super, meta, win, cmd, command
+    ///
+    /// Errors name the offending token (as written).
     pub fn parse(s: &str) -> Result<Self, HotkeyParseError> {
-        let lower = s.to_lowercase();
-        let parts: Vec<&str> = lower.split('+').map(|p| p.trim()).collect();
-
-        if parts.is_empty() {
+        if s.trim().is_empty() {
             return Err(HotkeyParseError::Empty);
         }
 
@@ -150,35 +662,63 @@ impl Hotkey {
         let mut shift = false;
         let mut alt = false;
         let mut super_key = false;
-        let mut main_key: Option<Key> = None;
+        let mut main_key: Option<(Trigger, &str)> = None;
 
-        for part in parts {
-            match part {
+        for part in s.split('+').map(str::trim) {
+            match part.to_lowercase().as_str() {
+                "" => return Err(HotkeyParseError::EmptyToken(s.to_string())),
                 "ctrl" | "control" => ctrl = true,
                 "shift" => shift = true,
-                "alt" | "option" => alt = true,
-                "super" | "meta" | "win" | "cmd" => super_key = true,
+                "alt" | "option" | "opt" => alt = true,
+                "super" | "meta" | "win" | "cmd" | "command" => super_key = true,
                 key_str => {
-                    main_key = Some(parse_key(key_str)?);
+                    let key = Trigger::parse(key_str)
+                        .map_err(|_| HotkeyParseError::UnknownKey(part.to_string()))?;
+                    if let Some((_, previous)) = main_key {
+                        return Err(HotkeyParseError::MultipleKeys(
+                            previous.to_string(),
+                            part.to_string(),
+                        ));
+                    }
+                    main_key = Some((key, part));
                 }
             }
         }
 
-        let key = main_key.ok_or(HotkeyParseError::NoMainKey)?;
+        let (trigger, _) = main_key.ok_or(HotkeyParseError::NoMainKey)?;
 
         Ok(Hotkey {
             ctrl,
             shift,
             alt,
             super_key,
-            key,
+            trigger,
         })
     }
 
-    /// Check if all required modifiers and the main key are currently pressed.
+    /// Check that this platform's listener can detect the trigger
+    /// (see the support table in the module docs).
+    pub fn check_supported(&self) -> Result<(), HotkeyParseError> {
+        let unsupported = |reason| HotkeyParseError::Unsupported {
+            trigger: self.trigger.name().to_string(),
+            platform: std::env::consts::OS,
+            reason,
+        };
+        match self.trigger {
+            Trigger::Mouse(_) if cfg!(target_os = "windows") => Err(unsupported(
+                "global hotkeys only cover the keyboard, use a key or media key",
+            )),
+            Trigger::Media(_) if cfg!(target_os = "macos") => Err(unsupported(
+                "media keys aren't delivered as key events, use a key or mouse button",
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check if all required modifiers and the trigger are currently pressed.
     ///
     /// This handles both left and right variants of modifier keys (e.g., ControlLeft/ControlRight).
-    pub fn is_pressed(&self, pressed: &HashSet<Key>) -> bool {
+    pub fn is_pressed(&self, pressed: &HashSet<Key>, buttons: &HashSet<MouseButton>) -> bool {
         let ctrl_ok = !self.ctrl
             || pressed.contains(&Key::ControlLeft)
             || pressed.contains(&Key::ControlRight);
@@ -188,15 +728,19 @@ impl Hotkey {
         let super_ok = !self.super_key
             || pressed.contains(&Key::MetaLeft)
             || pressed.contains(&Key::MetaRight);
-        let key_ok = pressed.contains(&self.key);
+        let trigger_ok = match &self.trigger {
+            Trigger::Key(key) => pressed.contains(key),
+            Trigger::Media(media) => pressed.contains(&media.rdev_key()),
+            Trigger::Mouse(button) => buttons.contains(button),
+        };
 
-        ctrl_ok && shift_ok && alt_ok && super_ok && key_ok
+        ctrl_ok && shift_ok && alt_ok && super_ok && trigger_ok
     }
 
     /// Convert the hotkey to a normalized string representation.
     ///
     /// Returns modifiers in consistent order (Ctrl, Alt, Shift, Super)
-    /// followed by the main key name.
+    /// followed by the trigger name.
     ///
     /// Examples:
     /// - "ctrl+alt+w" → "Ctrl+Alt+W"
@@ -215,20 +759,64 @@ impl Hotkey {
         if self.super_key {
             parts.push("Super");
         }
-        parts.push(key_to_string(&self.key));
+        parts.push(self.trigger.name());
+        parts.join("+")
+    }
+
+    /// Convert the hotkey to W3C key code names (e.g. "Ctrl+Alt+KeyW"),
+    /// the format used by Tauri and the global-hotkey crate.
+    ///
+    /// Modifiers are in the same order as [`Hotkey::to_normalized_string`].
+    pub fn to_code_string(&self) -> String {
+        let mut parts = Vec::new();
+        if self.ctrl {
+            parts.push("Ctrl");
+        }
+        if self.alt {
+            parts.push("Alt");
+        }
+        if self.shift {
+            parts.push("Shift");
+        }
+        if self.super_key {
+            parts.push("Super");
+        }
+        parts.push(self.trigger.code());
         parts.join("+")
     }
+
+    /// Convert the hotkey to the XDG shortcut format (e.g. "<Control><Alt>w"),
+    /// as used for preferred triggers by the GlobalShortcuts portal.
+    ///
+    /// Returns `None` for mouse buttons, which the portal can't bind.
+    pub fn to_xdg_trigger(&self) -> Option<String> {
+        let mut trigger = String::new();
+        if self.ctrl {
+            trigger.push_str("<Control>");
+        }
+        if self.alt {
+            trigger.push_str("<Alt>");
+        }
+        if self.shift {
+            trigger.push_str("<Shift>");
+        }
+        if self.super_key {
+            trigger.push_str("<Super>");
+        }
+        trigger.push_str(&self.trigger.keysym()?);
+        Some(trigger)
+    }
 }
 
-/// Parse a single key string into an rdev Key.
+/// Parse a single (lowercase) key string into an rdev Key.
 ///
-/// Handles both simple format ("w") and Tauri format ("keyw").
+/// Handles both simple format ("w", "1", "up") and W3C code names from Tauri
+/// ("keyw", "digit1", "arrowup").
 pub fn parse_key(s: &str) -> Result<Key, HotkeyParseError> {
-    // Handle "KeyX" format from Tauri (e.g., "keyw" -> "w")
-    let s = if s.starts_with("key") && s.len() == 4 {
-        &s[3..] // Extract just the letter
-    } else {
-        s
+    // Handle "KeyX" / "DigitX" / "ArrowX" formats (e.g., "keyw" -> "w")
+    let s = match s.strip_prefix("key").or_else(|| s.strip_prefix("digit")) {
+        Some(rest) if rest.len() == 1 => rest,
+        _ => s.strip_prefix("arrow").unwrap_or(s),
     };
 
     key_mappings!(s;
@@ -292,3 +880,228 @@ pub fn key_to_string(key: &Key) -> &'static str {
         RightArrow => "Right",
     )
 }
+
+/// Convert an rdev Key to its W3C code name ("KeyW", "Digit1", "ArrowUp").
+pub fn key_to_code(key: &Key) -> &'static str {
+    key_to_str!(key;
+        KeyA => "KeyA", KeyB => "KeyB", KeyC => "KeyC", KeyD => "KeyD", KeyE => "KeyE",
+        KeyF => "KeyF", KeyG => "KeyG", KeyH => "KeyH", KeyI => "KeyI", KeyJ => "KeyJ",
+        KeyK => "KeyK", KeyL => "KeyL", KeyM => "KeyM", KeyN => "KeyN", KeyO => "KeyO",
+        KeyP => "KeyP", KeyQ => "KeyQ", KeyR => "KeyR", KeyS => "KeyS", KeyT => "KeyT",
+        KeyU => "KeyU", KeyV => "KeyV", KeyW => "KeyW", KeyX => "KeyX", KeyY => "KeyY",
+        KeyZ => "KeyZ",
+        Num0 => "Digit0", Num1 => "Digit1", Num2 => "Digit2", Num3 => "Digit3",
+        Num4 => "Digit4", Num5 => "Digit5", Num6 => "Digit6", Num7 => "Digit7",
+        Num8 => "Digit8", Num9 => "Digit9",
+        F1 => "F1", F2 => "F2", F3 => "F3", F4 => "F4", F5 => "F5",
+        F6 => "F6", F7 => "F7", F8 => "F8", F9 => "F9", F10 => "F10",
+        F11 => "F11", F12 => "F12",
+        Space => "Space",
+        Return => "Enter",
+        Escape => "Escape",
+        Tab => "Tab",
+        Backspace => "Backspace",
+        Delete => "Delete",
+        Insert => "Insert",
+        Home => "Home",
+        End => "End",
+        PageUp => "PageUp",
+        PageDown => "PageDown",
+        UpArrow => "ArrowUp",
+        DownArrow => "ArrowDown",
+        LeftArrow => "ArrowLeft",
+        RightArrow => "ArrowRight",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(input: &str) -> String {
+        let hotkey = Hotkey::parse(input).unwrap();
+        let normalized = hotkey.to_normalized_string();
+        let reparsed = Hotkey::parse(&normalized).unwrap();
+        assert_eq!(reparsed.to_normalized_string(), normalized, "{input}");
+
+        let from_code = Hotkey::parse(&hotkey.to_code_string()).unwrap();
+        assert_eq!(from_code.to_normalized_string(), normalized, "{input}");
+        normalized
+    }
+
+    #[test]
+    fn test_round_trip() {
+        assert_eq!(round_trip("ctrl+alt+w"), "Ctrl+Alt+W");
+        assert_eq!(round_trip("super+shift+r"), "Shift+Super+R");
+        assert_eq!(round_trip("Ctrl + Shift + F12"), "Ctrl+Shift+F12");
+        assert_eq!(round_trip("alt+1"), "Alt+1");
+        assert_eq!(round_trip("ctrl+pgdn"), "Ctrl+PageDown");
+        assert_eq!(round_trip("shift+return"), "Shift+Enter");
+        assert_eq!(round_trip("ctrl+up"), "Ctrl+Up");
+        assert_eq!(round_trip("space"), "Space");
+    }
+
+    #[test]
+    fn test_mouse_and_media_triggers() {
+        assert_eq!(round_trip("mouse4"), "Mouse4");
+        assert_eq!(round_trip("ctrl+mouseforward"), "Ctrl+Mouse5");
+        assert_eq!(round_trip("MediaPlayPause"), "MediaPlayPause");
+        assert_eq!(round_trip("shift+audiovolumeup"), "Shift+VolumeUp");
+
+        let hotkey = Hotkey::parse("medianext").unwrap();
+        assert_eq!(hotkey.to_code_string(), "MediaTrackNext");
+
+        assert!(matches!(
+            Hotkey::parse("mouse4+mouse5"),
+            Err(HotkeyParseError::MultipleKeys(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_tracker_mouse_trigger() {
+        let mut tracker = HotkeyTracker::new(Hotkey::parse("ctrl+mouse3").unwrap());
+        assert_eq!(
+            tracker.handle(&EventType::ButtonPress(Button::Middle)),
+            None
+        );
+        assert_eq!(
+            tracker.handle(&EventType::ButtonRelease(Button::Middle)),
+            None
+        );
+
+        tracker.handle(&EventType::KeyPress(Key::ControlLeft));
+        assert_eq!(
+            tracker.handle(&EventType::ButtonPress(Button::Middle)),
+            Some(HotkeyTransition::Pressed)
+        );
+        assert_eq!(
+            tracker.handle(&EventType::ButtonPress(Button::Middle)),
+            None
+        );
+        assert_eq!(
+            tracker.handle(&EventType::ButtonRelease(Button::Middle)),
+            Some(HotkeyTransition::Released)
+        );
+    }
+
+    #[test]
+    fn test_tracker_ignores_auto_repeat() {
+        let mut tracker = HotkeyTracker::new(Hotkey::parse("f9").unwrap());
+        let press = EventType::KeyPress(Key::F9);
+        assert_eq!(tracker.handle(&press), Some(HotkeyTransition::Pressed));
+        assert_eq!(tracker.handle(&press), None);
+        assert_eq!(tracker.handle(&press), None);
+        assert_eq!(
+            tracker.handle(&EventType::KeyRelease(Key::F9)),
+            Some(HotkeyTransition::Released)
+        );
+    }
+
+    #[test]
+    fn test_tracker_debounce() {
+        let debounce = Duration::from_millis(30);
+        let mut tracker = HotkeyTracker::new(Hotkey::parse("f9").unwrap()).with_debounce(debounce);
+        let press = EventType::KeyPress(Key::F9);
+        let release = EventType::KeyRelease(Key::F9);
+        let t0 = Instant::now();
+        let ms = |n| t0 + Duration::from_millis(n);
+
+        assert_eq!(
+            tracker.handle_at(&press, t0),
+            Some(HotkeyTransition::Pressed)
+        );
+
+        // Bounce: up and down again within the window, nothing reported
+        assert_eq!(tracker.handle_at(&release, ms(100)), None);
+        assert!(tracker.release_pending());
+        assert_eq!(tracker.handle_at(&press, ms(105)), None);
+        assert!(!tracker.release_pending());
+        assert_eq!(tracker.poll_release_at(ms(140)), None);
+
+        // Real release: confirmed once the window has passed
+        assert_eq!(tracker.handle_at(&release, ms(200)), None);
+        assert_eq!(tracker.poll_release_at(ms(210)), None);
+        assert_eq!(
+            tracker.poll_release_at(ms(230)),
+            Some(HotkeyTransition::Released)
+        );
+
+        // And the next press starts again
+        assert_eq!(
+            tracker.handle_at(&press, ms(300)),
+            Some(HotkeyTransition::Pressed)
+        );
+    }
+
+    #[test]
+    fn test_modifier_aliases() {
+        for alias in ["super", "meta", "win", "cmd", "command"] {
+            assert_eq!(round_trip(&format!("{alias}+a")), "Super+A");
+        }
+        for alias in ["alt", "option", "opt"] {
+            assert_eq!(round_trip(&format!("{alias}+a")), "Alt+A");
+        }
+        assert_eq!(round_trip("control+a"), "Ctrl+A");
+    }
+
+    #[test]
+    fn test_code_names() {
+        assert_eq!(round_trip("Ctrl+Alt+KeyW"), "Ctrl+Alt+W");
+        assert_eq!(round_trip("Ctrl+Digit5"), "Ctrl+5");
+        assert_eq!(round_trip("Super+ArrowLeft"), "Super+Left");
+
+        let hotkey = Hotkey::parse("cmd+option+w").unwrap();
+        assert_eq!(hotkey.to_code_string(), "Alt+Super+KeyW");
+        let hotkey = Hotkey::parse("ctrl+3").unwrap();
+        assert_eq!(hotkey.to_code_string(), "Ctrl+Digit3");
+    }
+
+    #[test]
+    fn test_errors_name_token() {
+        assert!(matches!(Hotkey::parse(""), Err(HotkeyParseError::Empty)));
+        assert!(matches!(Hotkey::parse("  "), Err(HotkeyParseError::Empty)));
+        assert!(matches!(
+            Hotkey::parse("ctrl+alt"),
+            Err(HotkeyParseError::NoMainKey)
+        ));
+        assert!(matches!(
+            Hotkey::parse("ctrl++w"),
+            Err(HotkeyParseError::EmptyToken(_))
+        ));
+
+        let err = Hotkey::parse("Ctrl+Hyper+W").unwrap_err();
+        assert!(matches!(&err, HotkeyParseError::UnknownKey(token) if token == "Hyper"));
+        assert_eq!(err.to_string(), "Unknown key 'Hyper'");
+
+        let err = Hotkey::parse("ctrl+a+B").unwrap_err();
+        assert!(
+            matches!(&err, HotkeyParseError::MultipleKeys(first, second) if first == "a" && second == "B")
+        );
+    }
+
+    #[test]
+    fn test_xdg_trigger() {
+        let xdg = |input: &str| Hotkey::parse(input).unwrap().to_xdg_trigger();
+        assert_eq!(xdg("ctrl+alt+w").as_deref(), Some("<Control><Alt>w"));
+        assert_eq!(
+            xdg("super+shift+space").as_deref(),
+            Some("<Shift><Super>space")
+        );
+        assert_eq!(xdg("ctrl+pageup").as_deref(), Some("<Control>Page_Up"));
+        assert_eq!(xdg("f9").as_deref(), Some("F9"));
+        assert_eq!(xdg("mediaplaypause").as_deref(), Some("XF86AudioPlay"));
+        assert_eq!(xdg("mouse4"), None);
+    }
+
+    #[test]
+    fn test_grab_backoff() {
+        let mut backoff = GrabBackoff::new();
+        let delays: Vec<_> = (0..8).map(|_| backoff.failed().as_millis()).collect();
+        assert_eq!(delays, [300, 600, 1200, 2400, 4800, 9600, 10000, 10000]);
+        assert!(backoff.impaired());
+
+        backoff.reset();
+        assert!(!backoff.impaired());
+        assert_eq!(backoff.failed(), GrabBackoff::INITIAL_DELAY);
+    }
+}
@@ -15,14 +15,144 @@
 //! // Pre-warm during app startup (optional, non-blocking)
 //! whis_core::http::warmup_http_client()?;
 //! ```
+//!
+//! # Timeouts
+//!
+//! Transcription requests use [`RequestTimeouts`]: a connect timeout on the
+//! client, a deadline on the streamed audio body, and a request timeout of
+//! upload + response time. Apps call [`set_request_timeouts`] with the user's
+//! settings; the upload and response limits are applied per request, and a
+//! changed connect timeout replaces the shared client on its next use.
 
 use anyhow::{Context, Result};
-use std::sync::OnceLock;
+use futures_util::StreamExt;
+use std::io::Read;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
 
+use crate::configuration::{DEFAULT_CONNECT_TIMEOUT_SECS, DEFAULT_UPLOAD_TIMEOUT_SECS};
 use crate::provider::DEFAULT_TIMEOUT_SECS;
+use crate::settings::TimeoutSettings;
+
+/// Global HTTP client instance, with the connect timeout it was built with
+static HTTP_CLIENT: RwLock<Option<(Duration, reqwest::Client)>> = RwLock::new(None);
+
+/// Timeouts for transcription requests (None = defaults)
+static REQUEST_TIMEOUTS: RwLock<Option<RequestTimeouts>> = RwLock::new(None);
+
+/// Size of the pieces the audio body is streamed in
+const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Network timeouts for transcription requests
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestTimeouts {
+    /// Establishing the connection (TCP + TLS)
+    pub connect: Duration,
+    /// Uploading the audio body
+    pub upload: Duration,
+    /// Waiting for the response once the audio is uploaded
+    pub response: Duration,
+}
+
+impl RequestTimeouts {
+    /// Limit for the whole request (upload + response)
+    pub fn request(&self) -> Duration {
+        self.upload + self.response
+    }
+}
+
+impl Default for RequestTimeouts {
+    fn default() -> Self {
+        Self {
+            connect: Duration::from_secs(DEFAULT_CONNECT_TIMEOUT_SECS),
+            upload: Duration::from_secs(DEFAULT_UPLOAD_TIMEOUT_SECS),
+            response: Duration::from_secs(DEFAULT_TIMEOUT_SECS),
+        }
+    }
+}
+
+impl From<&TimeoutSettings> for RequestTimeouts {
+    fn from(settings: &TimeoutSettings) -> Self {
+        Self {
+            connect: Duration::from_secs(settings.connect_timeout_secs),
+            upload: Duration::from_secs(settings.upload_timeout_secs),
+            response: Duration::from_secs(settings.timeout_secs),
+        }
+    }
+}
+
+/// Use these timeouts for subsequent transcription requests
+///
+/// Takes effect for requests started afterwards, also when the shared
+/// client already exists (see [`get_http_client`]).
+pub fn set_request_timeouts(timeouts: RequestTimeouts) {
+    *REQUEST_TIMEOUTS.write().unwrap() = Some(timeouts);
+}
+
+/// Timeouts for transcription requests
+pub fn request_timeouts() -> RequestTimeouts {
+    REQUEST_TIMEOUTS.read().unwrap().unwrap_or_default()
+}
 
-/// Global HTTP client instance
-static HTTP_CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+/// Audio body that fails once uploading takes longer than `deadline`
+///
+/// The error surfaces as a retryable request error, like a 408 from the
+/// server. A fully stalled connection is caught by the request timeout.
+pub(crate) fn upload_body(data: Vec<u8>, deadline: Duration) -> reqwest::Body {
+    let started = Instant::now();
+    let pieces: Vec<Vec<u8>> = data.chunks(UPLOAD_CHUNK_SIZE).map(<[u8]>::to_vec).collect();
+    reqwest::Body::wrap_stream(futures_util::stream::iter(pieces).map(move |piece| {
+        if started.elapsed() > deadline {
+            Err(upload_timed_out(deadline))
+        } else {
+            Ok(piece)
+        }
+    }))
+}
+
+/// Blocking counterpart of [`upload_body`], for `Body::sized` and
+/// `Part::reader_with_length`
+pub(crate) fn upload_reader(data: Vec<u8>, deadline: Duration) -> impl Read + Send + 'static {
+    DeadlineReader {
+        inner: std::io::Cursor::new(data),
+        started: Instant::now(),
+        deadline,
+    }
+}
+
+/// Reader that fails once reading takes longer than `deadline`
+struct DeadlineReader<R> {
+    inner: R,
+    started: Instant,
+    deadline: Duration,
+}
+
+impl<R: Read> Read for DeadlineReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.started.elapsed() > self.deadline {
+            return Err(upload_timed_out(self.deadline));
+        }
+        let len = buf.len().min(UPLOAD_CHUNK_SIZE);
+        self.inner.read(&mut buf[..len])
+    }
+}
+
+fn upload_timed_out(deadline: Duration) -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::TimedOut,
+        format!("upload took longer than {}s", deadline.as_secs()),
+    )
+}
+
+/// Blocking client with the configured transcription timeouts
+pub(crate) fn blocking_client() -> Result<reqwest::blocking::Client> {
+    let timeouts = request_timeouts();
+    reqwest::blocking::Client::builder()
+        .connect_timeout(timeouts.connect)
+        .timeout(timeouts.request())
+        .build()
+        .context("Failed to create HTTP client")
+}
 
 /// Get the global HTTP client, creating it if necessary.
 ///
@@ -30,25 +160,33 @@ static HTTP_CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
 /// - Mobile (mobile-tls feature): Uses bundled Mozilla CA certificates
 /// - Desktop: Uses the default platform certificate verifier
 ///
-/// # Returns
-///
-/// A reference to the global HTTP client.
+/// The client is rebuilt when the connect timeout from
+/// [`set_request_timeouts`] has changed since it was created. Clones share
+/// the connection pool, so this is cheap.
 ///
 /// # Errors
 ///
 /// Returns an error if the client cannot be created (should be rare).
-pub fn get_http_client() -> Result<&'static reqwest::Client> {
-    // Try to get existing client first (fast path)
-    if let Some(client) = HTTP_CLIENT.get() {
-        return Ok(client);
-    }
+pub fn get_http_client() -> Result<reqwest::Client> {
+    let connect = request_timeouts().connect;
 
-    // Create and store client (slow path, only happens once)
-    let client = create_http_client()?;
+    // Fast path: client exists with the current connect timeout
+    if let Some((built_with, client)) = HTTP_CLIENT.read().unwrap().as_ref()
+        && *built_with == connect
+    {
+        return Ok(client.clone());
+    }
 
-    // Use get_or_init to handle race condition where multiple threads
-    // might try to initialize simultaneously
-    Ok(HTTP_CLIENT.get_or_init(|| client))
+    // Slow path: first use, or the connect timeout changed
+    let mut cached = HTTP_CLIENT.write().unwrap();
+    if let Some((built_with, client)) = cached.as_ref()
+        && *built_with == connect
+    {
+        return Ok(client.clone());
+    }
+    let client = create_http_client(connect)?;
+    *cached = Some((connect, client.clone()));
+    Ok(client)
 }
 
 /// Pre-warm the HTTP client by initializing it.
@@ -75,7 +213,7 @@ pub fn warmup_http_client() -> Result<()> {
 ///
 /// This can be used to check if warmup has completed.
 pub fn is_http_client_ready() -> bool {
-    HTTP_CLIENT.get().is_some()
+    HTTP_CLIENT.read().unwrap().is_some()
 }
 
 /// Create an HTTP client configured for the current platform.
@@ -83,7 +221,7 @@ pub fn is_http_client_ready() -> bool {
 /// On mobile (mobile-tls feature), uses bundled Mozilla CA certificates
 /// to avoid Android's platform verifier JNI initialization issues.
 /// On desktop, uses the default platform certificate verifier.
-fn create_http_client() -> Result<reqwest::Client> {
+fn create_http_client(connect_timeout: Duration) -> Result<reqwest::Client> {
     #[cfg(feature = "mobile-tls")]
     {
         // Mobile: Use bundled webpki-roots to avoid Android TLS issues
@@ -98,7 +236,8 @@ fn create_http_client() -> Result<reqwest::Client> {
 
         // Create reqwest client with pre-configured TLS
         reqwest::Client::builder()
-            .timeout(std::time::Duration::from_secs(DEFAULT_TIMEOUT_SECS))
+            .connect_timeout(connect_timeout)
+            .timeout(Duration::from_secs(DEFAULT_TIMEOUT_SECS))
             .use_preconfigured_tls(tls_config)
             .build()
             .context("Failed to create HTTP client")
@@ -108,7 +247,8 @@ fn create_http_client() -> Result<reqwest::Client> {
     {
         // Desktop: Use default platform verifier
         reqwest::Client::builder()
-            .timeout(std::time::Duration::from_secs(DEFAULT_TIMEOUT_SECS))
+            .connect_timeout(connect_timeout)
+            .timeout(Duration::from_secs(DEFAULT_TIMEOUT_SECS))
             .build()
             .context("Failed to create HTTP client")
     }
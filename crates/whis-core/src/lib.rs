@@ -1,3 +1,32 @@
+//! Core library for whis voice-to-text.
+//!
+//! Shared by the CLI, desktop and mobile apps, and usable on its own.
+//!
+//! # Embedding
+//!
+//! [`TranscribeOptions`] bundles provider, API key (or local model path),
+//! language and optional post-processing. Hand it to [`transcribe_file`],
+//! [`transcribe_samples`] or [`record_and_transcribe`]:
+//!
+//! ```rust,no_run
+//! use whis_core::{TranscribeOptions, TranscriptionProvider, record_and_transcribe};
+//! use std::time::Duration;
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let opts = TranscribeOptions::new(TranscriptionProvider::OpenAI, "sk-...")
+//!     .language("en")
+//!     .record_duration(Duration::from_secs(5));
+//! let result = record_and_transcribe(&opts).await?;
+//! println!("{}", result.text);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`TranscribeOptions::from_settings`] picks up what the user configured for
+//! the apps. Lower-level building blocks (progressive chunked transcription,
+//! providers, recorder) are re-exported below for callers that need more
+//! control.
+
 // Domain modules (organized by concern)
 pub mod audio;
 pub mod configuration;
@@ -24,26 +53,47 @@ pub mod verbose;
 
 // Re-export audio types
 pub use audio::{
-    AudioDeviceInfo, AudioRecorder, ChunkerConfig, ProgressiveChunk, ProgressiveChunker,
-    RecordingData, VadConfig, list_audio_devices,
+    AudioDeviceInfo, AudioEncoder, AudioRecorder, ChunkerConfig, Cue, DEFAULT_MP3_BITRATE,
+    LevelCallback, ProgressiveChunk, ProgressiveChunker, RecorderConfig, RecordingData,
+    SegmenterConfig, Utterance, UtteranceSegmenter, VadConfig, create_encoder, create_mp3_encoder,
+    list_audio_devices, microphone_permission_hint, play_cue, play_cue_and_wait, set_mp3_bitrate,
 };
 
 // Re-export configuration types
 pub use configuration::{
-    DEFAULT_LANGUAGE, DEFAULT_OLLAMA_MODEL, DEFAULT_OLLAMA_URL, DEFAULT_POST_PROCESSOR,
-    DEFAULT_PROVIDER, DEFAULT_SHORTCUT, DEFAULT_SHORTCUT_MODE, DEFAULT_VAD_ENABLED,
-    DEFAULT_VAD_THRESHOLD,
+    DEFAULT_CHUNK_DURATION_SECS, MAX_CHUNK_DURATION_SECS, MIN_CHUNK_DURATION_SECS,
+};
+pub use configuration::{DEFAULT_CONNECT_TIMEOUT_SECS, DEFAULT_UPLOAD_TIMEOUT_SECS};
+pub use configuration::{
+    DEFAULT_CUE_VOLUME, DEFAULT_HOTKEY_DEBOUNCE_MS, DEFAULT_LANGUAGE, DEFAULT_MIN_RECORDING_MS,
+    DEFAULT_OLLAMA_MODEL, DEFAULT_OLLAMA_URL, DEFAULT_POST_PROCESSING_CHUNK_CHARS,
+    DEFAULT_POST_PROCESSOR, DEFAULT_PROVIDER, DEFAULT_SHORTCUT, DEFAULT_SHORTCUT_MODE,
+    DEFAULT_VAD_ENABLED, DEFAULT_VAD_THRESHOLD,
+};
+pub use configuration::{
+    KeyFormatError, LANGUAGES, Preset, PresetSource, ProviderCapabilities, TranscriptionProvider,
+    normalize_language, parse_language, resolve_language,
 };
-pub use configuration::{Preset, PresetSource, TranscriptionProvider};
 
 // Re-export transcription types
 #[cfg(feature = "local-transcription")]
 pub use transcription::progressive_transcribe_local;
 pub use transcription::{
-    DEFAULT_POST_PROCESSING_PROMPT, PostProcessConfig, PostProcessor, WarmupConfig,
+    ChunkingConfig, DEFAULT_POST_PROCESSING_PROMPT, PROMPT_VARIABLES, PartialTranscriptCallback,
+    PostProcessConfig, PostProcessor, PromptVariables, StageTimings, WarmupConfig,
     clear_warmup_cache, post_process, preload_ollama, progressive_transcribe_cloud,
-    resolve_post_processor_config, warmup_configured,
+    progressive_transcribe_cloud_with_partials, progressive_transcribe_cloud_with_timings,
+    render_prompt, resolve_post_processor_config, strip_context, warmup_configured,
+    with_clipboard_context,
+};
+pub use transcription::{
+    DEFAULT_RECORD_DURATION, ListenEvent, ListenHandle, TranscribeOptions, format_spoken_numbers,
+    formats_numbers_locally, listen, listen_with_events, normalize_transcript,
+    record_and_transcribe, record_and_transcribe_until, transcribe_samples,
+    transcribe_samples_with_timeout, truncate_at_sentence,
 };
+#[cfg(feature = "wav")]
+pub use transcription::{transcribe_file, transcribe_file_with_timeout};
 
 // Re-export provider types
 #[cfg(feature = "realtime")]
@@ -58,8 +108,10 @@ pub use provider::transcribe_raw;
 #[cfg(feature = "local-transcription")]
 pub use provider::transcribe_raw_parakeet;
 pub use provider::{
-    DEFAULT_TIMEOUT_SECS, ProgressCallback, TranscriptionBackend, TranscriptionRequest,
-    TranscriptionResult, TranscriptionStage, registry,
+    AudioFormat, CancellationToken, DEFAULT_TIMEOUT_SECS, ProgressCallback, ResponseFormat,
+    TranscriptSegment, TranscriptionBackend, TranscriptionRequest, TranscriptionRequestBuilder,
+    TranscriptionResult, TranscriptionStage, registry, set_numeral_formatting,
+    set_smart_formatting, set_upload_compression,
 };
 #[cfg(feature = "realtime")]
 pub use provider::{RealtimeTranscriptionBackend, get_realtime_backend};
@@ -74,15 +126,18 @@ pub use autotyping::{
     AutotypeBackend, AutotypeToolStatus, OutputMethod, autotype_text, get_autotype_tool_status,
 };
 #[cfg(feature = "clipboard")]
-pub use clipboard::{ClipboardMethod, copy_to_clipboard};
-pub use error::{AudioError, ProviderError, Result, WhisError};
+pub use clipboard::{ClipboardMethod, copy_to_clipboard, is_clipboard_available};
+pub use error::{AudioError, ErrorKind, ProviderError, Result, WhisError};
 pub use http::{get_http_client, is_http_client_ready, warmup_http_client};
 pub use settings::Settings;
-pub use state::RecordingState;
+pub use state::{RecordingState, StopReason};
 pub use verbose::set_verbose;
 
 #[cfg(feature = "hotkey")]
-pub use hotkey::{Hotkey, HotkeyParseError, key_to_string, lock_or_recover, parse_key};
+pub use hotkey::{
+    GrabBackoff, GrabHealth, Hotkey, HotkeyParseError, HotkeyTracker, HotkeyTransition, MediaKey,
+    MouseButton, Trigger, key_to_code, key_to_string, lock_or_recover, parse_key,
+};
 pub use platform::{Compositor, Platform, PlatformInfo, detect_platform, is_flatpak};
 
 // Legacy module aliases for backward compatibility
@@ -94,9 +149,10 @@ pub mod config {
 #[doc(hidden)]
 pub mod defaults {
     pub use crate::configuration::{
-        DEFAULT_LANGUAGE, DEFAULT_OLLAMA_MODEL, DEFAULT_OLLAMA_URL, DEFAULT_POST_PROCESSOR,
-        DEFAULT_PROVIDER, DEFAULT_SHORTCUT, DEFAULT_SHORTCUT_MODE, DEFAULT_VAD_ENABLED,
-        DEFAULT_VAD_THRESHOLD,
+        DEFAULT_HOTKEY_DEBOUNCE_MS, DEFAULT_LANGUAGE, DEFAULT_MIN_RECORDING_MS,
+        DEFAULT_OLLAMA_MODEL, DEFAULT_OLLAMA_URL, DEFAULT_POST_PROCESSING_CHUNK_CHARS,
+        DEFAULT_POST_PROCESSOR, DEFAULT_PROVIDER, DEFAULT_SHORTCUT, DEFAULT_SHORTCUT_MODE,
+        DEFAULT_VAD_ENABLED, DEFAULT_VAD_THRESHOLD,
     };
 }
 
@@ -108,8 +164,8 @@ pub mod preset {
 #[doc(hidden)]
 pub mod post_processing {
     pub use crate::transcription::{
-        DEFAULT_POST_PROCESSING_PROMPT, PostProcessConfig, PostProcessor, post_process,
-        resolve_post_processor_config,
+        ChunkingConfig, DEFAULT_POST_PROCESSING_PROMPT, PostProcessConfig, PostProcessor,
+        post_process, resolve_post_processor_config,
     };
 }
 
@@ -124,8 +180,8 @@ pub mod transcribe {
 pub mod ollama {
     pub use crate::transcription::{
         DEFAULT_OLLAMA_MODEL, DEFAULT_OLLAMA_URL, OLLAMA_MODEL_OPTIONS, OllamaModel,
-        ensure_ollama_ready, ensure_ollama_running, has_model, is_ollama_installed,
-        is_ollama_running, list_models, pull_model, pull_model_with_progress,
+        ensure_ollama_ready, ensure_ollama_ready_with_progress, ensure_ollama_running, has_model,
+        is_ollama_installed, is_ollama_running, list_models, pull_model, pull_model_with_progress,
     };
 }
 
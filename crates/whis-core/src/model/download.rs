@@ -1,5 +1,6 @@
 //! Shared download logic for models
 
+use super::ready::DownloadGuard;
 use super::types::ModelType;
 use anyhow::{Context, Result, anyhow};
 use std::fs;
@@ -39,6 +40,9 @@ pub fn download<M: ModelType>(model_type: &M, model_name: &str, dest: &Path) ->
 ///
 /// The callback receives (downloaded_bytes, total_bytes) and is called
 /// approximately every 1% of progress or every 500KB, whichever is more frequent.
+///
+/// While running, the download is registered with the readiness gate so
+/// transcription waits for it (see [`super::ready::wait_until_ready`]).
 pub fn download_with_progress<M, F>(
     model_type: &M,
     model_name: &str,
@@ -59,11 +63,18 @@ where
         )
     })?;
 
-    // Create parent directory if needed
+    // Create parent directory if needed (the download marker goes there too)
     if let Some(parent) = dest.parent() {
         fs::create_dir_all(parent).context("Failed to create models directory")?;
     }
 
+    // Registered until this function returns (success or failure)
+    let gate = DownloadGuard::register(dest, model_name);
+    let on_progress = |downloaded: u64, total: u64| {
+        gate.progress(downloaded, total);
+        on_progress(downloaded, total);
+    };
+
     // Download with progress
     let client = reqwest::blocking::Client::builder()
         .timeout(std::time::Duration::from_secs(600)) // 10 min timeout for large files
@@ -78,12 +89,16 @@ where
 
     let total_size = response.content_length().unwrap_or(0);
 
-    // Create temp file first, then rename on success
+    // Download into the cache directory first, then move into place on success
+    let temp_dir = crate::Settings::load().ui.cache_dir().join("downloads");
+    fs::create_dir_all(&temp_dir)
+        .with_context(|| format!("Failed to create {}", temp_dir.display()))?;
+    let temp_name = temp_dir.join(dest.file_name().unwrap_or_default());
     let temp_path = if model_type.needs_extraction() {
         // For archives, use the download extension for temp file
-        dest.with_extension(format!("tmp{}", model_type.download_extension()))
+        temp_name.with_extension(format!("tmp{}", model_type.download_extension()))
     } else {
-        dest.with_extension(format!("{}tmp", model_type.download_extension()))
+        temp_name.with_extension(format!("{}tmp", model_type.download_extension()))
     };
 
     let mut file = fs::File::create(&temp_path).context("Failed to create temp file")?;
@@ -138,13 +153,27 @@ where
         fs::remove_file(&temp_path).ok();
         eprintln!("[+] Extraction complete!");
     } else {
-        // Rename temp file to final destination
-        fs::rename(&temp_path, dest).context("Failed to finalize download")?;
+        // Move temp file to final destination
+        move_file(&temp_path, dest).context("Failed to finalize download")?;
     }
 
     Ok(())
 }
 
+/// Rename a file, copying instead when the cache directory is on another filesystem
+///
+/// The copy goes next to `to` first, so an interrupted copy never leaves a
+/// truncated file that would pass as a downloaded model.
+fn move_file(from: &Path, to: &Path) -> io::Result<()> {
+    if fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+    let staging = to.with_extension("partial");
+    fs::copy(from, &staging)?;
+    fs::rename(&staging, to)?;
+    fs::remove_file(from)
+}
+
 /// Ensure a model is available, downloading it if necessary
 pub fn ensure<M: ModelType>(model_type: &M, model_name: &str) -> Result<()> {
     let path = model_type.default_path(model_name);
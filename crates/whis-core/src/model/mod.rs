@@ -13,7 +13,8 @@
 //! Generic Operations
 //!   ├── download()     - Download with progress
 //!   ├── ensure()       - Download if missing
-//!   └── verify()       - Check if valid
+//!   ├── verify()       - Check if valid
+//!   └── ready          - Wait for in-progress downloads
 //! ```
 //!
 //! # Usage
@@ -32,10 +33,12 @@
 
 pub mod download;
 pub mod parakeet;
+pub mod ready;
 pub mod types;
 pub mod whisper;
 
 // Re-export commonly used types
+pub use ready::{ActiveDownload, MODEL_READY_TIMEOUT, active_downloads, wait_until_ready};
 pub use types::{ModelInfo, ModelType};
 pub use whisper::WhisperModel;
 
@@ -0,0 +1,267 @@
+//! Model readiness gate
+//!
+//! Tracks model downloads that are in progress so that transcription can
+//! wait for a download to finish instead of failing with "model not found".
+//! Downloads register themselves in [`download_with_progress`]; loaders call
+//! [`wait_until_ready`] before touching the model path.
+//!
+//! Besides the in-process registry, each download keeps a marker file next
+//! to the model (`.<name>.downloading`) with its progress, so a download
+//! started by another process (e.g. `whis setup`) is waited on too. A marker
+//! that hasn't been updated for [`STALE_MARKER_AGE`] is left over from a
+//! download that died and is ignored.
+//!
+//! [`download_with_progress`]: super::download::download_with_progress
+
+use anyhow::{Result, anyhow};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Condvar, Mutex, OnceLock};
+use std::time::{Duration, Instant, SystemTime};
+
+/// How long transcription waits for an in-progress download by default
+pub const MODEL_READY_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// Age after which a download marker without updates is considered stale
+const STALE_MARKER_AGE: Duration = Duration::from_secs(120);
+
+/// How often the marker file is rewritten with the current progress
+const MARKER_UPDATE_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How often a download in another process is checked while waiting
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// A model download that is currently in progress
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ActiveDownload {
+    /// Destination path of the model
+    pub path: PathBuf,
+    /// Model name (e.g. "small", "parakeet-v3")
+    pub model_name: String,
+    /// Bytes downloaded so far
+    pub downloaded: u64,
+    /// Total bytes (0 if unknown)
+    pub total: u64,
+}
+
+struct Registry {
+    downloads: Mutex<HashMap<PathBuf, ActiveDownload>>,
+    changed: Condvar,
+}
+
+fn registry() -> &'static Registry {
+    static REGISTRY: OnceLock<Registry> = OnceLock::new();
+    REGISTRY.get_or_init(|| Registry {
+        downloads: Mutex::new(HashMap::new()),
+        changed: Condvar::new(),
+    })
+}
+
+/// Marker file announcing a download to `path` to other processes
+fn marker_path(path: &Path) -> PathBuf {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!(".{name}.downloading"))
+}
+
+/// Write the marker for `download` (best effort)
+fn write_marker(download: &ActiveDownload) {
+    let content = format!(
+        "{}\n{}\n{}\n",
+        download.model_name, download.downloaded, download.total
+    );
+    if let Err(e) = fs::write(marker_path(&download.path), content) {
+        crate::verbose!("Failed to write download marker: {e}");
+    }
+}
+
+/// The download announced by a fresh marker for `path`, if any
+fn read_marker(path: &Path) -> Option<ActiveDownload> {
+    let marker = marker_path(path);
+    let modified = fs::metadata(&marker).and_then(|m| m.modified()).ok()?;
+    let age = SystemTime::now()
+        .duration_since(modified)
+        .unwrap_or_default();
+    if age > STALE_MARKER_AGE {
+        return None;
+    }
+
+    let content = fs::read_to_string(&marker).ok()?;
+    let mut lines = content.lines();
+    let model_name = lines.next().unwrap_or_default().to_string();
+    let mut number = || lines.next().and_then(|n| n.parse().ok()).unwrap_or(0);
+    Some(ActiveDownload {
+        path: path.to_path_buf(),
+        model_name,
+        downloaded: number(),
+        total: number(),
+    })
+}
+
+/// Registration of an in-progress download; unregisters when dropped
+pub(crate) struct DownloadGuard {
+    path: PathBuf,
+    marker_written: Mutex<Instant>,
+}
+
+impl DownloadGuard {
+    /// Mark a download to `path` as in progress
+    pub(crate) fn register(path: &Path, model_name: &str) -> Self {
+        let download = ActiveDownload {
+            path: path.to_path_buf(),
+            model_name: model_name.to_string(),
+            downloaded: 0,
+            total: 0,
+        };
+        write_marker(&download);
+
+        let registry = registry();
+        registry
+            .downloads
+            .lock()
+            .unwrap()
+            .insert(path.to_path_buf(), download);
+        registry.changed.notify_all();
+        Self {
+            path: path.to_path_buf(),
+            marker_written: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Update the progress of this download
+    pub(crate) fn progress(&self, downloaded: u64, total: u64) {
+        if let Some(download) = registry().downloads.lock().unwrap().get_mut(&self.path) {
+            download.downloaded = downloaded;
+            download.total = total;
+
+            // Keep the marker fresh, without rewriting it for every chunk
+            let mut marker_written = self.marker_written.lock().unwrap();
+            if marker_written.elapsed() >= MARKER_UPDATE_INTERVAL {
+                write_marker(download);
+                *marker_written = Instant::now();
+            }
+        }
+    }
+}
+
+impl Drop for DownloadGuard {
+    fn drop(&mut self) {
+        fs::remove_file(marker_path(&self.path)).ok();
+        let registry = registry();
+        registry.downloads.lock().unwrap().remove(&self.path);
+        registry.changed.notify_all();
+    }
+}
+
+/// List model downloads currently in progress
+pub fn active_downloads() -> Vec<ActiveDownload> {
+    registry()
+        .downloads
+        .lock()
+        .unwrap()
+        .values()
+        .cloned()
+        .collect()
+}
+
+/// The download to `path` in progress in this or another process, if any
+fn current_download(path: &Path) -> Option<ActiveDownload> {
+    let in_process = registry().downloads.lock().unwrap().get(path).cloned();
+    in_process.or_else(|| read_marker(path))
+}
+
+/// Check whether the model at `path` is currently being downloaded
+///
+/// Also true while another process downloads it.
+pub fn is_downloading(path: &Path) -> bool {
+    current_download(path).is_some()
+}
+
+/// Wait for an in-progress download of the model at `path` to finish
+///
+/// Returns immediately if no download is running for this path, in this or
+/// another process. Tells the user which model it waits for, and fails if
+/// the download has not finished within `timeout`.
+pub fn wait_until_ready(path: &Path, timeout: Duration) -> Result<()> {
+    let registry = registry();
+    let deadline = Instant::now() + timeout;
+    let mut announced = false;
+
+    while let Some(download) = current_download(path) {
+        if !announced {
+            let percent = (download.downloaded * 100)
+                .checked_div(download.total)
+                .map(|p| format!(" ({p}%)"))
+                .unwrap_or_default();
+            eprintln!(
+                "Waiting for model '{}' to finish downloading{}...",
+                download.model_name, percent
+            );
+            announced = true;
+        }
+
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(anyhow!(
+                "Timed out after {}s waiting for model '{}' to download",
+                timeout.as_secs(),
+                download.model_name
+            ));
+        }
+
+        // Woken early when a download in this process finishes; downloads
+        // in other processes are polled
+        let downloads = registry.downloads.lock().unwrap();
+        let _ = registry
+            .changed
+            .wait_timeout(downloads, remaining.min(POLL_INTERVAL))
+            .unwrap();
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_model(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("whis-ready-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir.join(name)
+    }
+
+    #[test]
+    fn test_no_download_returns_immediately() {
+        let path = temp_model("idle.bin");
+        assert!(!is_downloading(&path));
+        assert!(wait_until_ready(&path, Duration::ZERO).is_ok());
+    }
+
+    #[test]
+    fn test_marker_from_other_process_is_waited_on() {
+        let path = temp_model("other.bin");
+        fs::write(marker_path(&path), "small\n50\n100\n").unwrap();
+
+        let download = read_marker(&path).unwrap();
+        assert_eq!(download.model_name, "small");
+        assert_eq!((download.downloaded, download.total), (50, 100));
+
+        let err = wait_until_ready(&path, Duration::from_millis(10)).unwrap_err();
+        assert!(err.to_string().contains("'small'"));
+        fs::remove_file(marker_path(&path)).unwrap();
+        assert!(!is_downloading(&path));
+    }
+
+    #[test]
+    fn test_guard_removes_marker() {
+        let path = temp_model("guarded.bin");
+        let guard = DownloadGuard::register(&path, "base");
+        assert!(marker_path(&path).exists());
+        assert!(is_downloading(&path));
+
+        drop(guard);
+        assert!(!marker_path(&path).exists());
+        assert!(wait_until_ready(&path, Duration::ZERO).is_ok());
+    }
+}
@@ -47,6 +47,14 @@ pub enum Compositor {
     Sway,
     /// Hyprland
     Hyprland,
+    /// River (wlroots-based Wayland compositor)
+    River,
+    /// i3 (X11 tiling window manager)
+    I3,
+    /// bspwm (X11, keybindings via sxhkd)
+    Bspwm,
+    /// dwm (X11, keybindings via sxhkd when not patched into config.h)
+    Dwm,
     /// Generic wlroots-based compositor
     Wlroots,
     /// Unknown compositor
@@ -63,6 +71,10 @@ impl Compositor {
             Compositor::KdePlasma => "KDE Plasma",
             Compositor::Sway => "Sway",
             Compositor::Hyprland => "Hyprland",
+            Compositor::River => "River",
+            Compositor::I3 => "i3",
+            Compositor::Bspwm => "bspwm",
+            Compositor::Dwm => "dwm",
             Compositor::Wlroots => "wlroots",
             Compositor::Unknown(name) => name,
         }
@@ -177,6 +189,14 @@ fn detect_compositor_linux() -> Compositor {
         Compositor::Sway
     } else if desktop.contains("hyprland") {
         Compositor::Hyprland
+    } else if desktop.contains("river") {
+        Compositor::River
+    } else if desktop == "i3" || desktop.contains("i3wm") {
+        Compositor::I3
+    } else if desktop.contains("bspwm") {
+        Compositor::Bspwm
+    } else if desktop.contains("dwm") {
+        Compositor::Dwm
     } else if env::var("WAYLAND_DISPLAY").is_ok() {
         // Wayland but unknown compositor - likely wlroots-based
         if desktop.is_empty() {
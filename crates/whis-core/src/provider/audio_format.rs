@@ -0,0 +1,174 @@
+//! Audio container formats accepted by transcription providers.
+//!
+//! `AudioFormat` is the single source of the extension and MIME type sent
+//! with an upload. `TranscriptionRequestBuilder` resolves every request to
+//! one of these, so the filename and `Content-Type` can't disagree.
+
+/// Container format of uploaded audio
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AudioFormat {
+    #[default]
+    Mp3,
+    Wav,
+    Webm,
+    Ogg,
+    M4a,
+    Flac,
+}
+
+impl AudioFormat {
+    pub const ALL: [AudioFormat; 6] = [
+        AudioFormat::Mp3,
+        AudioFormat::Wav,
+        AudioFormat::Webm,
+        AudioFormat::Ogg,
+        AudioFormat::M4a,
+        AudioFormat::Flac,
+    ];
+
+    /// Canonical file extension (without the dot)
+    pub fn extension(self) -> &'static str {
+        match self {
+            AudioFormat::Mp3 => "mp3",
+            AudioFormat::Wav => "wav",
+            AudioFormat::Webm => "webm",
+            AudioFormat::Ogg => "ogg",
+            AudioFormat::M4a => "m4a",
+            AudioFormat::Flac => "flac",
+        }
+    }
+
+    /// Canonical MIME type
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            AudioFormat::Mp3 => "audio/mpeg",
+            AudioFormat::Wav => "audio/wav",
+            AudioFormat::Webm => "audio/webm",
+            AudioFormat::Ogg => "audio/ogg",
+            AudioFormat::M4a => "audio/mp4",
+            AudioFormat::Flac => "audio/flac",
+        }
+    }
+
+    /// Format for a file extension (case-insensitive, with or without the dot)
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        let extension = extension.trim_start_matches('.').to_ascii_lowercase();
+        match extension.as_str() {
+            "mp3" => Some(AudioFormat::Mp3),
+            "wav" | "wave" => Some(AudioFormat::Wav),
+            "webm" => Some(AudioFormat::Webm),
+            "ogg" | "oga" | "opus" => Some(AudioFormat::Ogg),
+            "m4a" | "mp4" => Some(AudioFormat::M4a),
+            "flac" => Some(AudioFormat::Flac),
+            _ => None,
+        }
+    }
+
+    /// Format for a MIME type, ignoring parameters like `;codecs=opus`
+    pub fn from_mime_type(mime_type: &str) -> Option<Self> {
+        let essence = mime_type.split(';').next()?.trim().to_ascii_lowercase();
+        match essence.as_str() {
+            "audio/mpeg" | "audio/mp3" => Some(AudioFormat::Mp3),
+            "audio/wav" | "audio/x-wav" | "audio/wave" | "audio/vnd.wave" => Some(AudioFormat::Wav),
+            "audio/webm" | "video/webm" => Some(AudioFormat::Webm),
+            "audio/ogg" | "audio/opus" => Some(AudioFormat::Ogg),
+            "audio/mp4" | "audio/m4a" | "audio/x-m4a" => Some(AudioFormat::M4a),
+            "audio/flac" | "audio/x-flac" => Some(AudioFormat::Flac),
+            _ => None,
+        }
+    }
+
+    /// Format of a filename, by its extension
+    pub fn from_filename(filename: &str) -> Option<Self> {
+        Self::from_extension(filename.rsplit_once('.')?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_extension_and_mime_matrix() {
+        let expected = [
+            (AudioFormat::Mp3, "mp3", "audio/mpeg"),
+            (AudioFormat::Wav, "wav", "audio/wav"),
+            (AudioFormat::Webm, "webm", "audio/webm"),
+            (AudioFormat::Ogg, "ogg", "audio/ogg"),
+            (AudioFormat::M4a, "m4a", "audio/mp4"),
+            (AudioFormat::Flac, "flac", "audio/flac"),
+        ];
+        assert_eq!(expected.len(), AudioFormat::ALL.len());
+        for (format, ext, mime) in expected {
+            assert_eq!(format.extension(), ext);
+            assert_eq!(format.mime_type(), mime);
+        }
+    }
+
+    #[test]
+    fn test_format_round_trips() {
+        for format in AudioFormat::ALL {
+            assert_eq!(
+                AudioFormat::from_extension(format.extension()),
+                Some(format)
+            );
+            assert_eq!(
+                AudioFormat::from_mime_type(format.mime_type()),
+                Some(format)
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_mime_type_ignores_parameters() {
+        assert_eq!(
+            AudioFormat::from_mime_type("audio/webm;codecs=opus"),
+            Some(AudioFormat::Webm)
+        );
+        assert_eq!(
+            AudioFormat::from_mime_type("Audio/Ogg; codecs=opus"),
+            Some(AudioFormat::Ogg)
+        );
+        assert_eq!(AudioFormat::from_mime_type("text/plain"), None);
+    }
+
+    #[test]
+    fn test_from_filename() {
+        assert_eq!(
+            AudioFormat::from_filename("chunk_3.MP3"),
+            Some(AudioFormat::Mp3)
+        );
+        assert_eq!(
+            AudioFormat::from_filename("voice.m4a"),
+            Some(AudioFormat::M4a)
+        );
+        assert_eq!(AudioFormat::from_filename("meeting"), None);
+        assert_eq!(AudioFormat::from_filename("notes.txt"), None);
+    }
+
+    #[test]
+    fn test_request_filename_and_mime_agree() {
+        use crate::provider::TranscriptionRequest;
+
+        let request = TranscriptionRequest::builder()
+            .filename("chunk.mp3")
+            .mime_type("audio/ogg")
+            .build();
+        assert_eq!(request.filename, "chunk.ogg");
+        assert_eq!(request.mime_type, "audio/ogg");
+
+        let request = TranscriptionRequest::builder()
+            .mime_type("audio/webm;codecs=opus")
+            .build();
+        assert_eq!(request.filename, "audio.webm");
+        assert_eq!(request.mime_type, "audio/webm;codecs=opus");
+
+        let request = TranscriptionRequest::builder()
+            .format(AudioFormat::Wav)
+            .filename("meeting")
+            .mime_type("audio/mpeg")
+            .build();
+        assert_eq!(request.filename, "meeting.wav");
+        assert_eq!(request.mime_type, "audio/wav");
+    }
+}
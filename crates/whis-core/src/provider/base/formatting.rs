@@ -0,0 +1,37 @@
+//! Provider-side transcript formatting (Deepgram query options).
+//!
+//! - `transcription.smart_format` (on by default): punctuation, paragraphs
+//!   and formatted dates, times and amounts (`smart_format`). Turn it off for
+//!   verbatim output.
+//! - `transcription.format_numbers`: spoken numbers as digits (`numerals`).
+//!   Other providers' transcripts go through the local
+//!   [`format_spoken_numbers`](crate::transcription::format_spoken_numbers)
+//!   pass instead, see [`TranscriptionProvider::formats_numerals`](crate::config::TranscriptionProvider::formats_numerals).
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether providers should be asked to smart-format transcripts
+static SMART_FORMAT: AtomicBool = AtomicBool::new(true);
+
+/// Whether providers should be asked to format numbers
+static NUMERALS: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable provider-side smart formatting (on by default)
+pub fn set_smart_formatting(enabled: bool) {
+    SMART_FORMAT.store(enabled, Ordering::Relaxed);
+}
+
+/// Enable or disable provider-side number formatting
+pub fn set_numeral_formatting(enabled: bool) {
+    NUMERALS.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether provider-side smart formatting is on
+pub(crate) fn smart_format() -> bool {
+    SMART_FORMAT.load(Ordering::Relaxed)
+}
+
+/// Whether provider-side number formatting is on
+pub(crate) fn numerals() -> bool {
+    NUMERALS.load(Ordering::Relaxed)
+}
@@ -0,0 +1,154 @@
+//! Opt-in gzip compression of raw audio uploads.
+//!
+//! Providers that take the audio as the request body (Deepgram) can be sent
+//! `Content-Encoding: gzip`. Whether that pays off depends on the encoding:
+//! - MP3 (the default) is already entropy-coded; gzip barely shrinks it, so
+//!   the CPU time is mostly wasted
+//! - WAV/PCM (custom encoders) shrinks noticeably, which helps on slow uplinks
+//!
+//! Compression uses the fastest level, and the sizes and time spent are logged
+//! in verbose mode so the tradeoff can be checked on real recordings. Bodies
+//! that don't get smaller are sent uncompressed. If a provider rejects a gzip
+//! body (415, or a 400 whose message names the encoding), the request is
+//! resent uncompressed and compression stays off for that provider for the
+//! rest of the process. Other 400s are ordinary request errors.
+
+use std::io::Write;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Instant;
+
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use reqwest::StatusCode;
+
+/// Whether uploads should be compressed (`transcription.compress_uploads`)
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Providers that rejected a compressed body
+static REJECTED: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+
+/// Enable or disable gzip compression of raw audio uploads
+pub fn set_upload_compression(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+fn rejected_providers() -> std::sync::MutexGuard<'static, Vec<&'static str>> {
+    REJECTED.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn is_rejected(provider: &str) -> bool {
+    rejected_providers().contains(&provider)
+}
+
+/// Gzip `data` for upload to `provider`, or `None` if compression is off,
+/// was rejected by that provider, or doesn't help
+pub(crate) fn compress(provider: &str, data: &[u8]) -> Option<Vec<u8>> {
+    if !ENABLED.load(Ordering::Relaxed) || is_rejected(provider) {
+        return None;
+    }
+
+    let started = Instant::now();
+    let mut encoder = GzEncoder::new(Vec::with_capacity(data.len()), Compression::fast());
+    encoder.write_all(data).ok()?;
+    let compressed = encoder.finish().ok()?;
+
+    crate::verbose!(
+        "Gzip upload: {} -> {} bytes ({:.0}%) in {:.1}ms",
+        data.len(),
+        compressed.len(),
+        compressed.len() as f64 * 100.0 / data.len().max(1) as f64,
+        started.elapsed().as_secs_f64() * 1000.0
+    );
+
+    (compressed.len() < data.len()).then_some(compressed)
+}
+
+/// Whether an error response to a compressed upload means the server
+/// didn't accept the gzip body
+///
+/// A 415 always does; a 400 only when its message names the encoding, since
+/// most 400s (bad audio, bad parameters) have nothing to do with it.
+pub(crate) fn is_gzip_rejection(status: StatusCode, body: &str) -> bool {
+    match status {
+        StatusCode::UNSUPPORTED_MEDIA_TYPE => true,
+        StatusCode::BAD_REQUEST => {
+            let body = body.to_lowercase();
+            body.contains("gzip") || body.contains("encoding")
+        }
+        _ => false,
+    }
+}
+
+/// Handle an error response to a compressed upload to `provider`
+///
+/// Returns true (and turns compression off for that provider) if the server
+/// didn't accept the gzip body, so the caller should resend uncompressed.
+pub(crate) fn rejected(provider: &'static str, status: StatusCode, body: &str) -> bool {
+    if !is_gzip_rejection(status, body) {
+        return false;
+    }
+    let mut rejected = rejected_providers();
+    if !rejected.contains(&provider) {
+        rejected.push(provider);
+    }
+    crate::verbose!("{provider} rejected gzip upload ({status}), sending uncompressed");
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unsupported_media_type_is_rejection() {
+        assert!(is_gzip_rejection(StatusCode::UNSUPPORTED_MEDIA_TYPE, ""));
+    }
+
+    #[test]
+    fn test_bad_request_naming_encoding_is_rejection() {
+        assert!(is_gzip_rejection(
+            StatusCode::BAD_REQUEST,
+            r#"{"err_msg":"Unsupported Content-Encoding"}"#
+        ));
+        assert!(is_gzip_rejection(
+            StatusCode::BAD_REQUEST,
+            "could not decode GZIP body"
+        ));
+    }
+
+    #[test]
+    fn test_other_bad_request_is_not_rejection() {
+        assert!(!is_gzip_rejection(
+            StatusCode::BAD_REQUEST,
+            r#"{"err_msg":"Bad Request: failed to process audio: corrupt or unsupported data"}"#
+        ));
+    }
+
+    #[test]
+    fn test_other_statuses_are_not_rejection() {
+        assert!(!is_gzip_rejection(StatusCode::UNAUTHORIZED, "gzip"));
+        assert!(!is_gzip_rejection(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "gzip"
+        ));
+    }
+
+    #[test]
+    fn test_rejection_is_scoped_to_provider() {
+        assert!(!rejected(
+            "test-provider-a",
+            StatusCode::BAD_REQUEST,
+            "invalid model"
+        ));
+        assert!(!is_rejected("test-provider-a"));
+
+        assert!(rejected(
+            "test-provider-a",
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            ""
+        ));
+        assert!(is_rejected("test-provider-a"));
+        assert!(!is_rejected("test-provider-b"));
+    }
+}
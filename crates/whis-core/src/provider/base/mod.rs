@@ -1,5 +1,7 @@
 //! Base implementations and shared logic for transcription providers.
 
+pub(crate) mod formatting;
+pub(crate) mod gzip;
 mod openai_compatible;
 pub(crate) mod retry;
 
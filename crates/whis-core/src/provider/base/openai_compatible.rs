@@ -10,14 +10,22 @@
 //! - Multipart form upload with `model` and `file` fields
 //! - Authorization via `Bearer` token
 //! - JSON response with `text` field
+//!
+//! A `response_format` other than `json` is sent when the request asks for
+//! one and the provider takes it (`TranscriptionProvider::supports_timestamps`,
+//! so Voxtral always gets JSON); `verbose_json` adds timed segments and the
+//! detected language, `text`/`srt`/`vtt` answer with a plain body.
 
 use anyhow::{Context, Result};
 use serde::Deserialize;
 
 use super::super::{
-    DEFAULT_TIMEOUT_SECS, TranscriptionRequest, TranscriptionResult, TranscriptionStage,
+    ProviderError, ResponseFormat, TranscriptSegment, TranscriptionRequest, TranscriptionResult,
+    TranscriptionStage,
 };
 use super::retry::{RetryConfig, is_rate_limited, is_retryable_error, is_retryable_status};
+use crate::config::TranscriptionProvider;
+use crate::http::{blocking_client, request_timeouts, upload_body, upload_reader};
 
 /// Response structure for OpenAI-compatible APIs
 #[derive(Deserialize)]
@@ -25,9 +33,78 @@ struct OpenAICompatibleResponse {
     text: String,
 }
 
+/// Response structure for `response_format=verbose_json`
+#[derive(Deserialize)]
+struct VerboseResponse {
+    text: String,
+    /// Detected language, as an English name ("english")
+    #[serde(default)]
+    language: Option<String>,
+    #[serde(default)]
+    segments: Vec<VerboseSegment>,
+}
+
+#[derive(Deserialize)]
+struct VerboseSegment {
+    start: f64,
+    end: f64,
+    text: String,
+}
+
+/// Format to ask `provider` for: the requested one, or JSON for providers
+/// without OpenAI's `response_format` (see `supports_timestamps`)
+fn response_format(provider: &TranscriptionProvider, requested: ResponseFormat) -> ResponseFormat {
+    if provider.supports_timestamps() {
+        requested
+    } else {
+        ResponseFormat::Json
+    }
+}
+
+/// Parse a successful response body in the requested `format`
+fn parse_response(body: &str, format: ResponseFormat) -> Result<TranscriptionResult> {
+    match format {
+        ResponseFormat::Json => {
+            let resp: OpenAICompatibleResponse =
+                serde_json::from_str(body).context("Failed to parse API response")?;
+            Ok(TranscriptionResult {
+                text: resp.text,
+                ..Default::default()
+            })
+        }
+        ResponseFormat::VerboseJson => {
+            let resp: VerboseResponse =
+                serde_json::from_str(body).context("Failed to parse API response")?;
+            Ok(TranscriptionResult {
+                text: resp.text,
+                confidence: None,
+                segments: resp
+                    .segments
+                    .into_iter()
+                    .map(|segment| TranscriptSegment {
+                        start: segment.start,
+                        end: segment.end,
+                        text: segment.text.trim().to_string(),
+                    })
+                    .collect(),
+                language: resp
+                    .language
+                    .and_then(|lang| crate::configuration::normalize_language(&lang)),
+            })
+        }
+        ResponseFormat::Text | ResponseFormat::Srt | ResponseFormat::Vtt => {
+            Ok(TranscriptionResult {
+                text: body.trim_end().to_string(),
+                ..Default::default()
+            })
+        }
+    }
+}
+
 /// Transcribe audio using an OpenAI-compatible API (synchronous).
 ///
 /// # Parameters
+/// - `provider`: Provider serving `api_url`, named in errors
 /// - `api_url`: The API endpoint URL (e.g., "https://api.openai.com/v1/audio/transcriptions")
 /// - `model`: The model name to use (e.g., "whisper-1")
 /// - `api_key`: Bearer token for authentication
@@ -36,18 +113,19 @@ struct OpenAICompatibleResponse {
 /// # Returns
 /// Transcription result containing the text transcript
 pub(crate) fn openai_compatible_transcribe_sync(
+    provider: &TranscriptionProvider,
     api_url: &str,
     model: &str,
     api_key: &str,
     request: TranscriptionRequest,
 ) -> Result<TranscriptionResult> {
+    let response_format = response_format(provider, request.response_format);
+
     // Report uploading stage
     request.report(TranscriptionStage::Uploading);
 
-    let client = reqwest::blocking::Client::builder()
-        .timeout(std::time::Duration::from_secs(DEFAULT_TIMEOUT_SECS))
-        .build()
-        .context("Failed to create HTTP client")?;
+    let client = blocking_client()?;
+    let upload_timeout = request_timeouts().upload;
 
     let config = RetryConfig::default();
     let mut attempt = 0;
@@ -57,14 +135,23 @@ pub(crate) fn openai_compatible_transcribe_sync(
             .text("model", model.to_string())
             .part(
                 "file",
-                reqwest::blocking::multipart::Part::bytes(request.audio_data.clone())
-                    .file_name(request.filename.clone())
-                    .mime_str(&request.mime_type)?,
+                reqwest::blocking::multipart::Part::reader_with_length(
+                    upload_reader(request.audio_data.clone(), upload_timeout),
+                    request.audio_data.len() as u64,
+                )
+                .file_name(request.filename.clone())
+                .mime_str(&request.mime_type)?,
             );
 
         if let Some(lang) = request.language.clone() {
             form = form.text("language", lang);
         }
+        if response_format != ResponseFormat::Json {
+            form = form.text("response_format", response_format.as_str());
+        }
+
+        // Stop before (re)sending if the caller cancelled
+        request.check_cancelled()?;
 
         // Report transcribing stage (request sent, waiting for response)
         request.report(TranscriptionStage::Transcribing);
@@ -81,9 +168,7 @@ pub(crate) fn openai_compatible_transcribe_sync(
 
                 if status.is_success() {
                     let text = response.text().context("Failed to get response text")?;
-                    let resp: OpenAICompatibleResponse =
-                        serde_json::from_str(&text).context("Failed to parse API response")?;
-                    return Ok(TranscriptionResult { text: resp.text });
+                    return parse_response(&text, response_format);
                 }
 
                 // Check if error is retryable
@@ -105,7 +190,12 @@ pub(crate) fn openai_compatible_transcribe_sync(
                 let error_text = response
                     .text()
                     .unwrap_or_else(|_| "Unknown error".to_string());
-                anyhow::bail!("API error ({status}): {error_text}");
+                return Err(ProviderError::from_status(
+                    provider.display_name(),
+                    status,
+                    error_text,
+                )
+                .into());
             }
             Err(err) => {
                 // Check if network error is retryable
@@ -142,14 +232,18 @@ pub(crate) fn openai_compatible_transcribe_sync(
 /// Transcription result containing the text transcript
 pub(crate) async fn openai_compatible_transcribe_async(
     client: &reqwest::Client,
+    provider: &TranscriptionProvider,
     api_url: &str,
     model: &str,
     api_key: &str,
     request: TranscriptionRequest,
 ) -> Result<TranscriptionResult> {
+    let response_format = response_format(provider, request.response_format);
+
     // Report uploading stage
     request.report(TranscriptionStage::Uploading);
 
+    let timeouts = request_timeouts();
     let config = RetryConfig::default();
     let mut attempt = 0;
 
@@ -158,24 +252,37 @@ pub(crate) async fn openai_compatible_transcribe_async(
             .text("model", model.to_string())
             .part(
                 "file",
-                reqwest::multipart::Part::bytes(request.audio_data.clone())
-                    .file_name(request.filename.clone())
-                    .mime_str(&request.mime_type)?,
+                reqwest::multipart::Part::stream_with_length(
+                    upload_body(request.audio_data.clone(), timeouts.upload),
+                    request.audio_data.len() as u64,
+                )
+                .file_name(request.filename.clone())
+                .mime_str(&request.mime_type)?,
             );
 
         if let Some(lang) = request.language.clone() {
             form = form.text("language", lang);
         }
+        if response_format != ResponseFormat::Json {
+            form = form.text("response_format", response_format.as_str());
+        }
+
+        // Stop before (re)sending if the caller cancelled
+        request.check_cancelled()?;
 
         // Report transcribing stage
         request.report(TranscriptionStage::Transcribing);
 
-        let result = client
-            .post(api_url)
-            .header("Authorization", format!("Bearer {api_key}"))
-            .multipart(form)
-            .send()
-            .await;
+        let result = request
+            .cancellable(
+                client
+                    .post(api_url)
+                    .header("Authorization", format!("Bearer {api_key}"))
+                    .timeout(timeouts.request())
+                    .multipart(form)
+                    .send(),
+            )
+            .await?;
 
         match result {
             Ok(response) => {
@@ -186,9 +293,7 @@ pub(crate) async fn openai_compatible_transcribe_async(
                         .text()
                         .await
                         .context("Failed to get response text")?;
-                    let resp: OpenAICompatibleResponse =
-                        serde_json::from_str(&text).context("Failed to parse API response")?;
-                    return Ok(TranscriptionResult { text: resp.text });
+                    return parse_response(&text, response_format);
                 }
 
                 // Check if error is retryable
@@ -201,7 +306,7 @@ pub(crate) async fn openai_compatible_transcribe_async(
                         config.max_retries,
                         delay
                     );
-                    tokio::time::sleep(delay).await;
+                    request.cancellable(tokio::time::sleep(delay)).await?;
                     attempt += 1;
                     continue;
                 }
@@ -211,7 +316,12 @@ pub(crate) async fn openai_compatible_transcribe_async(
                     .text()
                     .await
                     .unwrap_or_else(|_| "Unknown error".to_string());
-                anyhow::bail!("API error ({status}): {error_text}");
+                return Err(ProviderError::from_status(
+                    provider.display_name(),
+                    status,
+                    error_text,
+                )
+                .into());
             }
             Err(err) => {
                 // Check if network error is retryable
@@ -224,7 +334,7 @@ pub(crate) async fn openai_compatible_transcribe_async(
                         delay,
                         err
                     );
-                    tokio::time::sleep(delay).await;
+                    request.cancellable(tokio::time::sleep(delay)).await?;
                     attempt += 1;
                     continue;
                 }
@@ -234,3 +344,53 @@ pub(crate) async fn openai_compatible_transcribe_async(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_verbose_json_response() {
+        let body = r#"{
+            "task": "transcribe",
+            "language": "english",
+            "duration": 3.2,
+            "text": "Hello there. General Kenobi.",
+            "segments": [
+                {"id": 0, "start": 0.0, "end": 1.4, "text": " Hello there."},
+                {"id": 1, "start": 1.6, "end": 3.2, "text": " General Kenobi."}
+            ]
+        }"#;
+        let result = parse_response(body, ResponseFormat::VerboseJson).unwrap();
+
+        assert_eq!(result.text, "Hello there. General Kenobi.");
+        assert_eq!(result.language.as_deref(), Some("en"));
+        assert_eq!(result.segments.len(), 2);
+        assert_eq!(result.segments[1].start, 1.6);
+        assert_eq!(result.segments[1].text, "General Kenobi.");
+    }
+
+    #[test]
+    fn test_parse_plain_body_responses() {
+        let srt = "1\n00:00:00,000 --> 00:00:01,400\nHello there.\n\n";
+        let result = parse_response(srt, ResponseFormat::Srt).unwrap();
+        assert_eq!(result.text, srt.trim_end());
+        assert!(result.segments.is_empty());
+    }
+
+    #[test]
+    fn test_response_format_only_for_timestamp_providers() {
+        for provider in [TranscriptionProvider::OpenAI, TranscriptionProvider::Groq] {
+            assert!(provider.supports_timestamps());
+            assert_eq!(
+                response_format(&provider, ResponseFormat::VerboseJson),
+                ResponseFormat::VerboseJson
+            );
+        }
+        assert!(!TranscriptionProvider::Mistral.supports_timestamps());
+        assert_eq!(
+            response_format(&TranscriptionProvider::Mistral, ResponseFormat::VerboseJson),
+            ResponseFormat::Json
+        );
+    }
+}
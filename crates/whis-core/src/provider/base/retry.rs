@@ -1,15 +1,36 @@
-//! Retry logic with exponential backoff for cloud transcription providers.
+//! Retry logic with exponential backoff for transcription providers.
 //!
 //! This module provides retry functionality for transient errors like:
-//! - 408 Request Timeout (SLOW_UPLOAD)
+//! - 408 Request Timeout (SLOW_UPLOAD), and uploads exceeding our own
+//!   upload deadline (see `http::RequestTimeouts`)
 //! - 429 Rate Limited
 //! - 5xx Server Errors
-//! - Network/connection errors
+//! - Network errors before the request was fully sent (see below)
+//! - Transient local inference failures (Whisper/Parakeet): out of memory
+//!   or a busy device
+//!
+//! ## Network errors after the upload
+//! Transcription and LLM requests aren't idempotent: once the body has been
+//! sent, the provider may already be working on (and billing for) it. An
+//! error after that point (connection reset while waiting, response timeout)
+//! doesn't say whether it did, so resending could pay for the same audio
+//! twice. [`is_retryable_error`] therefore only retries errors that happened
+//! before the request went out completely:
+//! - Connection failures (nothing was sent)
+//! - Request body errors (the upload broke off, e.g. past the upload deadline)
+//!
+//! The cost is that a connection dropped mid-upload without a body error, or
+//! a request timing out, fails instead of being retried. Idempotent requests
+//! (downloads) use [`is_retryable_idempotent_error`], which retries any
+//! transient network error.
 
 use std::time::Duration;
 
 use reqwest::StatusCode;
 
+#[cfg(feature = "local-transcription")]
+use super::super::ProviderError;
+
 /// Configuration for retry behavior
 #[derive(Debug, Clone)]
 pub struct RetryConfig {
@@ -58,7 +79,218 @@ pub fn is_rate_limited(status: StatusCode) -> bool {
     status == StatusCode::TOO_MANY_REQUESTS
 }
 
-/// Check if a reqwest error is retryable
+/// Check if a reqwest error from a non-idempotent request (upload) is retryable
+///
+/// Only errors raised before the request was fully sent: connection failures
+/// and body errors (which is how an exceeded upload deadline surfaces).
+/// Timeouts and other request errors may have happened after the provider
+/// received the audio, so they aren't retried.
 pub fn is_retryable_error(err: &reqwest::Error) -> bool {
-    err.is_timeout() || err.is_connect() || err.is_request()
+    err.is_connect() || err.is_body()
+}
+
+/// Check if a reqwest error from an idempotent request (download) is retryable
+pub fn is_retryable_idempotent_error(err: &reqwest::Error) -> bool {
+    err.is_timeout() || err.is_connect() || err.is_request() || err.is_body()
+}
+
+/// Maximum retry attempts for local transcription (inference is expensive)
+#[cfg(feature = "local-transcription")]
+const LOCAL_MAX_RETRIES: u32 = 2;
+
+/// Base delay between local retries in milliseconds (doubles with each attempt)
+#[cfg(feature = "local-transcription")]
+const LOCAL_BASE_DELAY_MS: u64 = 250;
+
+/// Check if a local transcription error indicates memory exhaustion
+#[cfg(feature = "local-transcription")]
+pub fn is_out_of_memory(err: &anyhow::Error) -> bool {
+    let msg = format!("{err:#}").to_lowercase();
+    ["out of memory", "failed to allocate", "bad_alloc"]
+        .iter()
+        .any(|needle| msg.contains(needle))
+        // "oom" only as a word, not inside "room" or "zoom"
+        || msg
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .any(|word| word == "oom")
+}
+
+/// Check if a local transcription error says the device is busy
+#[cfg(feature = "local-transcription")]
+pub fn is_device_busy(err: &anyhow::Error) -> bool {
+    let msg = format!("{err:#}").to_lowercase();
+    [
+        "device busy",
+        "device or resource busy",
+        "resource busy",
+        "busy or unavailable",
+        "temporarily unavailable",
+    ]
+    .iter()
+    .any(|needle| msg.contains(needle))
+}
+
+/// Check if a local transcription error is retryable
+///
+/// Only transient failures are: running out of memory (another model or
+/// app may free it) and a busy device. Anything else (missing or corrupt
+/// model, download timeout, cancellation) fails the same way again.
+#[cfg(feature = "local-transcription")]
+pub fn is_retryable_local_error(err: &anyhow::Error) -> bool {
+    if matches!(
+        err.downcast_ref::<ProviderError>(),
+        Some(
+            ProviderError::LocalModelError(_)
+                | ProviderError::ModelNotFound(_)
+                | ProviderError::Cancelled
+        )
+    ) {
+        return false;
+    }
+    is_out_of_memory(err) || is_device_busy(err)
+}
+
+/// Run a local transcription attempt, retrying transient failures
+///
+/// Out-of-memory errors that persist after the retries are surfaced with a
+/// hint to use a smaller model or GPU.
+#[cfg(feature = "local-transcription")]
+pub fn retry_local<T>(
+    engine: &str,
+    mut attempt_fn: impl FnMut() -> anyhow::Result<T>,
+) -> anyhow::Result<T> {
+    let mut attempt = 0;
+
+    loop {
+        match attempt_fn() {
+            Ok(value) => return Ok(value),
+            Err(err) if is_retryable_local_error(&err) && attempt < LOCAL_MAX_RETRIES => {
+                let delay = Duration::from_millis(LOCAL_BASE_DELAY_MS * 2u64.pow(attempt));
+                crate::verbose!(
+                    "{} transcription failed (attempt {}/{}), retrying in {:?}: {}",
+                    engine,
+                    attempt + 1,
+                    LOCAL_MAX_RETRIES,
+                    delay,
+                    err
+                );
+                std::thread::sleep(delay);
+                attempt += 1;
+            }
+            Err(err) if is_out_of_memory(&err) => {
+                return Err(ProviderError::provider_specific(
+                    engine,
+                    format!(
+                        "Ran out of memory during transcription ({err}). \
+                         Try a smaller model, shorter recordings, or a GPU-enabled build."
+                    ),
+                )
+                .into());
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "local-transcription"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_out_of_memory() {
+        for msg in [
+            "CUDA error: out of memory",
+            "ggml: failed to allocate 512 MB",
+            "std::bad_alloc",
+            "process killed (OOM)",
+            "oom-killer",
+        ] {
+            assert!(is_out_of_memory(&anyhow::anyhow!("{msg}")), "{msg}");
+        }
+    }
+
+    #[test]
+    fn test_is_out_of_memory_ignores_oom_inside_words() {
+        for msg in [
+            "no room left in buffer",
+            "failed to read /home/user/zoom/recording.wav",
+            "bloom filter mismatch",
+        ] {
+            assert!(!is_out_of_memory(&anyhow::anyhow!("{msg}")), "{msg}");
+        }
+    }
+
+    #[test]
+    fn test_only_transient_local_errors_are_retryable() {
+        for msg in [
+            "CUDA error: out of memory",
+            "ggml: failed to allocate 512 MB",
+            "Vulkan: device busy",
+            "open /dev/dri/renderD128: Device or resource busy",
+            "CUDA-capable device(s) is/are busy or unavailable",
+        ] {
+            assert!(is_retryable_local_error(&anyhow::anyhow!("{msg}")), "{msg}");
+        }
+
+        for err in [
+            anyhow::anyhow!("Failed to load whisper model: invalid magic"),
+            anyhow::anyhow!("Timed out after 600s waiting for model 'small' to download"),
+            anyhow::anyhow!("no room left in buffer"),
+            ProviderError::ModelNotFound("/models/ggml-small.bin".into()).into(),
+            ProviderError::LocalModelError("path not configured".into()).into(),
+            ProviderError::Cancelled.into(),
+        ] {
+            assert!(!is_retryable_local_error(&err), "{err}");
+        }
+    }
+
+    /// Run `retry_local` with an attempt that always fails with `err`,
+    /// returning how often it ran and the final error
+    fn attempts_until_failure(err: impl Fn() -> anyhow::Error) -> (u32, anyhow::Error) {
+        let mut attempts = 0;
+        let result: anyhow::Result<()> = retry_local("Test", || {
+            attempts += 1;
+            Err(err())
+        });
+        (attempts, result.unwrap_err())
+    }
+
+    #[test]
+    fn test_retry_local_fails_fast_on_permanent_errors() {
+        let (attempts, err) =
+            attempts_until_failure(|| ProviderError::ModelNotFound("missing".into()).into());
+        assert_eq!(attempts, 1);
+        assert!(matches!(
+            err.downcast_ref::<ProviderError>(),
+            Some(ProviderError::ModelNotFound(_))
+        ));
+
+        let (attempts, _) = attempts_until_failure(|| anyhow::anyhow!("invalid model file"));
+        assert_eq!(attempts, 1);
+    }
+
+    #[test]
+    fn test_retry_local_retries_transient_errors() {
+        let (attempts, err) = attempts_until_failure(|| anyhow::anyhow!("device busy"));
+        assert_eq!(attempts, 1 + LOCAL_MAX_RETRIES);
+        assert_eq!(err.to_string(), "device busy");
+
+        // Persistent OOM is retried, then reported with a hint
+        let (attempts, err) = attempts_until_failure(|| anyhow::anyhow!("out of memory"));
+        assert_eq!(attempts, 1 + LOCAL_MAX_RETRIES);
+        assert!(err.to_string().contains("smaller model"), "{err}");
+    }
+
+    #[test]
+    fn test_retry_local_returns_first_success() {
+        let mut attempts = 0;
+        let result = retry_local("Test", || {
+            attempts += 1;
+            if attempts < 2 {
+                anyhow::bail!("device busy")
+            }
+            Ok(attempts)
+        });
+        assert_eq!(result.unwrap(), 2);
+    }
 }
@@ -10,10 +10,12 @@ use async_trait::async_trait;
 use serde::Deserialize;
 
 use super::base::retry::{RetryConfig, is_rate_limited, is_retryable_error, is_retryable_status};
+use super::base::{formatting, gzip};
 use super::{
-    DEFAULT_TIMEOUT_SECS, TranscriptionBackend, TranscriptionRequest, TranscriptionResult,
+    ProviderError, TranscriptionBackend, TranscriptionRequest, TranscriptionResult,
     TranscriptionStage,
 };
+use crate::http::{blocking_client, request_timeouts, upload_body, upload_reader};
 
 const API_URL: &str = "https://api.deepgram.com/v1/listen";
 const MODEL: &str = "nova-2";
@@ -36,6 +38,8 @@ struct Channel {
 #[derive(Deserialize)]
 struct Alternative {
     transcript: String,
+    #[serde(default)]
+    confidence: Option<f32>,
 }
 
 /// Deepgram Nova transcription provider
@@ -55,6 +59,14 @@ impl TranscriptionBackend for DeepgramProvider {
         "Deepgram Nova"
     }
 
+    fn endpoint(&self) -> Option<&'static str> {
+        Some(API_URL)
+    }
+
+    fn default_model(&self) -> Option<&'static str> {
+        Some(MODEL)
+    }
+
     fn transcribe_sync(
         &self,
         api_key: &str,
@@ -63,32 +75,45 @@ impl TranscriptionBackend for DeepgramProvider {
         // Report uploading stage
         request.report(TranscriptionStage::Uploading);
 
-        let client = reqwest::blocking::Client::builder()
-            .timeout(std::time::Duration::from_secs(DEFAULT_TIMEOUT_SECS))
-            .build()
-            .context("Failed to create HTTP client")?;
+        let client = blocking_client()?;
+        let upload_timeout = request_timeouts().upload;
 
         let mut url = reqwest::Url::parse(API_URL).context("Failed to parse Deepgram URL")?;
         url.query_pairs_mut()
             .append_pair("model", MODEL)
-            .append_pair("smart_format", "true");
+            .append_pair("smart_format", &formatting::smart_format().to_string());
+        if request.numerals.unwrap_or_else(formatting::numerals) {
+            url.query_pairs_mut().append_pair("numerals", "true");
+        }
 
         if let Some(lang) = &request.language {
             url.query_pairs_mut().append_pair("language", lang);
         }
 
+        let mut gzipped = gzip::compress(self.name(), &request.audio_data);
         let config = RetryConfig::default();
         let mut attempt = 0;
 
         loop {
+            // Stop before (re)sending if the caller cancelled
+            request.check_cancelled()?;
+
             // Report transcribing stage
             request.report(TranscriptionStage::Transcribing);
 
-            let result = client
+            let body = gzipped.as_ref().unwrap_or(&request.audio_data);
+            let mut builder = client
                 .post(url.clone())
                 .header("Authorization", format!("Token {api_key}"))
-                .header("Content-Type", &request.mime_type)
-                .body(request.audio_data.clone())
+                .header("Content-Type", &request.mime_type);
+            if gzipped.is_some() {
+                builder = builder.header("Content-Encoding", "gzip");
+            }
+            let result = builder
+                .body(reqwest::blocking::Body::sized(
+                    upload_reader(body.clone(), upload_timeout),
+                    body.len() as u64,
+                ))
                 .send();
 
             match result {
@@ -100,19 +125,37 @@ impl TranscriptionBackend for DeepgramProvider {
                         let resp: Response = serde_json::from_str(&text)
                             .context("Failed to parse Deepgram API response")?;
 
-                        let transcript = resp
+                        let alternative = resp
                             .results
                             .channels
-                            .first()
-                            .and_then(|c| c.alternatives.first())
-                            .map(|a| a.transcript.clone())
+                            .into_iter()
+                            .next()
+                            .and_then(|c| c.alternatives.into_iter().next())
                             .ok_or_else(|| {
                                 anyhow::anyhow!(
                                     "Deepgram API returned unexpected response format: no transcript found"
                                 )
                             })?;
 
-                        return Ok(TranscriptionResult { text: transcript });
+                        return Ok(TranscriptionResult {
+                            text: alternative.transcript,
+                            confidence: alternative.confidence,
+                            ..Default::default()
+                        });
+                    }
+
+                    // Resend uncompressed if the server didn't take the gzip body
+                    if gzipped.is_some() && matches!(status.as_u16(), 400 | 415) {
+                        let error_text = response
+                            .text()
+                            .unwrap_or_else(|_| "Unknown error".to_string());
+                        if gzip::rejected(self.name(), status, &error_text) {
+                            gzipped = None;
+                            continue;
+                        }
+                        return Err(
+                            ProviderError::from_status("Deepgram", status, error_text).into()
+                        );
                     }
 
                     // Check if error is retryable
@@ -134,7 +177,7 @@ impl TranscriptionBackend for DeepgramProvider {
                     let error_text = response
                         .text()
                         .unwrap_or_else(|_| "Unknown error".to_string());
-                    anyhow::bail!("Deepgram API error ({status}): {error_text}");
+                    return Err(ProviderError::from_status("Deepgram", status, error_text).into());
                 }
                 Err(err) => {
                     // Check if network error is retryable
@@ -170,26 +213,44 @@ impl TranscriptionBackend for DeepgramProvider {
         let mut url = reqwest::Url::parse(API_URL).context("Failed to parse Deepgram URL")?;
         url.query_pairs_mut()
             .append_pair("model", MODEL)
-            .append_pair("smart_format", "true");
+            .append_pair("smart_format", &formatting::smart_format().to_string());
+        if request.numerals.unwrap_or_else(formatting::numerals) {
+            url.query_pairs_mut().append_pair("numerals", "true");
+        }
 
         if let Some(lang) = &request.language {
             url.query_pairs_mut().append_pair("language", lang);
         }
 
+        let timeouts = request_timeouts();
+        let mut gzipped = gzip::compress(self.name(), &request.audio_data);
         let config = RetryConfig::default();
         let mut attempt = 0;
 
         loop {
+            // Stop before (re)sending if the caller cancelled
+            request.check_cancelled()?;
+
             // Report transcribing stage
             request.report(TranscriptionStage::Transcribing);
 
-            let result = client
+            let body = gzipped.as_ref().unwrap_or(&request.audio_data);
+            let mut builder = client
                 .post(url.clone())
                 .header("Authorization", format!("Token {api_key}"))
                 .header("Content-Type", &request.mime_type)
-                .body(request.audio_data.clone())
-                .send()
-                .await;
+                .header("Content-Length", body.len())
+                .timeout(timeouts.request());
+            if gzipped.is_some() {
+                builder = builder.header("Content-Encoding", "gzip");
+            }
+            let result = request
+                .cancellable(
+                    builder
+                        .body(upload_body(body.clone(), timeouts.upload))
+                        .send(),
+                )
+                .await?;
 
             match result {
                 Ok(response) => {
@@ -203,19 +264,38 @@ impl TranscriptionBackend for DeepgramProvider {
                         let resp: Response = serde_json::from_str(&text)
                             .context("Failed to parse Deepgram API response")?;
 
-                        let transcript = resp
+                        let alternative = resp
                             .results
                             .channels
-                            .first()
-                            .and_then(|c| c.alternatives.first())
-                            .map(|a| a.transcript.clone())
+                            .into_iter()
+                            .next()
+                            .and_then(|c| c.alternatives.into_iter().next())
                             .ok_or_else(|| {
                                 anyhow::anyhow!(
                                     "Deepgram API returned unexpected response format: no transcript found"
                                 )
                             })?;
 
-                        return Ok(TranscriptionResult { text: transcript });
+                        return Ok(TranscriptionResult {
+                            text: alternative.transcript,
+                            confidence: alternative.confidence,
+                            ..Default::default()
+                        });
+                    }
+
+                    // Resend uncompressed if the server didn't take the gzip body
+                    if gzipped.is_some() && matches!(status.as_u16(), 400 | 415) {
+                        let error_text = response
+                            .text()
+                            .await
+                            .unwrap_or_else(|_| "Unknown error".to_string());
+                        if gzip::rejected(self.name(), status, &error_text) {
+                            gzipped = None;
+                            continue;
+                        }
+                        return Err(
+                            ProviderError::from_status("Deepgram", status, error_text).into()
+                        );
                     }
 
                     // Check if error is retryable
@@ -228,7 +308,7 @@ impl TranscriptionBackend for DeepgramProvider {
                             config.max_retries,
                             delay
                         );
-                        tokio::time::sleep(delay).await;
+                        request.cancellable(tokio::time::sleep(delay)).await?;
                         attempt += 1;
                         continue;
                     }
@@ -238,7 +318,7 @@ impl TranscriptionBackend for DeepgramProvider {
                         .text()
                         .await
                         .unwrap_or_else(|_| "Unknown error".to_string());
-                    anyhow::bail!("Deepgram API error ({status}): {error_text}");
+                    return Err(ProviderError::from_status("Deepgram", status, error_text).into());
                 }
                 Err(err) => {
                     // Check if network error is retryable
@@ -251,7 +331,7 @@ impl TranscriptionBackend for DeepgramProvider {
                             delay,
                             err
                         );
-                        tokio::time::sleep(delay).await;
+                        request.cancellable(tokio::time::sleep(delay)).await?;
                         attempt += 1;
                         continue;
                     }
@@ -26,10 +26,13 @@ use tokio_tungstenite::{
     },
 };
 
+use super::base::formatting;
 use super::{
     DeepgramProvider, RealtimeTranscriptionBackend, TranscriptionBackend, TranscriptionRequest,
-    TranscriptionResult,
+    TranscriptionResult, realtime::rejected_handshake,
 };
+use crate::config::TranscriptionProvider;
+use crate::transcription::PartialTranscriptCallback;
 
 const WS_URL: &str = "wss://api.deepgram.com/v1/listen";
 const MODEL: &str = "nova-3";
@@ -85,17 +88,23 @@ impl DeepgramRealtimeProvider {
     ///
     /// Connects to Deepgram Live Streaming API via WebSocket and streams audio chunks
     /// as they arrive. Returns the final transcript when the channel closes.
+    /// Interim results are passed to `on_partial` as they arrive.
     async fn transcribe_stream_impl(
         api_key: &str,
         mut audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
         language: Option<String>,
+        on_partial: Option<PartialTranscriptCallback>,
     ) -> Result<String> {
         // 1. Build WebSocket URL with query params
         let mut url = format!(
             "{WS_URL}?model={MODEL}&encoding=linear16&sample_rate={SAMPLE_RATE}\
-             &channels=1&smart_format=true&interim_results=true"
+             &channels=1&smart_format={}&interim_results=true",
+            formatting::smart_format()
         );
 
+        if formatting::numerals() {
+            url.push_str("&numerals=true");
+        }
         if let Some(lang) = language.clone() {
             url.push_str(&format!("&language={}", lang));
         }
@@ -129,6 +138,11 @@ impl DeepgramRealtimeProvider {
                     match timeout(Duration::from_secs(30), connect_async(request)).await {
                         Ok(Ok((stream, _response))) => break 'retry Ok(stream),
                         Ok(Err(e)) => {
+                            if let Some(err) =
+                                rejected_handshake(&TranscriptionProvider::Deepgram, &e)
+                            {
+                                break 'retry Err(err.into());
+                            }
                             last_error = Some(format!("Connection failed: {}", e));
                         }
                         Err(_) => {
@@ -168,7 +182,8 @@ impl DeepgramRealtimeProvider {
         let (done_tx, done_rx) = oneshot::channel::<usize>();
 
         // 5. Spawn read task to collect transcripts
-        let read_handle = tokio::spawn(async move { collect_transcripts(read, done_rx).await });
+        let read_handle =
+            tokio::spawn(async move { collect_transcripts(read, done_rx, on_partial).await });
 
         // 6. Spawn keepalive task
         let (keepalive_cancel_tx, keepalive_cancel_rx) = oneshot::channel();
@@ -253,7 +268,18 @@ impl DeepgramRealtimeProvider {
         audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
         language: Option<String>,
     ) -> Result<String> {
-        Self::transcribe_stream_impl(api_key, audio_rx, language).await
+        Self::transcribe_stream_impl(api_key, audio_rx, language, None).await
+    }
+
+    /// Like [`Self::transcribe_stream`], but reports the transcript so far
+    /// (final results plus the current interim result) to `on_partial`.
+    pub async fn transcribe_stream_with_partials(
+        api_key: &str,
+        audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        language: Option<String>,
+        on_partial: PartialTranscriptCallback,
+    ) -> Result<String> {
+        Self::transcribe_stream_impl(api_key, audio_rx, language, Some(on_partial)).await
     }
 }
 
@@ -305,11 +331,13 @@ where
 async fn collect_transcripts<S>(
     mut read: S,
     mut done_rx: oneshot::Receiver<usize>,
+    on_partial: Option<PartialTranscriptCallback>,
 ) -> Result<String>
 where
     S: Stream<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin,
 {
     let mut final_transcript = String::new();
+    let mut partials = Partials::new(on_partial);
 
     // Phase 1: Collect transcripts during streaming
     // total_samples will be received when streaming completes
@@ -328,7 +356,7 @@ where
 
             // Process WebSocket messages
             msg = read.next() => {
-                if let Some(result) = process_message(msg, &mut final_transcript)? {
+                if let Some(result) = process_message(msg, &mut final_transcript, &mut partials)? {
                     return Ok(result);
                 }
             }
@@ -371,7 +399,7 @@ where
             }
 
             msg = read.next() => {
-                if let Some(result) = process_message(msg, &mut final_transcript)? {
+                if let Some(result) = process_message(msg, &mut final_transcript, &mut partials)? {
                     return Ok(result);
                 }
                 // Continue waiting - don't reset the deadline, just process more messages
@@ -380,12 +408,42 @@ where
     }
 }
 
+/// Forwards interim transcripts to the caller's callback with increasing sequence numbers
+struct Partials {
+    on_partial: Option<PartialTranscriptCallback>,
+    sequence: usize,
+}
+
+impl Partials {
+    fn new(on_partial: Option<PartialTranscriptCallback>) -> Self {
+        Self {
+            on_partial,
+            sequence: 0,
+        }
+    }
+
+    /// Report the final transcript so far followed by the pending interim text
+    fn emit(&mut self, final_transcript: &str, interim: &str) {
+        let Some(on_partial) = &self.on_partial else {
+            return;
+        };
+        let text = format!("{final_transcript}{interim}");
+        let text = text.trim();
+        if text.is_empty() {
+            return;
+        }
+        self.sequence += 1;
+        on_partial(self.sequence, text);
+    }
+}
+
 /// Process a single WebSocket message.
 /// Returns Ok(Some(transcript)) if we should return immediately,
 /// Ok(None) to continue processing, or Err on error.
 fn process_message(
     msg: Option<Result<Message, tokio_tungstenite::tungstenite::Error>>,
     final_transcript: &mut String,
+    partials: &mut Partials,
 ) -> Result<Option<String>> {
     match msg {
         Some(Ok(Message::Text(text))) => {
@@ -401,14 +459,19 @@ fn process_message(
 
             match event.event_type.as_str() {
                 "Results" => {
-                    // Only collect final results (ignore interim results where is_final=false)
-                    if event.is_final
-                        && let Some(channel) = event.channel
+                    // Only collect final results; interim results (is_final=false)
+                    // are reported as partials and replaced by the next result
+                    if let Some(channel) = event.channel
                         && let Some(alt) = channel.alternatives.first()
                         && !alt.transcript.is_empty()
                     {
-                        final_transcript.push_str(&alt.transcript);
-                        final_transcript.push(' ');
+                        if event.is_final {
+                            final_transcript.push_str(&alt.transcript);
+                            final_transcript.push(' ');
+                            partials.emit(final_transcript, "");
+                        } else {
+                            partials.emit(final_transcript, &alt.transcript);
+                        }
                     }
 
                     // Note: Don't return immediately on from_finalize.
@@ -469,7 +532,7 @@ impl RealtimeTranscriptionBackend for DeepgramRealtimeProvider {
         audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
         language: Option<String>,
     ) -> Result<String> {
-        Self::transcribe_stream_impl(api_key, audio_rx, language).await
+        Self::transcribe_stream_impl(api_key, audio_rx, language, None).await
     }
 
     fn sample_rate(&self) -> u32 {
@@ -491,6 +554,14 @@ impl TranscriptionBackend for DeepgramRealtimeProvider {
         "Deepgram Realtime"
     }
 
+    fn endpoint(&self) -> Option<&'static str> {
+        Some(WS_URL)
+    }
+
+    fn default_model(&self) -> Option<&'static str> {
+        Some(MODEL)
+    }
+
     /// For file input, fall back to regular Deepgram API
     ///
     /// The Live Streaming API is designed for real-time mic input.
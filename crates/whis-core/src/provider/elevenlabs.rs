@@ -9,9 +9,10 @@ use serde::Deserialize;
 
 use super::base::retry::{RetryConfig, is_rate_limited, is_retryable_error, is_retryable_status};
 use super::{
-    DEFAULT_TIMEOUT_SECS, TranscriptionBackend, TranscriptionRequest, TranscriptionResult,
+    ProviderError, TranscriptionBackend, TranscriptionRequest, TranscriptionResult,
     TranscriptionStage,
 };
+use crate::http::{blocking_client, request_timeouts, upload_body, upload_reader};
 
 const API_URL: &str = "https://api.elevenlabs.io/v1/speech-to-text";
 const MODEL: &str = "scribe_v1";
@@ -39,6 +40,14 @@ impl TranscriptionBackend for ElevenLabsProvider {
         "ElevenLabs Scribe"
     }
 
+    fn endpoint(&self) -> Option<&'static str> {
+        Some(API_URL)
+    }
+
+    fn default_model(&self) -> Option<&'static str> {
+        Some(MODEL)
+    }
+
     fn transcribe_sync(
         &self,
         api_key: &str,
@@ -47,10 +56,8 @@ impl TranscriptionBackend for ElevenLabsProvider {
         // Report uploading stage
         request.report(TranscriptionStage::Uploading);
 
-        let client = reqwest::blocking::Client::builder()
-            .timeout(std::time::Duration::from_secs(DEFAULT_TIMEOUT_SECS))
-            .build()
-            .context("Failed to create HTTP client")?;
+        let client = blocking_client()?;
+        let upload_timeout = request_timeouts().upload;
 
         let config = RetryConfig::default();
         let mut attempt = 0;
@@ -60,15 +67,21 @@ impl TranscriptionBackend for ElevenLabsProvider {
                 .text("model_id", MODEL)
                 .part(
                     "file",
-                    reqwest::blocking::multipart::Part::bytes(request.audio_data.clone())
-                        .file_name(request.filename.clone())
-                        .mime_str(&request.mime_type)?,
+                    reqwest::blocking::multipart::Part::reader_with_length(
+                        upload_reader(request.audio_data.clone(), upload_timeout),
+                        request.audio_data.len() as u64,
+                    )
+                    .file_name(request.filename.clone())
+                    .mime_str(&request.mime_type)?,
                 );
 
             if let Some(lang) = request.language.clone() {
                 form = form.text("language_code", lang);
             }
 
+            // Stop before (re)sending if the caller cancelled
+            request.check_cancelled()?;
+
             // Report transcribing stage
             request.report(TranscriptionStage::Transcribing);
 
@@ -86,7 +99,11 @@ impl TranscriptionBackend for ElevenLabsProvider {
                         let text = response.text().context("Failed to get response text")?;
                         let resp: Response = serde_json::from_str(&text)
                             .context("Failed to parse ElevenLabs API response")?;
-                        return Ok(TranscriptionResult { text: resp.text });
+                        return Ok(TranscriptionResult {
+                            text: resp.text,
+                            confidence: None,
+                            ..Default::default()
+                        });
                     }
 
                     // Check if error is retryable
@@ -108,7 +125,7 @@ impl TranscriptionBackend for ElevenLabsProvider {
                     let error_text = response
                         .text()
                         .unwrap_or_else(|_| "Unknown error".to_string());
-                    anyhow::bail!("ElevenLabs API error ({status}): {error_text}");
+                    return Err(ProviderError::from_status("ElevenLabs", status, error_text).into());
                 }
                 Err(err) => {
                     // Check if network error is retryable
@@ -141,6 +158,7 @@ impl TranscriptionBackend for ElevenLabsProvider {
         // Report uploading stage
         request.report(TranscriptionStage::Uploading);
 
+        let timeouts = request_timeouts();
         let config = RetryConfig::default();
         let mut attempt = 0;
 
@@ -149,24 +167,34 @@ impl TranscriptionBackend for ElevenLabsProvider {
                 .text("model_id", MODEL)
                 .part(
                     "file",
-                    reqwest::multipart::Part::bytes(request.audio_data.clone())
-                        .file_name(request.filename.clone())
-                        .mime_str(&request.mime_type)?,
+                    reqwest::multipart::Part::stream_with_length(
+                        upload_body(request.audio_data.clone(), timeouts.upload),
+                        request.audio_data.len() as u64,
+                    )
+                    .file_name(request.filename.clone())
+                    .mime_str(&request.mime_type)?,
                 );
 
             if let Some(lang) = request.language.clone() {
                 form = form.text("language_code", lang);
             }
 
+            // Stop before (re)sending if the caller cancelled
+            request.check_cancelled()?;
+
             // Report transcribing stage
             request.report(TranscriptionStage::Transcribing);
 
-            let result = client
-                .post(API_URL)
-                .header("xi-api-key", api_key)
-                .multipart(form)
-                .send()
-                .await;
+            let result = request
+                .cancellable(
+                    client
+                        .post(API_URL)
+                        .header("xi-api-key", api_key)
+                        .timeout(timeouts.request())
+                        .multipart(form)
+                        .send(),
+                )
+                .await?;
 
             match result {
                 Ok(response) => {
@@ -179,7 +207,11 @@ impl TranscriptionBackend for ElevenLabsProvider {
                             .context("Failed to get response text")?;
                         let resp: Response = serde_json::from_str(&text)
                             .context("Failed to parse ElevenLabs API response")?;
-                        return Ok(TranscriptionResult { text: resp.text });
+                        return Ok(TranscriptionResult {
+                            text: resp.text,
+                            confidence: None,
+                            ..Default::default()
+                        });
                     }
 
                     // Check if error is retryable
@@ -192,7 +224,7 @@ impl TranscriptionBackend for ElevenLabsProvider {
                             config.max_retries,
                             delay
                         );
-                        tokio::time::sleep(delay).await;
+                        request.cancellable(tokio::time::sleep(delay)).await?;
                         attempt += 1;
                         continue;
                     }
@@ -202,7 +234,7 @@ impl TranscriptionBackend for ElevenLabsProvider {
                         .text()
                         .await
                         .unwrap_or_else(|_| "Unknown error".to_string());
-                    anyhow::bail!("ElevenLabs API error ({status}): {error_text}");
+                    return Err(ProviderError::from_status("ElevenLabs", status, error_text).into());
                 }
                 Err(err) => {
                     // Check if network error is retryable
@@ -215,7 +247,7 @@ impl TranscriptionBackend for ElevenLabsProvider {
                             delay,
                             err
                         );
-                        tokio::time::sleep(delay).await;
+                        request.cancellable(tokio::time::sleep(delay)).await?;
                         attempt += 1;
                         continue;
                     }
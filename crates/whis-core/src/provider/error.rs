@@ -1,6 +1,7 @@
 //! Provider-specific error types
 
 use crate::TranscriptionProvider;
+use crate::error::ErrorKind;
 use std::fmt;
 
 /// Errors that can occur during transcription provider operations
@@ -18,6 +19,10 @@ pub enum ProviderError {
     #[error("Invalid API key format for {provider}: {reason}")]
     InvalidApiKey { provider: String, reason: String },
 
+    /// The provider rejected the credentials (HTTP 401/403)
+    #[error("{provider} rejected the API key: {message}")]
+    Unauthorized { provider: String, message: String },
+
     /// Transcription request failed
     #[error("Transcription failed: {0}")]
     TranscriptionFailed(String),
@@ -42,6 +47,14 @@ pub enum ProviderError {
     #[error("Local model error: {0}")]
     LocalModelError(String),
 
+    /// Local model file or directory doesn't exist
+    #[error("Model not found: {0}")]
+    ModelNotFound(String),
+
+    /// Request was cancelled by the caller
+    #[error("Transcription cancelled")]
+    Cancelled,
+
     /// I/O error during provider operations
     #[error("Provider I/O error: {0}")]
     Io(#[from] std::io::Error),
@@ -77,6 +90,73 @@ impl ProviderError {
         Self::NetworkError(msg.to_string())
     }
 
+    /// Error for a failed (non-2xx) API response
+    ///
+    /// Rejected credentials and rate limiting get their own variants so
+    /// callers can tell them apart from other failures.
+    pub fn from_status(
+        provider: impl fmt::Display,
+        status: reqwest::StatusCode,
+        body: impl fmt::Display,
+    ) -> Self {
+        match status {
+            reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN => {
+                Self::Unauthorized {
+                    provider: provider.to_string(),
+                    message: format!("({status}) {body}"),
+                }
+            }
+            reqwest::StatusCode::TOO_MANY_REQUESTS => Self::RateLimitExceeded(provider.to_string()),
+            _ => Self::provider_specific(provider, format!("HTTP {status}: {body}")),
+        }
+    }
+
+    /// Classify a reqwest error, using `message` as the description
+    pub(crate) fn from_reqwest(err: &reqwest::Error, message: String) -> Self {
+        match err.status() {
+            Some(status) if status.is_client_error() || status.is_server_error() => {
+                Self::from_status(provider_for_url(err.url()), status, message)
+            }
+            _ if err.is_timeout() || err.is_connect() || err.is_request() || err.is_body() => {
+                Self::NetworkError(message)
+            }
+            _ => Self::TranscriptionFailed(message),
+        }
+    }
+
+    /// The kind of failure, see [`WhisError::kind`](crate::WhisError::kind)
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::MissingApiKey { .. } | Self::InvalidApiKey { .. } | Self::Unauthorized { .. } => {
+                ErrorKind::Auth
+            }
+            Self::NetworkError(_) => ErrorKind::Network,
+            Self::RateLimitExceeded(_) => ErrorKind::RateLimited,
+            Self::ModelNotFound(_) => ErrorKind::ModelNotFound,
+            Self::Cancelled => ErrorKind::Cancelled,
+            Self::Io(_) => ErrorKind::Io,
+            Self::Other(_) => ErrorKind::Other,
+            Self::NotFound(_) => ErrorKind::Config,
+            Self::LocalModelError(_) => ErrorKind::Model,
+            Self::TranscriptionFailed(_)
+            | Self::InvalidResponse(_)
+            | Self::ProviderSpecific { .. } => ErrorKind::Transcription,
+        }
+    }
+
+    /// Provider whose API key was rejected, if this is an auth failure
+    ///
+    /// Frontends use it to send the user straight to re-entering that key
+    /// instead of suggesting a retry.
+    pub fn rejected_key_provider(&self) -> Option<TranscriptionProvider> {
+        match self {
+            Self::Unauthorized { provider, .. } => {
+                TranscriptionProvider::from_display_name(provider)
+            }
+            _ => None,
+        }
+    }
+
     /// Create a provider-specific error
     pub fn provider_specific(provider: impl fmt::Display, message: impl fmt::Display) -> Self {
         Self::ProviderSpecific {
@@ -86,17 +166,148 @@ impl ProviderError {
     }
 }
 
+/// Display name of the provider serving `url`, "API" if it isn't one
+fn provider_for_url(url: Option<&reqwest::Url>) -> &'static str {
+    let provider = match url.and_then(|url| url.host_str()) {
+        Some("api.openai.com") => TranscriptionProvider::OpenAI,
+        Some("api.groq.com") => TranscriptionProvider::Groq,
+        Some("api.mistral.ai") => TranscriptionProvider::Mistral,
+        Some("api.deepgram.com") => TranscriptionProvider::Deepgram,
+        Some("api.elevenlabs.io") => TranscriptionProvider::ElevenLabs,
+        _ => return "API",
+    };
+    provider.display_name()
+}
+
 // Allow converting from reqwest errors
 impl From<reqwest::Error> for ProviderError {
     fn from(err: reqwest::Error) -> Self {
-        if err.is_timeout() {
-            ProviderError::NetworkError(format!("Request timeout: {}", err))
+        let message = if err.is_timeout() {
+            format!("Request timeout: {}", err)
         } else if err.is_connect() {
-            ProviderError::NetworkError(format!("Connection failed: {}", err))
-        } else if err.status() == Some(reqwest::StatusCode::TOO_MANY_REQUESTS) {
-            ProviderError::RateLimitExceeded("API".to_string())
+            format!("Connection failed: {}", err)
         } else {
-            ProviderError::NetworkError(err.to_string())
+            err.to_string()
+        };
+        match err.status() {
+            Some(_) => Self::from_reqwest(&err, message),
+            None => ProviderError::NetworkError(message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::StatusCode;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::time::Duration;
+
+    /// Serve one raw HTTP `response` on localhost, returning the URL
+    fn serve_once(response: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            if let Ok((mut stream, _)) = listener.accept() {
+                let _ = stream.read(&mut [0; 1024]);
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+        url
+    }
+
+    fn status_error(status_line: &'static str) -> reqwest::Error {
+        let url = serve_once(status_line);
+        reqwest::blocking::get(url)
+            .unwrap()
+            .error_for_status()
+            .unwrap_err()
+    }
+
+    #[test]
+    fn test_from_status_classifies() {
+        for status in [StatusCode::UNAUTHORIZED, StatusCode::FORBIDDEN] {
+            let err = ProviderError::from_status("OpenAI", status, "bad key");
+            assert_eq!(err.kind(), ErrorKind::Auth);
+            assert_eq!(
+                err.rejected_key_provider(),
+                Some(TranscriptionProvider::OpenAI)
+            );
+        }
+        let limited = ProviderError::from_status("Groq", StatusCode::TOO_MANY_REQUESTS, "");
+        assert!(matches!(&limited, ProviderError::RateLimitExceeded(p) if p == "Groq"));
+        assert_eq!(limited.kind(), ErrorKind::RateLimited);
+        for status in [StatusCode::INTERNAL_SERVER_ERROR, StatusCode::BAD_GATEWAY] {
+            let err = ProviderError::from_status("Deepgram", status, "down");
+            assert_eq!(err.kind(), ErrorKind::Transcription);
         }
+        let bad_request = ProviderError::from_status("Mistral", StatusCode::BAD_REQUEST, "");
+        assert_eq!(bad_request.kind(), ErrorKind::Transcription);
+    }
+
+    #[test]
+    fn test_rejected_key_provider_from_display_name() {
+        let err = ProviderError::from_status("OpenAI Realtime", StatusCode::UNAUTHORIZED, "");
+        assert_eq!(
+            err.rejected_key_provider(),
+            Some(TranscriptionProvider::OpenAIRealtime)
+        );
+        let unknown = ProviderError::from_status("API", StatusCode::UNAUTHORIZED, "");
+        assert_eq!(unknown.rejected_key_provider(), None);
+    }
+
+    #[test]
+    fn test_from_reqwest_status() {
+        let err = status_error("HTTP/1.1 401 Unauthorized\r\nContent-Length: 0\r\n\r\n");
+        assert_eq!(ProviderError::from(err).kind(), ErrorKind::Auth);
+
+        let err = status_error("HTTP/1.1 429 Too Many Requests\r\nContent-Length: 0\r\n\r\n");
+        assert_eq!(ProviderError::from(err).kind(), ErrorKind::RateLimited);
+
+        let err = status_error("HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\n\r\n");
+        assert_eq!(ProviderError::from(err).kind(), ErrorKind::Transcription);
+    }
+
+    #[test]
+    fn test_from_reqwest_connect_and_timeout() {
+        // Nothing listens on a port that was just released
+        let addr = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let err = reqwest::blocking::get(format!("http://{addr}/")).unwrap_err();
+        assert!(err.is_connect());
+        assert_eq!(ProviderError::from(err).kind(), ErrorKind::Network);
+
+        // Accepts the connection but never answers
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let err = reqwest::blocking::Client::new()
+            .get(url)
+            .timeout(Duration::from_millis(100))
+            .send()
+            .unwrap_err();
+        assert!(err.is_timeout());
+        assert_eq!(ProviderError::from(err).kind(), ErrorKind::Network);
+        drop(listener);
+    }
+
+    #[test]
+    fn test_provider_for_url() {
+        let url = |s: &str| reqwest::Url::parse(s).unwrap();
+        assert_eq!(
+            provider_for_url(Some(&url("https://api.openai.com/v1/audio"))),
+            "OpenAI"
+        );
+        assert_eq!(
+            provider_for_url(Some(&url("https://api.groq.com/x"))),
+            "Groq"
+        );
+        assert_eq!(
+            provider_for_url(Some(&url("http://localhost:8080/"))),
+            "API"
+        );
+        assert_eq!(provider_for_url(None), "API");
     }
 }
@@ -10,6 +10,7 @@ use super::{
     TranscriptionBackend, TranscriptionRequest, TranscriptionResult,
     openai_compatible_transcribe_async, openai_compatible_transcribe_sync,
 };
+use crate::config::TranscriptionProvider;
 
 const API_URL: &str = "https://api.groq.com/openai/v1/audio/transcriptions";
 const MODEL: &str = "whisper-large-v3-turbo";
@@ -31,12 +32,26 @@ impl TranscriptionBackend for GroqProvider {
         "Groq Whisper"
     }
 
+    fn endpoint(&self) -> Option<&'static str> {
+        Some(API_URL)
+    }
+
+    fn default_model(&self) -> Option<&'static str> {
+        Some(MODEL)
+    }
+
     fn transcribe_sync(
         &self,
         api_key: &str,
         request: TranscriptionRequest,
     ) -> Result<TranscriptionResult> {
-        openai_compatible_transcribe_sync(API_URL, MODEL, api_key, request)
+        openai_compatible_transcribe_sync(
+            &TranscriptionProvider::Groq,
+            API_URL,
+            MODEL,
+            api_key,
+            request,
+        )
     }
 
     async fn transcribe_async(
@@ -45,6 +60,14 @@ impl TranscriptionBackend for GroqProvider {
         api_key: &str,
         request: TranscriptionRequest,
     ) -> Result<TranscriptionResult> {
-        openai_compatible_transcribe_async(client, API_URL, MODEL, api_key, request).await
+        openai_compatible_transcribe_async(
+            client,
+            &TranscriptionProvider::Groq,
+            API_URL,
+            MODEL,
+            api_key,
+            request,
+        )
+        .await
     }
 }
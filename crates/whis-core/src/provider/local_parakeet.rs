@@ -10,7 +10,8 @@ use async_trait::async_trait;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Mutex, OnceLock};
 
-use super::{TranscriptionBackend, TranscriptionRequest, TranscriptionResult};
+use super::base::retry::retry_local;
+use super::{ProviderError, TranscriptionBackend, TranscriptionRequest, TranscriptionResult};
 
 /// Local Parakeet transcription provider
 #[derive(Debug, Default, Clone)]
@@ -59,11 +60,23 @@ pub fn transcribe_raw(model_path: &str, samples: Vec<f32>) -> Result<Transcripti
     transcribe_samples(model_path, samples)
 }
 
-/// Internal function to transcribe PCM samples using Parakeet
+/// Internal function to transcribe PCM samples, retrying transient failures
+fn transcribe_samples(model_path: &str, samples: Vec<f32>) -> Result<TranscriptionResult> {
+    // A download to this path may still be running (e.g. started from
+    // setup); waited for once, a timeout isn't worth retrying
+    crate::model::wait_until_ready(
+        std::path::Path::new(model_path),
+        crate::model::MODEL_READY_TIMEOUT,
+    )?;
+
+    retry_local("Parakeet", || transcribe_samples_once(model_path, &samples))
+}
+
+/// Single transcription attempt using Parakeet
 ///
 /// ONNX Runtime has memory constraints with long audio in Parakeet models.
 /// This function automatically chunks audio longer than 90 seconds to avoid ORT errors.
-fn transcribe_samples(model_path: &str, samples: Vec<f32>) -> Result<TranscriptionResult> {
+fn transcribe_samples_once(model_path: &str, samples: &[f32]) -> Result<TranscriptionResult> {
     use transcribe_rs::engines::parakeet::{ParakeetInferenceParams, TimestampGranularity};
 
     // Empirically tested: Parakeet works well up to ~90 seconds
@@ -87,7 +100,7 @@ fn transcribe_samples(model_path: &str, samples: Vec<f32>) -> Result<Transcripti
 
     // If audio is short, transcribe directly (no chunking needed)
     let result = if samples.len() <= CHUNK_SIZE {
-        transcribe_chunk_with_engine(&mut cached.engine, samples, &params)?
+        transcribe_chunk_with_engine(&mut cached.engine, samples.to_vec(), &params)?
     } else {
         // Split long audio into chunks with overlap
         let mut chunks = Vec::new();
@@ -116,6 +129,8 @@ fn transcribe_samples(model_path: &str, samples: Vec<f32>) -> Result<Transcripti
         // Concatenate chunk results with space separator
         TranscriptionResult {
             text: results.join(" "),
+            confidence: None,
+            ..Default::default()
         }
     };
 
@@ -147,6 +162,8 @@ fn transcribe_chunk_with_engine(
 
     Ok(TranscriptionResult {
         text: result.text.trim().to_string(),
+        confidence: None,
+        ..Default::default()
     })
 }
 
@@ -188,19 +205,21 @@ fn get_or_load_engine(model_path: &str) -> Result<()> {
         return Ok(()); // Already loaded
     }
 
-    // Validate model path
+    // Validate model path (not retryable)
     if model_path.is_empty() {
-        anyhow::bail!(
-            "Parakeet model path not configured. Set LOCAL_PARAKEET_MODEL_PATH or use: whis config --parakeet-model-path <path>"
-        );
+        return Err(ProviderError::LocalModelError(
+            "Parakeet model path not configured. Set LOCAL_PARAKEET_MODEL_PATH or use: whis config --parakeet-model-path <path>".to_string(),
+        )
+        .into());
     }
 
     if !Path::new(model_path).exists() {
-        anyhow::bail!(
+        return Err(ProviderError::ModelNotFound(format!(
             "Parakeet model not found at: {}\n\
              Download a model using: whis setup local",
             model_path
-        );
+        ))
+        .into());
     }
 
     crate::verbose!("Loading Parakeet model: {}", model_path);
@@ -254,8 +273,14 @@ pub fn preload_parakeet(model_path: &str) {
     std::thread::spawn(move || {
         crate::verbose!("Preloading Parakeet model: {}", model_path);
 
-        // Load into shared static cache using get_or_load_engine
-        if let Err(e) = get_or_load_engine(&model_path) {
+        // Load into shared static cache using get_or_load_engine, once any
+        // running download has finished
+        let loaded = crate::model::wait_until_ready(
+            std::path::Path::new(&model_path),
+            crate::model::MODEL_READY_TIMEOUT,
+        )
+        .and_then(|()| get_or_load_engine(&model_path));
+        if let Err(e) = loaded {
             eprintln!("Warning: Failed to preload Parakeet model: {}", e);
             return;
         }
@@ -14,7 +14,8 @@ use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Mutex, OnceLock};
 
-use super::{TranscriptionBackend, TranscriptionRequest, TranscriptionResult};
+use super::base::retry::retry_local;
+use super::{ProviderError, TranscriptionBackend, TranscriptionRequest, TranscriptionResult};
 
 // ============================================================================
 // stderr Suppression for GGML Vulkan Output
@@ -193,19 +194,21 @@ fn get_or_load_engine(model_path: &str) -> Result<()> {
         return Ok(()); // Already loaded
     }
 
-    // Validate model path
+    // Validate model path (not retryable)
     if model_path.is_empty() {
-        anyhow::bail!(
-            "Whisper model path not configured. Set LOCAL_WHISPER_MODEL_PATH or use: whis config --whisper-model-path <path>"
-        );
+        return Err(ProviderError::LocalModelError(
+            "Whisper model path not configured. Set LOCAL_WHISPER_MODEL_PATH or use: whis config --whisper-model-path <path>".to_string(),
+        )
+        .into());
     }
 
     if !Path::new(model_path).exists() {
-        anyhow::bail!(
+        return Err(ProviderError::ModelNotFound(format!(
             "Whisper model not found at: {}\n\
              Download a model from: https://huggingface.co/ggerganov/whisper.cpp/tree/main",
             model_path
-        );
+        ))
+        .into());
     }
 
     crate::verbose!("Loading whisper model from: {}", model_path);
@@ -235,11 +238,26 @@ fn get_or_load_engine(model_path: &str) -> Result<()> {
     Ok(())
 }
 
-/// Internal function to transcribe PCM samples using cached WhisperEngine
+/// Internal function to transcribe PCM samples, retrying transient failures
 fn transcribe_samples(
     model_path: &str,
     samples: &[f32],
     language: Option<&str>,
+) -> Result<TranscriptionResult> {
+    // A download to this path may still be running (e.g. started from
+    // setup); waited for once, a timeout isn't worth retrying
+    crate::model::wait_until_ready(Path::new(model_path), crate::model::MODEL_READY_TIMEOUT)?;
+
+    retry_local("Whisper", || {
+        transcribe_samples_once(model_path, samples, language)
+    })
+}
+
+/// Single transcription attempt using cached WhisperEngine
+fn transcribe_samples_once(
+    model_path: &str,
+    samples: &[f32],
+    language: Option<&str>,
 ) -> Result<TranscriptionResult> {
     use transcribe_rs::TranscriptionEngine;
     use transcribe_rs::engines::whisper::WhisperInferenceParams;
@@ -287,6 +305,9 @@ fn transcribe_samples(
 
     Ok(TranscriptionResult {
         text: text.trim().to_string(),
+        // transcribe-rs doesn't expose whisper.cpp token probabilities
+        confidence: None,
+        ..Default::default()
     })
 }
 
@@ -352,7 +373,10 @@ pub fn preload_model(path: &str) {
     let path = path.to_string();
     std::thread::spawn(move || {
         crate::verbose!("Preloading whisper model in background...");
-        if let Err(e) = get_or_load_engine(&path) {
+        let loaded =
+            crate::model::wait_until_ready(Path::new(&path), crate::model::MODEL_READY_TIMEOUT)
+                .and_then(|()| get_or_load_engine(&path));
+        if let Err(e) = loaded {
             crate::verbose!("Preload failed: {}", e);
         }
     });
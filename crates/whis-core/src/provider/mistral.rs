@@ -7,6 +7,7 @@ use super::{
     TranscriptionBackend, TranscriptionRequest, TranscriptionResult,
     openai_compatible_transcribe_async, openai_compatible_transcribe_sync,
 };
+use crate::config::TranscriptionProvider;
 
 const API_URL: &str = "https://api.mistral.ai/v1/audio/transcriptions";
 const MODEL: &str = "voxtral-mini-latest";
@@ -25,12 +26,26 @@ impl TranscriptionBackend for MistralProvider {
         "Mistral Voxtral"
     }
 
+    fn endpoint(&self) -> Option<&'static str> {
+        Some(API_URL)
+    }
+
+    fn default_model(&self) -> Option<&'static str> {
+        Some(MODEL)
+    }
+
     fn transcribe_sync(
         &self,
         api_key: &str,
         request: TranscriptionRequest,
     ) -> Result<TranscriptionResult> {
-        openai_compatible_transcribe_sync(API_URL, MODEL, api_key, request)
+        openai_compatible_transcribe_sync(
+            &TranscriptionProvider::Mistral,
+            API_URL,
+            MODEL,
+            api_key,
+            request,
+        )
     }
 
     async fn transcribe_async(
@@ -39,6 +54,14 @@ impl TranscriptionBackend for MistralProvider {
         api_key: &str,
         request: TranscriptionRequest,
     ) -> Result<TranscriptionResult> {
-        openai_compatible_transcribe_async(client, API_URL, MODEL, api_key, request).await
+        openai_compatible_transcribe_async(
+            client,
+            &TranscriptionProvider::Mistral,
+            API_URL,
+            MODEL,
+            api_key,
+            request,
+        )
+        .await
     }
 }
@@ -0,0 +1,55 @@
+//! Mock transcription provider for tests and offline smoke tests
+//!
+//! Returns a fixed transcript without any network call or model. The text is
+//! taken from `WHIS_MOCK_TRANSCRIPT`, which is resolved like an API key (so
+//! the provider is only usable while the variable is set):
+//!
+//! ```bash
+//! whis config provider mock
+//! WHIS_MOCK_TRANSCRIPT="Hello world" whis -f recording.wav --print
+//! ```
+//!
+//! In chunked recording each chunk returns the same text.
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use super::{TranscriptionBackend, TranscriptionRequest, TranscriptionResult};
+
+/// Mock transcription provider
+///
+/// The "API key" is the transcript to return; the audio is ignored.
+#[derive(Debug, Default, Clone)]
+pub struct MockProvider;
+
+#[async_trait]
+impl TranscriptionBackend for MockProvider {
+    fn name(&self) -> &'static str {
+        "mock"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Mock"
+    }
+
+    fn transcribe_sync(
+        &self,
+        api_key: &str,
+        _request: TranscriptionRequest,
+    ) -> Result<TranscriptionResult> {
+        Ok(TranscriptionResult {
+            text: api_key.to_string(),
+            confidence: None,
+            ..Default::default()
+        })
+    }
+
+    async fn transcribe_async(
+        &self,
+        _client: &reqwest::Client,
+        api_key: &str,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResult> {
+        self.transcribe_sync(api_key, request)
+    }
+}
@@ -29,6 +29,8 @@
 //! - Local Whisper (via transcribe-rs)
 //! - Local Parakeet (via transcribe-rs)
 //!
+//! **Mock** (testing): returns `$WHIS_MOCK_TRANSCRIPT` without a network call
+//!
 //! # Adding a New Provider
 //!
 //! 1. Create a new file in `provider/` (e.g., `myprovider.rs`)
@@ -42,8 +44,11 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use std::collections::HashMap;
+use std::future::Future;
 use std::sync::{Arc, OnceLock};
 
+pub use tokio_util::sync::CancellationToken;
+
 /// Stages of the transcription workflow for progress reporting
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TranscriptionStage {
@@ -78,6 +83,7 @@ impl TranscriptionStage {
 /// Progress callback type for reporting transcription stages
 pub type ProgressCallback = Arc<dyn Fn(TranscriptionStage) + Send + Sync>;
 
+mod audio_format;
 mod base;
 mod deepgram;
 #[cfg(feature = "realtime")]
@@ -90,15 +96,17 @@ mod local_parakeet;
 #[cfg(feature = "local-transcription")]
 pub mod local_whisper;
 mod mistral;
+mod mock;
 mod openai;
 #[cfg(feature = "realtime")]
 mod openai_realtime;
 #[cfg(feature = "realtime")]
 mod realtime;
 
-/// Default timeout for API requests (5 minutes)
+/// Default time a provider has to respond once the audio is uploaded (5 minutes)
 pub const DEFAULT_TIMEOUT_SECS: u64 = 300;
 
+pub use audio_format::AudioFormat;
 pub use deepgram::DeepgramProvider;
 #[cfg(feature = "realtime")]
 pub use deepgram_realtime::DeepgramRealtimeProvider;
@@ -123,6 +131,7 @@ pub use local_whisper::{
     unload_model as whisper_unload_model,
 };
 pub use mistral::MistralProvider;
+pub use mock::MockProvider;
 pub use openai::OpenAIProvider;
 #[cfg(feature = "realtime")]
 pub use openai_realtime::OpenAIRealtimeProvider;
@@ -131,6 +140,116 @@ pub use realtime::RealtimeTranscriptionBackend;
 
 use crate::config::TranscriptionProvider;
 
+/// Builder for [`TranscriptionRequest`]
+///
+/// Filename and MIME type are both resolved through one [`AudioFormat`]:
+/// an explicit `format()` wins, otherwise it's taken from the MIME type, then
+/// the filename's extension, falling back to MP3. The filename always ends in
+/// that format's extension, and an explicit MIME type is only kept (for its
+/// `;codecs=` parameter) when it names the same format.
+#[derive(Default)]
+pub struct TranscriptionRequestBuilder {
+    audio_data: Vec<u8>,
+    language: Option<String>,
+    format: Option<AudioFormat>,
+    filename: Option<String>,
+    mime_type: Option<String>,
+    response_format: ResponseFormat,
+    progress: Option<ProgressCallback>,
+    cancel: Option<CancellationToken>,
+    numerals: Option<bool>,
+}
+
+impl TranscriptionRequestBuilder {
+    /// Encoded audio bytes
+    pub fn audio_data(mut self, audio_data: Vec<u8>) -> Self {
+        self.audio_data = audio_data;
+        self
+    }
+
+    /// Language hint (ISO-639-1 code); auto-detect when `None`
+    pub fn language(mut self, language: Option<String>) -> Self {
+        self.language = language;
+        self
+    }
+
+    /// Container format of `audio_data`
+    pub fn format(mut self, format: AudioFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Filename sent with the upload (extension is replaced by the format's)
+    pub fn filename(mut self, filename: impl Into<String>) -> Self {
+        self.filename = Some(filename.into());
+        self
+    }
+
+    /// MIME type of `audio_data`, e.g. "audio/webm;codecs=opus" from a browser
+    pub fn mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+
+    /// Response format to ask OpenAI-compatible providers for (default: `Json`)
+    pub fn response_format(mut self, format: ResponseFormat) -> Self {
+        self.response_format = format;
+        self
+    }
+
+    /// Progress callback for status updates
+    pub fn progress(mut self, callback: Option<ProgressCallback>) -> Self {
+        self.progress = callback;
+        self
+    }
+
+    /// Cancellation token (aborts in-flight requests and pending retries)
+    pub fn cancel(mut self, token: Option<CancellationToken>) -> Self {
+        self.cancel = token;
+        self
+    }
+
+    /// Ask the provider to write spoken numbers as digits (Deepgram only;
+    /// default: the process-wide [`set_numeral_formatting`])
+    pub fn numerals(mut self, enabled: bool) -> Self {
+        self.numerals = Some(enabled);
+        self
+    }
+
+    pub fn build(self) -> TranscriptionRequest {
+        let format = self
+            .format
+            .or_else(|| AudioFormat::from_mime_type(self.mime_type.as_deref()?))
+            .or_else(|| AudioFormat::from_filename(self.filename.as_deref()?))
+            .unwrap_or_default();
+
+        let mime_type = self
+            .mime_type
+            .filter(|mime| AudioFormat::from_mime_type(mime) == Some(format))
+            .unwrap_or_else(|| format.mime_type().to_string());
+
+        let filename = self.filename.unwrap_or_else(|| "audio".to_string());
+        let stem = match AudioFormat::from_filename(&filename) {
+            Some(_) => filename
+                .rsplit_once('.')
+                .map_or(filename.as_str(), |(s, _)| s),
+            None => filename.as_str(),
+        };
+        let filename = format!("{stem}.{}", format.extension());
+
+        TranscriptionRequest {
+            audio_data: self.audio_data,
+            language: self.language,
+            filename,
+            mime_type,
+            response_format: self.response_format,
+            progress: self.progress,
+            cancel: self.cancel,
+            numerals: self.numerals,
+        }
+    }
+}
+
 /// Request data for transcription
 #[derive(Clone)]
 pub struct TranscriptionRequest {
@@ -138,20 +257,28 @@ pub struct TranscriptionRequest {
     pub language: Option<String>,
     pub filename: String,
     pub mime_type: String,
+    /// Response format asked of OpenAI-compatible providers (others ignore it)
+    pub response_format: ResponseFormat,
     /// Optional progress callback for status updates
     pub progress: Option<ProgressCallback>,
+    /// Optional cancellation token (aborts in-flight requests and pending retries)
+    pub cancel: Option<CancellationToken>,
+    /// Provider-side number formatting for this request (None = process-wide setting)
+    pub numerals: Option<bool>,
 }
 
 impl TranscriptionRequest {
-    /// Create a new request without progress callback
+    /// Create a new MP3 request without progress callback
     pub fn new(audio_data: Vec<u8>, language: Option<String>) -> Self {
-        Self {
-            audio_data,
-            language,
-            filename: "audio.mp3".to_string(),
-            mime_type: "audio/mpeg".to_string(),
-            progress: None,
-        }
+        Self::builder()
+            .audio_data(audio_data)
+            .language(language)
+            .build()
+    }
+
+    /// Start building a request (MP3 as `audio.mp3` unless told otherwise)
+    pub fn builder() -> TranscriptionRequestBuilder {
+        TranscriptionRequestBuilder::default()
     }
 
     /// Set the progress callback
@@ -160,21 +287,109 @@ impl TranscriptionRequest {
         self
     }
 
+    /// Set the cancellation token
+    pub fn with_cancel(mut self, token: CancellationToken) -> Self {
+        self.cancel = Some(token);
+        self
+    }
+
     /// Report progress if callback is set
     pub fn report(&self, stage: TranscriptionStage) {
         if let Some(cb) = &self.progress {
             cb(stage);
         }
     }
+
+    /// Check whether the request has been cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.as_ref().is_some_and(|t| t.is_cancelled())
+    }
+
+    /// Return a `ProviderError::Cancelled` error if the request has been cancelled
+    pub fn check_cancelled(&self) -> Result<()> {
+        if self.is_cancelled() {
+            return Err(ProviderError::Cancelled.into());
+        }
+        Ok(())
+    }
+
+    /// Run a future, aborting it if the request is cancelled first
+    ///
+    /// Dropping a reqwest future aborts the underlying HTTP request, so wrapping
+    /// `send()` with this stops an upload mid-flight.
+    pub async fn cancellable<F: Future>(&self, fut: F) -> Result<F::Output> {
+        match &self.cancel {
+            Some(token) => tokio::select! {
+                output = fut => Ok(output),
+                _ = token.cancelled() => Err(ProviderError::Cancelled.into()),
+            },
+            None => Ok(fut.await),
+        }
+    }
+}
+
+/// Response format of OpenAI-compatible transcription APIs (`response_format`)
+///
+/// `VerboseJson` adds timed segments and the detected language to the
+/// result, which is what subtitles need. `Text`, `Srt` and `Vtt` return the
+/// provider's document as the result's text, unchanged. OpenAI (`whisper-1`)
+/// and Groq support all of them; other providers always answer as `Json`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ResponseFormat {
+    /// Plain transcript
+    #[default]
+    Json,
+    /// Transcript with segment timestamps and the detected language
+    VerboseJson,
+    /// Plain text body instead of JSON
+    Text,
+    /// SubRip subtitles, timed by the provider
+    Srt,
+    /// WebVTT subtitles, timed by the provider
+    Vtt,
+}
+
+impl ResponseFormat {
+    /// Value of the `response_format` form field
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::VerboseJson => "verbose_json",
+            Self::Text => "text",
+            Self::Srt => "srt",
+            Self::Vtt => "vtt",
+        }
+    }
+}
+
+/// A stretch of transcript with its position in the audio
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranscriptSegment {
+    /// Start, in seconds from the start of the audio
+    pub start: f64,
+    /// End, in seconds from the start of the audio
+    pub end: f64,
+    pub text: String,
 }
 
 /// Result of a transcription
+#[derive(Default)]
 pub struct TranscriptionResult {
     pub text: String,
+    /// Overall confidence (0.0-1.0), if the provider reports one
+    pub confidence: Option<f32>,
+    /// Timed segments, if the provider returned them (`ResponseFormat::VerboseJson`)
+    pub segments: Vec<TranscriptSegment>,
+    /// Language the provider detected (ISO-639-1), if it reports one
+    pub language: Option<String>,
 }
 
 // Import shared helpers from base module
 pub(crate) use base::{openai_compatible_transcribe_async, openai_compatible_transcribe_sync};
+// Retry helpers are shared with the post-processing requests
+pub use base::formatting::{set_numeral_formatting, set_smart_formatting};
+pub use base::gzip::set_upload_compression;
+pub(crate) use base::retry;
 
 /// Trait for transcription providers
 ///
@@ -187,6 +402,16 @@ pub trait TranscriptionBackend: Send + Sync {
     /// Display name for UI (e.g., "OpenAI Whisper", "Deepgram Nova")
     fn display_name(&self) -> &'static str;
 
+    /// API endpoint requests are sent to (None for local providers)
+    fn endpoint(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Model sent with requests (None if the provider doesn't take one)
+    fn default_model(&self) -> Option<&'static str> {
+        None
+    }
+
     /// Synchronous transcription (for simple single-file case)
     fn transcribe_sync(
         &self,
@@ -226,6 +451,7 @@ impl ProviderRegistry {
         providers.insert("local-whisper", Arc::new(LocalWhisperProvider));
         #[cfg(feature = "local-transcription")]
         providers.insert("local-parakeet", Arc::new(LocalParakeetProvider));
+        providers.insert("mock", Arc::new(MockProvider));
 
         Self { providers }
     }
@@ -23,8 +23,10 @@ use tokio_tungstenite::{
 
 use super::{
     OpenAIProvider, RealtimeTranscriptionBackend, TranscriptionBackend, TranscriptionRequest,
-    TranscriptionResult,
+    TranscriptionResult, realtime::rejected_handshake,
 };
+use crate::config::TranscriptionProvider;
+use crate::transcription::PartialTranscriptCallback;
 
 const WS_URL: &str = "wss://api.openai.com/v1/realtime?intent=transcription";
 const REALTIME_SAMPLE_RATE: u32 = 24000;
@@ -106,6 +108,9 @@ struct RealtimeEvent {
     event_type: String,
     #[serde(default)]
     transcript: Option<String>,
+    /// Incremental text of `input_audio_transcription.delta` events
+    #[serde(default)]
+    delta: Option<String>,
     #[serde(default)]
     error: Option<RealtimeError>,
 }
@@ -127,10 +132,12 @@ impl OpenAIRealtimeProvider {
     ///
     /// Connects to OpenAI Realtime API via WebSocket and streams audio chunks
     /// as they arrive. Returns the final transcript when the channel closes.
+    /// Transcription deltas are passed to `on_partial` as they arrive.
     async fn transcribe_stream_impl(
         api_key: &str,
         mut audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
         language: Option<String>,
+        on_partial: Option<PartialTranscriptCallback>,
     ) -> Result<String> {
         // 1. Connect to WebSocket with retry logic
         let ws_stream = {
@@ -161,6 +168,11 @@ impl OpenAIRealtimeProvider {
                     match timeout(Duration::from_secs(30), connect_async(request)).await {
                         Ok(Ok((stream, _response))) => break 'retry Ok(stream),
                         Ok(Err(e)) => {
+                            if let Some(err) =
+                                rejected_handshake(&TranscriptionProvider::OpenAI, &e)
+                            {
+                                break 'retry Err(err.into());
+                            }
                             last_error = Some(format!("Connection failed: {}", e));
                         }
                         Err(_) => {
@@ -271,7 +283,9 @@ impl OpenAIRealtimeProvider {
         let (done_tx, done_rx) = oneshot::channel::<usize>(); // Now sends total_samples
 
         let read_handle =
-            tokio::spawn(async move { collect_transcripts(read, error_tx, done_rx).await });
+            tokio::spawn(
+                async move { collect_transcripts(read, error_tx, done_rx, on_partial).await },
+            );
 
         // 5. (No keepalive needed for OpenAI)
 
@@ -372,7 +386,18 @@ impl OpenAIRealtimeProvider {
         audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
         language: Option<String>,
     ) -> Result<String> {
-        Self::transcribe_stream_impl(api_key, audio_rx, language).await
+        Self::transcribe_stream_impl(api_key, audio_rx, language, None).await
+    }
+
+    /// Like [`Self::transcribe_stream`], but reports the transcript so far
+    /// to `on_partial` as transcription deltas arrive.
+    pub async fn transcribe_stream_with_partials(
+        api_key: &str,
+        audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
+        language: Option<String>,
+        on_partial: PartialTranscriptCallback,
+    ) -> Result<String> {
+        Self::transcribe_stream_impl(api_key, audio_rx, language, Some(on_partial)).await
     }
 }
 
@@ -391,12 +416,14 @@ async fn collect_transcripts<S>(
     mut read: S,
     error_tx: oneshot::Sender<anyhow::Error>,
     mut done_rx: oneshot::Receiver<usize>,
+    on_partial: Option<PartialTranscriptCallback>,
 ) -> Result<String>
 where
     S: futures_util::Stream<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin,
 {
     // Store transcript if received early (during Phase 1)
     let mut early_transcript: Option<String> = None;
+    let mut partials = Partials::new(on_partial);
 
     // Phase 1: Monitor for errors AND collect transcripts during audio streaming
     // total_samples will be received when streaming completes
@@ -440,6 +467,12 @@ where
                             return Err(err);
                         }
 
+                        if event.event_type == "conversation.item.input_audio_transcription.delta"
+                            && let Some(delta) = &event.delta
+                        {
+                            partials.push(delta);
+                        }
+
                         // Capture transcript if it arrives early (short recordings)
                         if event.event_type == "conversation.item.input_audio_transcription.completed"
                             && let Some(transcript) = event.transcript
@@ -528,6 +561,11 @@ where
                                     return Err(anyhow!("OpenAI Realtime error: {}", err.message));
                                 }
                             }
+                            "conversation.item.input_audio_transcription.delta" => {
+                                if let Some(delta) = &event.delta {
+                                    partials.push(delta);
+                                }
+                            }
                             "conversation.item.input_audio_transcription.completed" => {
                                 if let Some(transcript) = event.transcript {
                                     return Ok(transcript);
@@ -557,6 +595,37 @@ where
     }
 }
 
+/// Accumulates transcription deltas and forwards the text so far to the
+/// caller's callback with increasing sequence numbers
+struct Partials {
+    on_partial: Option<PartialTranscriptCallback>,
+    text: String,
+    sequence: usize,
+}
+
+impl Partials {
+    fn new(on_partial: Option<PartialTranscriptCallback>) -> Self {
+        Self {
+            on_partial,
+            text: String::new(),
+            sequence: 0,
+        }
+    }
+
+    fn push(&mut self, delta: &str) {
+        let Some(on_partial) = &self.on_partial else {
+            return;
+        };
+        self.text.push_str(delta);
+        let text = self.text.trim();
+        if text.is_empty() {
+            return;
+        }
+        self.sequence += 1;
+        on_partial(self.sequence, text);
+    }
+}
+
 /// Simple linear interpolation to resample from 16kHz to 24kHz
 ///
 /// For each output sample, we interpolate between two input samples.
@@ -591,7 +660,7 @@ impl RealtimeTranscriptionBackend for OpenAIRealtimeProvider {
         audio_rx: mpsc::UnboundedReceiver<Vec<f32>>,
         language: Option<String>,
     ) -> Result<String> {
-        Self::transcribe_stream_impl(api_key, audio_rx, language).await
+        Self::transcribe_stream_impl(api_key, audio_rx, language, None).await
     }
 
     fn sample_rate(&self) -> u32 {
@@ -613,6 +682,14 @@ impl TranscriptionBackend for OpenAIRealtimeProvider {
         "OpenAI Realtime"
     }
 
+    fn endpoint(&self) -> Option<&'static str> {
+        Some(WS_URL)
+    }
+
+    fn default_model(&self) -> Option<&'static str> {
+        Some("gpt-4o-transcribe")
+    }
+
     /// For file input, fall back to regular OpenAI API
     ///
     /// The Realtime API is designed for streaming mic input.
@@ -46,6 +46,10 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite;
+
+use super::ProviderError;
+use crate::config::TranscriptionProvider;
 
 /// Trait for realtime (WebSocket-based) transcription providers.
 ///
@@ -99,3 +103,32 @@ pub trait RealtimeTranscriptionBackend: Send + Sync {
         false
     }
 }
+
+/// Auth error when the WebSocket handshake was refused with 401/403
+///
+/// A rejected key won't start working on retry, so connect loops stop on it.
+pub(crate) fn rejected_handshake(
+    provider: &TranscriptionProvider,
+    err: &tungstenite::Error,
+) -> Option<ProviderError> {
+    let tungstenite::Error::Http(response) = err else {
+        return None;
+    };
+    let status = reqwest::StatusCode::from_u16(response.status().as_u16()).ok()?;
+    if !matches!(
+        status,
+        reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN
+    ) {
+        return None;
+    }
+    let body = response
+        .body()
+        .as_deref()
+        .map(String::from_utf8_lossy)
+        .unwrap_or_default();
+    Some(ProviderError::from_status(
+        provider.display_name(),
+        status,
+        body,
+    ))
+}
@@ -0,0 +1,137 @@
+//! Effective settings with the source of each value.
+//!
+//! A setting's resolved value can come from several layers:
+//!
+//! ```text
+//! flag     → per-invocation CLI override (applied by the frontend)
+//! env      → environment fallback used by the getters (OLLAMA_URL, *_API_KEY, ...)
+//! file     → settings.json
+//! default  → built-in default (field missing from the file, or no file)
+//! ```
+//!
+//! Env fallbacks only apply where the getters use them: when the field is
+//! unset in the file (e.g. `services.ollama.url` is null, or there's no
+//! `transcription.api_keys.openai` entry).
+
+use anyhow::Result;
+use serde_json::Value;
+use std::fmt;
+use std::fs;
+
+use super::Settings;
+use super::field_path::lookup_path;
+use crate::configuration::TranscriptionProvider;
+
+/// Where an effective setting value comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SettingSource {
+    /// Built-in default
+    Default,
+    /// The settings file
+    File,
+    /// Environment variable fallback
+    Env(&'static str),
+    /// Command-line flag for this invocation
+    Flag(&'static str),
+}
+
+impl fmt::Display for SettingSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SettingSource::Default => write!(f, "default"),
+            SettingSource::File => write!(f, "file"),
+            SettingSource::Env(var) => write!(f, "env ${var}"),
+            SettingSource::Flag(flag) => write!(f, "flag {flag}"),
+        }
+    }
+}
+
+/// A resolved setting and where its value comes from
+#[derive(Debug, Clone)]
+pub struct EffectiveSetting {
+    /// Field path (as used by `Settings::get_path`)
+    pub path: String,
+    pub value: Value,
+    pub source: SettingSource,
+}
+
+impl Settings {
+    /// Resolve every setting to its effective value and source, sorted by path
+    ///
+    /// `self` should be the settings loaded from disk: values are marked
+    /// `file` when the settings file sets them explicitly.
+    pub fn effective(&self) -> Result<Vec<EffectiveSetting>> {
+        // Settings::load() falls back to defaults on a parse error, so an
+        // unparsable file contributes nothing
+        let file = fs::read_to_string(Self::path())
+            .ok()
+            .and_then(|content| serde_json::from_str::<Value>(&content).ok())
+            .filter(|value| serde_json::from_value::<Settings>(value.clone()).is_ok());
+
+        let mut entries: Vec<EffectiveSetting> = self
+            .flatten_paths()?
+            .into_iter()
+            .map(|(path, value)| {
+                let in_file = file
+                    .as_ref()
+                    .is_some_and(|file| lookup_path(file, &path).is_some());
+                EffectiveSetting {
+                    source: if in_file {
+                        SettingSource::File
+                    } else {
+                        SettingSource::Default
+                    },
+                    path,
+                    value,
+                }
+            })
+            .collect();
+
+        // Optional fields the getters fill from the environment
+        let env_fallbacks = [
+            ("ui.cache_dir", "WHIS_CACHE_DIR"),
+            ("services.ollama.url", "OLLAMA_URL"),
+            ("services.ollama.model", "OLLAMA_MODEL"),
+            (
+                "transcription.local_models.whisper_path",
+                "LOCAL_WHISPER_MODEL_PATH",
+            ),
+            (
+                "transcription.local_models.parakeet_path",
+                "LOCAL_PARAKEET_MODEL_PATH",
+            ),
+        ];
+        for (path, var) in env_fallbacks {
+            if let Some(entry) = entries.iter_mut().find(|e| e.path == path)
+                && entry.value.is_null()
+                && let Ok(value) = std::env::var(var)
+            {
+                entry.value = Value::String(value);
+                entry.source = SettingSource::Env(var);
+            }
+        }
+
+        // API keys without an entry in the file come from the environment
+        let key_providers = TranscriptionProvider::cloud_providers()
+            .chain(std::iter::once(&TranscriptionProvider::Mock));
+        for provider in key_providers {
+            let var = provider.api_key_env_var();
+            if self.transcription.has_configured_api_key(provider) {
+                continue;
+            }
+            if let Ok(key) = std::env::var(var) {
+                let path = format!("transcription.api_keys.{}", provider.api_key_name());
+                // Replaces an empty entry, or the empty map itself
+                entries.retain(|e| e.path != path && e.path != "transcription.api_keys");
+                entries.push(EffectiveSetting {
+                    path,
+                    value: Value::String(key),
+                    source: SettingSource::Env(var),
+                });
+            }
+        }
+
+        entries.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(entries)
+    }
+}
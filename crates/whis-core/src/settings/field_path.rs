@@ -0,0 +1,478 @@
+//! Generic access to settings by field path.
+//!
+//! Lets frontends read and write any setting without a dedicated accessor,
+//! using the dotted JSON path of the field in `settings.json`:
+//!
+//! ```text
+//! transcription.provider                  → "openai"
+//! transcription.timeouts.timeout_secs     → 300
+//! transcription.api_keys.openai           → "sk-..."
+//! ui.vad.enabled                          → false
+//! ```
+//!
+//! Values are given as strings and converted to the type of the current
+//! value (bool, integer, float, string; JSON for lists, objects and unset
+//! options). The result is then deserialized into [`Settings`], so enum
+//! variants and field types are validated by the same rules as loading
+//! the file. Values the named `whis config` flags check further (ranges,
+//! API key formats, languages) get the same checks here, before anything
+//! is changed. Hyphens in path segments are accepted for underscores
+//! (`ui.microphone-device` = `ui.microphone_device`).
+
+use anyhow::{Context, Result, anyhow, bail};
+use serde_json::{Map, Value};
+
+use super::Settings;
+use crate::configuration::{
+    MAX_CHUNK_DURATION_SECS, MIN_CHUNK_DURATION_SECS, TranscriptionProvider, parse_language,
+};
+
+impl Settings {
+    /// Get the value at a field path (objects are returned whole)
+    pub fn get_path(&self, path: &str) -> Result<Value> {
+        let root = serde_json::to_value(self)?;
+        let segments = resolve(&root, path)?;
+        lookup(&root, &segments)
+            .cloned()
+            .ok_or_else(|| anyhow!("Unknown setting '{path}'"))
+    }
+
+    /// Set the value at a field path, parsing `value` to the field's type
+    ///
+    /// New entries can only be added to maps (e.g. `transcription.api_keys.groq`);
+    /// unknown struct fields are rejected.
+    pub fn set_path(&mut self, path: &str, value: &str) -> Result<()> {
+        let mut root = serde_json::to_value(&*self)?;
+        let segments = resolve(&root, path)?;
+        let (parent, field) = parent_mut(&mut root, &segments, path)?;
+
+        let parsed = parse_like(parent.get(field), value)
+            .with_context(|| format!("Invalid value for '{path}'"))?;
+        parent.insert(field.to_string(), parsed);
+
+        let updated: Settings =
+            serde_json::from_value(root).map_err(|e| anyhow!("Invalid value for '{path}': {e}"))?;
+
+        // Serde ignores unknown struct fields: if the value didn't survive
+        // the round trip, the path doesn't name a setting
+        if updated.get_path(path).is_err() {
+            bail!("Unknown setting '{path}'");
+        }
+        validate_value(&updated, &segments)
+            .with_context(|| format!("Invalid value for '{path}'"))?;
+
+        *self = updated;
+        Ok(())
+    }
+
+    /// Reset the value at a field path to its default (map entries are removed)
+    pub fn unset_path(&mut self, path: &str) -> Result<()> {
+        let mut root = serde_json::to_value(&*self)?;
+        let segments = resolve(&root, path)?;
+        if lookup(&root, &segments).is_none() {
+            bail!("Unknown setting '{path}'");
+        }
+
+        let defaults = serde_json::to_value(Settings::default())?;
+        let (parent, field) = parent_mut(&mut root, &segments, path)?;
+        match lookup(&defaults, &segments) {
+            Some(default) => {
+                parent.insert(field.to_string(), default.clone());
+            }
+            None => {
+                parent.remove(field);
+            }
+        }
+
+        *self =
+            serde_json::from_value(root).map_err(|e| anyhow!("Failed to reset '{path}': {e}"))?;
+        Ok(())
+    }
+
+    /// All leaf settings as `(path, value)`, sorted by path
+    ///
+    /// Lists and empty objects are reported as a single value.
+    pub fn flatten_paths(&self) -> Result<Vec<(String, Value)>> {
+        let root = serde_json::to_value(self)?;
+        let mut entries = Vec::new();
+        flatten_into(&root, String::new(), &mut entries);
+        Ok(entries)
+    }
+}
+
+/// Checks beyond the field's type, matching the named `whis config` flags
+fn validate_value(settings: &Settings, segments: &[String]) -> Result<()> {
+    fn unit_range(value: f32) -> Result<()> {
+        if !(0.0..=1.0).contains(&value) {
+            bail!("must be between 0.0 and 1.0");
+        }
+        Ok(())
+    }
+    fn not_empty(value: &Option<String>) -> Result<()> {
+        if value.as_ref().is_some_and(|v| v.trim().is_empty()) {
+            bail!("cannot be empty");
+        }
+        Ok(())
+    }
+    fn timeout(secs: u64) -> Result<()> {
+        if secs == 0 {
+            bail!("must be at least 1 second");
+        }
+        Ok(())
+    }
+
+    let transcription = &settings.transcription;
+    let ui = &settings.ui;
+    let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+    match segments.as_slice() {
+        ["transcription", "language"] => {
+            if let Some(language) = &transcription.language {
+                parse_language(language)?;
+            }
+        }
+        ["transcription", "api_keys", name] => {
+            let provider: TranscriptionProvider = name.parse().map_err(|e| anyhow!("{e}"))?;
+            provider.validate_key_format(&transcription.api_keys[*name])?;
+        }
+        ["transcription", "confidence_warn"] => {
+            if let Some(threshold) = transcription.confidence_warn {
+                unit_range(threshold)?;
+            }
+        }
+        ["transcription", "timeouts", "connect_timeout_secs"] => {
+            timeout(transcription.timeouts.connect_timeout_secs)?
+        }
+        ["transcription", "timeouts", "upload_timeout_secs"] => {
+            timeout(transcription.timeouts.upload_timeout_secs)?
+        }
+        ["transcription", "timeouts", "timeout_secs"] => {
+            timeout(transcription.timeouts.timeout_secs)?
+        }
+        ["transcription", "mp3_bitrate"] => {
+            let kbps = transcription.mp3_bitrate;
+            if crate::audio::clamp_mp3_bitrate(kbps) != kbps {
+                bail!("{kbps}kbps is not a supported MP3 bitrate");
+            }
+        }
+        ["transcription", "local_models", "whisper_path"] => {
+            not_empty(&transcription.local_models.whisper_path)?
+        }
+        ["transcription", "local_models", "parakeet_path"] => {
+            not_empty(&transcription.local_models.parakeet_path)?
+        }
+        ["services", "ollama", "url"] => not_empty(&settings.services.ollama.url)?,
+        ["services", "ollama", "model"] => not_empty(&settings.services.ollama.model)?,
+        ["ui", "vad", "threshold"] => unit_range(ui.vad.threshold)?,
+        ["ui", "cues", "volume"] => unit_range(ui.cues.volume)?,
+        ["ui", "chunk_duration_secs"] => {
+            if !(MIN_CHUNK_DURATION_SECS..=MAX_CHUNK_DURATION_SECS)
+                .contains(&ui.chunk_duration_secs)
+            {
+                bail!(
+                    "must be between {} and {} seconds",
+                    MIN_CHUNK_DURATION_SECS,
+                    MAX_CHUNK_DURATION_SECS
+                );
+            }
+        }
+        ["ui", "min_recording_ms"] => {
+            if ui.min_recording_ms > 10_000 {
+                bail!("must be at most 10000ms");
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Split a path into segments, matching hyphenated segments to existing
+/// underscored fields
+fn resolve(root: &Value, path: &str) -> Result<Vec<String>> {
+    if path.trim().is_empty() {
+        bail!("Empty setting path");
+    }
+
+    let mut segments = Vec::new();
+    let mut current = Some(root);
+    for raw in path.trim().split('.') {
+        if raw.is_empty() {
+            bail!("Invalid setting path '{path}'");
+        }
+        let object = current.and_then(Value::as_object);
+        let segment = match object {
+            Some(map) if !map.contains_key(raw) && map.contains_key(&raw.replace('-', "_")) => {
+                raw.replace('-', "_")
+            }
+            _ => raw.to_string(),
+        };
+        current = object.and_then(|map| map.get(&segment));
+        segments.push(segment);
+    }
+    Ok(segments)
+}
+
+/// Value at an exact dotted path in any JSON object (no hyphen matching)
+pub(super) fn lookup_path<'a>(root: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(root, |value, segment| value.as_object()?.get(segment))
+}
+
+fn lookup<'a>(root: &'a Value, segments: &[String]) -> Option<&'a Value> {
+    segments
+        .iter()
+        .try_fold(root, |value, segment| value.as_object()?.get(segment))
+}
+
+/// The object holding the last segment, and that segment's name
+fn parent_mut<'a>(
+    root: &'a mut Value,
+    segments: &'a [String],
+    path: &str,
+) -> Result<(&'a mut Map<String, Value>, &'a str)> {
+    let (field, parents) = segments
+        .split_last()
+        .ok_or_else(|| anyhow!("Empty setting path"))?;
+    let mut current = root;
+    for segment in parents {
+        current = current
+            .as_object_mut()
+            .and_then(|map| map.get_mut(segment))
+            .ok_or_else(|| anyhow!("Unknown setting '{path}'"))?;
+    }
+    let parent = current
+        .as_object_mut()
+        .ok_or_else(|| anyhow!("Unknown setting '{path}'"))?;
+    Ok((parent, field))
+}
+
+/// Parse a string to the JSON type of the current value
+fn parse_like(current: Option<&Value>, value: &str) -> Result<Value> {
+    let trimmed = value.trim();
+    match current {
+        Some(Value::Bool(_)) => match trimmed.to_lowercase().as_str() {
+            "true" | "on" | "yes" | "1" => Ok(Value::Bool(true)),
+            "false" | "off" | "no" | "0" => Ok(Value::Bool(false)),
+            _ => bail!("expected true or false, got '{trimmed}'"),
+        },
+        Some(Value::Number(n)) if n.is_f64() => trimmed
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(Value::Number)
+            .ok_or_else(|| anyhow!("expected a number, got '{trimmed}'")),
+        Some(Value::Number(_)) => trimmed
+            .parse::<i64>()
+            .map(Value::from)
+            .map_err(|_| anyhow!("expected a whole number, got '{trimmed}'")),
+        Some(Value::String(_)) => Ok(Value::String(value.to_string())),
+        // Unset options, lists, objects and new map entries: JSON if it
+        // parses, otherwise a plain string
+        _ => Ok(serde_json::from_str(trimmed).unwrap_or_else(|_| Value::String(value.to_string()))),
+    }
+}
+
+fn flatten_into(value: &Value, prefix: String, entries: &mut Vec<(String, Value)>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, child) in map {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                flatten_into(child, path, entries);
+            }
+        }
+        _ => entries.push((prefix, value.clone())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TranscriptionProvider;
+
+    #[test]
+    fn test_get_nested_paths() {
+        let settings = Settings::default();
+        assert_eq!(
+            settings
+                .get_path("transcription.timeouts.timeout_secs")
+                .unwrap(),
+            Value::from(settings.transcription.timeouts.timeout_secs)
+        );
+        assert!(
+            settings
+                .get_path("transcription.timeouts")
+                .unwrap()
+                .is_object()
+        );
+        // Hyphens stand in for underscores
+        assert_eq!(
+            settings.get_path("ui.microphone-device").unwrap(),
+            Value::Null
+        );
+    }
+
+    #[test]
+    fn test_unknown_paths_are_rejected() {
+        let mut settings = Settings::default();
+        assert!(settings.get_path("transcription.nope").is_err());
+        assert!(settings.get_path("").is_err());
+        assert!(settings.get_path("ui..vad").is_err());
+        assert!(settings.set_path("ui.vad.nope", "true").is_err());
+        assert!(settings.set_path("nope.deeper", "1").is_err());
+        assert!(settings.unset_path("ui.nope").is_err());
+    }
+
+    #[test]
+    fn test_set_coerces_to_field_type() {
+        let mut settings = Settings::default();
+
+        settings.set_path("ui.vad.enabled", "on").unwrap();
+        assert!(settings.ui.vad.enabled);
+        settings.set_path("ui.vad.enabled", "false").unwrap();
+        assert!(!settings.ui.vad.enabled);
+
+        settings.set_path("ui.chunk-duration-secs", " 45 ").unwrap();
+        assert_eq!(settings.ui.chunk_duration_secs, 45);
+
+        settings.set_path("ui.vad.threshold", "0.25").unwrap();
+        assert_eq!(settings.ui.vad.threshold, 0.25);
+
+        settings.set_path("transcription.provider", "groq").unwrap();
+        assert_eq!(settings.transcription.provider, TranscriptionProvider::Groq);
+
+        // Unset option: parsed as JSON
+        settings
+            .set_path("transcription.confidence_warn", "0.5")
+            .unwrap();
+        assert_eq!(settings.transcription.confidence_warn, Some(0.5));
+
+        // New map entry: plain string
+        settings
+            .set_path("transcription.api_keys.groq", "gsk_test_key_1234567890")
+            .unwrap();
+        assert_eq!(
+            settings.transcription.api_keys["groq"],
+            "gsk_test_key_1234567890"
+        );
+    }
+
+    #[test]
+    fn test_set_rejects_type_mismatches() {
+        let mut settings = Settings::default();
+        assert!(settings.set_path("ui.vad.enabled", "maybe").is_err());
+        assert!(settings.set_path("ui.chunk_duration_secs", "4.5").is_err());
+        assert!(settings.set_path("ui.vad.threshold", "high").is_err());
+        assert!(settings.set_path("transcription.provider", "nope").is_err());
+        assert!(settings.set_path("transcription.timeouts", "5").is_err());
+        // A rejected value leaves the settings unchanged
+        assert_eq!(
+            settings.transcription.provider,
+            Settings::default().transcription.provider
+        );
+    }
+
+    #[test]
+    fn test_set_rejects_out_of_range_values() {
+        let mut settings = Settings::default();
+        assert!(settings.set_path("ui.vad.threshold", "1.5").is_err());
+        assert!(settings.set_path("ui.cues.volume", "-0.1").is_err());
+        assert!(
+            settings
+                .set_path("transcription.confidence_warn", "2")
+                .is_err()
+        );
+        assert!(settings.set_path("ui.chunk_duration_secs", "1").is_err());
+        assert!(settings.set_path("ui.min_recording_ms", "20000").is_err());
+        assert!(
+            settings
+                .set_path("transcription.timeouts.timeout_secs", "0")
+                .is_err()
+        );
+        assert!(
+            settings
+                .set_path("transcription.mp3_bitrate", "65")
+                .is_err()
+        );
+        assert!(settings.set_path("services.ollama.url", " ").is_err());
+        assert!(
+            settings
+                .set_path("transcription.language", "klingon")
+                .is_err()
+        );
+
+        // Nothing was changed
+        assert_eq!(
+            serde_json::to_value(&settings).unwrap(),
+            serde_json::to_value(Settings::default()).unwrap()
+        );
+
+        settings.set_path("ui.vad.threshold", "1.0").unwrap();
+        settings.set_path("transcription.language", "de").unwrap();
+    }
+
+    #[test]
+    fn test_set_checks_api_key_format() {
+        let mut settings = Settings::default();
+        assert!(
+            settings
+                .set_path("transcription.api_keys.openai", "not-a-key")
+                .is_err()
+        );
+        assert!(
+            settings
+                .set_path("transcription.api_keys.nope", "sk-test-key-1234567890")
+                .is_err()
+        );
+        assert!(!settings.transcription.api_keys.contains_key("openai"));
+
+        settings
+            .set_path("transcription.api_keys.openai", "sk-test-key-1234567890")
+            .unwrap();
+    }
+
+    #[test]
+    fn test_unset_restores_defaults_and_removes_map_entries() {
+        let mut settings = Settings::default();
+        settings.set_path("ui.chunk_duration_secs", "45").unwrap();
+        settings
+            .set_path("transcription.api_keys.groq", "gsk_test_key_1234567890")
+            .unwrap();
+
+        settings.unset_path("ui.chunk_duration_secs").unwrap();
+        assert_eq!(
+            settings.ui.chunk_duration_secs,
+            Settings::default().ui.chunk_duration_secs
+        );
+        settings.unset_path("transcription.api_keys.groq").unwrap();
+        assert!(!settings.transcription.api_keys.contains_key("groq"));
+    }
+
+    #[test]
+    fn test_flatten_paths_lists_leaves() {
+        let mut settings = Settings::default();
+        settings
+            .set_path("transcription.api_keys.groq", "gsk_test_key_1234567890")
+            .unwrap();
+        let entries = settings.flatten_paths().unwrap();
+
+        let value = |path: &str| {
+            entries
+                .iter()
+                .find(|(p, _)| p == path)
+                .map(|(_, v)| v.clone())
+        };
+        assert_eq!(
+            value("transcription.api_keys.groq"),
+            Some(Value::from("gsk_test_key_1234567890"))
+        );
+        assert_eq!(
+            value("ui.vad.enabled"),
+            Some(Value::Bool(settings.ui.vad.enabled))
+        );
+        // Objects are broken up into their fields
+        assert_eq!(value("ui.vad"), None);
+    }
+}
@@ -0,0 +1,237 @@
+//! API keys in the OS keyring
+//!
+//! With `transcription.use_keyring` on (the default), [`Settings::save`]
+//! stores API keys in the OS keyring (Secret Service on Linux, Keychain on
+//! macOS, Credential Manager on Windows) and writes only [`KEYRING_REFERENCE`]
+//! in their place. [`Settings::load`] swaps the references back for the keys,
+//! so the rest of the app only ever sees real keys in `api_keys`.
+//!
+//! When no keyring is reachable (headless servers, builds without the
+//! `keyring` feature) keys stay in the settings file as before.
+//!
+//! [`Settings::save`]: super::Settings::save
+//! [`Settings::load`]: super::Settings::load
+
+use std::collections::HashMap;
+
+/// Placeholder written to settings.json for a key held by the keyring
+///
+/// Also left in `api_keys` in memory when the keyring was locked or
+/// unreachable at load, so the next save keeps the reference instead of
+/// losing the key. Such a key isn't usable (see [`is_usable_key`]).
+pub const KEYRING_REFERENCE: &str = "keyring";
+
+/// Whether `key` is a real API key (not empty or an unresolved reference)
+pub fn is_usable_key(key: &str) -> bool {
+    !key.is_empty() && key != KEYRING_REFERENCE
+}
+
+/// Result of looking a key up in the keyring
+#[derive(Debug, PartialEq, Eq)]
+pub(super) enum KeyLookup {
+    Found(String),
+    /// The keyring answered but has no entry for the key
+    Missing,
+    /// Locked, unreachable or compiled out: the entry may still exist
+    Unavailable,
+}
+
+#[cfg(all(feature = "keyring", not(test)))]
+mod os {
+    use super::KeyLookup;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// Keyring service name; the provider's key name is the user
+    const SERVICE: &str = "whis";
+
+    // Set after the first failure so a missing keyring costs one attempt per run
+    static UNAVAILABLE: AtomicBool = AtomicBool::new(false);
+
+    fn entry(name: &str) -> Option<keyring::Entry> {
+        if UNAVAILABLE.load(Ordering::Relaxed) {
+            return None;
+        }
+        keyring::Entry::new(SERVICE, name)
+            .inspect_err(unavailable)
+            .ok()
+    }
+
+    fn unavailable(err: &keyring::Error) {
+        if !UNAVAILABLE.swap(true, Ordering::Relaxed) {
+            crate::verbose!("OS keyring unavailable, keeping API keys in settings.json: {err}");
+        }
+    }
+
+    pub fn available() -> bool {
+        !UNAVAILABLE.load(Ordering::Relaxed)
+    }
+
+    pub fn load_key(name: &str) -> KeyLookup {
+        let Some(entry) = entry(name) else {
+            return KeyLookup::Unavailable;
+        };
+        match entry.get_password() {
+            Ok(key) => KeyLookup::Found(key),
+            Err(keyring::Error::NoEntry) => KeyLookup::Missing,
+            Err(e) => {
+                unavailable(&e);
+                KeyLookup::Unavailable
+            }
+        }
+    }
+
+    pub fn store_key(name: &str, key: &str) -> bool {
+        let Some(entry) = entry(name) else {
+            return false;
+        };
+        entry.set_password(key).inspect_err(unavailable).is_ok()
+    }
+
+    pub fn delete_key(name: &str) -> bool {
+        entry(name).is_some_and(|entry| {
+            matches!(
+                entry.delete_credential(),
+                Ok(()) | Err(keyring::Error::NoEntry)
+            )
+        })
+    }
+}
+
+#[cfg(all(not(feature = "keyring"), not(test)))]
+mod os {
+    use super::KeyLookup;
+
+    pub fn available() -> bool {
+        false
+    }
+
+    pub fn load_key(_name: &str) -> KeyLookup {
+        KeyLookup::Unavailable
+    }
+
+    pub fn store_key(_name: &str, _key: &str) -> bool {
+        false
+    }
+
+    pub fn delete_key(_name: &str) -> bool {
+        false
+    }
+}
+
+/// In-memory keyring for tests, one per test thread
+#[cfg(test)]
+mod os {
+    use super::KeyLookup;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    #[derive(Default)]
+    pub struct FakeKeyring {
+        pub locked: bool,
+        pub keys: HashMap<String, String>,
+        /// Number of store and delete calls that reached the keyring
+        pub writes: usize,
+    }
+
+    thread_local! {
+        pub static FAKE: RefCell<FakeKeyring> = RefCell::default();
+    }
+
+    pub fn available() -> bool {
+        FAKE.with_borrow(|fake| !fake.locked)
+    }
+
+    pub fn load_key(name: &str) -> KeyLookup {
+        FAKE.with_borrow(|fake| match fake.keys.get(name) {
+            _ if fake.locked => KeyLookup::Unavailable,
+            Some(key) => KeyLookup::Found(key.clone()),
+            None => KeyLookup::Missing,
+        })
+    }
+
+    pub fn store_key(name: &str, key: &str) -> bool {
+        FAKE.with_borrow_mut(|fake| {
+            if fake.locked {
+                return false;
+            }
+            fake.writes += 1;
+            fake.keys.insert(name.to_string(), key.to_string());
+            true
+        })
+    }
+
+    pub fn delete_key(name: &str) -> bool {
+        FAKE.with_borrow_mut(|fake| {
+            if fake.locked {
+                return false;
+            }
+            fake.writes += 1;
+            fake.keys.remove(name);
+            true
+        })
+    }
+}
+
+pub(super) use os::available;
+
+/// Keys this process has read from or written to the keyring, as the
+/// keyring holds them, so saves only touch keys that changed
+fn with_known<R>(f: impl FnOnce(&mut HashMap<String, String>) -> R) -> R {
+    #[cfg(not(test))]
+    {
+        static KNOWN: std::sync::Mutex<Option<HashMap<String, String>>> =
+            std::sync::Mutex::new(None);
+        let mut known = KNOWN.lock().unwrap_or_else(|e| e.into_inner());
+        f(known.get_or_insert_with(HashMap::new))
+    }
+    #[cfg(test)]
+    {
+        thread_local! {
+            static KNOWN: std::cell::RefCell<HashMap<String, String>> = Default::default();
+        }
+        KNOWN.with_borrow_mut(f)
+    }
+}
+
+/// Look up the key stored under `name`
+pub(super) fn load_key(name: &str) -> KeyLookup {
+    let lookup = os::load_key(name);
+    if let KeyLookup::Found(key) = &lookup {
+        with_known(|known| known.insert(name.to_string(), key.clone()));
+    }
+    lookup
+}
+
+/// Make the keyring hold `key` under `name`
+///
+/// Skips the write when the keyring already holds that key. Returns whether
+/// the keyring holds it (so the file can keep a reference instead).
+pub(super) fn store_key(name: &str, key: &str) -> bool {
+    if with_known(|known| known.get(name).is_some_and(|known| known == key)) {
+        return true;
+    }
+    let stored = os::store_key(name, key);
+    if stored {
+        with_known(|known| known.insert(name.to_string(), key.to_string()));
+    }
+    stored
+}
+
+/// Delete the key stored under `name` after the user cleared it
+///
+/// Only keys this process saw in the keyring are deleted: an empty key
+/// that was never resolved says nothing about the keyring entry.
+pub(super) fn delete_key(name: &str) {
+    if with_known(|known| known.contains_key(name)) && os::delete_key(name) {
+        with_known(|known| known.remove(name));
+    }
+}
+
+#[cfg(test)]
+pub(super) use os::FAKE;
+
+/// Forget which keys this test thread has seen in the keyring
+#[cfg(test)]
+pub(super) fn forget_known() {
+    with_known(HashMap::clear);
+}
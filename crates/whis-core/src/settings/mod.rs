@@ -34,9 +34,13 @@
 //!
 //! # File Location
 //!
-//! Settings are stored at `~/.config/whis/settings.json` with 0600 permissions
-//! to protect API keys.
+//! Settings are stored at `~/.config/whis/settings.json` with 0600 permissions.
+//! API keys go to the OS keyring where available, leaving only a reference in
+//! the file (see `transcription.use_keyring`).
 
+mod effective;
+mod field_path;
+mod keyring;
 mod post_processing;
 mod services;
 mod shortcuts;
@@ -44,17 +48,24 @@ mod store_adapter;
 mod transcription;
 mod ui;
 
+pub use effective::{EffectiveSetting, SettingSource};
+pub use keyring::KEYRING_REFERENCE;
 pub use post_processing::PostProcessingSettings;
 pub use services::{OllamaConfig, ServicesSettings};
-pub use shortcuts::{CliShortcutMode, ShortcutsSettings};
-pub use transcription::{LocalModelsConfig, TranscriptionSettings};
-pub use ui::{BubbleSettings, ModelMemorySettings, UiSettings, VadSettings};
+pub use shortcuts::{CliHotkeyBackend, CliShortcutMode, ShortcutsSettings};
+pub use transcription::{LocalModelsConfig, TimeoutSettings, TranscriptionSettings, mask_api_key};
+pub use ui::{
+    BubbleSettings, CueSettings, DefaultOutput, ModelMemorySettings, UiSettings, VadSettings,
+};
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
 
+use crate::configuration::Preset;
+use crate::transcription::PostProcessor;
+
 /// Application settings (aggregate root).
 ///
 /// Settings are organized hierarchically by concern:
@@ -89,8 +100,13 @@ impl Settings {
     pub fn load() -> Self {
         let path = Self::path();
         if let Ok(content) = fs::read_to_string(&path) {
-            match serde_json::from_str(&content) {
-                Ok(settings) => return settings,
+            match Self::parse(&content) {
+                Ok((settings, migrate_keys)) => {
+                    if migrate_keys && let Err(e) = settings.save() {
+                        eprintln!("Warning: Failed to move API keys to the keyring: {e}");
+                    }
+                    return settings;
+                }
                 Err(e) => {
                     eprintln!("Warning: Failed to parse {}: {}", path.display(), e);
                     eprintln!("Schema may have changed. Creating backup...");
@@ -117,8 +133,24 @@ impl Settings {
         Self::default()
     }
 
+    /// Parse settings.json and resolve its keyring references
+    ///
+    /// Also returns whether the file still holds plaintext keys that a save
+    /// would move into the keyring. This is decided on the file as written,
+    /// before references are resolved, so an already migrated file is never
+    /// rewritten just by loading it.
+    fn parse(content: &str) -> serde_json::Result<(Self, bool)> {
+        let mut settings = serde_json::from_str::<Self>(content)?;
+        let migrate_keys = settings.transcription.has_plaintext_keys();
+        settings.transcription.resolve_keyring_keys();
+        Ok((settings, migrate_keys))
+    }
+
     /// Save settings to disk with 0600 permissions.
     ///
+    /// API keys are stored in the OS keyring when `transcription.use_keyring`
+    /// is on and a keyring is available.
+    ///
     /// On Unix, creates the file with mode 0600 from the start to avoid
     /// a race condition where the file might briefly be world-readable.
     pub fn save(&self) -> Result<()> {
@@ -129,7 +161,10 @@ impl Settings {
             fs::create_dir_all(parent)?;
         }
 
-        let content = serde_json::to_string_pretty(self)?;
+        // API keys go to the keyring when possible; the file keeps references
+        let mut on_disk = self.clone();
+        on_disk.transcription.api_keys = self.transcription.api_keys_for_disk();
+        let content = serde_json::to_string_pretty(&on_disk)?;
 
         #[cfg(unix)]
         {
@@ -159,9 +194,227 @@ impl Settings {
     /// - Shortcuts don't conflict (CLI direct mode with same key as desktop)
     pub fn validate(&self) -> Result<()> {
         self.transcription.validate()?;
-        self.post_processing
-            .validate(&self.transcription.api_keys)?;
+        if self.post_processing.enabled
+            && let Some(missing) =
+                self.post_processor_missing(&self.post_processing.processor, None)
+        {
+            anyhow::bail!(missing);
+        }
         self.shortcuts.validate()?;
         Ok(())
     }
+
+    /// What `processor` still needs before it can run, if anything
+    ///
+    /// Doesn't depend on the transcription provider: a cloud LLM needs its
+    /// own provider's API key (settings or environment), Ollama a server URL
+    /// and model, which `preset` may supply instead of the settings. Whether
+    /// Ollama is running is only checked, and fixed, when post-processing
+    /// starts.
+    pub fn post_processor_missing(
+        &self,
+        processor: &PostProcessor,
+        preset: Option<&Preset>,
+    ) -> Option<String> {
+        match processor {
+            PostProcessor::None => None,
+            PostProcessor::OpenAI | PostProcessor::Mistral => {
+                let provider = processor.api_key_provider()?;
+                if self.transcription.api_key_for(&provider).is_some() {
+                    return None;
+                }
+                Some(format!(
+                    "{} API key not configured. Set it with: whis config {}-api-key <key> (or {})",
+                    provider.display_name(),
+                    provider.api_key_name(),
+                    provider.api_key_env_var()
+                ))
+            }
+            PostProcessor::Ollama => {
+                if self.services.ollama.url().is_none() {
+                    Some(
+                        "Ollama URL not configured. Set it with: whis config ollama-url <url>"
+                            .into(),
+                    )
+                } else if preset.and_then(|p| p.model.as_ref()).is_none()
+                    && self.services.ollama.model().is_none()
+                {
+                    Some(
+                        "Ollama model not configured. Set it with: whis config ollama-model <name>"
+                            .into(),
+                    )
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::keyring::{FAKE, forget_known};
+
+    fn preset_with_model(model: Option<&str>) -> Preset {
+        Preset {
+            name: "test".into(),
+            description: String::new(),
+            prompt: "Fix it".into(),
+            post_processor: Some("ollama".into()),
+            model: model.map(String::from),
+            language: None,
+        }
+    }
+
+    fn file_with_keys(keys: &[(&str, &str)]) -> String {
+        let mut settings = Settings::default();
+        for (name, key) in keys {
+            settings
+                .transcription
+                .api_keys
+                .insert(name.to_string(), key.to_string());
+        }
+        serde_json::to_string(&settings).unwrap()
+    }
+
+    fn reset_keyring(locked: bool, keys: &[(&str, &str)]) {
+        forget_known();
+        FAKE.with_borrow_mut(|fake| {
+            fake.locked = locked;
+            fake.writes = 0;
+            fake.keys = keys
+                .iter()
+                .map(|(name, key)| (name.to_string(), key.to_string()))
+                .collect();
+        });
+    }
+
+    #[test]
+    fn test_migrated_file_is_not_rewritten_on_load() {
+        reset_keyring(false, &[("openai", "sk-real")]);
+        let (settings, migrate_keys) =
+            Settings::parse(&file_with_keys(&[("openai", KEYRING_REFERENCE)])).unwrap();
+
+        assert!(!migrate_keys);
+        assert_eq!(settings.transcription.api_keys["openai"], "sk-real");
+    }
+
+    #[test]
+    fn test_plaintext_keys_are_migrated_on_load() {
+        reset_keyring(false, &[]);
+        let (settings, migrate_keys) =
+            Settings::parse(&file_with_keys(&[("openai", "sk-plain"), ("groq", "")])).unwrap();
+
+        assert!(migrate_keys);
+        assert_eq!(settings.transcription.api_keys["openai"], "sk-plain");
+    }
+
+    #[test]
+    fn test_plaintext_keys_stay_when_keyring_is_off() {
+        reset_keyring(true, &[]);
+        let (_, migrate_keys) =
+            Settings::parse(&file_with_keys(&[("openai", "sk-plain")])).unwrap();
+        assert!(!migrate_keys);
+
+        reset_keyring(false, &[]);
+        let mut settings = Settings::default();
+        settings.transcription.use_keyring = false;
+        settings
+            .transcription
+            .api_keys
+            .insert("openai".to_string(), "sk-plain".to_string());
+        let content = serde_json::to_string(&settings).unwrap();
+        let (_, migrate_keys) = Settings::parse(&content).unwrap();
+        assert!(!migrate_keys);
+    }
+
+    #[test]
+    fn test_post_processor_none_needs_nothing() {
+        let settings = Settings::default();
+        assert_eq!(
+            settings.post_processor_missing(&PostProcessor::None, None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cloud_post_processor_needs_its_own_key() {
+        let mut settings = Settings::default();
+        // The transcription provider's key doesn't count
+        settings
+            .transcription
+            .api_keys
+            .insert("groq".into(), "gsk_test_key_1234567890".into());
+        if std::env::var("MISTRAL_API_KEY").is_err() {
+            let missing = settings
+                .post_processor_missing(&PostProcessor::Mistral, None)
+                .unwrap();
+            assert!(missing.contains("Mistral API key"));
+        }
+
+        settings
+            .transcription
+            .api_keys
+            .insert("mistral".into(), "mistral-test-key-123456".into());
+        assert_eq!(
+            settings.post_processor_missing(&PostProcessor::Mistral, None),
+            None
+        );
+
+        settings
+            .transcription
+            .api_keys
+            .insert("openai".into(), "sk-test-key-1234567890".into());
+        assert_eq!(
+            settings.post_processor_missing(&PostProcessor::OpenAI, None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_ollama_needs_url_and_model() {
+        let mut settings = Settings::default();
+        assert_eq!(
+            settings.post_processor_missing(&PostProcessor::Ollama, None),
+            None
+        );
+
+        settings.services.ollama.model = None;
+        if std::env::var("OLLAMA_MODEL").is_err() {
+            let missing = settings
+                .post_processor_missing(&PostProcessor::Ollama, None)
+                .unwrap();
+            assert!(missing.contains("model"));
+        }
+
+        settings.services.ollama.url = None;
+        if std::env::var("OLLAMA_URL").is_err() {
+            let missing = settings
+                .post_processor_missing(&PostProcessor::Ollama, None)
+                .unwrap();
+            assert!(missing.contains("URL"));
+        }
+    }
+
+    #[test]
+    fn test_ollama_model_can_come_from_preset() {
+        let mut settings = Settings::default();
+        settings.services.ollama.model = None;
+
+        let preset = preset_with_model(Some("llama3.2:1b"));
+        assert_eq!(
+            settings.post_processor_missing(&PostProcessor::Ollama, Some(&preset)),
+            None
+        );
+
+        if std::env::var("OLLAMA_MODEL").is_err() {
+            let preset = preset_with_model(None);
+            assert!(
+                settings
+                    .post_processor_missing(&PostProcessor::Ollama, Some(&preset))
+                    .is_some()
+            );
+        }
+    }
 }
@@ -19,6 +19,19 @@ pub struct PostProcessingSettings {
     /// Custom prompt for post-processing (uses default if None)
     #[serde(default)]
     pub prompt: Option<String>,
+
+    /// Transcripts longer than this (characters) are post-processed in
+    /// sentence-aligned chunks; 0 always sends the whole transcript
+    #[serde(default = "default_chunk_chars")]
+    pub chunk_chars: usize,
+
+    /// Smooth the seams between chunks with one extra request per seam
+    #[serde(default)]
+    pub cohesion_pass: bool,
+}
+
+fn default_chunk_chars() -> usize {
+    crate::configuration::DEFAULT_POST_PROCESSING_CHUNK_CHARS
 }
 
 fn default_processor() -> PostProcessor {
@@ -31,6 +44,8 @@ impl Default for PostProcessingSettings {
             enabled: false,
             processor: crate::configuration::DEFAULT_POST_PROCESSOR,
             prompt: Some(crate::transcription::DEFAULT_POST_PROCESSING_PROMPT.to_string()),
+            chunk_chars: default_chunk_chars(),
+            cohesion_pass: false,
         }
     }
 }
@@ -67,11 +82,15 @@ impl PostProcessingSettings {
         &self,
         transcription_api_keys: &std::collections::HashMap<String, String>,
     ) -> Option<String> {
-        match &self.processor {
-            PostProcessor::None | PostProcessor::Ollama => None,
-            PostProcessor::OpenAI => transcription_api_keys.get("openai").cloned(),
-            PostProcessor::Mistral => transcription_api_keys.get("mistral").cloned(),
-        }
+        let name = match &self.processor {
+            PostProcessor::None | PostProcessor::Ollama => return None,
+            PostProcessor::OpenAI => "openai",
+            PostProcessor::Mistral => "mistral",
+        };
+        transcription_api_keys
+            .get(name)
+            .filter(|key| super::keyring::is_usable_key(key))
+            .cloned()
     }
 
     /// Check if post-processing is enabled and properly configured.
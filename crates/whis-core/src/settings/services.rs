@@ -30,6 +30,13 @@ pub struct OllamaConfig {
     /// Default: "5m" (Ollama's native default)
     #[serde(default)]
     pub keep_alive: Option<String>,
+
+    /// System prompt sent with post-processing requests (role/style).
+    ///
+    /// The per-run instruction goes into the user message alongside the
+    /// transcript. Default: `DEFAULT_OLLAMA_SYSTEM_PROMPT`
+    #[serde(default)]
+    pub system_prompt: Option<String>,
 }
 
 impl Default for OllamaConfig {
@@ -38,6 +45,7 @@ impl Default for OllamaConfig {
             url: Some(crate::configuration::DEFAULT_OLLAMA_URL.to_string()),
             model: Some(crate::configuration::DEFAULT_OLLAMA_MODEL.to_string()),
             keep_alive: Some(crate::configuration::DEFAULT_OLLAMA_KEEP_ALIVE.to_string()),
+            system_prompt: None,
         }
     }
 }
@@ -64,6 +72,13 @@ impl OllamaConfig {
             .unwrap_or_else(|| crate::configuration::DEFAULT_OLLAMA_KEEP_ALIVE.to_string())
     }
 
+    /// Get the Ollama system prompt, falling back to default.
+    pub fn system_prompt(&self) -> String {
+        self.system_prompt
+            .clone()
+            .unwrap_or_else(|| crate::configuration::DEFAULT_OLLAMA_SYSTEM_PROMPT.to_string())
+    }
+
     /// Preload Ollama model using this config's settings.
     ///
     /// Spawns a background thread that warms up the model by sending
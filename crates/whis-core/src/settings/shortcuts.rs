@@ -56,10 +56,67 @@ impl std::str::FromStr for CliShortcutMode {
     }
 }
 
+/// How the CLI captures the hotkey in direct mode on Linux.
+///
+/// The keyboard grab consumes the hotkey (other apps never see it) but needs
+/// access to input devices (`input` group). The GlobalShortcuts portal needs
+/// no permissions, but the desktop environment owns the binding, and the key
+/// may still reach other apps depending on the compositor.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CliHotkeyBackend {
+    /// Grab if input devices are accessible, otherwise the portal (Wayland)
+    #[default]
+    Auto,
+
+    /// Keyboard grab via evdev (requires input group membership)
+    Grab,
+
+    /// XDG GlobalShortcuts portal (Wayland: GNOME 48+, KDE, Hyprland)
+    Portal,
+}
+
+impl CliHotkeyBackend {
+    /// Returns the string representation for config display.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CliHotkeyBackend::Auto => "auto",
+            CliHotkeyBackend::Grab => "grab",
+            CliHotkeyBackend::Portal => "portal",
+        }
+    }
+}
+
+impl std::fmt::Display for CliHotkeyBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for CliHotkeyBackend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "auto" => Ok(CliHotkeyBackend::Auto),
+            "grab" => Ok(CliHotkeyBackend::Grab),
+            "portal" => Ok(CliHotkeyBackend::Portal),
+            _ => Err(format!(
+                "Invalid hotkey backend: '{}'. Use 'auto', 'grab' or 'portal'",
+                s
+            )),
+        }
+    }
+}
+
 fn default_shortcut() -> String {
     crate::configuration::DEFAULT_SHORTCUT.to_string()
 }
 
+fn default_hotkey_debounce() -> u64 {
+    crate::configuration::DEFAULT_HOTKEY_DEBOUNCE_MS
+}
+
 /// Settings for keyboard shortcuts.
 ///
 /// CLI and Desktop have separate shortcut keys to prevent conflicts
@@ -92,6 +149,17 @@ pub struct ShortcutsSettings {
     /// Only used when `cli_mode` is `direct`.
     #[serde(default)]
     pub cli_push_to_talk: bool,
+
+    /// Hotkey debounce window in milliseconds (direct capture only).
+    ///
+    /// A release is only reported after the trigger stayed up this long;
+    /// a press within the window is treated as switch bounce. 0 disables.
+    #[serde(default = "default_hotkey_debounce")]
+    pub hotkey_debounce_ms: u64,
+
+    /// How the CLI captures the hotkey on Linux (direct mode only).
+    #[serde(default)]
+    pub cli_hotkey_backend: CliHotkeyBackend,
 }
 
 impl Default for ShortcutsSettings {
@@ -101,6 +169,8 @@ impl Default for ShortcutsSettings {
             cli_key: default_shortcut(),
             desktop_key: default_shortcut(),
             cli_push_to_talk: false,
+            hotkey_debounce_ms: default_hotkey_debounce(),
+            cli_hotkey_backend: CliHotkeyBackend::default(),
         }
     }
 }
@@ -111,7 +181,9 @@ impl ShortcutsSettings {
     /// Returns an error if CLI is in direct mode and both keys are the same,
     /// as this would cause both apps to trigger simultaneously.
     pub fn validate(&self) -> anyhow::Result<()> {
-        if self.cli_mode == CliShortcutMode::Direct && self.cli_key == self.desktop_key {
+        if self.cli_mode == CliShortcutMode::Direct
+            && same_shortcut(&self.cli_key, &self.desktop_key)
+        {
             anyhow::bail!(
                 "Shortcut conflict: CLI and Desktop cannot use '{}' when cli_mode is 'direct'.\n\
                  Fix with one of:\n\
@@ -124,3 +196,16 @@ impl ShortcutsSettings {
         Ok(())
     }
 }
+
+/// Whether two shortcut strings name the same key combination
+/// (e.g. "ctrl+alt+w" and "Alt+Ctrl+KeyW")
+fn same_shortcut(a: &str, b: &str) -> bool {
+    #[cfg(feature = "hotkey")]
+    if let (Ok(a), Ok(b)) = (
+        crate::hotkey::Hotkey::parse(a),
+        crate::hotkey::Hotkey::parse(b),
+    ) {
+        return a.to_normalized_string() == b.to_normalized_string();
+    }
+    a.trim().eq_ignore_ascii_case(b.trim())
+}
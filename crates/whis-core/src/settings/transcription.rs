@@ -3,6 +3,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use super::keyring::{self, KEYRING_REFERENCE, KeyLookup, is_usable_key};
 use crate::config::TranscriptionProvider;
 
 #[cfg(feature = "local-transcription")]
@@ -21,12 +22,51 @@ pub struct TranscriptionSettings {
     pub language: Option<String>,
 
     /// API keys stored by provider name (e.g., "openai" -> "sk-...")
+    ///
+    /// Always the real keys in memory; on disk a key kept in the OS keyring
+    /// is written as a reference (see `use_keyring`).
     #[serde(default)]
     pub api_keys: HashMap<String, String>,
 
+    /// Keep API keys in the OS keyring instead of settings.json
+    ///
+    /// Turn off on headless machines without a keyring service; keys then
+    /// go back into the file on the next save.
+    #[serde(default = "default_use_keyring")]
+    pub use_keyring: bool,
+
     /// Local model configuration
     #[serde(default)]
     pub local_models: LocalModelsConfig,
+
+    /// Warn when a provider reports confidence below this (0.0-1.0)
+    /// None = never warn. Only providers that report confidence are checked.
+    #[serde(default)]
+    pub confidence_warn: Option<f32>,
+
+    /// Network timeouts for transcription requests
+    #[serde(default)]
+    pub timeouts: TimeoutSettings,
+
+    /// Gzip raw audio uploads (Deepgram). Only pays off for uncompressed
+    /// audio such as WAV; MP3 barely shrinks.
+    #[serde(default)]
+    pub compress_uploads: bool,
+
+    /// MP3 bitrate for uploads in kbps (8-160). Speech stays accurate down
+    /// to about 32; lower values shrink uploads on slow connections.
+    #[serde(default = "default_mp3_bitrate")]
+    pub mp3_bitrate: u32,
+
+    /// Let the provider format the transcript (Deepgram `smart_format`:
+    /// punctuation, dates, times, amounts). Off = verbatim output.
+    #[serde(default = "default_smart_format")]
+    pub smart_format: bool,
+
+    /// Write spoken numbers as digits ("twenty twenty four" -> "2024")
+    /// Deepgram does this itself; other providers get a local English pass.
+    #[serde(default)]
+    pub format_numbers: bool,
 }
 
 impl Default for TranscriptionSettings {
@@ -35,11 +75,90 @@ impl Default for TranscriptionSettings {
             provider: crate::configuration::DEFAULT_PROVIDER,
             language: crate::configuration::DEFAULT_LANGUAGE.map(String::from),
             api_keys: HashMap::new(),
+            use_keyring: default_use_keyring(),
             local_models: LocalModelsConfig::default(),
+            confidence_warn: None,
+            timeouts: TimeoutSettings::default(),
+            compress_uploads: false,
+            mp3_bitrate: default_mp3_bitrate(),
+            smart_format: default_smart_format(),
+            format_numbers: false,
         }
     }
 }
 
+/// Network timeouts for transcription requests (seconds)
+///
+/// A request may take up to `upload_timeout_secs + timeout_secs`: slow uploads
+/// get their own window without extending how long the provider has to answer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeoutSettings {
+    /// Establishing the connection (TCP + TLS)
+    #[serde(default = "default_connect_timeout")]
+    pub connect_timeout_secs: u64,
+
+    /// Uploading the audio of one request
+    #[serde(default = "default_upload_timeout")]
+    pub upload_timeout_secs: u64,
+
+    /// Waiting for the transcript once the audio is uploaded
+    #[serde(default = "default_timeout")]
+    pub timeout_secs: u64,
+}
+
+fn default_use_keyring() -> bool {
+    true
+}
+
+fn default_mp3_bitrate() -> u32 {
+    crate::audio::DEFAULT_MP3_BITRATE
+}
+
+fn default_smart_format() -> bool {
+    true
+}
+
+fn default_connect_timeout() -> u64 {
+    crate::configuration::DEFAULT_CONNECT_TIMEOUT_SECS
+}
+
+fn default_upload_timeout() -> u64 {
+    crate::configuration::DEFAULT_UPLOAD_TIMEOUT_SECS
+}
+
+fn default_timeout() -> u64 {
+    crate::provider::DEFAULT_TIMEOUT_SECS
+}
+
+impl Default for TimeoutSettings {
+    fn default() -> Self {
+        Self {
+            connect_timeout_secs: default_connect_timeout(),
+            upload_timeout_secs: default_upload_timeout(),
+            timeout_secs: default_timeout(),
+        }
+    }
+}
+
+/// Masked form of an API key for display, e.g. `sk-...abcd`
+///
+/// Keeps a known provider prefix (`sk-`, `gsk_`) and the last four
+/// characters, so keys can be told apart in screen shares and pasted
+/// configs without leaking. Short values are masked completely.
+pub fn mask_api_key(key: &str) -> String {
+    let chars: Vec<char> = key.chars().collect();
+    if chars.len() <= 12 {
+        return "***".to_string();
+    }
+    let prefix = TranscriptionProvider::all()
+        .iter()
+        .filter_map(TranscriptionProvider::api_key_prefix)
+        .find(|prefix| key.starts_with(prefix))
+        .unwrap_or_default();
+    let tail: String = chars[chars.len() - 4..].iter().collect();
+    format!("{prefix}...{tail}")
+}
+
 /// Configuration for local transcription models.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct LocalModelsConfig {
@@ -86,10 +205,15 @@ impl TranscriptionSettings {
     pub fn api_key_from_settings_for(&self, provider: &TranscriptionProvider) -> Option<String> {
         self.api_keys
             .get(provider.api_key_name())
-            .filter(|k| !k.is_empty())
+            .filter(|k| is_usable_key(k))
             .cloned()
     }
 
+    /// The API key for `provider` (settings, then environment), masked for display
+    pub fn masked_api_key_for(&self, provider: &TranscriptionProvider) -> Option<String> {
+        self.api_key_for(provider).map(|key| mask_api_key(&key))
+    }
+
     /// Check if an API key is explicitly configured in settings (not just in environment).
     ///
     /// Returns true only if the key exists in the api_keys HashMap.
@@ -99,8 +223,7 @@ impl TranscriptionSettings {
     pub fn has_configured_api_key(&self, provider: &TranscriptionProvider) -> bool {
         self.api_keys
             .get(provider.api_key_name())
-            .map(|k| !k.is_empty())
-            .unwrap_or(false)
+            .is_some_and(|k| is_usable_key(k))
     }
 
     /// Set the API key for a provider.
@@ -109,6 +232,60 @@ impl TranscriptionSettings {
             .insert(provider.api_key_name().to_string(), key);
     }
 
+    /// Swap keyring references loaded from disk for the keys they point to
+    ///
+    /// A reference whose keyring entry is gone becomes an empty (unset) key.
+    /// When the keyring is locked or unreachable the reference stays, so the
+    /// next save writes it back untouched instead of losing the key.
+    pub(super) fn resolve_keyring_keys(&mut self) {
+        for (name, key) in &mut self.api_keys {
+            if key == KEYRING_REFERENCE {
+                match keyring::load_key(name) {
+                    KeyLookup::Found(stored) => *key = stored,
+                    KeyLookup::Missing => key.clear(),
+                    KeyLookup::Unavailable => {}
+                }
+            }
+        }
+    }
+
+    /// Whether a save would move plaintext keys into the keyring
+    ///
+    /// Only meaningful on keys as read from disk: once references are
+    /// resolved every stored key looks like plaintext.
+    pub(super) fn has_plaintext_keys(&self) -> bool {
+        self.use_keyring
+            && keyring::available()
+            && self.api_keys.values().any(|key| is_usable_key(key))
+    }
+
+    /// API keys as written to settings.json
+    ///
+    /// Keys the keyring accepted are replaced by [`KEYRING_REFERENCE`]; the
+    /// rest (keyring off or unavailable) stay in plaintext. Unresolved
+    /// references are kept as they are, and a keyring entry is only deleted
+    /// when its key was cleared.
+    pub(super) fn api_keys_for_disk(&self) -> HashMap<String, String> {
+        self.api_keys
+            .iter()
+            .map(|(name, key)| {
+                let value = if key == KEYRING_REFERENCE {
+                    key.clone()
+                } else if key.is_empty() {
+                    if self.use_keyring {
+                        keyring::delete_key(name);
+                    }
+                    String::new()
+                } else if self.use_keyring && keyring::store_key(name, key) {
+                    KEYRING_REFERENCE.to_string()
+                } else {
+                    key.clone()
+                };
+                (name.clone(), value)
+            })
+            .collect()
+    }
+
     /// Check if an API key is configured for the current provider.
     pub fn has_api_key(&self) -> bool {
         self.api_key().is_some()
@@ -163,3 +340,135 @@ impl TranscriptionSettings {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::keyring::{FAKE, forget_known};
+
+    fn with_keys(keys: &[(&str, &str)]) -> TranscriptionSettings {
+        TranscriptionSettings {
+            api_keys: keys
+                .iter()
+                .map(|(name, key)| (name.to_string(), key.to_string()))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Load the settings `on_disk` and save them straight back
+    fn round_trip(on_disk: &[(&str, &str)]) -> (TranscriptionSettings, HashMap<String, String>) {
+        let mut settings = with_keys(on_disk);
+        settings.resolve_keyring_keys();
+        let saved = settings.api_keys_for_disk();
+        (settings, saved)
+    }
+
+    fn reset_keyring(locked: bool, keys: &[(&str, &str)]) {
+        forget_known();
+        FAKE.with_borrow_mut(|fake| {
+            fake.locked = locked;
+            fake.writes = 0;
+            fake.keys = keys
+                .iter()
+                .map(|(name, key)| (name.to_string(), key.to_string()))
+                .collect();
+        });
+    }
+
+    fn keyring_writes() -> usize {
+        FAKE.with_borrow(|fake| fake.writes)
+    }
+
+    #[test]
+    fn test_round_trip_resolves_without_rewriting() {
+        reset_keyring(false, &[("openai", "sk-real")]);
+        let (settings, saved) = round_trip(&[("openai", KEYRING_REFERENCE)]);
+
+        assert_eq!(settings.api_keys["openai"], "sk-real");
+        assert_eq!(saved["openai"], KEYRING_REFERENCE);
+        assert_eq!(keyring_writes(), 0);
+    }
+
+    #[test]
+    fn test_round_trip_keeps_reference_when_keyring_locked() {
+        reset_keyring(true, &[("openai", "sk-real")]);
+        let (settings, saved) = round_trip(&[("openai", KEYRING_REFERENCE)]);
+
+        assert_eq!(
+            settings.api_key_from_settings_for(&TranscriptionProvider::OpenAI),
+            None
+        );
+        assert!(!settings.has_configured_api_key(&TranscriptionProvider::OpenAI));
+        assert_eq!(saved["openai"], KEYRING_REFERENCE);
+
+        // Unlocked on a later run, the key is still there
+        FAKE.with_borrow_mut(|fake| fake.locked = false);
+        assert_eq!(
+            FAKE.with_borrow(|fake| fake.keys["openai"].clone()),
+            "sk-real"
+        );
+        let (settings, _) = round_trip(&[("openai", KEYRING_REFERENCE)]);
+        assert_eq!(settings.api_keys["openai"], "sk-real");
+    }
+
+    #[test]
+    fn test_missing_entry_becomes_unset_key() {
+        reset_keyring(false, &[]);
+        let (settings, saved) = round_trip(&[("groq", KEYRING_REFERENCE)]);
+
+        assert_eq!(settings.api_keys["groq"], "");
+        assert_eq!(saved["groq"], "");
+    }
+
+    #[test]
+    fn test_only_changed_keys_are_written() {
+        reset_keyring(false, &[("openai", "sk-old"), ("groq", "gsk-same")]);
+        let mut settings = with_keys(&[("openai", KEYRING_REFERENCE), ("groq", KEYRING_REFERENCE)]);
+        settings.resolve_keyring_keys();
+        settings.set_api_key(&TranscriptionProvider::OpenAI, "sk-new".to_string());
+
+        let saved = settings.api_keys_for_disk();
+        assert_eq!(saved["openai"], KEYRING_REFERENCE);
+        assert_eq!(saved["groq"], KEYRING_REFERENCE);
+        assert_eq!(keyring_writes(), 1);
+        assert_eq!(
+            FAKE.with_borrow(|fake| fake.keys["openai"].clone()),
+            "sk-new"
+        );
+
+        // Saving again writes nothing
+        settings.api_keys_for_disk();
+        assert_eq!(keyring_writes(), 1);
+    }
+
+    #[test]
+    fn test_clearing_a_key_deletes_it_from_the_keyring() {
+        reset_keyring(false, &[("openai", "sk-real")]);
+        let mut settings = with_keys(&[("openai", KEYRING_REFERENCE)]);
+        settings.resolve_keyring_keys();
+        settings.set_api_key(&TranscriptionProvider::OpenAI, String::new());
+
+        let saved = settings.api_keys_for_disk();
+        assert_eq!(saved["openai"], "");
+        assert!(FAKE.with_borrow(|fake| !fake.keys.contains_key("openai")));
+    }
+
+    #[test]
+    fn test_empty_key_never_seen_in_keyring_is_not_deleted() {
+        reset_keyring(false, &[("mistral", "orphan")]);
+        let (_, saved) = round_trip(&[("mistral", "")]);
+
+        assert_eq!(saved["mistral"], "");
+        assert_eq!(keyring_writes(), 0);
+    }
+
+    #[test]
+    fn test_plaintext_key_stays_in_file_when_keyring_locked() {
+        reset_keyring(true, &[]);
+        let (settings, saved) = round_trip(&[("deepgram", "dg-key")]);
+
+        assert!(!settings.has_plaintext_keys());
+        assert_eq!(saved["deepgram"], "dg-key");
+    }
+}
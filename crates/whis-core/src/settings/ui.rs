@@ -2,12 +2,13 @@
 //!
 //! This module contains settings for:
 //! - Audio recording configuration (microphone, VAD, chunking)
-//! - Output handling (clipboard backend, presets)
+//! - Output handling (clipboard backend, default CLI output, presets)
 //! - Desktop-specific features (floating bubble overlay)
 //!
 //! Note: Keyboard shortcuts are now in the `shortcuts` module.
 
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
 #[cfg(feature = "clipboard")]
 use crate::clipboard::ClipboardMethod;
@@ -33,6 +34,46 @@ pub struct UiSettings {
     #[serde(default)]
     pub clipboard_backend: ClipboardMethod,
 
+    /// Update the clipboard after every transcribed chunk (CLI recordings).
+    ///
+    /// For long dictations: the clipboard holds the transcript so far, so
+    /// partial results can be pasted before recording ends. Raw text only, so
+    /// it's skipped when post-processing or a preset is used, and when the
+    /// output doesn't go to the clipboard. Off by default.
+    #[serde(default)]
+    pub copy_as_you_go: bool,
+
+    /// Where the CLI delivers a transcript without `--print` or `--output`.
+    ///
+    /// - `clipboard`: Clipboard (or autotype, per `output_method`) (default)
+    /// - `stdout`: Print to stdout, e.g. for headless servers and SSH
+    /// - `file`: Write to `default_output_path`
+    ///
+    /// With `clipboard`, the CLI falls back to stdout when no clipboard is available.
+    #[serde(default)]
+    pub default_output: DefaultOutput,
+
+    /// File written when `default_output` is `file`.
+    #[serde(default)]
+    pub default_output_path: Option<PathBuf>,
+
+    /// Directory for temporary and regenerable files: the `--save-last`
+    /// recording and partial model downloads.
+    ///
+    /// Unset: `$WHIS_CACHE_DIR`, else the platform cache directory
+    /// (e.g. ~/.cache/whis). Created on demand.
+    #[serde(default)]
+    pub cache_dir: Option<PathBuf>,
+
+    /// Environment file the CLI loads at startup (e.g. `.env` with
+    /// `OPENAI_API_KEY=...`), relative paths resolved against the working
+    /// directory.
+    ///
+    /// Variables already set in the environment win over the file. Unset:
+    /// no file is loaded unless `--env-file` is passed.
+    #[serde(default)]
+    pub env_file: Option<PathBuf>,
+
     /// Selected microphone device name.
     ///
     /// - `null`: Use system default microphone
@@ -68,10 +109,31 @@ pub struct UiSettings {
     /// - Default (90s): Good balance of speed and accuracy
     /// - Higher (120s+): Better accuracy for complex speech
     ///
-    /// Valid range: 10-300 seconds
+    /// Valid range: 10-300 seconds (out-of-range values are clamped when used)
     #[serde(default = "default_chunk_duration")]
     pub chunk_duration_secs: u64,
 
+    /// Minimum recording length (milliseconds).
+    ///
+    /// Recordings shorter than this are discarded without transcription,
+    /// so accidental taps don't waste a provider call. 0 disables the check.
+    #[serde(default = "default_min_recording_ms")]
+    pub min_recording_ms: u64,
+
+    /// Stop the `whis start` service after this many minutes without a
+    /// recording (CLI only).
+    ///
+    /// Frees the socket and any kept-loaded local model when the service
+    /// is forgotten. 0 (default) keeps it running until `whis stop`.
+    #[serde(default)]
+    pub service_idle_shutdown_minutes: u32,
+
+    /// Feedback on recording start, stop and completed transcription.
+    ///
+    /// Off by default.
+    #[serde(default)]
+    pub cues: CueSettings,
+
     /// Floating bubble overlay settings (desktop only).
     ///
     /// Shows a small floating indicator during recording.
@@ -79,6 +141,13 @@ pub struct UiSettings {
     #[serde(default)]
     pub bubble: BubbleSettings,
 
+    /// Show a notification with a preview of each transcript (desktop only).
+    ///
+    /// Useful when recording with a global shortcut, with the window hidden.
+    /// Off by default.
+    #[serde(default)]
+    pub notify_on_complete: bool,
+
     /// Model memory management settings.
     ///
     /// Controls when local transcription models are loaded/unloaded.
@@ -125,10 +194,57 @@ pub struct UiSettings {
     pub autotype_delay_ms: Option<u32>,
 }
 
+/// Default CLI output target (see `UiSettings::default_output`).
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DefaultOutput {
+    #[default]
+    Clipboard,
+    Stdout,
+    File,
+}
+
+impl DefaultOutput {
+    /// Returns the string representation for config display.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DefaultOutput::Clipboard => "clipboard",
+            DefaultOutput::Stdout => "stdout",
+            DefaultOutput::File => "file",
+        }
+    }
+}
+
+impl std::fmt::Display for DefaultOutput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for DefaultOutput {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "clipboard" => Ok(DefaultOutput::Clipboard),
+            "stdout" => Ok(DefaultOutput::Stdout),
+            "file" => Ok(DefaultOutput::File),
+            _ => Err(format!(
+                "Invalid default output: '{}'. Use 'clipboard', 'stdout' or 'file'",
+                s
+            )),
+        }
+    }
+}
+
 fn default_chunk_duration() -> u64 {
     crate::configuration::DEFAULT_CHUNK_DURATION_SECS
 }
 
+fn default_min_recording_ms() -> u64 {
+    crate::configuration::DEFAULT_MIN_RECORDING_MS
+}
+
 /// Voice Activity Detection configuration.
 ///
 /// VAD automatically detects speech and skips silence,
@@ -163,6 +279,36 @@ impl Default for VadSettings {
     }
 }
 
+/// Sound and haptic cues on recording state changes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CueSettings {
+    /// Beep when recording starts and stops and when the transcript is ready.
+    #[serde(default)]
+    pub sound: bool,
+
+    /// Volume of the beeps (0.0-1.0).
+    #[serde(default = "default_cue_volume")]
+    pub volume: f32,
+
+    /// Vibrate on the same events (mobile only).
+    #[serde(default)]
+    pub haptic: bool,
+}
+
+fn default_cue_volume() -> f32 {
+    crate::configuration::DEFAULT_CUE_VOLUME
+}
+
+impl Default for CueSettings {
+    fn default() -> Self {
+        Self {
+            sound: false,
+            volume: default_cue_volume(),
+            haptic: false,
+        }
+    }
+}
+
 /// Floating bubble overlay settings (experimental).
 ///
 /// The bubble is a small floating indicator that shows
@@ -226,16 +372,40 @@ impl Default for ModelMemorySettings {
     }
 }
 
+impl UiSettings {
+    /// Get the cache directory, falling back to `$WHIS_CACHE_DIR` and then
+    /// the platform cache directory.
+    pub fn cache_dir(&self) -> PathBuf {
+        self.cache_dir
+            .clone()
+            .or_else(|| std::env::var_os("WHIS_CACHE_DIR").map(PathBuf::from))
+            .unwrap_or_else(|| {
+                dirs::cache_dir()
+                    .unwrap_or_else(std::env::temp_dir)
+                    .join("whis")
+            })
+    }
+}
+
 impl Default for UiSettings {
     fn default() -> Self {
         Self {
             #[cfg(feature = "clipboard")]
             clipboard_backend: ClipboardMethod::default(),
+            copy_as_you_go: false,
+            default_output: DefaultOutput::default(),
+            default_output_path: None,
+            cache_dir: None,
+            env_file: None,
             microphone_device: None,
             vad: VadSettings::default(),
             active_preset: None,
             chunk_duration_secs: crate::configuration::DEFAULT_CHUNK_DURATION_SECS,
+            min_recording_ms: crate::configuration::DEFAULT_MIN_RECORDING_MS,
+            service_idle_shutdown_minutes: 0,
+            cues: CueSettings::default(),
             bubble: BubbleSettings::default(),
+            notify_on_complete: false,
             model_memory: ModelMemorySettings::default(),
             #[cfg(feature = "autotyping")]
             output_method: OutputMethod::default(),
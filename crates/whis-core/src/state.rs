@@ -7,3 +7,32 @@ pub enum RecordingState {
     Recording,
     Transcribing,
 }
+
+/// Why a recording stopped
+///
+/// Reported by the frontends when a recording ends, so the UI can tell a
+/// transcribed recording apart from a discarded one. Recordings only stop on
+/// request; add a variant here alongside any automatic stop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StopReason {
+    /// Stopped by the user (hotkey, toggle, tray, bubble)
+    Manual,
+    /// Discarded without transcribing (e.g. on shutdown)
+    Cancelled,
+}
+
+impl StopReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StopReason::Manual => "manual",
+            StopReason::Cancelled => "cancelled",
+        }
+    }
+}
+
+impl std::fmt::Display for StopReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,440 @@
+//! High-level transcription API for embedding whis-core in other apps.
+//!
+//! Wraps provider dispatch (cloud or local), audio decoding/recording and
+//! optional post-processing behind [`TranscribeOptions`], so callers don't
+//! have to assemble a [`TranscriptionRequest`] themselves.
+//!
+//! ```rust,no_run
+//! use whis_core::{Settings, TranscribeOptions, TranscriptionProvider, transcribe_file};
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! // Explicit provider and key
+//! let opts = TranscribeOptions::new(TranscriptionProvider::Groq, "gsk_...").language("en");
+//! let result = transcribe_file("meeting.wav", &opts).await?;
+//! println!("{}", result.text);
+//!
+//! // Or reuse what the user configured for the CLI/desktop app
+//! let opts = TranscribeOptions::from_settings(&Settings::load())?;
+//! # Ok(())
+//! # }
+//! ```
+
+use anyhow::Context;
+use std::future::Future;
+use std::time::Duration;
+
+use super::no_speech::normalize_transcript;
+use super::numerals::{format_spoken_numbers, formats_numbers_locally};
+use super::post_processing::{
+    PostProcessConfig, PostProcessor, post_process, resolve_post_processor_config,
+};
+use super::prompt_template::{PromptVariables, render_prompt};
+use crate::audio::{AudioRecorder, RecorderConfig, SegmenterConfig};
+use crate::configuration::{Preset, TranscriptionProvider, normalize_language};
+use crate::error::{ProviderError, Result, WhisError};
+use crate::http::get_http_client;
+use crate::provider::{CancellationToken, TranscriptionRequest, TranscriptionResult, registry};
+use crate::resample::WHISPER_SAMPLE_RATE;
+use crate::settings::Settings;
+
+/// Recording length used by [`record_and_transcribe`] unless overridden
+pub const DEFAULT_RECORD_DURATION: Duration = Duration::from_secs(10);
+
+/// What to transcribe with, and what to do with the transcript afterwards
+#[derive(Debug, Clone)]
+pub struct TranscribeOptions {
+    provider: TranscriptionProvider,
+    /// API key for cloud providers, model path for local ones
+    api_key: String,
+    language: Option<String>,
+    pub(super) post_process: Option<PostProcessConfig>,
+    pub(super) recorder: RecorderConfig,
+    record_duration: Duration,
+    pub(super) segmenter: SegmenterConfig,
+    format_numbers: bool,
+    cancel: Option<CancellationToken>,
+}
+
+impl TranscribeOptions {
+    /// Options for `provider`
+    ///
+    /// `api_key` is the provider's API key, or the model path for
+    /// `LocalWhisper`/`LocalParakeet`.
+    pub fn new(provider: TranscriptionProvider, api_key: impl Into<String>) -> Self {
+        Self {
+            provider,
+            api_key: api_key.into(),
+            language: None,
+            post_process: None,
+            recorder: RecorderConfig::default(),
+            record_duration: DEFAULT_RECORD_DURATION,
+            segmenter: SegmenterConfig::default(),
+            format_numbers: false,
+            cancel: None,
+        }
+    }
+
+    /// Options matching the user's saved settings
+    ///
+    /// Uses the configured provider, key/model path, language, microphone,
+    /// number formatting and (when enabled) post-processing with the active
+    /// preset, and the VAD threshold for [`listen`](super::listen::listen).
+    pub fn from_settings(settings: &Settings) -> Result<Self> {
+        let provider = settings.transcription.provider.clone();
+        let api_key = match provider {
+            TranscriptionProvider::LocalWhisper => settings.transcription.whisper_model_path(),
+            TranscriptionProvider::LocalParakeet => settings.transcription.parakeet_model_path(),
+            _ => settings.transcription.api_key_for(&provider),
+        }
+        .ok_or_else(|| ProviderError::missing_api_key(&provider))?;
+
+        let mut opts = Self::new(provider, api_key);
+        opts.language = settings
+            .transcription
+            .language
+            .clone()
+            .map(|lang| normalize_language(&lang).unwrap_or(lang));
+        opts.recorder.device_name = settings.ui.microphone_device.clone();
+        opts.recorder.mp3_bitrate = Some(settings.transcription.mp3_bitrate);
+        opts.segmenter.threshold = settings.ui.vad.threshold;
+        opts.format_numbers = settings.transcription.format_numbers;
+
+        let preset = match &settings.ui.active_preset {
+            Some(name) => {
+                let (preset, _source) = Preset::load(name).map_err(WhisError::Config)?;
+                Some(preset)
+            }
+            None => None,
+        };
+        if settings.post_processing.enabled || preset.is_some() {
+            opts = opts.post_process_with(&preset, settings)?;
+        }
+        Ok(opts)
+    }
+
+    /// Language hint (ISO-639-1 code, e.g. "en"); auto-detect when unset
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Write spoken numbers as digits ("twenty twenty four" -> "2024")
+    ///
+    /// Deepgram is asked to format numbers itself; other providers'
+    /// transcripts go through [`format_spoken_numbers`].
+    pub fn format_numbers(mut self, enabled: bool) -> Self {
+        self.format_numbers = enabled;
+        self
+    }
+
+    /// Clean up the transcript with an LLM afterwards
+    pub fn post_process(mut self, config: PostProcessConfig) -> Self {
+        self.post_process = Some(config);
+        self
+    }
+
+    /// Post-process with the processor/prompt from `preset` (or settings when `None`)
+    pub fn post_process_with(self, preset: &Option<Preset>, settings: &Settings) -> Result<Self> {
+        let (processor, api_key_or_url, model, prompt) =
+            resolve_post_processor_config(preset, settings)?;
+        Ok(self.post_process(PostProcessConfig {
+            processor,
+            prompt,
+            api_key_or_url,
+            ollama_model: model,
+            ollama: settings.services.ollama.clone(),
+            chunking: (&settings.post_processing).into(),
+        }))
+    }
+
+    /// Input device for [`record_and_transcribe`] (system default when unset)
+    pub fn microphone_device(mut self, device: impl Into<String>) -> Self {
+        self.recorder.device_name = Some(device.into());
+        self
+    }
+
+    /// Recorder settings: input device, VAD and the encoder used for upload
+    ///
+    /// A custom [`AudioEncoder`](crate::AudioEncoder) set here also encodes
+    /// [`transcribe_file`] and [`transcribe_samples`] input for cloud providers.
+    pub fn recorder(mut self, config: RecorderConfig) -> Self {
+        self.recorder = config;
+        self
+    }
+
+    /// How long [`record_and_transcribe`] records
+    pub fn record_duration(mut self, duration: Duration) -> Self {
+        self.record_duration = duration;
+        self
+    }
+
+    /// How [`listen`](super::listen::listen) splits speech into utterances
+    /// (pause length, VAD threshold)
+    pub fn segmenter(mut self, config: SegmenterConfig) -> Self {
+        self.segmenter = config;
+        self
+    }
+
+    /// Cancellation token for cloud requests (in-flight upload and retries)
+    ///
+    /// Cancelled calls fail with an [`ErrorKind::Cancelled`](crate::ErrorKind::Cancelled)
+    /// error. Local transcription can't be interrupted and runs to completion.
+    pub fn cancel(mut self, token: CancellationToken) -> Self {
+        self.cancel = Some(token);
+        self
+    }
+}
+
+/// Transcribe an audio file (WAV), then post-process if configured
+///
+/// Fails with [`WhisError::NoSpeech`] when the transcript is blank; the same
+/// holds for the other entry points below.
+#[cfg(feature = "wav")]
+pub async fn transcribe_file(
+    path: impl AsRef<std::path::Path>,
+    opts: &TranscribeOptions,
+) -> Result<TranscriptionResult> {
+    let path = path.as_ref();
+    let samples = crate::audio::read_audio_file(path)?;
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    Ok(transcribe(samples, &stem, opts).await?)
+}
+
+/// Transcribe 16kHz mono f32 samples, then post-process if configured
+pub async fn transcribe_samples(
+    samples: Vec<f32>,
+    opts: &TranscribeOptions,
+) -> Result<TranscriptionResult> {
+    Ok(transcribe(samples, "audio", opts).await?)
+}
+
+/// [`transcribe_file`], giving up after `timeout`
+///
+/// Decoding the file counts against `timeout`; otherwise behaves like
+/// [`transcribe_samples_with_timeout`].
+#[cfg(feature = "wav")]
+pub async fn transcribe_file_with_timeout(
+    path: impl AsRef<std::path::Path>,
+    opts: &TranscribeOptions,
+    timeout: Duration,
+) -> Result<TranscriptionResult> {
+    with_timeout(opts, timeout, |opts| async move {
+        transcribe_file(path, &opts).await
+    })
+    .await
+}
+
+/// [`transcribe_samples`], giving up after `timeout`
+///
+/// Bounds the whole call, whatever the provider: once `timeout` has passed
+/// it fails with [`WhisError::TimedOut`], cancelling the cloud request and
+/// any post-processing. A local model keeps running in the background until
+/// it finishes, but its result is discarded.
+///
+/// This is separate from the HTTP timeout, which applies to each upload
+/// attempt. Cloud requests retry transient failures (up to 3 retries, 1-16s
+/// apart and longer when rate limited), and those attempts and waits all
+/// count against `timeout`, so a short deadline can end the call during a
+/// retry. A cancellation token set with [`TranscribeOptions::cancel`] still
+/// stops the call early.
+pub async fn transcribe_samples_with_timeout(
+    samples: Vec<f32>,
+    opts: &TranscribeOptions,
+    timeout: Duration,
+) -> Result<TranscriptionResult> {
+    with_timeout(opts, timeout, |opts| async move {
+        transcribe_samples(samples, &opts).await
+    })
+    .await
+}
+
+/// Run `transcribe` with a cancellation token that fires after `timeout`
+///
+/// The token is a child of the caller's, so cancelling it on timeout doesn't
+/// cancel theirs, while cancelling theirs still stops this call.
+async fn with_timeout<F, Fut>(
+    opts: &TranscribeOptions,
+    timeout: Duration,
+    transcribe: F,
+) -> Result<TranscriptionResult>
+where
+    F: FnOnce(TranscribeOptions) -> Fut,
+    Fut: Future<Output = Result<TranscriptionResult>>,
+{
+    let token = match &opts.cancel {
+        Some(parent) => parent.child_token(),
+        None => CancellationToken::new(),
+    };
+    let opts = opts.clone().cancel(token.clone());
+    match tokio::time::timeout(timeout, transcribe(opts)).await {
+        Ok(result) => result,
+        Err(_) => {
+            token.cancel();
+            Err(WhisError::TimedOut(timeout))
+        }
+    }
+}
+
+/// Record from the microphone for the configured duration and transcribe it
+pub async fn record_and_transcribe(opts: &TranscribeOptions) -> Result<TranscriptionResult> {
+    record_and_transcribe_until(opts, tokio::time::sleep(opts.record_duration)).await
+}
+
+/// Record from the microphone until `stop` resolves and transcribe it
+pub async fn record_and_transcribe_until(
+    opts: &TranscribeOptions,
+    stop: impl Future<Output = ()>,
+) -> Result<TranscriptionResult> {
+    let mut recorder = AudioRecorder::new()?;
+    let vad = &opts.recorder.vad;
+    recorder.set_vad(vad.enabled, vad.threshold);
+    recorder.start_recording_with_device(opts.recorder.device_name.as_deref())?;
+    stop.await;
+    let samples = recorder.stop_recording()?.finalize_raw();
+    transcribe_samples(samples, opts).await
+}
+
+async fn transcribe(
+    samples: Vec<f32>,
+    file_stem: &str,
+    opts: &TranscribeOptions,
+) -> anyhow::Result<TranscriptionResult> {
+    let result = match &opts.provider {
+        #[cfg(feature = "local-transcription")]
+        TranscriptionProvider::LocalParakeet => {
+            let model_path = opts.api_key.clone();
+            tokio::task::spawn_blocking(move || {
+                crate::provider::transcribe_raw_parakeet(&model_path, samples)
+            })
+            .await
+            .context("Transcription task panicked")??
+        }
+
+        #[cfg(feature = "local-transcription")]
+        TranscriptionProvider::LocalWhisper => {
+            let model_path = opts.api_key.clone();
+            let language = opts.language.clone();
+            tokio::task::spawn_blocking(move || {
+                crate::provider::transcribe_raw(&model_path, &samples, language.as_deref())
+            })
+            .await
+            .context("Transcription task panicked")??
+        }
+
+        provider => {
+            // Cloud providers: encode (MP3 unless overridden) and send
+            let encoder = opts.recorder.encoder();
+            let audio_data = encoder.encode_samples(&samples, WHISPER_SAMPLE_RATE)?;
+            let request = TranscriptionRequest::builder()
+                .audio_data(audio_data)
+                .language(opts.language.clone())
+                .filename(file_stem)
+                .format(encoder.format())
+                .cancel(opts.cancel.clone())
+                .numerals(opts.format_numbers)
+                .build();
+            registry()
+                .get_by_kind(provider)?
+                .transcribe_async(&get_http_client()?, &opts.api_key, request)
+                .await?
+        }
+    };
+
+    let mut text = normalize_transcript(&result.text).ok_or(WhisError::NoSpeech)?;
+    if opts.format_numbers && formats_numbers_locally(&opts.provider, opts.language.as_deref()) {
+        text = format_spoken_numbers(&text);
+    }
+
+    let text = match &opts.post_process {
+        Some(config) if config.processor != PostProcessor::None => {
+            let prompt = render_prompt(
+                &config.prompt,
+                &PromptVariables::for_prompt(&config.prompt, opts.language.clone()),
+            );
+            post_process(
+                &text,
+                &config.processor,
+                &config.api_key_or_url,
+                &prompt,
+                config.ollama_model.as_deref(),
+                Some(&config.ollama),
+                config.chunking,
+            )
+            .await?
+        }
+        _ => text,
+    };
+
+    // Segments keep the provider's wording (and timing) even when the text
+    // was formatted or post-processed
+    Ok(TranscriptionResult {
+        text,
+        confidence: result.confidence,
+        segments: result.segments,
+        language: result.language,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    fn run<F: Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    /// Run `with_timeout` on a transcription that never completes, returning
+    /// the result and the token the transcription was given
+    fn never_completes(
+        opts: &TranscribeOptions,
+    ) -> (Result<TranscriptionResult>, CancellationToken) {
+        let seen = RefCell::new(None);
+        let result = run(with_timeout(opts, Duration::from_millis(10), |opts| {
+            *seen.borrow_mut() = opts.cancel.clone();
+            std::future::pending::<Result<TranscriptionResult>>()
+        }));
+        (
+            result,
+            seen.into_inner().expect("transcription got no token"),
+        )
+    }
+
+    #[test]
+    fn test_timeout_returns_timed_out() {
+        let opts = TranscribeOptions::new(TranscriptionProvider::OpenAI, "sk-test");
+        let (result, _) = never_completes(&opts);
+        assert!(matches!(
+            result,
+            Err(WhisError::TimedOut(timeout)) if timeout == Duration::from_millis(10)
+        ));
+    }
+
+    #[test]
+    fn test_timeout_cancels_child_token_only() {
+        let parent = CancellationToken::new();
+        let opts =
+            TranscribeOptions::new(TranscriptionProvider::OpenAI, "sk-test").cancel(parent.clone());
+
+        let (result, child) = never_completes(&opts);
+        assert!(matches!(result, Err(WhisError::TimedOut(_))));
+        assert!(child.is_cancelled());
+        assert!(!parent.is_cancelled());
+    }
+
+    #[test]
+    fn test_completing_in_time_returns_result() {
+        let opts = TranscribeOptions::new(TranscriptionProvider::OpenAI, "sk-test");
+        let result = run(with_timeout(&opts, Duration::from_secs(5), |_| async {
+            Ok(TranscriptionResult {
+                text: "hello".into(),
+                ..Default::default()
+            })
+        }));
+        assert_eq!(result.unwrap().text, "hello");
+    }
+}
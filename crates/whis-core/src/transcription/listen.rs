@@ -0,0 +1,300 @@
+//! Always-listening transcription for embedders
+//!
+//! [`listen`] keeps the microphone open, cuts the stream into utterances on
+//! pauses ([`UtteranceSegmenter`]) and hands each transcript to a callback,
+//! e.g. for voice assistants or live captions:
+//!
+//! ```rust,no_run
+//! use whis_core::{Settings, TranscribeOptions, listen};
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let opts = TranscribeOptions::from_settings(&Settings::load())?;
+//! let handle = listen(&opts, |result| println!("{}", result.text))?;
+//! tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+//! handle.stop().await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//!
+//! [`listen_with_events`] additionally reports interim transcripts of the
+//! utterance still being spoken (for captions that update while talking).
+//!
+//! Capture runs on its own thread and never waits for transcription;
+//! utterances are transcribed one at a time, in order. Utterances without
+//! speech are skipped, and a failed utterance (network, rate limit) is
+//! reported as a warning and skipped. Only a rejected API key ends listening early.
+
+use anyhow::anyhow;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, oneshot, watch};
+use tokio::task::JoinHandle;
+
+use super::api::{TranscribeOptions, transcribe_samples};
+use crate::audio::{AudioRecorder, Utterance, UtteranceSegmenter};
+use crate::error::{ErrorKind, Result, WhisError};
+use crate::provider::TranscriptionResult;
+
+/// What a [`listen_with_events`] session reports
+pub enum ListenEvent {
+    /// Provisional transcript of utterance `id`, which is still going on
+    ///
+    /// Not post-processed. Superseded by later interims and the final
+    /// transcript with the same `id`.
+    Interim { id: u64, text: String },
+    /// Transcript of finished utterance `id` (ids count up from 0)
+    Final {
+        id: u64,
+        /// Offset of the utterance from the start of the session
+        start: Duration,
+        end: Duration,
+        result: TranscriptionResult,
+    },
+}
+
+/// Handle to a running [`listen`] session
+///
+/// Dropping it stops listening too, without waiting for the last utterance.
+pub struct ListenHandle {
+    stop_tx: Option<oneshot::Sender<()>>,
+    cut_tx: mpsc::UnboundedSender<()>,
+    transcriber: JoinHandle<Result<()>>,
+    emitter: JoinHandle<()>,
+}
+
+impl ListenHandle {
+    /// Stop listening and wait until the utterance in progress is transcribed
+    ///
+    /// Returns the error that ended the session early, if any.
+    pub async fn stop(mut self) -> Result<()> {
+        if let Some(stop_tx) = self.stop_tx.take() {
+            let _ = stop_tx.send(());
+        }
+        let result = self
+            .transcriber
+            .await
+            .map_err(|e| anyhow!("Transcription task panicked: {e}"))?;
+        let _ = self.emitter.await;
+        result
+    }
+
+    /// End the utterance in progress now instead of waiting for a pause
+    /// (e.g. on a key press)
+    pub fn cut(&self) {
+        let _ = self.cut_tx.send(());
+    }
+
+    /// Whether the session ended on its own (after an error)
+    pub fn is_finished(&self) -> bool {
+        self.transcriber.is_finished()
+    }
+}
+
+/// Keep listening and call `on_utterance` with each utterance's transcript
+///
+/// Records from the device in `opts`, splitting utterances with its
+/// segmenter settings ([`TranscribeOptions::segmenter`]), and transcribes
+/// (and post-processes) each like [`transcribe_samples`]. Must be called
+/// within a Tokio runtime; needs the `vad` feature.
+pub fn listen<F>(opts: &TranscribeOptions, on_utterance: F) -> Result<ListenHandle>
+where
+    F: Fn(TranscriptionResult) + Send + 'static,
+{
+    listen_with_events(opts, None, move |event| {
+        if let ListenEvent::Final { result, .. } = event {
+            on_utterance(result);
+        }
+    })
+}
+
+/// Like [`listen`], reporting [`ListenEvent`]s
+///
+/// With `interim_every` set, the utterance in progress is transcribed again
+/// at most that often (skipped while the previous interim is still being
+/// transcribed). Each interim is an extra request to cloud providers.
+pub fn listen_with_events<F>(
+    opts: &TranscribeOptions,
+    interim_every: Option<Duration>,
+    mut on_event: F,
+) -> Result<ListenHandle>
+where
+    F: FnMut(ListenEvent) + Send + 'static,
+{
+    let mut segmenter = UtteranceSegmenter::new(opts.segmenter.clone())?;
+    let Capture {
+        mut audio_rx,
+        stop_tx: capture_stop,
+    } = start_capture(opts.recorder.device_name.clone())?;
+
+    let (stop_tx, mut stop_rx) = oneshot::channel();
+    let (cut_tx, mut cut_rx) = mpsc::unbounded_channel::<()>();
+    let (utterance_tx, mut utterance_rx) = mpsc::unbounded_channel::<(u64, Utterance)>();
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel::<ListenEvent>();
+    // Latest audio of the utterance in progress (older snapshots are skipped)
+    let (interim_tx, mut interim_rx) = watch::channel::<Option<(u64, Arc<Vec<f32>>)>>(None);
+
+    // Segmentation: cheap (VAD only), so it keeps up with capture
+    tokio::spawn(async move {
+        let mut next_id = 0;
+        let mut last_interim = Instant::now();
+        // With `cut`, the utterance in progress ends after `samples`
+        let mut split = |segmenter: &mut UtteranceSegmenter, samples: &[f32], cut: bool| {
+            let mut utterances = segmenter.push(samples);
+            if cut {
+                utterances.extend(segmenter.cut());
+            }
+            for utterance in utterances {
+                let _ = utterance_tx.send((next_id, utterance));
+                next_id += 1;
+            }
+            if let Some(every) = interim_every
+                && last_interim.elapsed() >= every
+                && let Some(audio) = segmenter.in_progress()
+            {
+                interim_tx.send_replace(Some((next_id, Arc::new(audio.to_vec()))));
+                last_interim = Instant::now();
+            }
+        };
+
+        loop {
+            tokio::select! {
+                samples = audio_rx.recv() => match samples {
+                    Some(samples) => split(&mut segmenter, &samples, false),
+                    None => break,
+                },
+                Some(()) = cut_rx.recv() => split(&mut segmenter, &[], true),
+                // Stop requested, or the handle was dropped
+                _ = &mut stop_rx => break,
+                // Transcription ended early (rejected API key)
+                _ = utterance_tx.closed() => break,
+            }
+        }
+
+        // Release the microphone, then flush what was captured so far
+        let _ = capture_stop.send(());
+        while let Some(samples) = audio_rx.recv().await {
+            split(&mut segmenter, &samples, false);
+        }
+        split(&mut segmenter, &[], true);
+    });
+
+    if interim_every.is_some() {
+        // Interims are raw transcripts: skip the LLM
+        let mut opts = opts.clone();
+        opts.post_process = None;
+        let event_tx = event_tx.clone();
+        tokio::spawn(async move {
+            while interim_rx.changed().await.is_ok() {
+                let Some((id, samples)) = interim_rx.borrow_and_update().clone() else {
+                    continue;
+                };
+                match transcribe_samples(samples.to_vec(), &opts).await {
+                    Ok(result) => {
+                        let _ = event_tx.send(ListenEvent::Interim {
+                            id,
+                            text: result.text,
+                        });
+                    }
+                    Err(e) => crate::verbose!("Skipping interim transcript: {e}"),
+                }
+            }
+        });
+    }
+
+    let opts = opts.clone();
+    let transcriber = tokio::spawn(async move {
+        while let Some((id, utterance)) = utterance_rx.recv().await {
+            let Utterance {
+                samples,
+                start,
+                end,
+            } = utterance;
+            match transcribe_samples(samples, &opts).await {
+                Ok(result) => {
+                    let _ = event_tx.send(ListenEvent::Final {
+                        id,
+                        start,
+                        end,
+                        result,
+                    });
+                }
+                Err(WhisError::NoSpeech) => {
+                    crate::verbose!("No speech in utterance at {:.1}s", start.as_secs_f64());
+                }
+                Err(e) if e.kind() == ErrorKind::Auth => return Err(e),
+                Err(e) => {
+                    crate::warn!(
+                        "Could not transcribe utterance at {:.1}s: {e}",
+                        start.as_secs_f64()
+                    )
+                }
+            }
+        }
+        Ok(())
+    });
+
+    // One task calls back, so interims arriving after their final are dropped
+    let emitter = tokio::spawn(async move {
+        let mut finalized = None;
+        while let Some(event) = event_rx.recv().await {
+            match &event {
+                ListenEvent::Interim { id, .. } if finalized.is_some_and(|done| *id <= done) => {
+                    continue;
+                }
+                ListenEvent::Final { id, .. } => finalized = Some(*id),
+                ListenEvent::Interim { .. } => {}
+            }
+            on_event(event);
+        }
+    });
+
+    Ok(ListenHandle {
+        stop_tx: Some(stop_tx),
+        cut_tx,
+        transcriber,
+        emitter,
+    })
+}
+
+/// Microphone capture running on its own thread
+///
+/// The recorder stays on the thread that created it (the cpal stream isn't
+/// `Send` on every platform); samples arrive through `audio_rx`.
+struct Capture {
+    audio_rx: mpsc::Receiver<Vec<f32>>,
+    /// Stops recording (so `audio_rx` closes)
+    stop_tx: std::sync::mpsc::Sender<()>,
+}
+
+fn start_capture(device_name: Option<String>) -> Result<Capture> {
+    let (ready_tx, ready_rx) = std::sync::mpsc::channel();
+    let (stop_tx, stop_rx) = std::sync::mpsc::channel::<()>();
+
+    std::thread::spawn(move || {
+        let started = AudioRecorder::new().and_then(|mut recorder| {
+            // Samples are consumed from the stream, so the recorder needn't keep them
+            recorder.set_keep_samples(false);
+            let audio_rx =
+                recorder.start_recording_streaming_with_device(device_name.as_deref())?;
+            Ok((recorder, audio_rx))
+        });
+
+        match started {
+            Ok((mut recorder, audio_rx)) => {
+                let _ = ready_tx.send(Ok(audio_rx));
+                // Record until stopped, or until the session is gone
+                let _ = stop_rx.recv();
+                let _ = recorder.stop_recording();
+            }
+            Err(e) => {
+                let _ = ready_tx.send(Err(e));
+            }
+        }
+    });
+
+    let audio_rx = ready_rx
+        .recv()
+        .map_err(|_| anyhow!("Audio capture thread exited unexpectedly"))??;
+    Ok(Capture { audio_rx, stop_tx })
+}
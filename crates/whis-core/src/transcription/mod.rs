@@ -1,28 +1,61 @@
 //! Transcription pipeline and related utilities.
 //!
 //! This module contains:
+//! - High-level embedding API (`TranscribeOptions`, `transcribe_file`, ...)
+//! - Always-listening transcription with a per-utterance callback (`listen`)
 //! - Progressive transcription functions (cloud and local)
+//! - Blank transcript detection (`normalize_transcript`)
+//! - Spoken numbers to digits (`format_spoken_numbers`)
+//! - Short previews of transcripts for UIs (`truncate_at_sentence`)
 //! - Ollama integration for local LLM
 //! - Post-processing with LLM cleanup
+//! - Prompt variable substitution
+//! - Per-stage latency measurement
 //! - Connection warmup utilities
 
+mod api;
+mod listen;
+mod no_speech;
+mod numerals;
 mod ollama;
 mod ollama_manager;
 mod post_processing;
+mod post_processing_chunks;
+mod preview;
+mod prompt_template;
+mod timing;
 mod transcribe;
 mod warmup;
 
+pub use api::{
+    DEFAULT_RECORD_DURATION, TranscribeOptions, record_and_transcribe, record_and_transcribe_until,
+    transcribe_samples, transcribe_samples_with_timeout,
+};
+#[cfg(feature = "wav")]
+pub use api::{transcribe_file, transcribe_file_with_timeout};
+pub use listen::{ListenEvent, ListenHandle, listen, listen_with_events};
+pub use no_speech::normalize_transcript;
+pub use numerals::{format_spoken_numbers, formats_numbers_locally};
 pub use ollama::{
     DEFAULT_OLLAMA_MODEL, DEFAULT_OLLAMA_URL, OLLAMA_MODEL_OPTIONS, OllamaModel,
-    ensure_ollama_ready, ensure_ollama_running, has_model, is_ollama_installed, is_ollama_running,
-    list_models, pull_model, pull_model_with_progress,
+    ensure_ollama_ready, ensure_ollama_ready_with_progress, ensure_ollama_running, has_model,
+    is_ollama_installed, is_ollama_running, list_models, pull_model, pull_model_with_progress,
 };
 pub use ollama_manager::{clear_warmup_cache, preload_ollama};
 pub use post_processing::{
-    DEFAULT_POST_PROCESSING_PROMPT, PostProcessConfig, PostProcessor, post_process,
+    ChunkingConfig, DEFAULT_POST_PROCESSING_PROMPT, PostProcessConfig, PostProcessor, post_process,
     resolve_post_processor_config,
 };
-pub use transcribe::progressive_transcribe_cloud;
+pub use preview::truncate_at_sentence;
+pub use prompt_template::{
+    MAX_CLIPBOARD_CONTEXT_CHARS, PROMPT_VARIABLES, PromptVariables, render_prompt, strip_context,
+    truncate_context, uses_variable, with_clipboard_context,
+};
+pub use timing::StageTimings;
 #[cfg(feature = "local-transcription")]
 pub use transcribe::progressive_transcribe_local;
+pub use transcribe::{
+    PartialTranscriptCallback, progressive_transcribe_cloud,
+    progressive_transcribe_cloud_with_partials, progressive_transcribe_cloud_with_timings,
+};
 pub use warmup::{WarmupConfig, warmup_configured};
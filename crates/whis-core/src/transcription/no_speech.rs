@@ -0,0 +1,52 @@
+//! Detecting transcripts without speech.
+//!
+//! Silent audio comes back differently per backend: most cloud APIs return an
+//! empty or whitespace-only string, while whisper.cpp emits marker tokens such
+//! as `[BLANK_AUDIO]`. `normalize_transcript` maps all of these to `None`, so
+//! callers can report `WhisError::NoSpeech` instead of outputting nothing.
+
+/// Tokens local models emit for non-speech segments
+const NO_SPEECH_MARKERS: &[&str] = &[
+    "[BLANK_AUDIO]",
+    "[ Silence ]",
+    "[silence]",
+    "[SILENCE]",
+    "(silence)",
+];
+
+/// Trimmed transcript, or `None` if it's blank or only no-speech markers
+pub fn normalize_transcript(text: &str) -> Option<String> {
+    let text = text.trim();
+    let blank = NO_SPEECH_MARKERS
+        .iter()
+        .fold(text.to_string(), |rest, marker| rest.replace(marker, ""))
+        .trim()
+        .is_empty();
+    (!blank).then(|| text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blank_transcripts() {
+        assert_eq!(normalize_transcript(""), None);
+        assert_eq!(normalize_transcript("  \n\t "), None);
+        assert_eq!(normalize_transcript("[BLANK_AUDIO]"), None);
+        assert_eq!(normalize_transcript(" [BLANK_AUDIO] [BLANK_AUDIO]\n"), None);
+        assert_eq!(normalize_transcript("(silence)"), None);
+    }
+
+    #[test]
+    fn test_speech_is_kept() {
+        assert_eq!(
+            normalize_transcript("  Hello world. \n"),
+            Some("Hello world.".to_string())
+        );
+        assert_eq!(
+            normalize_transcript("[BLANK_AUDIO] Hello"),
+            Some("[BLANK_AUDIO] Hello".to_string())
+        );
+    }
+}
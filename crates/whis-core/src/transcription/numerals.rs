@@ -0,0 +1,593 @@
+//! Spoken numbers to digits ("twenty twenty four" → "2024")
+//!
+//! Local fallback for `transcription.format_numbers` on providers that can't
+//! format numbers themselves (Deepgram does, with `numerals=true`). English
+//! only; converts:
+//! - Cardinals: "one hundred and twenty three" → "123", "three million" → "3,000,000"
+//! - Years: "nineteen eighty four" → "1984", "twenty twenty four" → "2024"
+//! - Ordinals: "twenty first" → "21st", "one hundredth" → "100th"
+//! - Decimals: "three point one four" → "3.14"
+//! - Money and percentages: "five dollars and fifty cents" → "$5.50",
+//!   "ten euros" → "€10", "fifteen percent" → "15%"
+//!
+//! Lone numbers and ordinals below ten ("one of them", "the first time")
+//! stay words, as most style guides spell them out. Punctuation ends a
+//! number, so "twenty, four" stays two numbers.
+
+use crate::configuration::TranscriptionProvider;
+
+const UNITS: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+const TEENS: [&str; 10] = [
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+const TENS: [&str; 8] = [
+    "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+const ORDINAL_UNITS: [&str; 10] = [
+    "zeroth", "first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth", "ninth",
+];
+const ORDINAL_TEENS: [&str; 10] = [
+    "tenth",
+    "eleventh",
+    "twelfth",
+    "thirteenth",
+    "fourteenth",
+    "fifteenth",
+    "sixteenth",
+    "seventeenth",
+    "eighteenth",
+    "nineteenth",
+];
+const ORDINAL_TENS: [&str; 8] = [
+    "twentieth",
+    "thirtieth",
+    "fortieth",
+    "fiftieth",
+    "sixtieth",
+    "seventieth",
+    "eightieth",
+    "ninetieth",
+];
+
+/// Currency words and the symbol written before the amount
+const CURRENCIES: [(&str, &str); 4] = [
+    ("dollar", "$"),
+    ("dollars", "$"),
+    ("euro", "€"),
+    ("euros", "€"),
+];
+
+/// Whether `provider`'s transcripts in `language` need [`format_spoken_numbers`]
+///
+/// False for providers that format numbers themselves and for languages
+/// other than English (auto-detect counts as English).
+pub fn formats_numbers_locally(provider: &TranscriptionProvider, language: Option<&str>) -> bool {
+    !provider.formats_numerals() && language.is_none_or(|lang| lang.eq_ignore_ascii_case("en"))
+}
+
+/// Write spoken numbers in `text` as digits
+pub fn format_spoken_numbers(text: &str) -> String {
+    let words = split_words(text);
+    let mut out = String::with_capacity(text.len());
+    out.push_str(&text[..text.len() - text.trim_start().len()]);
+
+    let mut i = 0;
+    while i < words.len() {
+        match convert_at(&words, i) {
+            Some((digits, end)) => {
+                let last = &words[end - 1];
+                out.push_str(words[i].prefix);
+                out.push_str(&digits);
+                out.push_str(last.suffix);
+                out.push_str(last.space);
+                i = end;
+            }
+            None => {
+                let word = &words[i];
+                out.push_str(word.prefix);
+                out.push_str(word.core);
+                out.push_str(word.suffix);
+                out.push_str(word.space);
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+/// A whitespace-separated word, split around its letters and digits
+struct Word<'a> {
+    /// Leading punctuation, e.g. `(`
+    prefix: &'a str,
+    core: &'a str,
+    /// Trailing punctuation, e.g. `,` or `.`
+    suffix: &'a str,
+    /// Whitespace after the word
+    space: &'a str,
+}
+
+impl Word<'_> {
+    fn is(&self, candidates: &[&str]) -> bool {
+        self.prefix.is_empty() && candidates.contains(&self.core.to_lowercase().as_str())
+    }
+}
+
+fn split_words(text: &str) -> Vec<Word<'_>> {
+    let mut words = Vec::new();
+    let mut rest = text.trim_start();
+    while !rest.is_empty() {
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let (word, after) = rest.split_at(end);
+        let space_end = after
+            .find(|c: char| !c.is_whitespace())
+            .unwrap_or(after.len());
+        let (space, next) = after.split_at(space_end);
+
+        let (prefix, core, suffix) = match word.find(char::is_alphanumeric) {
+            Some(start) => {
+                let last = word.rfind(char::is_alphanumeric).unwrap_or(start);
+                let end = last + word[last..].chars().next().map_or(1, char::len_utf8);
+                (&word[..start], &word[start..end], &word[end..])
+            }
+            None => (word, "", ""),
+        };
+        words.push(Word {
+            prefix,
+            core,
+            suffix,
+            space,
+        });
+        rest = next;
+    }
+    words
+}
+
+/// Digits for the number starting at word `i`, and the index past its last word
+fn convert_at(words: &[Word], i: usize) -> Option<(String, usize)> {
+    let (number, mut end) = parse_number(words, i)?;
+    let mut digits = number.digits();
+    let mut unit = false;
+
+    // "fifteen percent", "fifteen per cent"
+    let open = words[end - 1].suffix.is_empty();
+    if open && !number.ordinal && words.get(end).is_some_and(|w| w.is(&["percent"])) {
+        digits.push('%');
+        end += 1;
+        unit = true;
+    } else if open
+        && !number.ordinal
+        && words
+            .get(end)
+            .is_some_and(|w| w.is(&["per"]) && w.suffix.is_empty())
+        && words.get(end + 1).is_some_and(|w| w.is(&["cent"]))
+    {
+        digits.push('%');
+        end += 2;
+        unit = true;
+    } else if open
+        && !number.ordinal
+        && let Some(symbol) = words.get(end).and_then(currency_symbol)
+    {
+        end += 1;
+        // "five dollars (and) fifty cents"
+        if number.decimals.is_empty()
+            && let Some((cents, cents_end)) = parse_cents(words, end)
+        {
+            digits.push_str(&format!(".{cents:02}"));
+            end = cents_end;
+        }
+        digits.insert_str(0, symbol);
+        unit = true;
+    }
+
+    // Lone "one" or "first" reads better as a word
+    if !unit && number.atoms == 1 && number.value() < 10 && number.decimals.is_empty() {
+        return None;
+    }
+    Some((digits, end))
+}
+
+fn currency_symbol(word: &Word) -> Option<&'static str> {
+    let core = word.core.to_lowercase();
+    CURRENCIES
+        .iter()
+        .find(|(name, _)| word.prefix.is_empty() && *name == core)
+        .map(|(_, symbol)| *symbol)
+}
+
+/// "(and) fifty cents" starting at word `i`
+fn parse_cents(words: &[Word], i: usize) -> Option<(u64, usize)> {
+    if !words.get(i - 1)?.suffix.is_empty() {
+        return None;
+    }
+    let start = match words.get(i) {
+        Some(word) if word.is(&["and"]) && word.suffix.is_empty() => i + 1,
+        _ => i,
+    };
+    let (cents, end) = parse_number(words, start)?;
+    let value = cents.value();
+    let is_cents = words[end - 1].suffix.is_empty()
+        && words.get(end).is_some_and(|w| w.is(&["cent", "cents"]));
+    (is_cents && value < 100 && !cents.ordinal && cents.decimals.is_empty())
+        .then_some((value, end + 1))
+}
+
+/// Longest number starting at word `i`, and the index past its last word
+fn parse_number(words: &[Word], i: usize) -> Option<(Number, usize)> {
+    let mut number = Number::default();
+    let mut best = None;
+
+    for (j, word) in words.iter().enumerate().skip(i) {
+        if j > i && !word.prefix.is_empty() {
+            break;
+        }
+        let Some(atoms) = word_atoms(&word.core.to_lowercase()) else {
+            break;
+        };
+        // A word is all number or none of it ("twenty-four", not "twenty-ish")
+        let mut next = number.clone();
+        if !atoms
+            .into_iter()
+            .all(|(atom, ordinal)| next.feed(atom, ordinal))
+        {
+            break;
+        }
+        number = next;
+        if number.is_complete() {
+            best = Some((number.clone(), j + 1));
+        }
+        if !word.suffix.is_empty() || number.ordinal {
+            break;
+        }
+    }
+    best
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Atom {
+    /// zero to nine
+    Unit(u64),
+    /// ten to nineteen
+    Teen(u64),
+    /// twenty, thirty, ... ninety
+    Tens(u64),
+    Hundred,
+    /// thousand, million, billion
+    Scale(u64),
+    And,
+    Point,
+}
+
+/// Number words in `word` (split on hyphens), each flagged if it's an ordinal
+fn word_atoms(word: &str) -> Option<Vec<(Atom, bool)>> {
+    let parts: Vec<&str> = word.split('-').collect();
+    let mut atoms = Vec::with_capacity(parts.len());
+    for (index, part) in parts.iter().enumerate() {
+        let (atom, ordinal) = atom(part)?;
+        // Only the last part of a hyphenated word can be an ordinal, and
+        // "and"/"point" stand alone
+        let last = index + 1 == parts.len();
+        if (ordinal && !last) || (parts.len() > 1 && matches!(atom, Atom::And | Atom::Point)) {
+            return None;
+        }
+        atoms.push((atom, ordinal));
+    }
+    Some(atoms)
+}
+
+fn atom(word: &str) -> Option<(Atom, bool)> {
+    if let Some(n) = position(&UNITS, word) {
+        return Some((Atom::Unit(n), false));
+    }
+    if let Some(n) = position(&TEENS, word) {
+        return Some((Atom::Teen(10 + n), false));
+    }
+    if let Some(n) = position(&TENS, word) {
+        return Some((Atom::Tens(20 + 10 * n), false));
+    }
+    if let Some(n) = position(&ORDINAL_UNITS, word) {
+        return Some((Atom::Unit(n), true));
+    }
+    if let Some(n) = position(&ORDINAL_TEENS, word) {
+        return Some((Atom::Teen(10 + n), true));
+    }
+    if let Some(n) = position(&ORDINAL_TENS, word) {
+        return Some((Atom::Tens(20 + 10 * n), true));
+    }
+    let atom = match word {
+        "hundred" => (Atom::Hundred, false),
+        "hundredth" => (Atom::Hundred, true),
+        "thousand" => (Atom::Scale(1_000), false),
+        "thousandth" => (Atom::Scale(1_000), true),
+        "million" => (Atom::Scale(1_000_000), false),
+        "millionth" => (Atom::Scale(1_000_000), true),
+        "billion" => (Atom::Scale(1_000_000_000), false),
+        "billionth" => (Atom::Scale(1_000_000_000), true),
+        "and" => (Atom::And, false),
+        "point" => (Atom::Point, false),
+        _ => return None,
+    };
+    Some(atom)
+}
+
+fn position(list: &[&str], word: &str) -> Option<u64> {
+    list.iter().position(|w| *w == word).map(|i| i as u64)
+}
+
+/// What the last number word was
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Last {
+    #[default]
+    Start,
+    Unit,
+    Teen,
+    Tens,
+    /// "twenty four"
+    TensUnit,
+    Hundred,
+    Scale,
+    And,
+    Point,
+    Decimal,
+}
+
+/// A number being parsed, one word at a time
+#[derive(Debug, Clone, Default)]
+struct Number {
+    /// Sum of completed scales ("three million five" → 3,000,000)
+    total: u64,
+    /// Part below the last scale
+    current: u64,
+    last: Last,
+    /// Largest scale still allowed ("thousand million" isn't a number)
+    scale_limit: Option<u64>,
+    /// First half of a year ("nineteen" in "nineteen eighty four")
+    year_head: Option<u64>,
+    /// Digits after "point"
+    decimals: String,
+    ordinal: bool,
+    /// Number words consumed
+    atoms: usize,
+}
+
+impl Number {
+    /// Add the next number word, or return false if it doesn't continue this number
+    fn feed(&mut self, atom: Atom, ordinal: bool) -> bool {
+        if self.ordinal {
+            return false;
+        }
+        let in_year = self.year_head.is_some();
+        let scale_limit = self.scale_limit;
+
+        let fits = match (self.last, atom) {
+            (Last::Point | Last::Decimal, Atom::Unit(digit)) if !ordinal => {
+                self.decimals.push_str(&digit.to_string());
+                self.last = Last::Decimal;
+                true
+            }
+            (Last::Point | Last::Decimal, _) => false,
+            (
+                Last::Unit | Last::Teen | Last::Tens | Last::TensUnit | Last::Hundred | Last::Scale,
+                Atom::Point,
+            ) if !in_year => {
+                self.last = Last::Point;
+                true
+            }
+            // Years: "nineteen eighty", "twenty twenty", "twenty ten". Only
+            // a teen or twenty starts one, so "thirty forty" stays two numbers
+            (Last::Teen | Last::Tens, Atom::Tens(n) | Atom::Teen(n))
+                if !in_year && self.total == 0 && (11..=20).contains(&self.current) =>
+            {
+                self.year_head = Some(self.current);
+                self.current = n;
+                self.last = tens_or_teen(atom);
+                true
+            }
+            (Last::Start | Last::Hundred | Last::Scale | Last::And, Atom::Unit(n)) => {
+                self.current += n;
+                self.last = Last::Unit;
+                true
+            }
+            (
+                Last::Start | Last::Hundred | Last::Scale | Last::And,
+                Atom::Teen(n) | Atom::Tens(n),
+            ) => {
+                self.current += n;
+                self.last = tens_or_teen(atom);
+                true
+            }
+            (Last::Tens, Atom::Unit(n)) if n > 0 => {
+                self.current += n;
+                self.last = Last::TensUnit;
+                true
+            }
+            (Last::Unit | Last::Teen | Last::Tens | Last::TensUnit, Atom::Hundred)
+                if !in_year && self.current > 0 && self.current < 100 =>
+            {
+                self.current *= 100;
+                self.last = Last::Hundred;
+                true
+            }
+            (
+                Last::Unit | Last::Teen | Last::Tens | Last::TensUnit | Last::Hundred,
+                Atom::Scale(scale),
+            ) if !in_year && self.current > 0 && scale_limit.is_none_or(|limit| scale < limit) => {
+                self.total += self.current * scale;
+                self.current = 0;
+                self.scale_limit = Some(scale);
+                self.last = Last::Scale;
+                true
+            }
+            (Last::Hundred | Last::Scale, Atom::And) => {
+                self.last = Last::And;
+                true
+            }
+            _ => false,
+        };
+
+        if fits {
+            self.atoms += 1;
+            self.ordinal = ordinal;
+        }
+        fits
+    }
+
+    fn is_complete(&self) -> bool {
+        !matches!(self.last, Last::Start | Last::And | Last::Point)
+    }
+
+    fn value(&self) -> u64 {
+        match self.year_head {
+            Some(head) => head * 100 + self.current,
+            None => self.total + self.current,
+        }
+    }
+
+    fn digits(&self) -> String {
+        let value = self.value();
+        // Years and four-digit numbers read better without a separator
+        let mut digits = if self.year_head.is_some() || value < 10_000 {
+            value.to_string()
+        } else {
+            group_thousands(value)
+        };
+        if !self.decimals.is_empty() {
+            digits.push('.');
+            digits.push_str(&self.decimals);
+        }
+        if self.ordinal {
+            digits.push_str(ordinal_suffix(value));
+        }
+        digits
+    }
+}
+
+fn tens_or_teen(atom: Atom) -> Last {
+    match atom {
+        Atom::Tens(_) => Last::Tens,
+        _ => Last::Teen,
+    }
+}
+
+/// 3500000 → "3,500,000"
+fn group_thousands(value: u64) -> String {
+    let digits = value.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+fn ordinal_suffix(value: u64) -> &'static str {
+    match (value % 10, value % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_converts_cardinals() {
+        assert_eq!(format_spoken_numbers("one hundred twenty three"), "123");
+        assert_eq!(
+            format_spoken_numbers("one hundred and five people"),
+            "105 people"
+        );
+        assert_eq!(format_spoken_numbers("Twenty-four hours."), "24 hours.");
+        assert_eq!(
+            format_spoken_numbers("three million five hundred thousand"),
+            "3,500,000"
+        );
+        assert_eq!(format_spoken_numbers("twelve thousand"), "12,000");
+        assert_eq!(format_spoken_numbers("ten minutes"), "10 minutes");
+    }
+
+    #[test]
+    fn test_converts_years() {
+        assert_eq!(format_spoken_numbers("twenty twenty four"), "2024");
+        assert_eq!(format_spoken_numbers("in nineteen eighty four"), "in 1984");
+        assert_eq!(format_spoken_numbers("two thousand and ten"), "2010");
+        assert_eq!(format_spoken_numbers("nineteen hundred"), "1900");
+    }
+
+    #[test]
+    fn test_keeps_tens_pairs_apart() {
+        assert_eq!(format_spoken_numbers("thirty forty people"), "30 40 people");
+        assert_eq!(format_spoken_numbers("fifty sixty"), "50 60");
+        assert_eq!(format_spoken_numbers("ninety nineteen"), "90 19");
+        assert_eq!(format_spoken_numbers("twenty thirty"), "2030");
+    }
+
+    #[test]
+    fn test_converts_ordinals() {
+        assert_eq!(
+            format_spoken_numbers("the twenty first of May"),
+            "the 21st of May"
+        );
+        assert_eq!(format_spoken_numbers("forty-second street"), "42nd street");
+        assert_eq!(format_spoken_numbers("eleventh hour"), "11th hour");
+        assert_eq!(format_spoken_numbers("one hundredth"), "100th");
+    }
+
+    #[test]
+    fn test_converts_decimals() {
+        assert_eq!(format_spoken_numbers("three point one four"), "3.14");
+        assert_eq!(format_spoken_numbers("zero point five"), "0.5");
+    }
+
+    #[test]
+    fn test_converts_money_and_percentages() {
+        assert_eq!(format_spoken_numbers("twenty five dollars"), "$25");
+        assert_eq!(format_spoken_numbers("one dollar"), "$1");
+        assert_eq!(
+            format_spoken_numbers("five dollars and fifty cents."),
+            "$5.50."
+        );
+        assert_eq!(format_spoken_numbers("ten euros five cents"), "€10.05");
+        assert_eq!(format_spoken_numbers("fifteen percent"), "15%");
+        assert_eq!(format_spoken_numbers("five per cent"), "5%");
+    }
+
+    #[test]
+    fn test_keeps_small_numbers_as_words() {
+        assert_eq!(format_spoken_numbers("one of them"), "one of them");
+        assert_eq!(format_spoken_numbers("the first time"), "the first time");
+        assert_eq!(format_spoken_numbers("no one knows"), "no one knows");
+    }
+
+    #[test]
+    fn test_leaves_other_text_alone() {
+        assert_eq!(format_spoken_numbers("twenty, four"), "20, four");
+        assert_eq!(
+            format_spoken_numbers("a hundred and one"),
+            "a hundred and one"
+        );
+        assert_eq!(format_spoken_numbers("rock and roll"), "rock and roll");
+        assert_eq!(
+            format_spoken_numbers("  spaced\n out  "),
+            "  spaced\n out  "
+        );
+        assert_eq!(format_spoken_numbers(""), "");
+    }
+}
@@ -68,13 +68,29 @@ impl OllamaModel {
 /// Progress response from Ollama pull API (streaming NDJSON)
 #[derive(Debug, Deserialize)]
 struct PullProgress {
+    #[serde(default)]
     status: String,
     #[serde(default)]
+    error: Option<String>,
+    #[serde(default)]
     completed: u64,
     #[serde(default)]
     total: u64,
 }
 
+/// Convert a configured keep_alive value into its JSON request form
+///
+/// Ollama parses string values as Go durations, which rejects bare numbers
+/// like "-1". Plain integers are therefore sent as numbers (seconds, negative
+/// meaning forever), everything else ("5m", "1h") as a string.
+pub(crate) fn keep_alive_json(keep_alive: &str) -> serde_json::Value {
+    let keep_alive = keep_alive.trim();
+    match keep_alive.parse::<i64>() {
+        Ok(secs) => serde_json::Value::from(secs),
+        Err(_) => serde_json::Value::from(keep_alive),
+    }
+}
+
 /// Check if Ollama is reachable at the given URL
 ///
 /// Returns `Ok(true)` if connected successfully, or an error with details about why
@@ -101,6 +117,20 @@ fn is_flatpak() -> bool {
     std::path::Path::new("/.flatpak-info").exists()
 }
 
+/// Check if the `ollama` CLI can be invoked from this process
+///
+/// Unlike [`is_ollama_installed`], this never assumes the binary exists inside
+/// a sandbox, so it is safe to use before shelling out.
+fn has_ollama_cli() -> bool {
+    !is_flatpak()
+        && Command::new("ollama")
+            .arg("--version")
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .is_ok()
+}
+
 /// Check if Ollama binary is installed
 pub fn is_ollama_installed() -> bool {
     // In Flatpak, we can't check for host binaries
@@ -257,31 +287,35 @@ pub fn list_models(url: &str) -> Result<Vec<OllamaModel>> {
 
 /// Pull a model from Ollama registry
 ///
-/// Note: Uses the ollama CLI which displays its own progress output.
+/// Uses the Ollama HTTP API, so no `ollama` binary is required.
 /// Callers should print appropriate status messages with bracket notation.
-pub fn pull_model(_url: &str, model: &str) -> Result<()> {
-    // Use ollama CLI for pulling (better progress display)
-    let status = Command::new("ollama")
-        .args(["pull", model])
-        .status()
-        .context("Failed to run ollama pull")?;
-
-    if !status.success() {
-        return Err(anyhow!("Failed to pull model '{}'", model));
-    }
-
-    Ok(())
+pub fn pull_model(url: &str, model: &str) -> Result<()> {
+    pull_model_with_progress(url, model, |_, _| {})
 }
 
 /// Pull a model from Ollama registry with progress callback
 ///
 /// Uses the Ollama HTTP API for streaming progress updates.
 /// Calls `on_progress(completed_bytes, total_bytes)` during download.
+/// If the HTTP pull fails and the `ollama` CLI is available, falls back to
+/// `ollama pull` (which displays its own progress output).
 pub fn pull_model_with_progress(
     url: &str,
     model: &str,
     on_progress: impl Fn(u64, u64),
 ) -> Result<()> {
+    match pull_model_http(url, model, on_progress) {
+        Ok(()) => Ok(()),
+        Err(e) if has_ollama_cli() => {
+            crate::verbose!("HTTP pull failed ({}), falling back to ollama CLI", e);
+            pull_model_cli(model)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Pull a model via the streaming `/api/pull` endpoint
+fn pull_model_http(url: &str, model: &str, on_progress: impl Fn(u64, u64)) -> Result<()> {
     use std::io::BufRead;
 
     let client = reqwest::blocking::Client::builder()
@@ -307,6 +341,7 @@ pub fn pull_model_with_progress(
 
     // Stream the response line by line (NDJSON format)
     let reader = std::io::BufReader::new(response);
+    let mut succeeded = false;
     for line in reader.lines() {
         let line = line.context("Failed to read response")?;
         if line.is_empty() {
@@ -315,30 +350,68 @@ pub fn pull_model_with_progress(
 
         // Parse the JSON progress
         if let Ok(progress) = serde_json::from_str::<PullProgress>(&line) {
+            // Ollama reports failures mid-stream as {"error": "..."}
+            if let Some(error) = progress.error {
+                return Err(anyhow!("Pull failed: {}", error));
+            }
+            if progress.status.contains("error") {
+                return Err(anyhow!("Pull failed: {}", progress.status));
+            }
+
             // Report progress when we have total size info
             if progress.total > 0 {
                 on_progress(progress.completed, progress.total);
             }
 
-            // Check for error status
-            if progress.status.contains("error") {
-                return Err(anyhow!("Pull failed: {}", progress.status));
+            if progress.status == "success" {
+                succeeded = true;
             }
         }
     }
 
+    if !succeeded {
+        return Err(anyhow!("Pull of '{}' ended before completing", model));
+    }
+
+    Ok(())
+}
+
+/// Pull a model using the `ollama` CLI
+fn pull_model_cli(model: &str) -> Result<()> {
+    let status = Command::new("ollama")
+        .args(["pull", model])
+        .status()
+        .context("Failed to run ollama pull")?;
+
+    if !status.success() {
+        return Err(anyhow!("Failed to pull model '{}'", model));
+    }
+
     Ok(())
 }
 
 /// Ensure Ollama is running and has the specified model
 pub fn ensure_ollama_ready(url: &str, model: &str) -> Result<()> {
+    ensure_ollama_ready_with_progress(url, model, |_, _| {}).map(|_| ())
+}
+
+/// Ensure Ollama is running and has the specified model, reporting pull progress
+///
+/// Returns `Ok(true)` if the model had to be pulled, `Ok(false)` if it was
+/// already available.
+pub fn ensure_ollama_ready_with_progress(
+    url: &str,
+    model: &str,
+    on_progress: impl Fn(u64, u64),
+) -> Result<bool> {
     // Start Ollama if needed
     ensure_ollama_running(url)?;
 
     // Check if model is available
-    if !has_model(url, model)? {
-        pull_model(url, model)?;
+    if has_model(url, model)? {
+        return Ok(false);
     }
 
-    Ok(())
+    pull_model_with_progress(url, model, on_progress)?;
+    Ok(true)
 }
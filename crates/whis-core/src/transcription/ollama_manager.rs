@@ -118,7 +118,7 @@ fn warm_model(server_url: &str, model: &str, keep_alive: &str) -> Result<(), Str
             "model": model,
             "messages": [],
             "stream": false,
-            "keep_alive": keep_alive
+            "keep_alive": super::ollama::keep_alive_json(keep_alive)
         }))
         .send()
         .map_err(|e| {
@@ -10,6 +10,10 @@
 //! - **Ollama** - Local LLMs (no API key required, just server URL)
 //! - **None** - Pass through without processing
 //!
+//! Transcripts longer than the chunk threshold (see [`ChunkingConfig`]) are
+//! processed in sentence-aligned chunks and stitched back together, so long
+//! dictation stays within the model's context.
+//!
 //! # Usage
 //!
 //! ```ignore
@@ -21,19 +25,60 @@
 //!     "sk-...",
 //!     "Clean up this transcript",
 //!     None,
+//!     None,
+//!     ChunkingConfig::default(),
 //! ).await?;
 //! ```
 
 use anyhow::{Result, anyhow};
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::future::Future;
 
+use super::post_processing_chunks::{
+    COHESION_PROMPT, chunk_ranges, first_sentence, last_sentence, seam_separator,
+};
+use crate::configuration::DEFAULT_POST_PROCESSING_CHUNK_CHARS;
+use crate::configuration::TranscriptionProvider;
 use crate::http::get_http_client;
+use crate::provider::retry::{RetryConfig, is_retryable_idempotent_error, is_retryable_status};
 
 const OPENAI_CHAT_URL: &str = "https://api.openai.com/v1/chat/completions";
 const MISTRAL_CHAT_URL: &str = "https://api.mistral.ai/v1/chat/completions";
 const DEFAULT_TIMEOUT_SECS: u64 = 60;
 
+/// How long transcripts are split up for post-processing
+///
+/// Each chunk (at most `chunk_chars`, ending at a sentence) is one LLM
+/// request. With `cohesion_pass`, the two sentences meeting at each seam are
+/// sent once more to smooth the transition, one extra request per seam.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkingConfig {
+    /// Transcript length (characters) above which post-processing is
+    /// chunked; 0 always sends the whole transcript
+    pub chunk_chars: usize,
+    /// Smooth the seams between chunks
+    pub cohesion_pass: bool,
+}
+
+impl Default for ChunkingConfig {
+    fn default() -> Self {
+        Self {
+            chunk_chars: DEFAULT_POST_PROCESSING_CHUNK_CHARS,
+            cohesion_pass: false,
+        }
+    }
+}
+
+impl From<&PostProcessingSettings> for ChunkingConfig {
+    fn from(settings: &PostProcessingSettings) -> Self {
+        Self {
+            chunk_chars: settings.chunk_chars,
+            cohesion_pass: settings.cohesion_pass,
+        }
+    }
+}
+
 pub const DEFAULT_POST_PROCESSING_PROMPT: &str = "Clean up this voice transcript. \
 Remove filler words (um, uh, like, you know). \
 Fix grammar and punctuation. Keep technical terms intact. \
@@ -88,6 +133,18 @@ impl PostProcessor {
     pub fn requires_api_key(&self) -> bool {
         matches!(self, PostProcessor::OpenAI | PostProcessor::Mistral)
     }
+
+    /// Provider whose API key this post-processor uses
+    ///
+    /// Keys are stored per provider, so one OpenAI key serves both OpenAI
+    /// transcription and OpenAI post-processing, whichever is used.
+    pub fn api_key_provider(&self) -> Option<TranscriptionProvider> {
+        match self {
+            PostProcessor::OpenAI => Some(TranscriptionProvider::OpenAI),
+            PostProcessor::Mistral => Some(TranscriptionProvider::Mistral),
+            PostProcessor::None | PostProcessor::Ollama => None,
+        }
+    }
 }
 
 /// Configuration for post-processing a transcript.
@@ -104,8 +161,10 @@ pub struct PostProcessConfig {
     pub api_key_or_url: String,
     /// Ollama model name (only used when processor is Ollama)
     pub ollama_model: Option<String>,
-    /// Ollama keep_alive duration (only used when processor is Ollama)
-    pub ollama_keep_alive: String,
+    /// Ollama service settings: keep_alive and system prompt (only used when processor is Ollama)
+    pub ollama: OllamaConfig,
+    /// Splitting of long transcripts
+    pub chunking: ChunkingConfig,
 }
 
 #[derive(Debug, Deserialize)]
@@ -126,19 +185,126 @@ struct Message {
 /// Post-process (clean up) a transcript using the specified LLM provider
 ///
 /// For cloud providers (OpenAI, Mistral), `api_key_or_url` is the API key.
-/// For Ollama, `api_key_or_url` is the server URL (e.g., http://localhost:11434).
+/// For Ollama, `api_key_or_url` is the server URL (e.g., http://localhost:11434),
+/// and `ollama` supplies keep_alive and the system prompt (defaults apply when
+/// `None`). Ollama sends `prompt` as the instruction in the user message;
+/// cloud providers use it as the system prompt and ignore `ollama`.
+///
+/// Long transcripts are processed in chunks, see [`ChunkingConfig`].
 pub async fn post_process(
     text: &str,
     post_processor: &PostProcessor,
     api_key_or_url: &str,
     prompt: &str,
     model: Option<&str>,
+    ollama: Option<&OllamaConfig>,
+    chunking: ChunkingConfig,
+) -> Result<String> {
+    if *post_processor == PostProcessor::None {
+        return Ok(text.to_string());
+    }
+    post_process_chunked(text, chunking, |chunk, cohesion| async move {
+        let prompt = if cohesion { COHESION_PROMPT } else { prompt };
+        post_process_once(
+            &chunk,
+            post_processor,
+            api_key_or_url,
+            prompt,
+            model,
+            ollama,
+        )
+        .await
+    })
+    .await
+}
+
+/// Run `process` on `text`, in chunks if it's longer than `chunking` allows
+///
+/// `process` gets the text to clean up and whether it's a cohesion pass
+/// over a seam (which uses its own prompt).
+async fn post_process_chunked<F, Fut>(
+    text: &str,
+    chunking: ChunkingConfig,
+    mut process: F,
+) -> Result<String>
+where
+    F: FnMut(String, bool) -> Fut,
+    Fut: Future<Output = Result<String>>,
+{
+    let threshold = chunking.chunk_chars;
+    if threshold == 0 || text.chars().count() <= threshold {
+        return process(text.to_string(), false).await;
+    }
+
+    let ranges = chunk_ranges(text, threshold);
+    crate::verbose!(
+        "Post-processing {} characters in {} chunks",
+        text.chars().count(),
+        ranges.len()
+    );
+    let mut chunks = Vec::with_capacity(ranges.len());
+    let mut separators = Vec::with_capacity(ranges.len());
+    for (i, range) in ranges.iter().enumerate() {
+        let processed = process(text[range.clone()].to_string(), false)
+            .await
+            .map_err(|e| e.context(format!("chunk {} of {}", i + 1, ranges.len())))?;
+        chunks.push(processed.trim().to_string());
+        if let Some(next) = ranges.get(i + 1) {
+            separators.push(seam_separator(&text[range.end..next.start]));
+        }
+    }
+
+    if chunking.cohesion_pass {
+        for i in 1..chunks.len() {
+            // Rewrite the sentence ending the previous chunk together with
+            // the one starting this chunk
+            let (Some(tail), Some(head)) =
+                (last_sentence(&chunks[i - 1]), first_sentence(&chunks[i]))
+            else {
+                continue;
+            };
+            let seam = format!(
+                "{}{}{}",
+                &chunks[i - 1][tail.clone()],
+                separators[i - 1],
+                &chunks[i][head.clone()]
+            );
+            let smoothed = process(seam, true).await?;
+            chunks[i - 1].replace_range(tail.start.., smoothed.trim());
+            chunks[i] = chunks[i][head.end..].trim_start().to_string();
+        }
+    }
+
+    // Chunks emptied by the cohesion pass drop out along with their separator
+    let mut stitched = String::with_capacity(text.len());
+    for (i, chunk) in chunks.iter().enumerate() {
+        if chunk.is_empty() {
+            continue;
+        }
+        if !stitched.is_empty() {
+            stitched.push_str(separators[i - 1]);
+        }
+        stitched.push_str(chunk);
+    }
+    Ok(stitched)
+}
+
+/// Post-process `text` in a single request
+async fn post_process_once(
+    text: &str,
+    post_processor: &PostProcessor,
+    api_key_or_url: &str,
+    prompt: &str,
+    model: Option<&str>,
+    ollama: Option<&OllamaConfig>,
 ) -> Result<String> {
     match post_processor {
         PostProcessor::None => Ok(text.to_string()),
         PostProcessor::OpenAI => post_process_openai(text, api_key_or_url, prompt, model).await,
         PostProcessor::Mistral => post_process_mistral(text, api_key_or_url, prompt, model).await,
-        PostProcessor::Ollama => post_process_ollama(text, api_key_or_url, prompt, model).await,
+        PostProcessor::Ollama => {
+            post_process_ollama(text, api_key_or_url, prompt, model, ollama).await
+        }
     }
 }
 
@@ -216,9 +382,11 @@ async fn post_process_mistral(
         .ok_or_else(|| anyhow!("No response from Mistral"))
 }
 
-use super::ollama::{DEFAULT_OLLAMA_MODEL, DEFAULT_OLLAMA_URL, ensure_ollama_running};
+use super::ollama::{
+    DEFAULT_OLLAMA_MODEL, DEFAULT_OLLAMA_URL, ensure_ollama_running, keep_alive_json,
+};
 use crate::configuration::Preset;
-use crate::settings::Settings;
+use crate::settings::{OllamaConfig, PostProcessingSettings, Settings};
 
 /// Ollama API response structure
 #[derive(Debug, Deserialize)]
@@ -231,52 +399,139 @@ struct OllamaMessage {
     content: String,
 }
 
+/// Build the user message: the per-run instruction followed by the transcript
+fn ollama_user_message(instruction: &str, text: &str) -> String {
+    format!("{instruction}\n\n<transcript>\n{text}\n</transcript>")
+}
+
+/// Build the request body for Ollama's `/api/chat` endpoint
+fn ollama_chat_body(
+    model: &str,
+    system_prompt: &str,
+    instruction: &str,
+    text: &str,
+    keep_alive: &str,
+) -> serde_json::Value {
+    serde_json::json!({
+        "model": model,
+        "messages": [
+            {"role": "system", "content": system_prompt},
+            {"role": "user", "content": ollama_user_message(instruction, text)}
+        ],
+        "stream": false,
+        "keep_alive": keep_alive_json(keep_alive)
+    })
+}
+
 async fn post_process_ollama(
     text: &str,
     server_url: &str,
-    system_prompt: &str,
+    instruction: &str,
     model: Option<&str>,
+    ollama: Option<&OllamaConfig>,
 ) -> Result<String> {
     let model = model.unwrap_or(DEFAULT_OLLAMA_MODEL);
+    let default_config = OllamaConfig::default();
+    let ollama = ollama.unwrap_or(&default_config);
+    let keep_alive = ollama.keep_alive();
+    let system_prompt = ollama.system_prompt();
     let base_url = if server_url.is_empty() {
         DEFAULT_OLLAMA_URL
     } else {
         server_url
     };
     let url = format!("{}/api/chat", base_url.trim_end_matches('/'));
+    crate::verbose!("Ollama keep_alive: {}", keep_alive);
 
     let client = get_http_client()?;
-    let response = client
-        .post(&url)
-        .json(&serde_json::json!({
-            "model": model,
-            "messages": [
-                {"role": "system", "content": system_prompt},
-                {"role": "user", "content": text}
-            ],
-            "stream": false
-        }))
-        .timeout(std::time::Duration::from_secs(120)) // Longer timeout for local LLM
-        .send()
-        .await
-        .map_err(|e| {
-            if e.is_connect() {
-                anyhow!(
-                    "Cannot connect to Ollama at {}. Is Ollama running? Start with: ollama serve",
-                    base_url
-                )
-            } else {
-                anyhow!("Ollama request failed: {}", e)
+    let body = ollama_chat_body(model, &system_prompt, instruction, text, &keep_alive);
+    let config = ollama_retry_config();
+    let mut attempt = 0;
+
+    loop {
+        let result = client
+            .post(&url)
+            .json(&body)
+            .timeout(std::time::Duration::from_secs(120)) // Longer timeout for local LLM
+            .send()
+            .await;
+
+        let err = match result {
+            Ok(response) => {
+                let status = response.status();
+                match response.text().await {
+                    Ok(response_text) if status.is_success() => {
+                        let ollama_response: OllamaResponse = serde_json::from_str(&response_text)?;
+                        return Ok(ollama_response.message.content.trim().to_string());
+                    }
+                    Ok(error_text) => {
+                        let retryable =
+                            is_retryable_status(status) || is_ollama_loading(&error_text);
+                        if retryable && attempt < config.max_retries {
+                            let delay = config.delay_for_attempt(attempt, false);
+                            crate::verbose!(
+                                "Ollama request failed with {} (attempt {}/{}), retrying in {:?}",
+                                status,
+                                attempt + 1,
+                                config.max_retries,
+                                delay
+                            );
+                            tokio::time::sleep(delay).await;
+                            attempt += 1;
+                            continue;
+                        }
+                        return Err(anyhow!("Ollama post-processing failed: {}", error_text));
+                    }
+                    // Connection dropped while reading the response
+                    Err(e) => e,
+                }
             }
-        })?;
+            Err(e) => e,
+        };
+
+        // Connection refused/reset is common while Ollama is still starting up.
+        // Resending is harmless: Ollama runs locally and costs nothing per request
+        if is_retryable_idempotent_error(&err) && attempt < config.max_retries {
+            let delay = config.delay_for_attempt(attempt, false);
+            crate::verbose!(
+                "Ollama request failed with network error (attempt {}/{}), retrying in {:?}: {}",
+                attempt + 1,
+                config.max_retries,
+                delay,
+                err
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+            continue;
+        }
 
-    if !response.status().is_success() {
-        let error_text = response.text().await?;
-        return Err(anyhow!("Ollama post-processing failed: {}", error_text));
+        return Err(if err.is_connect() {
+            anyhow!(
+                "Cannot connect to Ollama at {}. Is Ollama running? Start with: ollama serve",
+                base_url
+            )
+        } else {
+            anyhow!("Ollama request failed: {}", err)
+        });
+    }
+}
+
+/// Retry settings for Ollama requests
+///
+/// Shorter delays than cloud providers: the usual failure is a local server
+/// that is still starting up or loading the model.
+fn ollama_retry_config() -> RetryConfig {
+    RetryConfig {
+        base_delay_ms: 500,
+        max_delay_ms: 4000,
+        ..RetryConfig::default()
     }
+}
 
-    let ollama_response: OllamaResponse = response.json().await?;
-    Ok(ollama_response.message.content.trim().to_string())
+/// Check if an Ollama error body indicates the model is still loading
+fn is_ollama_loading(error_text: &str) -> bool {
+    let error_text = error_text.to_lowercase();
+    error_text.contains("loading") || error_text.contains("server busy")
 }
 
 /// Resolve post-processing configuration from settings and optional preset.
@@ -314,6 +569,10 @@ pub fn resolve_post_processor_config(
     // Get API key/URL and model based on processor type
     match processor {
         PostProcessor::Ollama => {
+            if let Some(missing) = settings.post_processor_missing(&processor, preset.as_ref()) {
+                return Err(anyhow!(missing));
+            }
+
             // Start Ollama if not running
             let ollama_url = settings
                 .services
@@ -328,42 +587,179 @@ pub fn resolve_post_processor_config(
                 .and_then(|p| p.model.clone())
                 .or_else(|| settings.services.ollama.model());
 
-            if model.is_none() {
-                return Err(anyhow!("Ollama model not configured"));
-            }
-
             Ok((PostProcessor::Ollama, ollama_url, model, prompt))
         }
-        PostProcessor::OpenAI => {
+        PostProcessor::OpenAI | PostProcessor::Mistral => {
+            // The key of the processor actually used (a preset may pick a
+            // different one than settings), whatever transcribes
+            let provider = processor
+                .api_key_provider()
+                .expect("cloud post-processors have an API key provider");
             let api_key = settings
-                .post_processing
-                .api_key(&settings.transcription.api_keys)
+                .transcription
+                .api_key_for(&provider)
                 .ok_or_else(|| {
                     anyhow!(
-                        "OpenAI API key not configured. Set it with: whis config --openai-api-key <key>"
+                        settings
+                            .post_processor_missing(&processor, preset.as_ref())
+                            .unwrap_or_default()
                     )
                 })?;
 
             // Model from preset if available
             let model = preset.as_ref().and_then(|p| p.model.clone());
 
-            Ok((PostProcessor::OpenAI, api_key, model, prompt))
+            Ok((processor, api_key, model, prompt))
         }
-        PostProcessor::Mistral => {
-            let api_key = settings
-                .post_processing
-                .api_key(&settings.transcription.api_keys)
-                .ok_or_else(|| {
-                    anyhow!(
-                        "Mistral API key not configured. Set it with: whis config --mistral-api-key <key>"
-                    )
-                })?;
+        PostProcessor::None => Err(anyhow!("Post-processing not configured. Run: whis setup")),
+    }
+}
 
-            // Model from preset if available
-            let model = preset.as_ref().and_then(|p| p.model.clone());
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ollama_chat_body_keep_alive_duration() {
+        let body = ollama_chat_body("qwen2.5:1.5b", "system", "prompt", "text", "10m");
+        assert_eq!(body["keep_alive"], serde_json::json!("10m"));
+    }
+
+    #[test]
+    fn test_ollama_chat_body_keep_alive_numeric() {
+        let body = ollama_chat_body("qwen2.5:1.5b", "system", "prompt", "text", "-1");
+        assert_eq!(body["keep_alive"], serde_json::json!(-1));
+
+        let body = ollama_chat_body("qwen2.5:1.5b", "system", "prompt", "text", "0");
+        assert_eq!(body["keep_alive"], serde_json::json!(0));
+    }
+
+    #[test]
+    fn test_is_ollama_loading() {
+        assert!(is_ollama_loading(r#"{"error":"model is loading"}"#));
+        assert!(is_ollama_loading(
+            r#"{"error":"server busy, please try again"}"#
+        ));
+        assert!(!is_ollama_loading(r#"{"error":"model 'foo' not found"}"#));
+    }
+
+    #[test]
+    fn test_ollama_chat_body_splits_system_and_instruction() {
+        let body = ollama_chat_body(
+            "qwen2.5:1.5b",
+            "You edit text.",
+            "Fix grammar.",
+            "hello",
+            "5m",
+        );
+        assert_eq!(body["messages"][0]["role"], "system");
+        assert_eq!(body["messages"][0]["content"], "You edit text.");
+        assert_eq!(body["messages"][1]["role"], "user");
+        assert_eq!(
+            body["messages"][1]["content"],
+            "Fix grammar.\n\n<transcript>\nhello\n</transcript>"
+        );
+    }
 
-            Ok((PostProcessor::Mistral, api_key, model, prompt))
+    /// Run [`post_process_chunked`] with a fake LLM that upper-cases text,
+    /// answers seams with `seam_reply` and fails on call `fail_on` (1-based)
+    fn run_chunked(
+        text: &str,
+        chunking: ChunkingConfig,
+        seam_reply: &str,
+        fail_on: Option<usize>,
+    ) -> (Result<String>, Vec<(String, bool)>) {
+        let calls = std::cell::RefCell::new(Vec::new());
+        let process = |chunk: String, cohesion: bool| {
+            calls.borrow_mut().push((chunk.clone(), cohesion));
+            let result = if fail_on == Some(calls.borrow().len()) {
+                Err(anyhow!("rate limited"))
+            } else if cohesion {
+                Ok(seam_reply.to_string())
+            } else {
+                Ok(chunk.to_uppercase())
+            };
+            std::future::ready(result)
+        };
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let result = runtime.block_on(post_process_chunked(text, chunking, process));
+        (result, calls.into_inner())
+    }
+
+    fn chunking(chunk_chars: usize, cohesion_pass: bool) -> ChunkingConfig {
+        ChunkingConfig {
+            chunk_chars,
+            cohesion_pass,
         }
-        PostProcessor::None => Err(anyhow!("Post-processing not configured. Run: whis setup")),
+    }
+
+    #[test]
+    fn test_short_text_is_sent_whole() {
+        let text = "Short enough. Really.";
+        for config in [chunking(100, true), chunking(0, true)] {
+            let (result, calls) = run_chunked(text, config, "unused", None);
+            assert_eq!(result.unwrap(), "SHORT ENOUGH. REALLY.");
+            assert_eq!(calls, [(text.to_string(), false)]);
+        }
+    }
+
+    #[test]
+    fn test_multi_chunk_input_is_stitched_in_order() {
+        let text = "First sentence here. Second one is here. Third! Fourth?";
+        let (result, calls) = run_chunked(text, chunking(42, false), "unused", None);
+
+        assert_eq!(
+            result.unwrap(),
+            "FIRST SENTENCE HERE. SECOND ONE IS HERE. THIRD! FOURTH?"
+        );
+        let chunks: Vec<&str> = calls.iter().map(|(chunk, _)| chunk.as_str()).collect();
+        assert_eq!(
+            chunks,
+            ["First sentence here. Second one is here.", "Third! Fourth?"]
+        );
+    }
+
+    #[test]
+    fn test_paragraph_breaks_survive_stitching() {
+        let text = "Para one.\n\nPara two. Still two.\nLine three.";
+        let (result, _) = run_chunked(text, chunking(10, false), "unused", None);
+        assert_eq!(
+            result.unwrap(),
+            "PARA ONE.\n\nPARA TWO. STILL TWO. LINE THREE."
+        );
+    }
+
+    #[test]
+    fn test_failing_chunk_stops_with_its_position() {
+        let (result, calls) =
+            run_chunked("One. Two. Three.", chunking(6, false), "unused", Some(2));
+
+        let err = format!("{:#}", result.unwrap_err());
+        assert!(err.contains("chunk 2 of 3"), "{err}");
+        assert!(err.contains("rate limited"), "{err}");
+        // Nothing is sent after the failure
+        assert_eq!(calls.len(), 2);
+    }
+
+    #[test]
+    fn test_cohesion_pass_rewrites_seams() {
+        let (result, calls) = run_chunked(
+            "Start here. Ends. Begins. Rest of it.",
+            chunking(18, true),
+            "Ends and begins.",
+            None,
+        );
+
+        assert_eq!(result.unwrap(), "START HERE. Ends and begins. REST OF IT.");
+        assert_eq!(calls.last().unwrap(), &("ENDS. BEGINS.".to_string(), true));
+    }
+
+    #[test]
+    fn test_cohesion_pass_drops_emptied_chunks() {
+        // Each chunk is a single sentence, so the seam swallows the second one
+        let (result, _) = run_chunked("One. Two.", chunking(4, true), "Joined.", None);
+        assert_eq!(result.unwrap(), "Joined.");
     }
 }
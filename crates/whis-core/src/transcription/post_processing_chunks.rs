@@ -0,0 +1,168 @@
+//! Splitting long transcripts for post-processing.
+//!
+//! An hour of dictation can exceed what an LLM takes in (or returns) in one
+//! request. Above a length threshold, the transcript is cleaned up in chunks
+//! that end at sentence boundaries, so no sentence is split between two
+//! requests, and the results are stitched back together. An optional
+//! cohesion pass then smooths each seam by rewriting the sentence pair that
+//! meets there.
+
+use std::ops::Range;
+
+/// Characters that end a sentence when followed by whitespace or the end
+const SENTENCE_ENDS: &[char] = &['.', '!', '?', '…'];
+
+/// Instruction for smoothing the transition between two chunks
+pub(super) const COHESION_PROMPT: &str = "These sentences were edited separately and now \
+follow each other. Make them read naturally together: fix repeated words, broken \
+transitions and inconsistent punctuation where they meet. Change nothing else. \
+Output only the text, no explanations.";
+
+/// Split `text` into chunks of at most `max_chars` characters
+///
+/// Chunks end at sentence ends or line breaks. A single sentence longer than
+/// `max_chars` is split at word boundaries (or mid-word if it has no spaces).
+/// Ranges are byte ranges into `text`, trimmed of surrounding whitespace.
+pub(super) fn chunk_ranges(text: &str, max_chars: usize) -> Vec<Range<usize>> {
+    let max_chars = max_chars.max(1);
+    let mut chunks: Vec<Range<usize>> = Vec::new();
+    for sentence in sentence_ranges(text) {
+        for piece in split_long(text, sentence, max_chars) {
+            match chunks.last_mut() {
+                Some(chunk) if text[chunk.start..piece.end].chars().count() <= max_chars => {
+                    chunk.end = piece.end;
+                }
+                _ => chunks.push(piece),
+            }
+        }
+    }
+    chunks
+}
+
+/// Separator for stitching the chunks on either side of `gap`
+///
+/// Keeps paragraph breaks from the original transcript; anything else
+/// becomes a space.
+pub(super) fn seam_separator(gap: &str) -> &'static str {
+    if gap.matches('\n').count() >= 2 {
+        "\n\n"
+    } else {
+        " "
+    }
+}
+
+/// Byte range of the last sentence of `text`, for the cohesion pass
+pub(super) fn last_sentence(text: &str) -> Option<Range<usize>> {
+    sentence_ranges(text).pop()
+}
+
+/// Byte range of the first sentence of `text`, for the cohesion pass
+pub(super) fn first_sentence(text: &str) -> Option<Range<usize>> {
+    sentence_ranges(text).into_iter().next()
+}
+
+/// Sentences and lines of `text`, as trimmed byte ranges
+fn sentence_ranges(text: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        let end = i + c.len_utf8();
+        let at_boundary = c == '\n'
+            || (SENTENCE_ENDS.contains(&c)
+                && text[end..].chars().next().is_none_or(char::is_whitespace));
+        if at_boundary {
+            push_trimmed(&mut ranges, text, start..end);
+            start = end;
+        }
+    }
+    push_trimmed(&mut ranges, text, start..text.len());
+    ranges
+}
+
+/// Push `range` without surrounding whitespace, unless that leaves nothing
+fn push_trimmed(ranges: &mut Vec<Range<usize>>, text: &str, range: Range<usize>) {
+    let slice = &text[range.clone()];
+    let trimmed = slice.trim_start();
+    let start = range.start + (slice.len() - trimmed.len());
+    let end = start + trimmed.trim_end().len();
+    if start < end {
+        ranges.push(start..end);
+    }
+}
+
+/// Split a sentence longer than `max_chars` at word boundaries
+fn split_long(text: &str, range: Range<usize>, max_chars: usize) -> Vec<Range<usize>> {
+    let mut pieces = Vec::new();
+    let mut rest = range;
+    while text[rest.clone()].chars().count() > max_chars {
+        let slice = &text[rest.clone()];
+        let limit = slice
+            .char_indices()
+            .nth(max_chars)
+            .map_or(slice.len(), |(i, _)| i);
+        // Break before the word that doesn't fit; a single overlong word is cut
+        let cut = if slice[limit..].starts_with(char::is_whitespace) {
+            limit
+        } else {
+            slice[..limit]
+                .rfind(char::is_whitespace)
+                .filter(|&i| i > 0)
+                .unwrap_or(limit)
+        };
+        push_trimmed(&mut pieces, text, rest.start..rest.start + cut);
+        let next = &slice[cut..];
+        rest = rest.start + cut + (next.len() - next.trim_start().len())..rest.end;
+    }
+    push_trimmed(&mut pieces, text, rest);
+    pieces
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunks(text: &str, max_chars: usize) -> Vec<&str> {
+        chunk_ranges(text, max_chars)
+            .into_iter()
+            .map(|range| &text[range])
+            .collect()
+    }
+
+    #[test]
+    fn test_chunks_end_at_sentences() {
+        let text = "First sentence here. Second one is here. Third! Fourth?";
+        assert_eq!(
+            chunks(text, 42),
+            vec!["First sentence here. Second one is here.", "Third! Fourth?"]
+        );
+        assert_eq!(chunks(text, 1000), vec![text]);
+    }
+
+    #[test]
+    fn test_overlong_sentence_splits_at_words() {
+        let text = "one two three four five six";
+        assert_eq!(chunks(text, 10), vec!["one two", "three four", "five six"]);
+        assert_eq!(chunks("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+    }
+
+    #[test]
+    fn test_line_breaks_are_boundaries() {
+        let text = "no punctuation here\n\nnext paragraph starts";
+        let ranges = chunk_ranges(text, 25);
+        assert_eq!(ranges.len(), 2);
+        assert_eq!(&text[ranges[0].clone()], "no punctuation here");
+        assert_eq!(
+            seam_separator(&text[ranges[0].end..ranges[1].start]),
+            "\n\n"
+        );
+        assert_eq!(seam_separator(" "), " ");
+    }
+
+    #[test]
+    fn test_first_and_last_sentence() {
+        let text = "One. Two three. Four…";
+        assert_eq!(&text[first_sentence(text).unwrap()], "One.");
+        assert_eq!(&text[last_sentence(text).unwrap()], "Four…");
+        assert_eq!(last_sentence("  "), None);
+    }
+}
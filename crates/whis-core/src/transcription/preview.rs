@@ -0,0 +1,100 @@
+//! Short transcript previews for UIs.
+//!
+//! Tray tooltips, notifications and the bubble only have room for a line or
+//! two. `truncate_at_sentence` shortens a transcript without cutting words in
+//! half (or splitting a multi-byte character, which byte slicing would).
+
+/// Characters that end a sentence when followed by whitespace or the end
+const SENTENCE_ENDS: &[char] = &['.', '!', '?', '…'];
+
+/// Shorten `text` to at most `max_chars` characters for a preview
+///
+/// Cuts after the last sentence that fits, as long as that keeps at least
+/// half of the allowed length. Otherwise cuts at the last word boundary and
+/// appends `…` (counted in `max_chars`). Text that fits is only trimmed.
+pub fn truncate_at_sentence(text: &str, max_chars: usize) -> String {
+    let text = text.trim();
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+
+    let head = &text[..byte_offset(text, max_chars)];
+    let sentence_end = head
+        .char_indices()
+        .filter(|&(i, c)| {
+            let end = i + c.len_utf8();
+            SENTENCE_ENDS.contains(&c) && text[end..].chars().next().is_none_or(char::is_whitespace)
+        })
+        .map(|(i, c)| i + c.len_utf8())
+        .last();
+    if let Some(end) = sentence_end
+        && text[..end].chars().count() * 2 >= max_chars
+    {
+        return text[..end].to_string();
+    }
+
+    // Leave room for the ellipsis
+    let limit = byte_offset(text, max_chars.saturating_sub(1));
+    let head = &text[..limit];
+    let cut = if text[limit..].starts_with(char::is_whitespace) {
+        head
+    } else {
+        // A single overlong word is cut anyway
+        head.rfind(char::is_whitespace).map_or(head, |i| &head[..i])
+    };
+    format!("{}…", cut.trim_end())
+}
+
+/// Byte offset of character `n` in `text` (or its length)
+fn byte_offset(text: &str, n: usize) -> usize {
+    text.char_indices().nth(n).map_or(text.len(), |(i, _)| i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_text_unchanged() {
+        assert_eq!(truncate_at_sentence("  Hello there.\n", 20), "Hello there.");
+        assert_eq!(truncate_at_sentence("", 10), "");
+    }
+
+    #[test]
+    fn test_cuts_at_sentence_boundary() {
+        let text = "This is the first sentence. And here comes a second one.";
+        assert_eq!(
+            truncate_at_sentence(text, 40),
+            "This is the first sentence."
+        );
+        assert_eq!(
+            truncate_at_sentence("Is it done? Yes, all of it is done now.", 20),
+            "Is it done?"
+        );
+    }
+
+    #[test]
+    fn test_cuts_at_word_with_ellipsis() {
+        let text = "A sentence that keeps going without any stop at all";
+        assert_eq!(truncate_at_sentence(text, 20), "A sentence that…");
+        // A sentence end that keeps too little text isn't used
+        assert_eq!(
+            truncate_at_sentence("Ok. Then a much longer sentence follows", 24),
+            "Ok. Then a much longer…"
+        );
+        // Periods inside words (numbers, domains) aren't sentence ends
+        assert_eq!(
+            truncate_at_sentence("Version 2.5 of example.com is out now", 16),
+            "Version 2.5 of…"
+        );
+    }
+
+    #[test]
+    fn test_never_splits_characters() {
+        assert_eq!(
+            truncate_at_sentence("Grüße aus Köln und Düsseldorf", 14),
+            "Grüße aus…"
+        );
+        assert_eq!(truncate_at_sentence("Supercalifragilistic", 6), "Super…");
+    }
+}
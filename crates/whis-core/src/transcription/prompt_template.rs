@@ -0,0 +1,278 @@
+//! Variable substitution for post-processing prompts.
+//!
+//! Preset and settings prompts may contain `{{variable}}` placeholders that
+//! are resolved right before the LLM call.
+//!
+//! # Variables
+//!
+//! - `{{date}}` - Current local date (`YYYY-MM-DD`)
+//! - `{{language}}` - Transcription language code (`auto` when auto-detecting)
+//! - `{{clipboard}}` - Current clipboard text (empty if unavailable)
+//!
+//! Unknown variables are left untouched. Write `\{{` to produce a literal `{{`.
+//!
+//! Clipboard text can also be attached as reply context with
+//! [`with_clipboard_context`], and echoed context removed from the LLM output
+//! with [`strip_context`].
+//!
+//! # Usage
+//!
+//! ```ignore
+//! use whis_core::{PromptVariables, render_prompt};
+//!
+//! let vars = PromptVariables::for_prompt(&prompt, Some("en".into()));
+//! let prompt = render_prompt(&prompt, &vars);
+//! ```
+
+/// Maximum clipboard characters included in a prompt (longer text is truncated)
+pub const MAX_CLIPBOARD_CONTEXT_CHARS: usize = 4000;
+
+/// Variables available in prompts, with a short description of each
+pub const PROMPT_VARIABLES: &[(&str, &str)] = &[
+    ("date", "Current local date (YYYY-MM-DD)"),
+    (
+        "language",
+        "Transcription language code (auto when auto-detecting)",
+    ),
+    ("clipboard", "Current clipboard text (empty if unavailable)"),
+];
+
+/// Values substituted into a prompt template
+#[derive(Debug, Clone, Default)]
+pub struct PromptVariables {
+    /// Transcription language code (None = auto-detect)
+    pub language: Option<String>,
+    /// Clipboard text, if it was read
+    pub clipboard: Option<String>,
+}
+
+impl PromptVariables {
+    /// Create variables for the given language
+    pub fn new(language: Option<String>) -> Self {
+        Self {
+            language,
+            clipboard: None,
+        }
+    }
+
+    /// Set the clipboard text (truncated to [`MAX_CLIPBOARD_CONTEXT_CHARS`])
+    pub fn with_clipboard(mut self, text: impl AsRef<str>) -> Self {
+        self.clipboard = Some(truncate_context(text.as_ref()));
+        self
+    }
+
+    /// Collect variables for a prompt, reading the clipboard only if the
+    /// prompt actually references it
+    pub fn for_prompt(prompt: &str, language: Option<String>) -> Self {
+        let vars = Self::new(language);
+
+        #[cfg(feature = "clipboard")]
+        if uses_variable(prompt, "clipboard") {
+            let backend = crate::Settings::load().ui.clipboard_backend;
+            match crate::clipboard::read_from_clipboard(backend) {
+                Ok(text) => return vars.with_clipboard(text),
+                Err(e) => crate::verbose!("Could not read clipboard for prompt: {}", e),
+            }
+        }
+
+        #[cfg(not(feature = "clipboard"))]
+        let _ = prompt;
+
+        vars
+    }
+
+    fn value(&self, name: &str) -> Option<String> {
+        match name {
+            "date" => Some(chrono::Local::now().format("%Y-%m-%d").to_string()),
+            "language" => Some(self.language.clone().unwrap_or_else(|| "auto".to_string())),
+            "clipboard" => Some(self.clipboard.clone().unwrap_or_default()),
+            _ => None,
+        }
+    }
+}
+
+/// Truncate context text to [`MAX_CLIPBOARD_CONTEXT_CHARS`] characters
+pub fn truncate_context(text: &str) -> String {
+    let text = text.trim();
+    match text.char_indices().nth(MAX_CLIPBOARD_CONTEXT_CHARS) {
+        Some((idx, _)) => format!("{}…", &text[..idx]),
+        None => text.to_string(),
+    }
+}
+
+/// Append clipboard text to a prompt as reference context
+///
+/// The LLM is told to use the context for understanding only (e.g. the
+/// message being replied to) and not to repeat it. Pair with
+/// [`strip_context`] on the LLM output.
+pub fn with_clipboard_context(prompt: &str, clipboard: &str) -> String {
+    let context = truncate_context(clipboard);
+    if context.is_empty() {
+        return prompt.to_string();
+    }
+
+    format!(
+        "{prompt}\n\n\
+         The user copied the following text before dictating. Use it only as context \
+         (for example, the message being replied to). Do not repeat or quote it in your output.\n\
+         <context>\n{context}\n</context>"
+    )
+}
+
+/// Remove echoed context from LLM output
+///
+/// Models occasionally repeat the context block or the context text itself
+/// before (or after) their answer; strip both so only the processed
+/// transcript remains. The context text is only removed as a whole leading
+/// or trailing echo, never from the middle of the answer.
+pub fn strip_context(output: &str, clipboard: &str) -> String {
+    let mut text = output.to_string();
+
+    // Drop any echoed <context>...</context> block
+    while let (Some(start), Some(end)) = (text.find("<context>"), text.find("</context>")) {
+        if end < start {
+            break;
+        }
+        text.replace_range(start..end + "</context>".len(), "");
+    }
+
+    let text = text.trim();
+    let context = truncate_context(clipboard);
+    strip_echo(text, &context)
+        .unwrap_or(text)
+        .trim()
+        .to_string()
+}
+
+/// `text` without a verbatim leading or trailing copy of `context`
+///
+/// The echo has to end (or start) at a word boundary and leave something
+/// behind, so a short context like "ok" doesn't eat part of "okay".
+fn strip_echo<'a>(text: &'a str, context: &str) -> Option<&'a str> {
+    if context.is_empty() {
+        return None;
+    }
+    let boundary = |c: char| !c.is_alphanumeric();
+    let leading = text
+        .strip_prefix(context)
+        .filter(|rest| rest.starts_with(boundary));
+    let trailing = || {
+        text.strip_suffix(context)
+            .filter(|rest| rest.ends_with(boundary))
+    };
+    leading
+        .or_else(trailing)
+        .filter(|rest| !rest.trim().is_empty())
+}
+
+/// Check whether a prompt references a variable
+pub fn uses_variable(prompt: &str, name: &str) -> bool {
+    prompt.contains(&format!("{{{{{name}}}}}")) || prompt.contains(&format!("{{{{ {name} }}}}"))
+}
+
+/// Substitute `{{variable}}` placeholders in a prompt
+pub fn render_prompt(prompt: &str, vars: &PromptVariables) -> String {
+    let mut output = String::with_capacity(prompt.len());
+    let mut rest = prompt;
+
+    while let Some(pos) = rest.find("{{") {
+        // Escaped: `\{{` becomes a literal `{{`
+        if rest[..pos].ends_with('\\') {
+            output.push_str(&rest[..pos - 1]);
+            output.push_str("{{");
+            rest = &rest[pos + 2..];
+            continue;
+        }
+
+        output.push_str(&rest[..pos]);
+        let after = &rest[pos + 2..];
+
+        let Some(end) = after.find("}}") else {
+            output.push_str("{{");
+            rest = after;
+            continue;
+        };
+
+        let name = after[..end].trim();
+        match vars.value(name) {
+            Some(value) => output.push_str(&value),
+            None => {
+                // Unknown variable: keep as written
+                output.push_str("{{");
+                output.push_str(&after[..end]);
+                output.push_str("}}");
+            }
+        }
+        rest = &after[end + 2..];
+    }
+
+    output.push_str(rest);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars() -> PromptVariables {
+        PromptVariables::new(Some("de".into())).with_clipboard("  Hi there  ")
+    }
+
+    #[test]
+    fn test_render_known_variables() {
+        let rendered = render_prompt("Lang {{language}}, reply to: {{ clipboard }}", &vars());
+        assert_eq!(rendered, "Lang de, reply to: Hi there");
+
+        let auto = render_prompt("{{language}}", &PromptVariables::default());
+        assert_eq!(auto, "auto");
+
+        let date = render_prompt("{{date}}", &vars());
+        assert_eq!(date.len(), "YYYY-MM-DD".len());
+    }
+
+    #[test]
+    fn test_render_keeps_unknown_and_escaped() {
+        assert_eq!(
+            render_prompt("{{name}} {{ x }}", &vars()),
+            "{{name}} {{ x }}"
+        );
+        assert_eq!(render_prompt("\\{{language}}", &vars()), "{{language}}");
+        assert_eq!(render_prompt("open {{language", &vars()), "open {{language");
+    }
+
+    #[test]
+    fn test_uses_variable() {
+        assert!(uses_variable("Reply to {{clipboard}}", "clipboard"));
+        assert!(uses_variable("Reply to {{ clipboard }}", "clipboard"));
+        assert!(!uses_variable("Reply to the clipboard", "clipboard"));
+    }
+
+    #[test]
+    fn test_strip_context_block() {
+        let output = "<context>\nHi there\n</context>\nSounds good.";
+        assert_eq!(strip_context(output, "Hi there"), "Sounds good.");
+    }
+
+    #[test]
+    fn test_strip_context_leading_and_trailing_echo() {
+        let clipboard = "Are you coming tonight?";
+        assert_eq!(
+            strip_context("Are you coming tonight?\n\nYes, at eight.", clipboard),
+            "Yes, at eight."
+        );
+        assert_eq!(
+            strip_context("Yes, at eight.\nAre you coming tonight?", clipboard),
+            "Yes, at eight."
+        );
+    }
+
+    #[test]
+    fn test_strip_context_keeps_inner_and_partial_matches() {
+        assert_eq!(
+            strip_context("I said ok to Anna, ok?", "ok"),
+            "I said ok to Anna, ok?"
+        );
+        assert_eq!(strip_context("okay, see you", "ok"), "okay, see you");
+        assert_eq!(strip_context("Anna", "Anna"), "Anna");
+    }
+}
@@ -0,0 +1,111 @@
+//! Per-stage latency measurement for the transcription pipeline.
+//!
+//! `StageTimings` accumulates wall-clock time per `TranscriptionStage`.
+//! Stages reported through a `ProgressCallback` (Uploading → Transcribing)
+//! only mark where a stage begins, so the previous stage is closed whenever
+//! a new one is entered and the last one is closed with `finish()`.
+//!
+//! Chunk stages (encoding, upload, transcription) overlap with recording in
+//! progressive mode, so the totals don't add up to the end-to-end latency.
+
+use std::fmt::Write as _;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::provider::{ProgressCallback, TranscriptionStage};
+
+/// Stages listed in a breakdown, in pipeline order
+const BREAKDOWN_STAGES: &[(TranscriptionStage, &str)] = &[
+    (TranscriptionStage::Recording, "recording"),
+    (TranscriptionStage::Encoding, "encoding"),
+    (TranscriptionStage::Uploading, "upload"),
+    (TranscriptionStage::Transcribing, "transcription"),
+    (TranscriptionStage::PostProcessing, "post-processing"),
+];
+
+#[derive(Default)]
+struct TimingState {
+    totals: Vec<(TranscriptionStage, Duration)>,
+    current: Option<(TranscriptionStage, Instant)>,
+}
+
+impl TimingState {
+    fn add(&mut self, stage: TranscriptionStage, elapsed: Duration) {
+        match self.totals.iter_mut().find(|(s, _)| *s == stage) {
+            Some((_, total)) => *total += elapsed,
+            None => self.totals.push((stage, elapsed)),
+        }
+    }
+
+    fn close_current(&mut self) {
+        if let Some((stage, started)) = self.current.take() {
+            self.add(stage, started.elapsed());
+        }
+    }
+}
+
+/// Accumulated time per pipeline stage (cheap to clone, shared across tasks)
+#[derive(Clone, Default)]
+pub struct StageTimings {
+    state: Arc<Mutex<TimingState>>,
+}
+
+impl StageTimings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start timing `stage`, closing the stage that was running before
+    pub fn enter(&self, stage: TranscriptionStage) {
+        let mut state = self.state.lock().unwrap();
+        state.close_current();
+        if stage != TranscriptionStage::Complete {
+            state.current = Some((stage, Instant::now()));
+        }
+    }
+
+    /// Close the currently running stage
+    pub fn finish(&self) {
+        self.state.lock().unwrap().close_current();
+    }
+
+    /// Add a separately measured duration to `stage`
+    pub fn add(&self, stage: TranscriptionStage, elapsed: Duration) {
+        self.state.lock().unwrap().add(stage, elapsed);
+    }
+
+    /// Total time recorded for `stage` (zero if never entered)
+    pub fn get(&self, stage: TranscriptionStage) -> Duration {
+        let state = self.state.lock().unwrap();
+        state
+            .totals
+            .iter()
+            .find(|(s, _)| *s == stage)
+            .map(|(_, total)| *total)
+            .unwrap_or_default()
+    }
+
+    /// Progress callback that marks stage boundaries reported by providers
+    pub fn progress_callback(&self) -> ProgressCallback {
+        let timings = self.clone();
+        Arc::new(move |stage| timings.enter(stage))
+    }
+
+    /// Measured stages in pipeline order, as `(name, total)`
+    pub fn measured(&self) -> Vec<(&'static str, Duration)> {
+        BREAKDOWN_STAGES
+            .iter()
+            .map(|(stage, name)| (*name, self.get(*stage)))
+            .filter(|(_, elapsed)| !elapsed.is_zero())
+            .collect()
+    }
+
+    /// Human-readable breakdown, one `name  seconds` line per measured stage
+    pub fn breakdown(&self) -> String {
+        let mut out = String::new();
+        for (name, elapsed) in self.measured() {
+            let _ = writeln!(out, "  {name:<16}{:>7.2}s", elapsed.as_secs_f64());
+        }
+        out
+    }
+}
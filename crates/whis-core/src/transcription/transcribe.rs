@@ -10,16 +10,37 @@ use anyhow::{Context, Result};
 
 use crate::config::TranscriptionProvider;
 use crate::http::get_http_client;
-use crate::provider::{TranscriptionRequest, registry};
+use crate::provider::{
+    CancellationToken, ProviderError, TranscriptionRequest, TranscriptionResult,
+    TranscriptionStage, registry,
+};
 
 /// Maximum words to search for overlap between chunks
 const MAX_OVERLAP_WORDS: usize = 15;
 
 /// Result of transcribing a single chunk
+#[derive(Clone)]
 struct ChunkTranscription {
     index: usize,
     text: String,
+    confidence: Option<f32>,
     has_leading_overlap: bool,
+    /// Length of the chunk's audio, weighting its confidence
+    samples: usize,
+}
+
+/// Duration-weighted mean confidence over the chunks that report one
+///
+/// Chunks without a confidence are left out rather than counted as 0.
+fn merged_confidence(transcriptions: &[ChunkTranscription]) -> Option<f32> {
+    let (sum, total) = transcriptions
+        .iter()
+        .filter_map(|t| {
+            let weight = t.samples as f32;
+            t.confidence.map(|c| (c * weight, weight))
+        })
+        .fold((0.0, 0.0), |(sum, total), (c, w)| (sum + c, total + w));
+    (total > 0.0).then(|| sum / total)
 }
 
 /// Merge transcription results, handling overlaps
@@ -114,7 +135,16 @@ fn remove_overlap(existing: &str, new_text: &str) -> String {
 // Progressive Transcription Functions
 //
 
+use super::timing::StageTimings;
 use crate::audio::chunker::AudioChunk as ProgressiveChunk;
+use crate::audio::{AudioEncoder, create_encoder};
+use std::sync::Arc;
+
+/// Callback receiving interim transcripts: `(sequence, merged_text_so_far)`
+///
+/// The sequence number increases with every call, so consumers can discard
+/// stale updates that arrive out of order.
+pub type PartialTranscriptCallback = Box<dyn Fn(usize, &str) + Send + Sync>;
 
 /// Progressive transcription for cloud providers
 ///
@@ -128,13 +158,100 @@ use crate::audio::chunker::AudioChunk as ProgressiveChunk;
 /// * `language` - Optional language hint
 /// * `chunk_rx` - Channel receiving audio chunks during recording
 /// * `progress_callback` - Optional progress reporting
+/// * `cancel` - Optional token that aborts in-flight requests and skips remaining chunks
 pub async fn progressive_transcribe_cloud(
     provider: &TranscriptionProvider,
     api_key: &str,
     language: Option<&str>,
-    mut chunk_rx: tokio::sync::mpsc::UnboundedReceiver<ProgressiveChunk>,
+    chunk_rx: tokio::sync::mpsc::UnboundedReceiver<ProgressiveChunk>,
     progress_callback: Option<Box<dyn Fn(usize, usize) + Send + Sync>>,
+    cancel: Option<CancellationToken>,
 ) -> Result<String> {
+    transcribe_cloud_chunks(
+        provider,
+        api_key,
+        language,
+        chunk_rx,
+        create_encoder().into(),
+        progress_callback,
+        None,
+        None,
+        cancel,
+    )
+    .await
+    .map(|result| result.text)
+}
+
+/// Progressive cloud transcription that also reports interim transcripts
+///
+/// Same as [`progressive_transcribe_cloud`], but after each chunk the merged
+/// transcript so far is passed to `on_partial`. The final return value
+/// supersedes all interim text.
+pub async fn progressive_transcribe_cloud_with_partials(
+    provider: &TranscriptionProvider,
+    api_key: &str,
+    language: Option<&str>,
+    chunk_rx: tokio::sync::mpsc::UnboundedReceiver<ProgressiveChunk>,
+    on_partial: PartialTranscriptCallback,
+    cancel: Option<CancellationToken>,
+) -> Result<String> {
+    transcribe_cloud_chunks(
+        provider,
+        api_key,
+        language,
+        chunk_rx,
+        create_encoder().into(),
+        None,
+        Some(on_partial),
+        None,
+        cancel,
+    )
+    .await
+    .map(|result| result.text)
+}
+
+/// Progressive cloud transcription that records per-stage latency
+///
+/// Same as [`progressive_transcribe_cloud`], but time spent encoding,
+/// uploading, and transcribing each chunk is accumulated in `timings`, and
+/// interim transcripts are passed to `on_partial` when given. Chunks are
+/// encoded with `encoder` (see `RecorderConfig::encoder`). The result
+/// carries the duration-weighted mean confidence of chunks that report one.
+pub async fn progressive_transcribe_cloud_with_timings(
+    provider: &TranscriptionProvider,
+    api_key: &str,
+    language: Option<&str>,
+    chunk_rx: tokio::sync::mpsc::UnboundedReceiver<ProgressiveChunk>,
+    encoder: Arc<dyn AudioEncoder>,
+    timings: StageTimings,
+    on_partial: Option<PartialTranscriptCallback>,
+    cancel: Option<CancellationToken>,
+) -> Result<TranscriptionResult> {
+    transcribe_cloud_chunks(
+        provider,
+        api_key,
+        language,
+        chunk_rx,
+        encoder,
+        None,
+        on_partial,
+        Some(timings),
+        cancel,
+    )
+    .await
+}
+
+async fn transcribe_cloud_chunks(
+    provider: &TranscriptionProvider,
+    api_key: &str,
+    language: Option<&str>,
+    mut chunk_rx: tokio::sync::mpsc::UnboundedReceiver<ProgressiveChunk>,
+    encoder: Arc<dyn AudioEncoder>,
+    progress_callback: Option<Box<dyn Fn(usize, usize) + Send + Sync>>,
+    on_partial: Option<PartialTranscriptCallback>,
+    timings: Option<StageTimings>,
+    cancel: Option<CancellationToken>,
+) -> Result<TranscriptionResult> {
     let client = get_http_client()?;
     let provider_impl = registry().get_by_kind(provider)?;
     let mut transcriptions = Vec::new();
@@ -142,41 +259,64 @@ pub async fn progressive_transcribe_cloud(
 
     // Process chunks sequentially as they arrive (true progressive)
     while let Some(chunk) = chunk_rx.recv().await {
+        check_cancelled(cancel.as_ref())?;
+
         chunk_count += 1;
         let chunk_index = chunk.index;
         let has_leading_overlap = chunk.has_leading_overlap;
+        let sample_count = chunk.samples.len();
 
-        // Convert samples to MP3
-        let mp3_data =
-            samples_to_mp3(&chunk.samples).context("Failed to encode audio chunk to MP3")?;
-
-        let request = TranscriptionRequest {
-            audio_data: mp3_data,
-            language: language.map(|s| s.to_string()),
-            filename: format!("audio_chunk_{chunk_index}.mp3"),
-            mime_type: "audio/mpeg".to_string(),
-            progress: None,
-        };
+        // Encode for upload (MP3 unless overridden)
+        if let Some(ref timings) = timings {
+            timings.enter(TranscriptionStage::Encoding);
+        }
+        let audio_data = encoder
+            .encode_samples(&chunk.samples, crate::resample::WHISPER_SAMPLE_RATE)
+            .context("Failed to encode audio chunk")?;
+
+        let request = TranscriptionRequest::builder()
+            .audio_data(audio_data)
+            .language(language.map(|s| s.to_string()))
+            .filename(format!("audio_chunk_{chunk_index}"))
+            .format(encoder.format())
+            .progress(timings.as_ref().map(StageTimings::progress_callback))
+            .cancel(cancel.clone())
+            .build();
 
         let result = provider_impl
-            .transcribe_async(client, api_key, request)
+            .transcribe_async(&client, api_key, request)
             .await
             .with_context(|| format!("Failed to transcribe chunk {chunk_index}"))?;
+        if let Some(ref timings) = timings {
+            timings.finish();
+        }
+        crate::verbose!("Transcribed chunk {chunk_index}");
 
         transcriptions.push(ChunkTranscription {
             index: chunk_index,
             text: result.text,
+            confidence: result.confidence,
             has_leading_overlap,
+            samples: sample_count,
         });
 
         // Progress reporting (total unknown until channel closes)
         if let Some(ref callback) = progress_callback {
             callback(chunk_count, 0); // Total is 0 since we don't know how many more chunks will arrive
         }
+
+        // Interim transcript: chunks are processed in order, so the merge is coherent
+        if let Some(ref callback) = on_partial {
+            callback(chunk_count, &merge_transcriptions(transcriptions.clone()));
+        }
     }
 
     // Results are already in correct order (sequential processing, no sorting needed)
-    Ok(merge_transcriptions(transcriptions))
+    Ok(TranscriptionResult {
+        confidence: merged_confidence(&transcriptions),
+        text: merge_transcriptions(transcriptions),
+        ..Default::default()
+    })
 }
 
 /// Progressive transcription for local providers (Whisper + Parakeet)
@@ -190,21 +330,27 @@ pub async fn progressive_transcribe_cloud(
 /// * `model_path` - Path to local model directory
 /// * `chunk_rx` - Channel receiving audio chunks during recording
 /// * `progress_callback` - Optional progress reporting
+/// * `cancel` - Optional token that skips remaining chunks (a running inference finishes)
 #[cfg(feature = "local-transcription")]
 pub async fn progressive_transcribe_local(
     model_path: &str,
     mut chunk_rx: tokio::sync::mpsc::UnboundedReceiver<ProgressiveChunk>,
+    encoder: Arc<dyn AudioEncoder>,
     progress_callback: Option<Box<dyn Fn(usize, usize) + Send + Sync>>,
+    cancel: Option<CancellationToken>,
 ) -> Result<String> {
     let mut transcriptions = Vec::new();
     let mut chunk_count = 0;
 
     // Process chunks sequentially as they arrive (true progressive)
     while let Some(chunk) = chunk_rx.recv().await {
+        check_cancelled(cancel.as_ref())?;
+
         chunk_count += 1;
         let chunk_index = chunk.index;
         let has_leading_overlap = chunk.has_leading_overlap;
         let samples = chunk.samples;
+        let sample_count = samples.len();
         let model_path_owned = model_path.to_string();
 
         // Run transcription in blocking task (CPU-bound work)
@@ -218,7 +364,9 @@ pub async fn progressive_transcribe_local(
         transcriptions.push(ChunkTranscription {
             index: chunk_index,
             text: result.text,
+            confidence: result.confidence,
             has_leading_overlap,
+            samples: sample_count,
         });
 
         // Progress reporting (total unknown until channel closes)
@@ -231,11 +379,49 @@ pub async fn progressive_transcribe_local(
     Ok(merge_transcriptions(transcriptions))
 }
 
-/// Convert f32 samples to MP3 bytes
-fn samples_to_mp3(samples: &[f32]) -> Result<Vec<u8>> {
-    use crate::audio::create_encoder;
-    let encoder = create_encoder();
-    encoder
-        .encode_samples(samples, crate::resample::WHISPER_SAMPLE_RATE)
-        .context("Failed to encode audio to MP3")
+/// Bail out with `ProviderError::Cancelled` if the token has fired
+fn check_cancelled(cancel: Option<&CancellationToken>) -> Result<()> {
+    if cancel.is_some_and(|t| t.is_cancelled()) {
+        return Err(ProviderError::Cancelled.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunk(text: &str, confidence: Option<f32>, samples: usize) -> ChunkTranscription {
+        ChunkTranscription {
+            index: 0,
+            text: text.to_string(),
+            confidence,
+            has_leading_overlap: false,
+            samples,
+        }
+    }
+
+    #[test]
+    fn test_merged_confidence_weights_by_duration() {
+        // 3s at 0.9 and 1s at 0.5: (0.9 * 3 + 0.5 * 1) / 4
+        let chunks = [
+            chunk("a", Some(0.9), 48_000),
+            chunk("many more words here", Some(0.5), 16_000),
+        ];
+        let confidence = merged_confidence(&chunks).unwrap();
+        assert!((confidence - 0.8).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_merged_confidence_skips_chunks_without_confidence() {
+        let chunks = [chunk("a", None, 160_000), chunk("b", Some(0.7), 16_000)];
+        let confidence = merged_confidence(&chunks).unwrap();
+        assert!((confidence - 0.7).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_merged_confidence_none_when_unreported() {
+        assert_eq!(merged_confidence(&[]), None);
+        assert_eq!(merged_confidence(&[chunk("a", None, 16_000)]), None);
+    }
 }
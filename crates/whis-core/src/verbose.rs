@@ -18,12 +18,19 @@
 //! error!("Failed to connect: {}", err);
 //! ```
 
+use std::collections::VecDeque;
 use std::io::{self, IsTerminal, Write};
 use std::sync::Mutex;
 use std::sync::atomic::{AtomicBool, Ordering};
 
+/// Maximum number of verbose lines kept in the capture buffer
+const LOG_CAPTURE_CAPACITY: usize = 2000;
+
 static VERBOSE: AtomicBool = AtomicBool::new(false);
+static VERBOSE_STDERR: AtomicBool = AtomicBool::new(false);
 static STDOUT_LOCK: Mutex<()> = Mutex::new(());
+static LOG_CAPTURE: AtomicBool = AtomicBool::new(false);
+static LOG_BUFFER: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
 
 /// Enable or disable verbose logging
 pub fn set_verbose(enabled: bool) {
@@ -35,8 +42,44 @@ pub fn is_verbose() -> bool {
     VERBOSE.load(Ordering::SeqCst)
 }
 
-/// Write a verbose message atomically to stdout.
+/// Send verbose output to stderr instead of stdout
+///
+/// Used by `--quiet` so stdout only carries results.
+pub fn set_verbose_stderr(enabled: bool) {
+    VERBOSE_STDERR.store(enabled, Ordering::SeqCst);
+}
+
+/// Enable or disable capturing verbose output into an in-memory buffer
+///
+/// For GUI apps without a visible terminal, so users can copy logs into bug
+/// reports. Only the most recent lines are kept.
+pub fn set_log_capture(enabled: bool) {
+    LOG_CAPTURE.store(enabled, Ordering::SeqCst);
+}
+
+/// Get the captured verbose lines, oldest first
+pub fn captured_logs() -> Vec<String> {
+    let buffer = LOG_BUFFER.lock().unwrap_or_else(|e| e.into_inner());
+    buffer.iter().cloned().collect()
+}
+
+/// Clear the captured verbose lines
+pub fn clear_captured_logs() {
+    LOG_BUFFER.lock().unwrap_or_else(|e| e.into_inner()).clear();
+}
+
+/// Append a verbose line to the capture buffer, dropping the oldest when full
+fn capture_line(line: String) {
+    let mut buffer = LOG_BUFFER.lock().unwrap_or_else(|e| e.into_inner());
+    if buffer.len() >= LOG_CAPTURE_CAPACITY {
+        buffer.pop_front();
+    }
+    buffer.push_back(line);
+}
+
+/// Write a verbose message atomically to stdout (or stderr, see `set_verbose_stderr`).
 /// Uses a mutex to prevent interleaving from concurrent threads.
+/// Also appends the message to the capture buffer when capture is enabled.
 ///
 /// Note: When the CLI enters raw mode (via crossterm), some terminals stop
 /// translating `\n` into CRLF. If we only print `\n`, subsequent lines can start
@@ -44,19 +87,30 @@ pub fn is_verbose() -> bool {
 /// output aligned, we emit `\r\n` when stdout is a TTY.
 pub fn write_verbose(args: std::fmt::Arguments) {
     let _guard = STDOUT_LOCK.lock().unwrap_or_else(|e| e.into_inner());
-    let is_tty = io::stdout().is_terminal();
 
-    let mut stdout = io::stdout().lock();
+    if VERBOSE_STDERR.load(Ordering::SeqCst) {
+        write_line(io::stderr().is_terminal(), &mut io::stderr().lock(), args);
+    } else {
+        write_line(io::stdout().is_terminal(), &mut io::stdout().lock(), args);
+    }
+
+    if LOG_CAPTURE.load(Ordering::SeqCst) {
+        capture_line(args.to_string());
+    }
+}
+
+/// Write one `[verbose]` line, using CRLF on a TTY (see `write_verbose`)
+fn write_line(is_tty: bool, out: &mut impl Write, args: std::fmt::Arguments) {
     if is_tty {
-        let _ = write!(stdout, "[verbose] {}\r\n", args);
+        let _ = write!(out, "[verbose] {}\r\n", args);
     } else {
-        let _ = writeln!(stdout, "[verbose] {}", args);
+        let _ = writeln!(out, "[verbose] {}", args);
     }
-    let _ = stdout.flush();
+    let _ = out.flush();
 }
 
 /// Log a formatted message if verbose mode is enabled.
-/// Outputs to stdout atomically to prevent interleaving from concurrent threads.
+/// Outputs to stdout (stderr under `set_verbose_stderr`) atomically to prevent interleaving.
 #[macro_export]
 macro_rules! verbose {
     ($($arg:tt)*) => {
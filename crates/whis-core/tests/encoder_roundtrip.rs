@@ -0,0 +1,200 @@
+//! Round trips between the encoders and the local decoder
+//!
+//! Guards against the encoder and decoder drifting apart: a known tone goes
+//! in, and the sample rate, duration and pitch have to come back out.
+//!
+//! The local decoder only reads WAV, so MP3 output (the upload format) is
+//! checked by walking its frame headers instead: every frame has to be
+//! 16kHz mono, and the frames have to add up to the recording's length.
+//! The decoder has to reject MP3 with a clear error rather than returning no
+//! samples.
+
+#![cfg(all(feature = "embedded-encoder", feature = "wav"))]
+
+use std::f32::consts::PI;
+use std::io::Cursor;
+
+use whis_core::audio::decode_audio_bytes;
+use whis_core::provider::AudioFormat;
+use whis_core::resample::WHISPER_SAMPLE_RATE;
+use whis_core::{AudioEncoder, create_encoder, create_mp3_encoder};
+
+const TONE_HZ: f32 = 440.0;
+
+/// Samples per MPEG-2 Layer III frame
+const MP3_FRAME_SAMPLES: usize = 576;
+
+/// `seconds` of a sine tone at `sample_rate`, repeated on each channel
+fn tone(sample_rate: u32, channels: u16, seconds: f32) -> Vec<f32> {
+    let frames = (sample_rate as f32 * seconds) as usize;
+    (0..frames)
+        .flat_map(|i| {
+            let sample = 0.5 * (2.0 * PI * TONE_HZ * i as f32 / sample_rate as f32).sin();
+            std::iter::repeat_n(sample, channels as usize)
+        })
+        .collect()
+}
+
+/// Frequency of a mono signal, from its zero crossings
+fn frequency(samples: &[f32], sample_rate: u32) -> f32 {
+    let crossings = samples
+        .windows(2)
+        .filter(|pair| (pair[0] < 0.0) != (pair[1] < 0.0))
+        .count();
+    crossings as f32 / 2.0 / (samples.len() as f32 / sample_rate as f32)
+}
+
+/// 16-bit WAV file of interleaved `samples`
+fn wav_bytes(samples: &[f32], sample_rate: u32, channels: u16) -> Vec<u8> {
+    let spec = hound::WavSpec {
+        channels,
+        sample_rate,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut data = Cursor::new(Vec::new());
+    let mut writer = hound::WavWriter::new(&mut data, spec).unwrap();
+    for &sample in samples {
+        writer
+            .write_sample((sample * i16::MAX as f32) as i16)
+            .unwrap();
+    }
+    writer.finalize().unwrap();
+    data.into_inner()
+}
+
+/// Header of one MPEG audio frame
+#[derive(Debug)]
+struct Mp3Frame {
+    sample_rate: u32,
+    mono: bool,
+    bitrate_kbps: u32,
+    length: usize,
+}
+
+/// Parse the MPEG-2 Layer III frame header at the start of `data`
+fn parse_mp3_frame(data: &[u8]) -> Option<Mp3Frame> {
+    const BITRATES: [u32; 16] = [
+        0, 8, 16, 24, 32, 40, 48, 56, 64, 80, 96, 112, 128, 144, 160, 0,
+    ];
+    const SAMPLE_RATES: [u32; 3] = [22050, 24000, 16000];
+
+    let header = u32::from_be_bytes(data.get(..4)?.try_into().ok()?);
+    let sync = header >> 21 == 0x7FF;
+    let mpeg2 = (header >> 19) & 0b11 == 0b10;
+    let layer3 = (header >> 17) & 0b11 == 0b01;
+    if !(sync && mpeg2 && layer3) {
+        return None;
+    }
+
+    let bitrate_kbps = BITRATES[((header >> 12) & 0xF) as usize];
+    let sample_rate = *SAMPLE_RATES.get(((header >> 10) & 0b11) as usize)?;
+    let padding = ((header >> 9) & 1) as usize;
+    if bitrate_kbps == 0 {
+        return None;
+    }
+    Some(Mp3Frame {
+        sample_rate,
+        mono: (header >> 6) & 0b11 == 0b11,
+        bitrate_kbps,
+        length: (72_000 * bitrate_kbps / sample_rate) as usize + padding,
+    })
+}
+
+/// All frames of an MP3 file, failing if anything between them isn't a frame
+fn mp3_frames(data: &[u8]) -> Vec<Mp3Frame> {
+    let mut offset = 0;
+    let mut frames = Vec::new();
+    while offset < data.len() {
+        let frame = parse_mp3_frame(&data[offset..])
+            .unwrap_or_else(|| panic!("No MP3 frame at byte {offset} of {}", data.len()));
+        offset += frame.length;
+        frames.push(frame);
+    }
+    frames
+}
+
+#[test]
+fn test_parse_mp3_frame_header() {
+    // MPEG-2 Layer III, 128 kbps, 16kHz, no padding, mono
+    let frame = parse_mp3_frame(&[0xFF, 0xF3, 0xC8, 0xC0]).unwrap();
+    assert_eq!(frame.sample_rate, 16000);
+    assert_eq!(frame.bitrate_kbps, 128);
+    assert!(frame.mono);
+    assert_eq!(frame.length, 576);
+    assert!(parse_mp3_frame(b"RIFF").is_none());
+}
+
+#[test]
+fn test_wav_round_trip_keeps_rate_duration_and_pitch() {
+    let samples = tone(WHISPER_SAMPLE_RATE, 1, 1.0);
+    let decoded = decode_audio_bytes(&wav_bytes(&samples, WHISPER_SAMPLE_RATE, 1)).unwrap();
+
+    assert_eq!(decoded.len(), samples.len());
+    let hz = frequency(&decoded, WHISPER_SAMPLE_RATE);
+    assert!((hz - TONE_HZ).abs() < 5.0, "decoded tone is {hz}Hz");
+}
+
+#[test]
+fn test_wav_at_other_rates_is_resampled_to_16k() {
+    let samples = tone(44_100, 2, 1.0);
+    let decoded = decode_audio_bytes(&wav_bytes(&samples, 44_100, 2)).unwrap();
+
+    let expected = WHISPER_SAMPLE_RATE as usize;
+    assert!(
+        decoded.len().abs_diff(expected) <= expected / 100,
+        "1s at 44.1kHz decoded to {} samples",
+        decoded.len()
+    );
+    let hz = frequency(&decoded, WHISPER_SAMPLE_RATE);
+    assert!((hz - TONE_HZ).abs() < 5.0, "decoded tone is {hz}Hz");
+}
+
+#[test]
+fn test_mp3_keeps_rate_and_duration() {
+    let samples = tone(WHISPER_SAMPLE_RATE, 1, 1.0);
+    let encoder = create_encoder();
+    assert_eq!(encoder.format(), AudioFormat::Mp3);
+    let data = encoder
+        .encode_samples(&samples, WHISPER_SAMPLE_RATE)
+        .unwrap();
+
+    let frames = mp3_frames(&data);
+    assert!(
+        frames
+            .iter()
+            .all(|f| f.sample_rate == WHISPER_SAMPLE_RATE && f.mono),
+        "unexpected frame format: {frames:?}"
+    );
+    // LAME adds an encoder delay and pads the last frame
+    let encoded = frames.len() * MP3_FRAME_SAMPLES;
+    assert!(
+        encoded.abs_diff(samples.len()) <= 6 * MP3_FRAME_SAMPLES,
+        "{} samples encoded to {encoded}",
+        samples.len()
+    );
+}
+
+#[test]
+fn test_mp3_bitrate_is_applied() {
+    let samples = tone(WHISPER_SAMPLE_RATE, 1, 1.0);
+    let data = create_mp3_encoder(32)
+        .encode_samples(&samples, WHISPER_SAMPLE_RATE)
+        .unwrap();
+
+    assert!(mp3_frames(&data).iter().all(|f| f.bitrate_kbps == 32));
+}
+
+#[test]
+fn test_local_decoder_rejects_mp3_clearly() {
+    let samples = tone(WHISPER_SAMPLE_RATE, 1, 0.5);
+    let data = create_encoder()
+        .encode_samples(&samples, WHISPER_SAMPLE_RATE)
+        .unwrap();
+
+    let error = decode_audio_bytes(&data).unwrap_err().to_string();
+    assert!(
+        error.contains("Unsupported audio format: MP3"),
+        "unexpected error: {error}"
+    );
+}
@@ -7,7 +7,7 @@
 //!
 //! ```text
 //! commands/
-//! ├── system.rs          - System utilities (audio devices, exit, toggle cmd)
+//! ├── system.rs          - System utilities (audio devices, exit, toggle cmd, logs)
 //! ├── validation.rs      - API key validators
 //! ├── recording.rs       - Recording status commands
 //! ├── settings.rs        - Settings management & config readiness
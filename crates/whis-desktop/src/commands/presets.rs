@@ -148,6 +148,7 @@ pub fn create_preset(input: CreatePresetInput) -> Result<PresetInfo, String> {
         prompt: input.prompt,
         post_processor: input.post_processor,
         model: input.model,
+        language: None,
     };
 
     preset.save()?;
@@ -11,6 +11,10 @@ use whis_core::RecordingState;
 pub struct StatusResponse {
     pub state: String,
     pub config_valid: bool,
+    /// Seconds since recording started (None when not recording)
+    pub elapsed_secs: Option<f64>,
+    /// Seconds of audio captured so far (None when not recording)
+    pub audio_secs: Option<f64>,
 }
 
 /// Check if API/model is configured for the current transcription provider
@@ -32,6 +36,23 @@ pub async fn get_status(state: State<'_, AppState>) -> Result<StatusResponse, St
         has_cached_config || settings.transcription.is_configured()
     };
 
+    let (elapsed_secs, audio_secs) = if current_state == RecordingState::Recording {
+        let elapsed = state
+            .recording_started
+            .lock()
+            .unwrap()
+            .map(|started| started.elapsed().as_secs_f64());
+        let audio = state
+            .recorder
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|r| r.buffered_secs() as f64);
+        (elapsed, audio)
+    } else {
+        (None, None)
+    };
+
     Ok(StatusResponse {
         state: match current_state {
             RecordingState::Idle => "Idle".to_string(),
@@ -39,6 +60,8 @@ pub async fn get_status(state: State<'_, AppState>) -> Result<StatusResponse, St
             RecordingState::Transcribing => "Transcribing".to_string(),
         },
         config_valid,
+        elapsed_secs,
+        audio_secs,
     })
 }
 
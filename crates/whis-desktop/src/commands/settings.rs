@@ -185,28 +185,20 @@ pub fn get_defaults() -> serde_json::Value {
         "desktop_key": DEFAULT_SHORTCUT,
         "vad_enabled": DEFAULT_VAD_ENABLED,
         "vad_threshold": DEFAULT_VAD_THRESHOLD,
+        "post_processing_chunk_chars": DEFAULT_POST_PROCESSING_CHUNK_CHARS,
     })
 }
 
-/// Cloud provider option for the frontend dropdown
-#[derive(serde::Serialize)]
-pub struct CloudProviderOption {
-    pub value: String,
-    pub label: String,
-}
-
 /// Get cloud providers in recommended order
 ///
 /// Returns cloud providers (excluding local and realtime variants) in the
-/// order defined by TranscriptionProvider::all() for consistent UI display.
+/// order defined by TranscriptionProvider::all() for consistent UI display,
+/// with everything the provider picker shows (key URL, streaming support, ...)
 #[tauri::command]
-pub fn get_cloud_providers() -> Vec<CloudProviderOption> {
+pub fn get_cloud_providers() -> Vec<whis_core::ProviderCapabilities> {
     use whis_core::TranscriptionProvider;
 
     TranscriptionProvider::cloud_providers()
-        .map(|p| CloudProviderOption {
-            value: p.as_str().to_string(),
-            label: p.display_name().to_string(),
-        })
+        .map(TranscriptionProvider::capabilities)
         .collect()
 }
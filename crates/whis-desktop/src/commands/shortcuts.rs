@@ -2,14 +2,37 @@
 //!
 //! Provides Tauri commands for configuring and managing global keyboard shortcuts.
 
-use crate::shortcuts::ShortcutBackendInfo;
+use crate::shortcuts::{GnomeWhisShortcut, ShortcutBackendInfo};
 use crate::state::AppState;
 use tauri::{AppHandle, State};
 
 /// Get the current shortcut backend information
 #[tauri::command]
-pub fn shortcut_backend() -> ShortcutBackendInfo {
-    crate::shortcuts::backend_info()
+pub fn shortcut_backend(state: State<'_, AppState>) -> ShortcutBackendInfo {
+    #[cfg(target_os = "linux")]
+    let rdev_active = state.rdev_guard.lock().unwrap().is_some();
+    #[cfg(not(target_os = "linux"))]
+    let rdev_active = {
+        let _ = state;
+        false
+    };
+    crate::shortcuts::backend_info(rdev_active)
+}
+
+/// Bind a new shortcut without restarting the app
+///
+/// Works for the Tauri plugin and direct capture (RdevGrab); fails when
+/// the backend needs a restart to pick up the change (Portal, manual setup).
+/// Doesn't save the shortcut to settings.
+#[tauri::command]
+pub fn rebind_shortcut(app: AppHandle, trigger: String) -> Result<(), String> {
+    let needs_restart =
+        crate::shortcuts::update_shortcut(&app, &trigger).map_err(|e| e.to_string())?;
+    if needs_restart {
+        Err("This shortcut backend requires a restart to change the shortcut".to_string())
+    } else {
+        Ok(())
+    }
 }
 
 /// Open shortcut configuration dialog (Portal v2+) or bind directly (Portal v1)
@@ -152,12 +175,13 @@ pub fn open_keyboard_settings(compositor: String) -> Result<(), String> {
 pub fn get_shortcut_instructions(shortcut: String) -> ShortcutInstructions {
     let capability = crate::shortcuts::detect_backend();
     let compositor = &capability.platform_info.compositor;
+    let command = super::system::get_toggle_command();
 
     ShortcutInstructions {
         compositor_name: compositor.display_name().to_string(),
-        instructions: crate::shortcuts::get_instructions(compositor, &shortcut),
+        instructions: crate::shortcuts::get_instructions(compositor, &shortcut, &command),
         config_path: crate::shortcuts::get_config_path(compositor).map(|s| s.to_string()),
-        config_snippet: crate::shortcuts::get_config_snippet(compositor, &shortcut),
+        config_snippet: crate::shortcuts::get_config_snippet(compositor, &shortcut, &command),
         has_settings_app: matches!(
             compositor,
             whis_core::Compositor::Gnome | whis_core::Compositor::KdePlasma
@@ -288,3 +312,34 @@ pub fn update_shortcut_command() -> Result<(), String> {
 pub fn update_shortcut_command() -> Result<(), String> {
     Err("Not supported on this platform".to_string())
 }
+
+/// List every GNOME custom shortcut that toggles whis
+///
+/// More than one entry means a single key press toggles twice; entries
+/// marked stale point to a binary that no longer exists.
+#[tauri::command]
+pub fn gnome_whis_shortcuts() -> Vec<GnomeWhisShortcut> {
+    crate::shortcuts::portal::list_gnome_whis_shortcuts()
+}
+
+/// Remove the GNOME custom shortcuts whose whis binary no longer exists
+///
+/// Returns the names (or dconf paths) of the removed shortcuts.
+#[cfg(target_os = "linux")]
+#[tauri::command]
+pub fn remove_stale_gnome_shortcuts() -> Result<Vec<String>, String> {
+    let mut removed = Vec::new();
+    for shortcut in crate::shortcuts::list_gnome_whis_shortcuts() {
+        if shortcut.stale {
+            crate::shortcuts::remove_gnome_custom_shortcut(&shortcut.path)?;
+            removed.push(shortcut.name.unwrap_or(shortcut.path));
+        }
+    }
+    Ok(removed)
+}
+
+#[cfg(not(target_os = "linux"))]
+#[tauri::command]
+pub fn remove_stale_gnome_shortcuts() -> Result<Vec<String>, String> {
+    Err("Not supported on this platform".to_string())
+}
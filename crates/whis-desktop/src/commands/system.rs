@@ -1,9 +1,12 @@
 //! System Utility Commands
 //!
 //! Provides Tauri commands for system-level operations like audio device listing,
-//! CLI toggle command retrieval, window reopening checks, and app exit.
+//! CLI toggle command retrieval, window reopening checks, app exit, and verbose logs.
 
-use crate::state::AppState;
+use crate::recording::control::recorder_error;
+use crate::recording::level::level_callback;
+use crate::shortcuts::ShortcutBackend;
+use crate::state::{AppState, RecordingState};
 use tauri::{AppHandle, State};
 use whis_core::{
     AutotypeToolStatus, Settings, WarmupConfig, get_autotype_tool_status, warmup_configured,
@@ -45,29 +48,82 @@ pub fn can_reopen_window(state: State<'_, AppState>) -> bool {
     }
 
     // Check shortcut backend - some always work, some need verification
-    let backend_info = crate::shortcuts::backend_info();
-    match backend_info.backend.as_str() {
-        "TauriPlugin" => true, // X11 shortcuts always work
-        "ManualSetup" => true, // IPC toggle always available
-        "PortalGlobalShortcuts" => {
+    match crate::shortcuts::detect_backend().backend {
+        ShortcutBackend::TauriPlugin => true, // X11 shortcuts always work
+        ShortcutBackend::ManualSetup => true, // IPC toggle always available
+        ShortcutBackend::PortalGlobalShortcuts => {
             // Portal needs a bound shortcut without errors
             let has_shortcut = state.portal_shortcut.lock().unwrap().is_some();
             let no_error = state.portal_bind_error.lock().unwrap().is_none();
             has_shortcut && no_error
         }
-        _ => false,
+        ShortcutBackend::RdevGrab => false,
     }
 }
 
+/// List input devices, with PulseAudio metadata (form factor, bus) where available
 #[tauri::command]
-pub fn list_audio_devices() -> Result<Vec<whis_core::AudioDeviceInfo>, String> {
+pub fn list_input_devices() -> Result<Vec<whis_core::AudioDeviceInfo>, String> {
     whis_core::list_audio_devices().map_err(|e| e.to_string())
 }
 
+/// Selected input device (None = system default)
+#[tauri::command]
+pub fn get_input_device(state: State<'_, AppState>) -> Option<String> {
+    state.settings.lock().unwrap().ui.microphone_device.clone()
+}
+
+/// Select the input device for recording and persist it (None = system default)
+#[tauri::command]
+pub fn set_input_device(state: State<'_, AppState>, name: Option<String>) -> Result<(), String> {
+    let mut settings = state.settings.lock().unwrap();
+    settings.ui.microphone_device = name;
+    settings.save().map_err(|e| e.to_string())
+}
+
+/// Start streaming `whis://level` events from an input device (mic test)
+///
+/// Opens the device without recording anything; only allowed while idle.
+/// Restarting replaces the running meter (e.g. after switching devices).
+#[tauri::command]
+pub fn start_level_meter(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    device: Option<String>,
+) -> Result<(), String> {
+    if state.get_state() != RecordingState::Idle {
+        return Err("Cannot test the microphone while recording".to_string());
+    }
+
+    stop_meter(&state);
+
+    let mut recorder = whis_core::AudioRecorder::new().map_err(|e| e.to_string())?;
+    recorder.set_level_callback(Some(level_callback(app)));
+    recorder
+        .start_recording_with_device(device.as_deref())
+        .map_err(recorder_error)?;
+    *state.level_meter.lock().unwrap() = Some(recorder);
+    Ok(())
+}
+
+/// Stop the mic test meter (no-op if it isn't running)
+#[tauri::command]
+pub fn stop_level_meter(state: State<'_, AppState>) {
+    stop_meter(&state);
+}
+
+fn stop_meter(state: &AppState) {
+    if let Some(mut recorder) = state.level_meter.lock().unwrap().take() {
+        let _ = recorder.stop_recording();
+    }
+}
+
 /// Exit the application gracefully
 /// Called after settings have been flushed to disk
 #[tauri::command]
-pub fn exit_app(app: AppHandle) {
+pub fn exit_app(app: AppHandle, state: State<'_, AppState>) {
+    // Abort in-flight provider requests so they don't outlive the app
+    state.cancel_transcription();
     app.exit(0);
 }
 
@@ -121,3 +177,27 @@ pub async fn warmup_connections() -> Result<(), String> {
 pub fn get_autotype_tool_status_cmd() -> AutotypeToolStatus {
     get_autotype_tool_status()
 }
+
+/// Enable or disable verbose logging at runtime
+#[tauri::command]
+pub fn set_verbose(enabled: bool) {
+    whis_core::verbose::set_verbose(enabled);
+}
+
+/// Check if verbose logging is enabled
+#[tauri::command]
+pub fn is_verbose() -> bool {
+    whis_core::verbose::is_verbose()
+}
+
+/// Get verbose log lines captured since startup (most recent last)
+#[tauri::command]
+pub fn get_verbose_logs() -> Vec<String> {
+    whis_core::verbose::captured_logs()
+}
+
+/// Clear the captured verbose log lines
+#[tauri::command]
+pub fn clear_verbose_logs() {
+    whis_core::verbose::clear_captured_logs();
+}
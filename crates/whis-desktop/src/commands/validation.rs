@@ -1,65 +1,46 @@
 //! API Key Validation Commands
 //!
 //! Provides Tauri commands for validating API keys from various transcription providers.
-//! These commands perform basic format validation before saving to settings.
+//! These commands perform basic format validation before saving to settings, using
+//! the same rules as the CLI (`TranscriptionProvider::validate_key_format`).
+
+use whis_core::TranscriptionProvider;
 
 /// Validate API key format
 ///
-/// - Empty keys are valid (fall back to env var)
-/// - If `required_prefix` is set, key must start with it
-/// - If `min_length` is set, key must be at least that long
-fn validate_key_format(
-    key: &str,
-    provider: &str,
-    required_prefix: Option<&str>,
-    min_length: Option<usize>,
-) -> Result<bool, String> {
+/// Empty keys are valid (fall back to env var).
+fn validate_key_format(key: &str, provider: TranscriptionProvider) -> Result<bool, String> {
     if key.is_empty() {
         return Ok(true); // Empty falls back to env var
     }
 
-    if let Some(prefix) = required_prefix
-        && !key.starts_with(prefix)
-    {
-        return Err(format!(
-            "Invalid key format. {} keys start with '{}'",
-            provider, prefix
-        ));
-    }
-
-    if let Some(min_len) = min_length
-        && key.trim().len() < min_len
-    {
-        return Err(format!(
-            "Invalid {} API key: key appears too short",
-            provider
-        ));
-    }
-
-    Ok(true)
+    provider
+        .validate_key_format(key)
+        .map(|()| true)
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
 pub fn validate_openai_api_key(api_key: String) -> Result<bool, String> {
-    validate_key_format(&api_key, "OpenAI", Some("sk-"), None)
+    validate_key_format(&api_key, TranscriptionProvider::OpenAI)
 }
 
 #[tauri::command]
 pub fn validate_mistral_api_key(api_key: String) -> Result<bool, String> {
-    validate_key_format(&api_key, "Mistral", None, Some(20))
+    validate_key_format(&api_key, TranscriptionProvider::Mistral)
 }
 
 #[tauri::command]
 pub fn validate_groq_api_key(api_key: String) -> Result<bool, String> {
-    validate_key_format(&api_key, "Groq", Some("gsk_"), None)
+    validate_key_format(&api_key, TranscriptionProvider::Groq)
 }
 
 #[tauri::command]
 pub fn validate_deepgram_api_key(api_key: String) -> Result<bool, String> {
-    validate_key_format(&api_key, "Deepgram", None, Some(20))
+    validate_key_format(&api_key, TranscriptionProvider::Deepgram)
 }
 
 #[tauri::command]
 pub fn validate_elevenlabs_api_key(api_key: String) -> Result<bool, String> {
-    validate_key_format(&api_key, "ElevenLabs", None, Some(20))
+    validate_key_format(&api_key, TranscriptionProvider::ElevenLabs)
 }
@@ -9,6 +9,7 @@
 //! whis-desktop/
 //! ├── bubble/        - Floating bubble overlay (experimental)
 //! ├── commands/      - Tauri command handlers (30+ commands)
+//! ├── notification.rs - Transcript notifications with quick actions
 //! ├── recording/     - Recording orchestration & pipeline
 //! ├── shortcuts/     - Global keyboard shortcuts (3 backends)
 //! ├── tray/          - System tray UI & interactions
@@ -20,6 +21,7 @@
 
 pub mod bubble;
 mod commands;
+mod notification;
 pub mod recording;
 pub mod shortcuts;
 mod state;
@@ -45,13 +47,24 @@ pub fn run(start_in_tray: bool) {
             }
         }))
         .plugin(tauri_plugin_process::init())
+        .plugin(tauri_plugin_notification::init())
         .setup(move |app| {
+            // Keep verbose output in memory so it can be copied from the UI
+            whis_core::verbose::set_log_capture(true);
+
+            // Start IPC listener for --toggle CLI commands first, so toggles sent
+            // during startup are queued rather than failing to connect
+            shortcuts::start_ipc_listener(app.handle().clone());
+
             // Load settings from disk
             let loaded_settings = Settings::load();
 
             // Initialize state with tray availability
             app.manage(state::AppState::new(loaded_settings, true));
 
+            // Inside Flatpak, ask the Device portal for the microphone up front
+            recording::microphone::init(app.handle());
+
             // Initialize system tray (optional - may fail on tray-less environments)
             let _tray_available = match tray::setup_tray(app) {
                 Ok(_) => true,
@@ -69,8 +82,8 @@ pub fn run(start_in_tray: bool) {
             // Setup global shortcuts (hybrid: Tauri plugin / Portal / CLI fallback)
             shortcuts::setup_shortcuts(app);
 
-            // Start IPC listener for --toggle CLI commands
-            shortcuts::start_ipc_listener(app.handle().clone());
+            // AppState and shortcuts are ready: apply a toggle queued during startup
+            shortcuts::apply_pending_toggle(app.handle());
 
             // Only show main window if NOT starting in tray
             if !start_in_tray {
@@ -91,10 +104,18 @@ pub fn run(start_in_tray: bool) {
             // System commands
             commands::get_toggle_command,
             commands::can_reopen_window,
-            commands::list_audio_devices,
+            commands::list_input_devices,
+            commands::get_input_device,
+            commands::set_input_device,
+            commands::start_level_meter,
+            commands::stop_level_meter,
             commands::exit_app,
             commands::warmup_connections,
             commands::get_autotype_tool_status_cmd,
+            commands::set_verbose,
+            commands::is_verbose,
+            commands::get_verbose_logs,
+            commands::clear_verbose_logs,
             // Validation commands
             commands::validate_openai_api_key,
             commands::validate_mistral_api_key,
@@ -115,6 +136,7 @@ pub fn run(start_in_tray: bool) {
             commands::shortcut_backend,
             commands::configure_shortcut,
             commands::configure_shortcut_with_trigger,
+            commands::rebind_shortcut,
             commands::portal_shortcut,
             commands::reset_shortcut,
             commands::portal_bind_error,
@@ -125,6 +147,8 @@ pub fn run(start_in_tray: bool) {
             commands::system_shortcut_from_dconf,
             commands::check_shortcut_path_mismatch,
             commands::update_shortcut_command,
+            commands::gnome_whis_shortcuts,
+            commands::remove_stale_gnome_shortcuts,
             // Model commands
             commands::download_whisper_model,
             commands::get_whisper_models,
@@ -20,6 +20,9 @@ fn main() {
     if args.contains(&"--toggle".to_string()) || args.contains(&"-t".to_string()) {
         if let Err(e) = whis_desktop::shortcuts::send_toggle_command() {
             eprintln!("Failed to toggle: {e}");
+            if whis_desktop::shortcuts::is_unreachable(&e) {
+                std::process::exit(whis_desktop::shortcuts::EXIT_UNREACHABLE);
+            }
             std::process::exit(1);
         }
         return;
@@ -63,6 +66,11 @@ fn main() {
         println!();
         println!("For Wayland without portal support, configure your compositor");
         println!("to run 'whis-desktop --toggle' on your preferred shortcut.");
+        println!();
+        println!("EXIT STATUS (--toggle):");
+        println!("    0    Toggle sent");
+        println!("    1    Toggle failed");
+        println!("    2    No running instance could be reached");
         return;
     }
 
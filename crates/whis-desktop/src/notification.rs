@@ -0,0 +1,110 @@
+//! Transcript Notifications
+//!
+//! With `ui.notify_on_complete` on, a native notification shows a preview of
+//! each transcript, so recordings started with a global shortcut give
+//! feedback without opening the window.
+//!
+//! On Linux the notification offers quick actions (XDG notification servers
+//! support buttons):
+//! - **Copy**: copy the transcript to the clipboard
+//! - **Paste**: type the transcript into the focused window
+//! - **Discard**: clear the clipboard if it still holds the transcript
+//!
+//! Other platforms show the preview only (the notification plugin has no
+//! desktop action buttons).
+
+use tauri::AppHandle;
+use whis_core::truncate_at_sentence;
+
+/// Longest transcript preview in a notification (characters)
+const PREVIEW_CHARS: usize = 200;
+
+/// Show the transcript notification if enabled in settings
+pub fn notify_transcript(app: &AppHandle, text: &str) {
+    use tauri::Manager;
+
+    let state = app.state::<crate::state::AppState>();
+    if !state.with_settings(|s| s.ui.notify_on_complete) {
+        return;
+    }
+    show(
+        app,
+        text.to_string(),
+        truncate_at_sentence(text, PREVIEW_CHARS),
+    );
+}
+
+#[cfg(target_os = "linux")]
+fn show(app: &AppHandle, text: String, preview: String) {
+    let app = app.clone();
+    // Waiting for an action blocks until the notification is closed
+    std::thread::spawn(move || {
+        let handle = match notify_rust::Notification::new()
+            .appname("Whis")
+            .summary("Transcript ready")
+            .body(&preview)
+            .icon("whis")
+            .action("copy", "Copy")
+            .action("paste", "Paste")
+            .action("discard", "Discard")
+            .show()
+        {
+            Ok(handle) => handle,
+            Err(e) => {
+                whis_core::warn!("Failed to show notification: {e}");
+                return;
+            }
+        };
+        handle.wait_for_action(|action| {
+            if let Err(e) = run_action(&app, action, &text) {
+                whis_core::warn!("Notification action '{action}' failed: {e}");
+            }
+        });
+    });
+}
+
+#[cfg(not(target_os = "linux"))]
+fn show(app: &AppHandle, _text: String, preview: String) {
+    use tauri_plugin_notification::NotificationExt;
+
+    if let Err(e) = app
+        .notification()
+        .builder()
+        .title("Transcript ready")
+        .body(preview)
+        .show()
+    {
+        whis_core::warn!("Failed to show notification: {e}");
+    }
+}
+
+/// Handle a notification button
+#[cfg(target_os = "linux")]
+fn run_action(app: &AppHandle, action: &str, text: &str) -> anyhow::Result<()> {
+    use tauri::Manager;
+    use whis_core::{autotype_text, clipboard::read_from_clipboard, copy_to_clipboard};
+
+    let state = app.state::<crate::state::AppState>();
+    let (clipboard_method, autotype_backend, autotype_delay_ms) = state.with_settings(|s| {
+        (
+            s.ui.clipboard_backend.clone(),
+            s.ui.autotype_backend.clone(),
+            s.ui.autotype_delay_ms,
+        )
+    });
+
+    match action {
+        "copy" => copy_to_clipboard(text, clipboard_method)?,
+        "paste" => autotype_text(text, autotype_backend, autotype_delay_ms)?,
+        "discard" => {
+            // Leave the clipboard alone if something else was copied since
+            if read_from_clipboard(clipboard_method.clone()).is_ok_and(|current| current == text) {
+                copy_to_clipboard("", clipboard_method)?;
+            }
+            *state.last_transcript_preview.lock().unwrap() = None;
+        }
+        // Closed or timed out
+        _ => {}
+    }
+    Ok(())
+}
@@ -10,6 +10,11 @@ use whis_core::TranscriptionProvider;
 pub fn load_transcription_config(state: &AppState) -> Result<TranscriptionConfig, String> {
     let settings = state.settings.lock().unwrap();
     let provider = settings.transcription.provider.clone();
+    whis_core::http::set_request_timeouts((&settings.transcription.timeouts).into());
+    whis_core::set_upload_compression(settings.transcription.compress_uploads);
+    whis_core::set_mp3_bitrate(settings.transcription.mp3_bitrate);
+    whis_core::set_smart_formatting(settings.transcription.smart_format);
+    whis_core::set_numeral_formatting(settings.transcription.format_numbers);
 
     // Get API key/model path based on provider type
     let api_key = match provider {
@@ -27,7 +32,11 @@ pub fn load_transcription_config(state: &AppState) -> Result<TranscriptionConfig
             .ok_or_else(|| format!("No {} API key configured. Add it in Settings.", provider))?,
     };
 
-    let language = settings.transcription.language.clone();
+    let language = settings
+        .transcription
+        .language
+        .clone()
+        .map(|lang| whis_core::normalize_language(&lang).unwrap_or(lang));
 
     Ok(TranscriptionConfig {
         provider,
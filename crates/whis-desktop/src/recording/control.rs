@@ -4,15 +4,55 @@
 
 use super::config::load_transcription_config;
 use crate::state::{AppState, RecordingState};
-use tauri::AppHandle;
+use tauri::{AppHandle, Emitter, Manager};
 use tokio::sync::{mpsc, oneshot};
 #[cfg(feature = "local-transcription")]
 use whis_core::progressive_transcribe_local;
 use whis_core::{
-    AudioRecorder, ChunkerConfig, PostProcessor, ProgressiveChunker, Settings,
-    TranscriptionProvider, progressive_transcribe_cloud,
+    AudioError, AudioRecorder, CancellationToken, ChunkerConfig, PostProcessor, ProgressiveChunker,
+    Settings, StopReason, TranscriptionProvider, WhisError, microphone_permission_hint,
+    progressive_transcribe_cloud,
 };
 
+/// Message for a recorder error, with OS guidance when microphone access was denied
+pub(crate) fn recorder_error(err: anyhow::Error) -> String {
+    match err.downcast_ref::<WhisError>() {
+        Some(WhisError::Audio(AudioError::PermissionDenied(_))) => {
+            format!("{err}. {}", microphone_permission_hint())
+        }
+        _ => err.to_string(),
+    }
+}
+
+/// Event emitted when the provider rejects the API key
+/// (payload: its key in `api_keys`, e.g. "deepgram")
+pub const API_KEY_REJECTED_EVENT: &str = "api-key-rejected";
+
+/// Message for a transcription error
+///
+/// A rejected API key (401/403) also emits [`API_KEY_REJECTED_EVENT`], so the
+/// UI can send the user to re-enter it rather than retry.
+fn transcription_error(app: &AppHandle, err: anyhow::Error) -> String {
+    let err = WhisError::from(err);
+    if let WhisError::Provider(provider_err) = &err
+        && let Some(provider) = provider_err.rejected_key_provider()
+    {
+        let _ = app.emit(API_KEY_REJECTED_EVENT, provider.api_key_name());
+        if let Some(window) = app.get_webview_window("main") {
+            let _ = window.show();
+            let _ = window.set_focus();
+        }
+    }
+    err.to_string()
+}
+
+fn is_device_not_found(err: &anyhow::Error) -> bool {
+    matches!(
+        err.downcast_ref::<WhisError>(),
+        Some(WhisError::Audio(AudioError::DeviceNotFound(_)))
+    )
+}
+
 /// Start recording with progressive transcription (default mode)
 ///
 /// Starts streaming audio recording and spawns background tasks for:
@@ -22,7 +62,10 @@ use whis_core::{
 ///
 /// The transcription result will be available via the oneshot channel
 /// stored in AppState when recording completes.
-pub fn start_recording_sync(_app: &AppHandle, state: &AppState) -> Result<(), String> {
+pub fn start_recording_sync(app: &AppHandle, state: &AppState) -> Result<(), String> {
+    // Flatpak: don't open the stream if the Device portal refused the microphone
+    super::microphone::check_access(app, state)?;
+
     // Cancel any pending idle model unload (user is recording again)
     state.cancel_idle_unload();
 
@@ -43,8 +86,14 @@ pub fn start_recording_sync(_app: &AppHandle, state: &AppState) -> Result<(), St
     // Check if this is a realtime provider (for branching later)
     let is_realtime = whis_core::is_realtime_provider(&provider);
 
+    // The mic test meter holds the device; recording takes over
+    if let Some(mut meter) = state.level_meter.lock().unwrap().take() {
+        let _ = meter.stop_recording();
+    }
+
     // Create recorder and start streaming
     let mut recorder = AudioRecorder::new().map_err(|e| e.to_string())?;
+    recorder.set_level_callback(Some(super::level::level_callback(app.clone())));
 
     // Configure VAD from settings (disabled for realtime - they handle silence detection)
     let vad_enabled = {
@@ -64,16 +113,28 @@ pub fn start_recording_sync(_app: &AppHandle, state: &AppState) -> Result<(), St
     let vad_threshold = state.settings.lock().unwrap().ui.vad.threshold;
     recorder.set_vad(vad_enabled, vad_threshold);
 
+    // The start cue finishes before capture begins, so it isn't recorded
+    super::start_cue(state);
+
     // Start streaming recording
     let device_name = state.settings.lock().unwrap().ui.microphone_device.clone();
     let mut audio_rx_bounded = if let Some(device) = device_name.as_deref() {
-        recorder
-            .start_recording_streaming_with_device(Some(device))
-            .map_err(|e| e.to_string())?
+        match recorder.start_recording_streaming_with_device(Some(device)) {
+            Err(err) if is_device_not_found(&err) => {
+                // Selected mic is gone (e.g. Bluetooth headset unplugged): keep
+                // the selection for when it's back, record from the default now
+                eprintln!("Input device '{device}' not found, using the system default");
+                let _ = app.emit("input-device-fallback", device);
+                recorder
+                    .start_recording_streaming()
+                    .map_err(recorder_error)?
+            }
+            result => result.map_err(recorder_error)?,
+        }
     } else {
         recorder
             .start_recording_streaming()
-            .map_err(|e| e.to_string())?
+            .map_err(recorder_error)?
     };
 
     // Create unbounded channel adapter (used by both realtime and chunked paths)
@@ -89,6 +150,9 @@ pub fn start_recording_sync(_app: &AppHandle, state: &AppState) -> Result<(), St
     // Create oneshot channel for transcription result
     let (result_tx, result_rx) = oneshot::channel();
 
+    // Cancellation token lets exit/cancel abort in-flight provider requests
+    let cancel_token = CancellationToken::new();
+
     // Preload models in background to reduce latency
     {
         let settings = state.settings.lock().unwrap();
@@ -126,11 +190,12 @@ pub fn start_recording_sync(_app: &AppHandle, state: &AppState) -> Result<(), St
             let realtime_backend =
                 whis_core::get_realtime_backend(&provider).map_err(|e| e.to_string())?;
 
+            let app = app.clone();
             tauri::async_runtime::spawn(async move {
                 let result = realtime_backend
                     .transcribe_stream(&api_key, audio_rx_unbounded, language)
                     .await
-                    .map_err(|e| e.to_string());
+                    .map_err(|e| transcription_error(&app, e));
                 let _ = result_tx.send(result);
             });
 
@@ -150,12 +215,7 @@ pub fn start_recording_sync(_app: &AppHandle, state: &AppState) -> Result<(), St
 
         // Create chunker config from settings
         let target = state.settings.lock().unwrap().ui.chunk_duration_secs;
-        let chunker_config = ChunkerConfig {
-            target_duration_secs: target,
-            min_duration_secs: target * 2 / 3,
-            max_duration_secs: target * 4 / 3,
-            vad_aware: vad_enabled,
-        };
+        let chunker_config = ChunkerConfig::from_target_secs(target, vad_enabled);
 
         // Spawn chunker task
         let mut chunker = ProgressiveChunker::new(chunker_config, chunk_tx);
@@ -164,13 +224,15 @@ pub fn start_recording_sync(_app: &AppHandle, state: &AppState) -> Result<(), St
         });
 
         // Spawn transcription task
+        let cancel = cancel_token.clone();
+        let app = app.clone();
         tauri::async_runtime::spawn(async move {
             let result: Result<String, String> = {
                 #[cfg(feature = "local-transcription")]
                 if provider == TranscriptionProvider::LocalParakeet {
                     match Settings::load().transcription.parakeet_model_path() {
                         Some(model_path) => {
-                            progressive_transcribe_local(&model_path, chunk_rx, None)
+                            progressive_transcribe_local(&model_path, chunk_rx, None, Some(cancel))
                                 .await
                                 .map_err(|e| e.to_string())
                         }
@@ -183,9 +245,10 @@ pub fn start_recording_sync(_app: &AppHandle, state: &AppState) -> Result<(), St
                         language.as_deref(),
                         chunk_rx,
                         None,
+                        Some(cancel),
                     )
                     .await
-                    .map_err(|e| e.to_string())
+                    .map_err(|e| transcription_error(&app, e))
                 }
 
                 #[cfg(not(feature = "local-transcription"))]
@@ -195,9 +258,10 @@ pub fn start_recording_sync(_app: &AppHandle, state: &AppState) -> Result<(), St
                     language.as_deref(),
                     chunk_rx,
                     None,
+                    Some(cancel),
                 )
                 .await
-                .map_err(|e| e.to_string())
+                .map_err(|e| transcription_error(&app, e))
             };
 
             let _ = result_tx.send(result);
@@ -208,8 +272,51 @@ pub fn start_recording_sync(_app: &AppHandle, state: &AppState) -> Result<(), St
 
     // Store receiver for later retrieval
     *state.transcription_rx.lock().unwrap() = Some(result_rx);
+    *state.transcription_cancel.lock().unwrap() = Some(cancel_token);
     *state.recorder.lock().unwrap() = Some(recorder);
+    *state.recording_started.lock().unwrap() = Some(std::time::Instant::now());
     *state.state.lock().unwrap() = RecordingState::Recording;
 
     Ok(())
 }
+
+/// Event emitted when a recording is discarded for being too short
+/// (payload: its length in milliseconds)
+pub const RECORDING_TOO_SHORT_EVENT: &str = "recording-too-short";
+
+/// Discard the current recording if it's shorter than `ui.min_recording_ms`
+///
+/// Stops the recorder and cancels its transcription without calling the
+/// provider. Returns true if the recording was discarded (state is back to idle).
+pub fn discard_if_too_short(app: &AppHandle, state: &AppState) -> bool {
+    let min_recording =
+        std::time::Duration::from_millis(state.with_settings(|s| s.ui.min_recording_ms));
+    let Some(elapsed) = state
+        .recording_started
+        .lock()
+        .unwrap()
+        .map(|started| started.elapsed())
+    else {
+        return false;
+    };
+    if elapsed >= min_recording {
+        return false;
+    }
+
+    state.cancel_transcription();
+    if let Some(mut recorder) = state.recorder.lock().unwrap().take() {
+        let _ = recorder.stop_recording();
+    }
+    state.recording_started.lock().unwrap().take();
+    state.transcription_rx.lock().unwrap().take();
+    state.set_state(RecordingState::Idle);
+
+    let ms = elapsed.as_millis() as u64;
+    println!("Recording too short ({ms}ms), discarded");
+    let _ = app.emit(
+        super::pipeline::RECORDING_STOPPED_EVENT,
+        StopReason::Cancelled,
+    );
+    let _ = app.emit(RECORDING_TOO_SHORT_EVENT, ms);
+    true
+}
@@ -0,0 +1,32 @@
+//! Live Input Level
+//!
+//! Forwards the recorder's level callback to the frontend as `whis://level`
+//! events, used by the settings mic meter and the bubble pulse.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+use whis_core::LevelCallback;
+
+/// Event carrying the input level (f32 RMS, 0.0-1.0)
+pub const LEVEL_EVENT: &str = "whis://level";
+
+/// Minimum time between two level events (~20/s)
+const EMIT_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Level callback emitting the loudest buffer of each interval to the frontend
+///
+/// The recorder calls this for every device buffer; events are throttled so
+/// the IPC channel isn't flooded, keeping the peak so short sounds still show.
+pub fn level_callback(app: AppHandle) -> LevelCallback {
+    // (last emit, loudest level since then)
+    let pending = Mutex::new((Instant::now(), 0.0f32));
+    Arc::new(move |level| {
+        let mut pending = pending.lock().unwrap();
+        pending.1 = pending.1.max(level);
+        if pending.0.elapsed() >= EMIT_INTERVAL {
+            let _ = app.emit(LEVEL_EVENT, pending.1);
+            *pending = (Instant::now(), 0.0);
+        }
+    })
+}
@@ -0,0 +1,95 @@
+//! Microphone Access (Flatpak)
+//!
+//! Inside Flatpak, audio reaches the app through PipeWire/PulseAudio, which
+//! only hands out the microphone once the user granted it through the XDG
+//! Device portal. We ask the portal at startup and cache the answer; cpal then
+//! records through the sandbox's audio socket as usual.
+//!
+//! Outside Flatpak, or when the portal can't be reached, nothing is requested
+//! and cpal opens the device directly.
+
+use crate::state::AppState;
+use tauri::{AppHandle, Manager};
+use whis_core::{is_flatpak, microphone_permission_hint, verbose};
+
+/// Answer from the Device portal
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MicrophoneAccess {
+    Granted,
+    Denied,
+    /// Portal missing or the request failed (recording falls back to cpal)
+    Unavailable,
+}
+
+/// Ask the XDG Device portal for microphone access
+#[cfg(target_os = "linux")]
+pub async fn request_microphone_access() -> MicrophoneAccess {
+    use ashpd::desktop::device::{Device, DeviceProxy};
+
+    let proxy = match DeviceProxy::new().await {
+        Ok(proxy) => proxy,
+        Err(e) => {
+            verbose!("Device portal unavailable: {e}");
+            return MicrophoneAccess::Unavailable;
+        }
+    };
+
+    let request = match proxy
+        .access_device(std::process::id(), &[Device::Microphone])
+        .await
+    {
+        Ok(request) => request,
+        Err(e) => {
+            verbose!("Device portal request failed: {e}");
+            return MicrophoneAccess::Unavailable;
+        }
+    };
+
+    match request.response() {
+        Ok(()) => MicrophoneAccess::Granted,
+        Err(ashpd::Error::Response(_)) => MicrophoneAccess::Denied,
+        Err(e) => {
+            verbose!("Device portal response failed: {e}");
+            MicrophoneAccess::Unavailable
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub async fn request_microphone_access() -> MicrophoneAccess {
+    MicrophoneAccess::Unavailable
+}
+
+/// Request microphone access in the background (Flatpak only)
+///
+/// Must be called once `AppState` is managed.
+pub fn init(app: &AppHandle) {
+    if !is_flatpak() {
+        return;
+    }
+    refresh(app.clone());
+}
+
+/// Re-ask the portal and cache the answer in `AppState`
+fn refresh(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let access = request_microphone_access().await;
+        println!("Microphone access via portal: {access:?}");
+        *app.state::<AppState>().microphone_access.lock().unwrap() = Some(access);
+    });
+}
+
+/// Fail before opening the audio stream if the portal denied access
+///
+/// A denial is re-checked in the background, so granting access in the
+/// system settings takes effect on the next recording without a restart.
+pub fn check_access(app: &AppHandle, state: &AppState) -> Result<(), String> {
+    if *state.microphone_access.lock().unwrap() != Some(MicrophoneAccess::Denied) {
+        return Ok(());
+    }
+    refresh(app.clone());
+    Err(format!(
+        "Microphone access denied by the desktop portal. {}",
+        microphone_permission_hint()
+    ))
+}
@@ -11,12 +11,16 @@
 //! recording/
 //! ├── config.rs      - Configuration loading from settings
 //! ├── control.rs     - Start/stop recording logic
+//! ├── level.rs       - Live input level events (mic meter, bubble pulse)
+//! ├── microphone.rs  - Microphone access via the XDG Device portal (Flatpak)
 //! ├── pipeline.rs    - Transcription pipeline orchestration
 //! └── mod.rs         - Public API (toggle, start, stop)
 //! ```
 
 pub mod config;
 pub mod control;
+pub mod level;
+pub mod microphone;
 pub mod pipeline;
 
 // Re-export public APIs
@@ -26,8 +30,26 @@ pub use pipeline::stop_and_transcribe;
 
 use crate::state::{AppState, RecordingState};
 use crate::{bubble, tray};
-use tauri::{AppHandle, Manager};
-use whis_core::error;
+use tauri::{AppHandle, Emitter, Manager};
+use whis_core::{Cue, StopReason, error, play_cue, play_cue_and_wait};
+
+/// Play `cue` if sound cues are enabled in settings
+pub(crate) fn sound_cue(state: &AppState, cue: Cue) {
+    let cues = state.with_settings(|s| s.ui.cues.clone());
+    if cues.sound {
+        play_cue(cue, cues.volume);
+    }
+}
+
+/// Play the start cue if enabled, returning once it has finished
+///
+/// Called before the microphone opens so the beep isn't recorded.
+pub(crate) fn start_cue(state: &AppState) {
+    let cues = state.with_settings(|s| s.ui.cues.clone());
+    if cues.sound {
+        play_cue_and_wait(Cue::RecordingStarted, cues.volume);
+    }
+}
 
 /// Toggle recording state (start if idle, stop if recording)
 /// Called from global shortcuts, tray menu, and IPC
@@ -40,6 +62,7 @@ pub fn toggle_recording(app: AppHandle) {
             // Start recording
             if let Err(e) = start_recording_sync(&app, &state) {
                 error!("Failed to start recording: {e}");
+                let _ = app.emit("recording-error", &e);
             } else {
                 // Update UI (tray and bubble)
                 tray::menu::update_tray(&app, RecordingState::Recording);
@@ -47,6 +70,13 @@ pub fn toggle_recording(app: AppHandle) {
             }
         }
         RecordingState::Recording => {
+            // Accidental taps are dropped before reaching the provider
+            if control::discard_if_too_short(&app, &state) {
+                tray::menu::update_tray(&app, RecordingState::Idle);
+                bubble::hide_bubble(&app);
+                return;
+            }
+
             // Stop recording and transcribe
             let app_clone = app.clone();
             tauri::async_runtime::spawn(async move {
@@ -55,8 +85,9 @@ pub fn toggle_recording(app: AppHandle) {
                 bubble::update_bubble_state(&app_clone, RecordingState::Transcribing);
 
                 // Run transcription pipeline
-                if let Err(e) = stop_and_transcribe(&app_clone).await {
+                if let Err(e) = stop_and_transcribe(&app_clone, StopReason::Manual).await {
                     error!("Failed to transcribe: {e}");
+                    let _ = app_clone.emit("recording-error", &e);
                 }
 
                 // Update UI back to idle
@@ -7,17 +7,22 @@
 //! 4. Copy to clipboard
 //! 5. Emit completion event
 
+use crate::commands::OllamaPullProgress;
 use crate::state::{AppState, RecordingState};
 use std::time::Duration;
 use tauri::{AppHandle, Emitter, Manager};
 use whis_core::{
-    AutotypeBackend, ClipboardMethod, DEFAULT_POST_PROCESSING_PROMPT, OutputMethod,
-    PostProcessConfig, PostProcessor, TranscriptionProvider, autotype_text, copy_to_clipboard,
-    ollama, post_process, warn,
+    AutotypeBackend, ClipboardMethod, Cue, DEFAULT_POST_PROCESSING_PROMPT, OutputMethod,
+    PostProcessConfig, PostProcessor, PromptVariables, StopReason, TranscriptionProvider,
+    WhisError, autotype_text, copy_to_clipboard, format_spoken_numbers, formats_numbers_locally,
+    normalize_transcript, ollama, post_process, render_prompt, truncate_at_sentence, warn,
 };
 #[cfg(feature = "local-transcription")]
 use whis_core::{unload_parakeet, whisper_unload_model};
 
+/// Longest transcript preview shown in the tray tooltip (characters)
+const TRAY_PREVIEW_CHARS: usize = 80;
+
 /// Output text based on configured output method
 fn output_text(
     text: &str,
@@ -43,18 +48,26 @@ fn output_text(
     Ok(())
 }
 
+/// Event emitted when a recording stops (payload: `StopReason`, e.g. "manual")
+pub const RECORDING_STOPPED_EVENT: &str = "recording-stopped";
+
 /// Stop recording and run the full transcription pipeline (progressive mode)
 /// Guarantees state cleanup on both success and failure
-pub async fn stop_and_transcribe(app: &AppHandle) -> Result<(), String> {
+pub async fn stop_and_transcribe(app: &AppHandle, reason: StopReason) -> Result<(), String> {
     let state = app.state::<AppState>();
 
     // Stop recording (closes audio stream, signals chunker/transcription to finish)
     {
         let mut recorder = state.recorder.lock().unwrap().take();
+        state.recording_started.lock().unwrap().take();
         if let Some(ref mut rec) = recorder {
-            rec.stop_recording().map_err(|e| e.to_string())?;
+            rec.stop_recording()
+                .map_err(super::control::recorder_error)?;
         }
     }
+    println!("Recording stopped ({reason})");
+    super::sound_cue(&state, Cue::RecordingStopped);
+    let _ = app.emit(RECORDING_STOPPED_EVENT, reason);
 
     // Update state to transcribing
     {
@@ -68,6 +81,7 @@ pub async fn stop_and_transcribe(app: &AppHandle) -> Result<(), String> {
     // Always reset state, regardless of success or failure
     {
         *state.state.lock().unwrap() = RecordingState::Idle;
+        state.transcription_cancel.lock().unwrap().take();
     }
 
     result
@@ -88,6 +102,19 @@ async fn do_progressive_transcription(app: &AppHandle, state: &AppState) -> Resu
         .await
         .map_err(|_| "Transcription task dropped unexpectedly".to_string())?
         .map_err(|e| format!("Transcription failed: {e}"))?;
+    let mut transcription =
+        normalize_transcript(&transcription).ok_or_else(|| WhisError::NoSpeech.to_string())?;
+    {
+        let settings = state.settings.lock().unwrap();
+        if settings.transcription.format_numbers
+            && formats_numbers_locally(
+                &settings.transcription.provider,
+                settings.transcription.language.as_deref(),
+            )
+        {
+            transcription = format_spoken_numbers(&transcription);
+        }
+    }
 
     // Extract post-processing config and output settings from settings
     let (post_process_config, clipboard_method, output_method, autotype_backend, autotype_delay_ms) = {
@@ -105,8 +132,12 @@ async fn do_progressive_transcription(app: &AppHandle, state: &AppState) -> Resu
                 .prompt
                 .clone()
                 .unwrap_or_else(|| DEFAULT_POST_PROCESSING_PROMPT.to_string());
+            let prompt = render_prompt(
+                &prompt,
+                &PromptVariables::for_prompt(&prompt, settings.transcription.language.clone()),
+            );
             let ollama_model = settings.services.ollama.model.clone();
-            let ollama_keep_alive = settings.services.ollama.keep_alive();
+            let ollama = settings.services.ollama.clone();
 
             let api_key_or_url = if processor.requires_api_key() {
                 settings
@@ -128,7 +159,8 @@ async fn do_progressive_transcription(app: &AppHandle, state: &AppState) -> Resu
                 prompt,
                 api_key_or_url: key_or_url,
                 ollama_model,
-                ollama_keep_alive,
+                ollama,
+                chunking: (&settings.post_processing).into(),
             })
         } else {
             None
@@ -146,8 +178,25 @@ async fn do_progressive_transcription(app: &AppHandle, state: &AppState) -> Resu
     let final_text = if let Some(config) = post_process_config {
         if config.processor == PostProcessor::Ollama {
             let url_for_check = config.api_key_or_url.clone();
+            let model_for_check = config.ollama_model.clone();
+            let app_for_pull = app.clone();
             let ollama_result = tauri::async_runtime::spawn_blocking(move || {
-                ollama::ensure_ollama_running(&url_for_check)
+                let Some(model) = model_for_check else {
+                    return ollama::ensure_ollama_running(&url_for_check).map(|_| ());
+                };
+
+                // Pull the model over HTTP if missing, so no ollama CLI is required
+                ollama::ensure_ollama_ready_with_progress(
+                    &url_for_check,
+                    &model,
+                    |downloaded, total| {
+                        let _ = app_for_pull.emit(
+                            "ollama-pull-progress",
+                            OllamaPullProgress { downloaded, total },
+                        );
+                    },
+                )
+                .map(|_| ())
             })
             .await
             .map_err(|e| format!("Task join failed: {e}"))?;
@@ -168,9 +217,9 @@ async fn do_progressive_transcription(app: &AppHandle, state: &AppState) -> Resu
 
                 println!(
                     "Done (unprocessed): {}",
-                    &transcription[..transcription.len().min(50)]
+                    truncate_at_sentence(&transcription, 50)
                 );
-                let _ = app.emit("transcription-complete", &transcription);
+                announce(app, state, &transcription);
                 return Ok(());
             }
 
@@ -200,6 +249,8 @@ async fn do_progressive_transcription(app: &AppHandle, state: &AppState) -> Resu
             &config.api_key_or_url,
             &config.prompt,
             model,
+            Some(&config.ollama),
+            config.chunking,
         )
         .await
         {
@@ -224,10 +275,9 @@ async fn do_progressive_transcription(app: &AppHandle, state: &AppState) -> Resu
         autotype_delay_ms,
     )?;
 
-    println!("Done: {}", &final_text[..final_text.len().min(50)]);
-
-    // Emit event to frontend
-    let _ = app.emit("transcription-complete", &final_text);
+    println!("Done: {}", truncate_at_sentence(&final_text, 50));
+    announce(app, state, &final_text);
+    super::sound_cue(state, Cue::TranscriptionDone);
 
     // Schedule idle model unload (if configured)
     schedule_idle_model_unload(app, state);
@@ -235,6 +285,14 @@ async fn do_progressive_transcription(app: &AppHandle, state: &AppState) -> Resu
     Ok(())
 }
 
+/// Report a finished transcript: frontend event, tray tooltip, notification
+fn announce(app: &AppHandle, state: &AppState, text: &str) {
+    *state.last_transcript_preview.lock().unwrap() =
+        Some(truncate_at_sentence(text, TRAY_PREVIEW_CHARS));
+    let _ = app.emit("transcription-complete", text);
+    crate::notification::notify_transcript(app, text);
+}
+
 /// Schedule automatic model unload after idle timeout
 ///
 /// If keep_model_loaded is true and unload_after_minutes > 0, spawns a background
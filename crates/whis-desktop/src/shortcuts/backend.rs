@@ -44,11 +44,20 @@ pub fn portal_version() -> u32 {
 }
 
 /// Get backend info for the frontend
-pub fn backend_info() -> ShortcutBackendInfo {
+///
+/// `rdev_active` is whether the RdevGrab listener is running (the
+/// `rdev_guard` in `AppState`).
+pub fn backend_info(rdev_active: bool) -> ShortcutBackendInfo {
     let capability = detect_backend();
 
-    // RdevGrab and Portal require restart to update shortcuts
-    let requires_restart = !matches!(capability.backend, ShortcutBackend::TauriPlugin);
+    // The Tauri plugin rebinds at runtime, RdevGrab only once its listener
+    // runs (a grab that never started is retried on restart), Portal always
+    // requires a restart
+    let requires_restart = match capability.backend {
+        ShortcutBackend::TauriPlugin => false,
+        ShortcutBackend::RdevGrab => !rdev_active,
+        ShortcutBackend::PortalGlobalShortcuts | ShortcutBackend::ManualSetup => true,
+    };
 
     ShortcutBackendInfo {
         backend: format!("{:?}", capability.backend),
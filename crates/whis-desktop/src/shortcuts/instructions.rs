@@ -6,15 +6,21 @@
 use whis_core::Compositor;
 
 /// Get setup instructions for the given compositor
-pub fn get_instructions(compositor: &Compositor, shortcut: &str) -> String {
+///
+/// `command` is the toggle command for this install (see `get_toggle_command`),
+/// as in `get_config_snippet`.
+pub fn get_instructions(compositor: &Compositor, shortcut: &str, command: &str) -> String {
     match compositor {
-        Compositor::Gnome => gnome_instructions(shortcut),
-        Compositor::KdePlasma => kde_instructions(shortcut),
-        Compositor::Sway => sway_instructions(shortcut),
-        Compositor::Hyprland => hyprland_instructions(shortcut),
-        Compositor::Wlroots => wlroots_instructions(shortcut),
-        Compositor::Unknown(name) => unknown_instructions(name, shortcut),
-        Compositor::Native | Compositor::X11 => native_instructions(shortcut),
+        Compositor::Gnome => gnome_instructions(shortcut, command),
+        Compositor::KdePlasma => kde_instructions(shortcut, command),
+        Compositor::Sway => sway_instructions(shortcut, command),
+        Compositor::Hyprland => hyprland_instructions(shortcut, command),
+        Compositor::River => river_instructions(shortcut, command),
+        Compositor::I3 => i3_instructions(shortcut, command),
+        Compositor::Bspwm | Compositor::Dwm => sxhkd_instructions(shortcut, command),
+        Compositor::Wlroots => wlroots_instructions(shortcut, command),
+        Compositor::Unknown(name) => unknown_instructions(name, shortcut, command),
+        Compositor::Native | Compositor::X11 => native_instructions(shortcut, command),
     }
 }
 
@@ -23,26 +29,108 @@ pub fn get_config_path(compositor: &Compositor) -> Option<&'static str> {
     match compositor {
         Compositor::Sway => Some("~/.config/sway/config"),
         Compositor::Hyprland => Some("~/.config/hypr/hyprland.conf"),
+        Compositor::River => Some("~/.config/river/init"),
+        Compositor::I3 => Some("~/.config/i3/config"),
+        Compositor::Bspwm | Compositor::Dwm => Some("~/.config/sxhkd/sxhkdrc"),
         _ => None,
     }
 }
 
-/// Get the command to copy (for the UI copy button)
-pub fn get_config_snippet(compositor: &Compositor, shortcut: &str) -> Option<String> {
+/// Get the config line(s) to copy (for the UI copy button)
+///
+/// `command` is the toggle command for this install (see `get_toggle_command`),
+/// so the snippet works as pasted.
+pub fn get_config_snippet(
+    compositor: &Compositor,
+    shortcut: &str,
+    command: &str,
+) -> Option<String> {
     match compositor {
         Compositor::Sway => Some(format!(
-            "bindsym {} exec whis-desktop --toggle",
+            "bindsym {} exec {command}",
             shortcut.to_lowercase()
         )),
         Compositor::Hyprland => Some(format!(
-            "bind = {}, exec, whis-desktop --toggle",
+            "bind = {}, exec, {command}",
             shortcut.replace('+', ", ")
         )),
+        Compositor::River => Some(format!(
+            "riverctl map normal {} spawn '{command}'",
+            river_binding(shortcut)
+        )),
+        Compositor::I3 => Some(format!(
+            "bindsym {} exec --no-startup-id {command}",
+            i3_binding(shortcut)
+        )),
+        Compositor::Bspwm | Compositor::Dwm => {
+            Some(format!("{}\n    {command}", sxhkd_binding(shortcut)))
+        }
         _ => None,
     }
 }
 
-fn gnome_instructions(shortcut: &str) -> String {
+/// Split "Ctrl+Alt+W" into modifiers and key
+fn split_shortcut(shortcut: &str) -> (Vec<&str>, &str) {
+    let mut parts: Vec<&str> = shortcut.split('+').map(str::trim).collect();
+    let key = parts.pop().unwrap_or_default();
+    (parts, key)
+}
+
+/// i3 binding: "Ctrl+Alt+W" -> "Ctrl+Mod1+w" (i3 has no Alt/Super aliases)
+fn i3_binding(shortcut: &str) -> String {
+    let (modifiers, key) = split_shortcut(shortcut);
+    let mut parts: Vec<String> = modifiers
+        .iter()
+        .map(|m| match m.to_lowercase().as_str() {
+            "alt" | "option" => "Mod1".to_string(),
+            "super" | "cmd" | "meta" | "win" => "Mod4".to_string(),
+            "ctrl" | "control" => "Ctrl".to_string(),
+            "shift" => "Shift".to_string(),
+            _ => m.to_string(),
+        })
+        .collect();
+    parts.push(key.to_lowercase());
+    parts.join("+")
+}
+
+/// sxhkd binding: "Ctrl+Alt+W" -> "ctrl + alt + w"
+fn sxhkd_binding(shortcut: &str) -> String {
+    let (modifiers, key) = split_shortcut(shortcut);
+    let mut parts: Vec<String> = modifiers
+        .iter()
+        .map(|m| match m.to_lowercase().as_str() {
+            "cmd" | "win" => "super".to_string(),
+            "option" => "alt".to_string(),
+            "control" => "ctrl".to_string(),
+            other => other.to_string(),
+        })
+        .collect();
+    parts.push(key.to_lowercase());
+    parts.join(" + ")
+}
+
+/// river binding: "Ctrl+Alt+W" -> "Control+Alt W"
+fn river_binding(shortcut: &str) -> String {
+    let (modifiers, key) = split_shortcut(shortcut);
+    let modifiers: Vec<&str> = modifiers
+        .iter()
+        .map(|m| match m.to_lowercase().as_str() {
+            "ctrl" | "control" => "Control",
+            "alt" | "option" => "Alt",
+            "super" | "cmd" | "meta" | "win" => "Super",
+            "shift" => "Shift",
+            _ => *m,
+        })
+        .collect();
+    let modifiers = if modifiers.is_empty() {
+        "None".to_string()
+    } else {
+        modifiers.join("+")
+    };
+    format!("{modifiers} {}", key.to_uppercase())
+}
+
+fn gnome_instructions(shortcut: &str, command: &str) -> String {
     format!(
         r#"Configure in GNOME Settings:
 
@@ -50,38 +138,39 @@ fn gnome_instructions(shortcut: &str) -> String {
 2. Scroll to "Custom Shortcuts" and click +
 3. Set:
    • Name: Whis Toggle Recording
-   • Command: whis-desktop --toggle
+   • Command: {command}
    • Shortcut: {shortcut}
 
 Or use the command line:
   gsettings set org.gnome.settings-daemon.plugins.media-keys custom-keybindings \
     "['/org/gnome/settings-daemon/plugins/media-keys/custom-keybindings/whis/']"
   dconf write /org/gnome/settings-daemon/plugins/media-keys/custom-keybindings/whis/name "'Whis Toggle'"
-  dconf write /org/gnome/settings-daemon/plugins/media-keys/custom-keybindings/whis/command "'whis-desktop --toggle'"
+  dconf write /org/gnome/settings-daemon/plugins/media-keys/custom-keybindings/whis/command "'{command}'"
   dconf write /org/gnome/settings-daemon/plugins/media-keys/custom-keybindings/whis/binding "'<{shortcut}>'"
 "#
     )
 }
 
-fn kde_instructions(_shortcut: &str) -> String {
-    r#"Configure in KDE System Settings:
+fn kde_instructions(_shortcut: &str, command: &str) -> String {
+    format!(
+        r#"Configure in KDE System Settings:
 
 1. Open System Settings → Shortcuts
 2. Click "Add New" → "Command or Script"
 3. Set:
    • Name: Whis Toggle Recording
-   • Command: whis-desktop --toggle
+   • Command: {command}
    • Trigger: Click and press your desired key combination
 "#
-    .to_string()
+    )
 }
 
-fn sway_instructions(shortcut: &str) -> String {
+fn sway_instructions(shortcut: &str, command: &str) -> String {
     let binding = shortcut.to_lowercase();
     format!(
         r#"Add to your Sway config (~/.config/sway/config):
 
-bindsym {binding} exec whis-desktop --toggle
+bindsym {binding} exec {command}
 
 Then reload Sway:
   swaymsg reload
@@ -89,12 +178,12 @@ Then reload Sway:
     )
 }
 
-fn hyprland_instructions(shortcut: &str) -> String {
+fn hyprland_instructions(shortcut: &str, command: &str) -> String {
     let binding = shortcut.replace('+', ", ");
     format!(
         r#"Add to your Hyprland config (~/.config/hypr/hyprland.conf):
 
-bind = {binding}, exec, whis-desktop --toggle
+bind = {binding}, exec, {command}
 
 Then reload Hyprland:
   hyprctl reload
@@ -102,34 +191,148 @@ Then reload Hyprland:
     )
 }
 
-fn wlroots_instructions(_shortcut: &str) -> String {
-    r#"Configure your compositor's keybindings to run:
+fn river_instructions(shortcut: &str, command: &str) -> String {
+    let binding = river_binding(shortcut);
+    format!(
+        r#"Add to your River init (~/.config/river/init):
+
+riverctl map normal {binding} spawn '{command}'
+
+Run the same line in a terminal to apply it without restarting River.
+"#
+    )
+}
+
+fn i3_instructions(shortcut: &str, command: &str) -> String {
+    let binding = i3_binding(shortcut);
+    format!(
+        r#"Add to your i3 config (~/.config/i3/config):
+
+bindsym {binding} exec --no-startup-id {command}
+
+Then reload i3:
+  i3-msg reload
+"#
+    )
+}
+
+fn sxhkd_instructions(shortcut: &str, command: &str) -> String {
+    let binding = sxhkd_binding(shortcut);
+    format!(
+        r#"Add to your sxhkd config (~/.config/sxhkd/sxhkdrc):
 
-whis-desktop --toggle
+{binding}
+    {command}
+
+Then reload sxhkd:
+  pkill -USR1 -x sxhkd
+"#
+    )
+}
+
+fn wlroots_instructions(_shortcut: &str, command: &str) -> String {
+    format!(
+        r#"Configure your compositor's keybindings to run:
+
+{command}
 
 Check your compositor's documentation for keybinding syntax.
 "#
-    .to_string()
+    )
 }
 
-fn unknown_instructions(compositor_name: &str, _shortcut: &str) -> String {
+fn unknown_instructions(compositor_name: &str, _shortcut: &str, command: &str) -> String {
     format!(
         r#"Configure {compositor_name} to run:
 
-whis-desktop --toggle
+{command}
 
 Check your compositor's documentation for keybinding configuration.
 "#
     )
 }
 
-fn native_instructions(_shortcut: &str) -> String {
-    r#"Shortcuts should be automatically available.
+fn native_instructions(_shortcut: &str, command: &str) -> String {
+    format!(
+        r#"Shortcuts should be automatically available.
 
 If shortcuts are not working, try restarting the application.
 If the issue persists, configure your system to run:
 
-whis-desktop --toggle
+{command}
 "#
-    .to_string()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMMAND: &str = "flatpak run ink.whis.Whis --toggle";
+
+    #[test]
+    fn test_i3_binding() {
+        assert_eq!(i3_binding("Ctrl+Alt+W"), "Ctrl+Mod1+w");
+        assert_eq!(i3_binding("Super+Shift+R"), "Mod4+Shift+r");
+        assert_eq!(i3_binding("Cmd+Option+Space"), "Mod4+Mod1+space");
+        assert_eq!(i3_binding("F9"), "f9");
+    }
+
+    #[test]
+    fn test_sxhkd_binding() {
+        assert_eq!(sxhkd_binding("Ctrl+Alt+W"), "ctrl + alt + w");
+        assert_eq!(sxhkd_binding("Win+Shift+R"), "super + shift + r");
+        assert_eq!(sxhkd_binding("Control+Option+Space"), "ctrl + alt + space");
+        assert_eq!(sxhkd_binding("F9"), "f9");
+    }
+
+    #[test]
+    fn test_river_binding() {
+        assert_eq!(river_binding("Ctrl+Alt+W"), "Control+Alt W");
+        assert_eq!(river_binding("Meta+Shift+r"), "Super+Shift R");
+        assert_eq!(river_binding("F9"), "None F9");
+    }
+
+    #[test]
+    fn test_snippets_use_the_toggle_command() {
+        assert_eq!(
+            get_config_snippet(&Compositor::I3, "Ctrl+Alt+W", COMMAND).as_deref(),
+            Some("bindsym Ctrl+Mod1+w exec --no-startup-id flatpak run ink.whis.Whis --toggle")
+        );
+        assert_eq!(
+            get_config_snippet(&Compositor::Bspwm, "Ctrl+Alt+W", COMMAND).as_deref(),
+            Some("ctrl + alt + w\n    flatpak run ink.whis.Whis --toggle")
+        );
+        assert_eq!(
+            get_config_snippet(&Compositor::River, "Ctrl+Alt+W", COMMAND).as_deref(),
+            Some("riverctl map normal Control+Alt W spawn 'flatpak run ink.whis.Whis --toggle'")
+        );
+        assert_eq!(
+            get_config_snippet(&Compositor::Gnome, "Ctrl+Alt+W", COMMAND),
+            None
+        );
+    }
+
+    #[test]
+    fn test_instructions_use_the_toggle_command() {
+        for compositor in [
+            Compositor::Gnome,
+            Compositor::KdePlasma,
+            Compositor::Sway,
+            Compositor::Hyprland,
+            Compositor::River,
+            Compositor::I3,
+            Compositor::Dwm,
+            Compositor::Wlroots,
+            Compositor::Unknown("niri".to_string()),
+            Compositor::X11,
+        ] {
+            let instructions = get_instructions(&compositor, "Ctrl+Alt+W", COMMAND);
+            assert!(instructions.contains(COMMAND), "{compositor:?}");
+            assert!(
+                !instructions.contains("whis-desktop --toggle"),
+                "{compositor:?}"
+            );
+        }
+    }
 }
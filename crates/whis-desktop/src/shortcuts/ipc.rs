@@ -2,35 +2,122 @@
 //!
 //! Provides Unix socket-based IPC for external toggle commands.
 //! Allows CLI invocations like `whis-desktop --toggle` to communicate with the running instance.
+//!
+//! The listener starts before `AppState` is managed so shortcuts firing during
+//! startup are queued (see `apply_pending_toggle`) instead of being dropped.
 
 use std::env;
+use std::sync::Mutex;
+#[cfg(unix)]
+use std::time::{Duration, Instant};
 use tauri::AppHandle;
 
+/// How long `--toggle` keeps retrying while the app is still starting up
+#[cfg(unix)]
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Exit code of `whis-desktop --toggle` when no running instance could be reached
+pub const EXIT_UNREACHABLE: i32 = 2;
+
+/// Toggles received before `AppState` is managed
+///
+/// `ready` flips once setup has finished; until then a toggle only flips
+/// `pending`, so two toggles during startup cancel out.
+struct ToggleQueue {
+    ready: bool,
+    pending: bool,
+}
+
+static TOGGLE_QUEUE: Mutex<ToggleQueue> = Mutex::new(ToggleQueue {
+    ready: false,
+    pending: false,
+});
+
 /// Send toggle command to running instance via Unix socket
+///
+/// Retries for a few seconds when the socket is missing or refuses
+/// connections, since the instance may still be starting up. Errors of kind
+/// `NotFound`/`ConnectionRefused` mean the app could not be reached at all.
 #[cfg(unix)]
-pub fn send_toggle_command() -> Result<(), Box<dyn std::error::Error>> {
-    use std::io::Write;
+pub fn send_toggle_command() -> std::io::Result<()> {
+    use std::io::{ErrorKind, Write};
     use std::os::unix::net::UnixStream;
 
     let socket_path = socket_path();
+    let deadline = Instant::now() + CONNECT_TIMEOUT;
 
-    match UnixStream::connect(&socket_path) {
-        Ok(mut stream) => {
-            stream.write_all(b"toggle")?;
-            println!("Toggle command sent");
-            Ok(())
-        }
-        Err(e) => {
-            eprintln!("Could not connect to running instance: {e}");
-            eprintln!("Is whis-desktop running?");
-            Err(e.into())
+    loop {
+        match UnixStream::connect(&socket_path) {
+            Ok(mut stream) => {
+                stream.write_all(b"toggle")?;
+                println!("Toggle command sent");
+                return Ok(());
+            }
+            Err(e)
+                if matches!(e.kind(), ErrorKind::NotFound | ErrorKind::ConnectionRefused)
+                    && Instant::now() < deadline =>
+            {
+                std::thread::sleep(Duration::from_millis(100));
+            }
+            Err(e) => {
+                eprintln!("Could not connect to running instance: {e}");
+                eprintln!("Is whis-desktop running?");
+                return Err(e);
+            }
         }
     }
 }
 
 #[cfg(not(unix))]
-pub fn send_toggle_command() -> Result<(), Box<dyn std::error::Error>> {
-    Err("Unix sockets not available on this platform".into())
+pub fn send_toggle_command() -> std::io::Result<()> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "Unix sockets not available on this platform",
+    ))
+}
+
+/// Whether an error from `send_toggle_command` means no instance was reachable
+pub fn is_unreachable(error: &std::io::Error) -> bool {
+    matches!(
+        error.kind(),
+        std::io::ErrorKind::NotFound | std::io::ErrorKind::ConnectionRefused
+    )
+}
+
+/// Toggle recording, or queue the toggle if the app is still starting up
+#[cfg(unix)]
+fn request_toggle(app_handle: &AppHandle) {
+    {
+        let mut queue = TOGGLE_QUEUE.lock().unwrap();
+        if !queue.ready {
+            queue.pending = !queue.pending;
+            println!("IPC: app still starting, toggle queued");
+            return;
+        }
+    }
+    dispatch_toggle(app_handle.clone());
+}
+
+/// Mark the app as ready and apply a toggle queued during startup
+///
+/// Must be called once `AppState` is managed.
+pub fn apply_pending_toggle(app_handle: &AppHandle) {
+    let pending = {
+        let mut queue = TOGGLE_QUEUE.lock().unwrap();
+        queue.ready = true;
+        std::mem::take(&mut queue.pending)
+    };
+    if pending {
+        println!("IPC: applying toggle queued during startup");
+        dispatch_toggle(app_handle.clone());
+    }
+}
+
+fn dispatch_toggle(handle: AppHandle) {
+    // Dispatch to Tauri's async runtime - the IPC thread has no Tokio runtime
+    tauri::async_runtime::spawn(async move {
+        crate::recording::toggle_recording(handle);
+    });
 }
 
 /// Start listening for IPC commands
@@ -63,11 +150,7 @@ pub fn start_ipc_listener(app_handle: AppHandle) {
                         let cmd = String::from_utf8_lossy(&buf[..n]);
                         if cmd.trim() == "toggle" {
                             println!("IPC: toggle command received");
-                            let handle = app_handle.clone();
-                            // Dispatch to Tauri's async runtime - the IPC thread has no Tokio runtime
-                            tauri::async_runtime::spawn(async move {
-                                crate::recording::toggle_recording(handle);
-                            });
+                            request_toggle(&app_handle);
                         }
                     }
                 }
@@ -37,11 +37,13 @@ pub use backend::{
 };
 
 // Re-export portal functions (Linux only)
+pub use portal::GnomeWhisShortcut;
 #[cfg(target_os = "linux")]
 pub use portal::{
-    bind_shortcut_with_trigger, configure_with_preferred_trigger, open_configure_shortcuts,
-    read_gnome_custom_shortcut, read_gnome_custom_shortcut_command,
-    read_portal_shortcut_from_dconf, register_app_with_portal, setup_portal_shortcuts,
+    bind_shortcut_with_trigger, configure_with_preferred_trigger, list_gnome_whis_shortcuts,
+    open_configure_shortcuts, read_gnome_custom_shortcut, read_gnome_custom_shortcut_command,
+    read_portal_shortcut_from_dconf, register_app_with_portal, remove_gnome_custom_shortcut,
+    setup_portal_shortcuts,
 };
 
 // Re-export tauri plugin functions
@@ -52,7 +54,9 @@ pub use tauri_plugin::{setup_tauri_shortcut, update_tauri_shortcut};
 pub use rdev_grab::{RdevGrabGuard, setup_rdev_grab};
 
 // Re-export IPC functions
-pub use ipc::{send_toggle_command, start_ipc_listener};
+pub use ipc::{
+    EXIT_UNREACHABLE, apply_pending_toggle, is_unreachable, send_toggle_command, start_ipc_listener,
+};
 
 // Re-export manual instructions
 pub use manual::print_manual_setup_instructions;
@@ -78,6 +82,8 @@ pub fn setup_shortcuts(app: &tauri::App) {
     let state = app.state::<crate::state::AppState>();
     let settings = state.settings.lock().unwrap();
     let shortcut_str = settings.shortcuts.desktop_key.clone();
+    #[cfg(target_os = "linux")]
+    let debounce = std::time::Duration::from_millis(settings.shortcuts.hotkey_debounce_ms);
     drop(settings);
 
     let compositor_name = capability.platform_info.compositor.display_name();
@@ -101,7 +107,7 @@ pub fn setup_shortcuts(app: &tauri::App) {
         }
         #[cfg(target_os = "linux")]
         ShortcutBackend::RdevGrab => {
-            match setup_rdev_grab(app, &shortcut_str) {
+            match setup_rdev_grab(app, &shortcut_str, debounce) {
                 Ok(guard) => {
                     // Store the guard to keep the thread alive
                     state.rdev_guard.lock().unwrap().replace(guard);
@@ -156,6 +162,33 @@ pub fn setup_shortcuts(app: &tauri::App) {
             print_manual_setup_instructions(&capability.platform_info.compositor, &shortcut_str);
         }
     }
+
+    #[cfg(target_os = "linux")]
+    if capability.platform_info.compositor == whis_core::Compositor::Gnome {
+        warn_duplicate_gnome_shortcuts();
+    }
+}
+
+/// Warn when several GNOME custom shortcuts toggle whis
+///
+/// Each of them sends its own toggle, so one key press starts and
+/// immediately stops recording.
+#[cfg(target_os = "linux")]
+fn warn_duplicate_gnome_shortcuts() {
+    let shortcuts = list_gnome_whis_shortcuts();
+    if shortcuts.len() > 1 {
+        eprintln!(
+            "Warning: {} GNOME custom shortcuts toggle whis; remove the extras in Settings > Keyboard",
+            shortcuts.len()
+        );
+    }
+    for shortcut in shortcuts.iter().filter(|s| s.stale) {
+        eprintln!(
+            "Warning: GNOME shortcut '{}' points to a missing binary: {}",
+            shortcut.name.as_deref().unwrap_or(&shortcut.path),
+            shortcut.command
+        );
+    }
 }
 
 /// Update shortcut. Returns Ok(true) if restart is needed, Ok(false) if applied immediately.
@@ -170,6 +203,22 @@ pub fn update_shortcut(
             update_tauri_shortcut(app, new_shortcut)?;
             Ok(false) // No restart needed
         }
+        #[cfg(target_os = "linux")]
+        ShortcutBackend::RdevGrab => {
+            let state = app.state::<crate::state::AppState>();
+            let guard = state.rdev_guard.lock().unwrap();
+            match guard.as_ref() {
+                Some(guard) => {
+                    guard.rebind(new_shortcut)?;
+                    Ok(false) // No restart needed
+                }
+                None => {
+                    // The grab never started (e.g. missing permissions)
+                    println!("Shortcut saved. Restart required for changes to take effect.");
+                    Ok(true)
+                }
+            }
+        }
         _ => {
             // For portals and CLI, dynamic updates require restart.
             println!("Shortcut saved. Restart required for changes to take effect.");
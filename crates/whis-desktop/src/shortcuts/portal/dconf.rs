@@ -3,6 +3,35 @@
 //! Provides functionality to read shortcuts from GNOME's dconf database:
 //! - **Portal shortcuts**: `/org/gnome/settings-daemon/global-shortcuts/` (XDG Portal)
 //! - **Custom shortcuts**: `/org/gnome/settings-daemon/plugins/media-keys/custom-keybindings/` (GNOME Settings)
+//!
+//! Reinstalls and moved binaries tend to leave several whis entries behind in
+//! the custom shortcuts, each firing its own `--toggle` on the same key.
+//! [`list_gnome_whis_shortcuts`] reports them so stale ones can be removed.
+
+/// Base dconf path of GNOME Settings custom shortcuts
+#[cfg(target_os = "linux")]
+const CUSTOM_KEYBINDINGS_DIR: &str =
+    "/org/gnome/settings-daemon/plugins/media-keys/custom-keybindings/";
+
+/// dconf key listing the paths of all active custom shortcuts
+#[cfg(target_os = "linux")]
+const CUSTOM_KEYBINDINGS_KEY: &str =
+    "/org/gnome/settings-daemon/plugins/media-keys/custom-keybindings";
+
+/// A GNOME custom shortcut that runs `whis ... --toggle`
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct GnomeWhisShortcut {
+    /// dconf section name, e.g. "custom0"
+    pub path: String,
+    pub name: Option<String>,
+    /// Binding in human-readable format like "Ctrl+Alt+F"
+    pub binding: Option<String>,
+    pub command: String,
+    /// The command's binary no longer exists
+    pub stale: bool,
+    /// Another whis entry exists next to this one
+    pub duplicate: bool,
+}
 
 /// Read the actual portal shortcut from dconf (GNOME)
 /// Returns the shortcut in format like "Ctrl+Alt+M" if found
@@ -154,3 +183,262 @@ pub fn read_gnome_custom_shortcut_command() -> Option<String> {
 pub fn read_gnome_custom_shortcut_command() -> Option<String> {
     None
 }
+
+/// List every active GNOME custom shortcut that runs whis with `--toggle`
+///
+/// Entries are returned in dconf order. All of them are marked `duplicate`
+/// when there is more than one; `stale` ones point to a binary that no
+/// longer exists (e.g. an old AppImage or build directory). Sections left
+/// in dconf but missing from the active shortcut list never fire and are
+/// skipped.
+#[cfg(target_os = "linux")]
+pub fn list_gnome_whis_shortcuts() -> Vec<GnomeWhisShortcut> {
+    let read = run_dconf(&["read", CUSTOM_KEYBINDINGS_KEY])
+        .and_then(|list| Ok((list, run_dconf(&["dump", CUSTOM_KEYBINDINGS_DIR])?)));
+    let (list, dump) = match read {
+        Ok(read) => read,
+        Err(e) => {
+            whis_core::warn!("Failed to read GNOME custom shortcuts: {e}");
+            return Vec::new();
+        }
+    };
+
+    let mut shortcuts = parse_whis_shortcuts(&dump, &parse_keybinding_list(&list));
+    for shortcut in &mut shortcuts {
+        shortcut.stale = !command_binary_exists(&shortcut.command);
+    }
+    shortcuts
+}
+
+/// whis `--toggle` shortcuts in a `dconf dump` of [`CUSTOM_KEYBINDINGS_DIR`]
+///
+/// Only sections whose path is in `active` (the parsed
+/// [`CUSTOM_KEYBINDINGS_KEY`] list) are kept. `duplicate` is set when more
+/// than one is left; `stale` is left for the caller to check.
+#[cfg(target_os = "linux")]
+fn parse_whis_shortcuts(dump: &str, active: &[String]) -> Vec<GnomeWhisShortcut> {
+    // Split the dump into sections: [custom0] followed by key='value' lines
+    let mut sections: Vec<(String, std::collections::HashMap<&str, String>)> = Vec::new();
+    for line in dump.lines() {
+        if let Some(path) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            sections.push((path.to_string(), Default::default()));
+        } else if let Some((key, value)) = line.split_once('=')
+            && let Some((_, keys)) = sections.last_mut()
+        {
+            keys.insert(
+                key,
+                value.trim_matches(|c| c == '\'' || c == '"').to_string(),
+            );
+        }
+    }
+
+    let mut shortcuts: Vec<GnomeWhisShortcut> = sections
+        .into_iter()
+        .filter(|(path, _)| {
+            let full_path = format!("{CUSTOM_KEYBINDINGS_DIR}{path}/");
+            active.contains(&full_path)
+        })
+        .filter_map(|(path, mut keys)| {
+            let command = keys.remove("command")?;
+            if !command.to_lowercase().contains("whis") || !command.contains("--toggle") {
+                return None;
+            }
+            Some(GnomeWhisShortcut {
+                path,
+                name: keys.remove("name"),
+                binding: keys
+                    .remove("binding")
+                    .map(|b| convert_gvariant_shortcut(&b)),
+                command,
+                stale: false,
+                duplicate: false,
+            })
+        })
+        .collect();
+
+    let duplicate = shortcuts.len() > 1;
+    for shortcut in &mut shortcuts {
+        shortcut.duplicate = duplicate;
+    }
+    shortcuts
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn list_gnome_whis_shortcuts() -> Vec<GnomeWhisShortcut> {
+    Vec::new()
+}
+
+/// Whether the program a shortcut command starts can still be found
+///
+/// Absolute paths are checked directly, bare names are looked up on PATH.
+#[cfg(target_os = "linux")]
+fn command_binary_exists(command: &str) -> bool {
+    let command = command.trim_start();
+    let program = match command.strip_prefix('"') {
+        Some(rest) => rest.split('"').next().unwrap_or(rest),
+        None => command.split_whitespace().next().unwrap_or(command),
+    };
+    if program.contains('/') {
+        return std::path::Path::new(program).exists();
+    }
+    std::env::var_os("PATH")
+        .is_some_and(|paths| std::env::split_paths(&paths).any(|dir| dir.join(program).is_file()))
+}
+
+/// Remove a GNOME custom shortcut by its section name (e.g. "custom0")
+///
+/// Drops it from the active shortcut list, then clears its settings.
+#[cfg(target_os = "linux")]
+pub fn remove_gnome_custom_shortcut(path: &str) -> Result<(), String> {
+    let full_path = format!("{CUSTOM_KEYBINDINGS_DIR}{path}/");
+
+    let list = run_dconf(&["read", CUSTOM_KEYBINDINGS_KEY])?;
+    let remaining: Vec<String> = parse_keybinding_list(&list)
+        .into_iter()
+        .filter(|entry| *entry != full_path)
+        .collect();
+
+    run_dconf(&[
+        "write",
+        CUSTOM_KEYBINDINGS_KEY,
+        &format_keybinding_list(&remaining),
+    ])?;
+    run_dconf(&["reset", "-f", &full_path])?;
+    Ok(())
+}
+
+/// Run dconf, returning its stdout, or an error if it failed to start or exited non-zero
+#[cfg(target_os = "linux")]
+fn run_dconf(args: &[&str]) -> Result<String, String> {
+    let output = std::process::Command::new("dconf")
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to run dconf {}: {e}", args[0]))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!(
+            "dconf {} failed ({}): {}",
+            args[0],
+            output.status,
+            stderr.trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Paths in a dconf string array
+///
+/// Format: `['/org/.../custom0/', '/org/.../custom1/']`, or `@as []` when
+/// empty (and nothing at all when the key was never set).
+#[cfg(target_os = "linux")]
+fn parse_keybinding_list(list: &str) -> Vec<String> {
+    list.trim()
+        .trim_start_matches("@as")
+        .trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(|entry| entry.trim().trim_matches('\''))
+        .filter(|entry| !entry.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Paths as a dconf string array (see [`parse_keybinding_list`])
+#[cfg(target_os = "linux")]
+fn format_keybinding_list(paths: &[String]) -> String {
+    if paths.is_empty() {
+        return "@as []".to_string();
+    }
+    let quoted: Vec<String> = paths.iter().map(|p| format!("'{p}'")).collect();
+    format!("[{}]", quoted.join(", "))
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn remove_gnome_custom_shortcut(_path: &str) -> Result<(), String> {
+    Err("Not supported on this platform".to_string())
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_keybinding_list() {
+        let list = "['/org/gnome/settings-daemon/plugins/media-keys/custom-keybindings/custom0/', \
+                    '/org/gnome/settings-daemon/plugins/media-keys/custom-keybindings/custom1/']\n";
+        assert_eq!(
+            parse_keybinding_list(list),
+            [
+                format!("{CUSTOM_KEYBINDINGS_DIR}custom0/"),
+                format!("{CUSTOM_KEYBINDINGS_DIR}custom1/"),
+            ]
+        );
+        assert!(parse_keybinding_list("@as []\n").is_empty());
+        assert!(parse_keybinding_list("").is_empty());
+    }
+
+    #[test]
+    fn test_format_keybinding_list_round_trips() {
+        assert_eq!(format_keybinding_list(&[]), "@as []");
+        let paths = vec!["/a/custom0/".to_string(), "/a/custom2/".to_string()];
+        let formatted = format_keybinding_list(&paths);
+        assert_eq!(formatted, "['/a/custom0/', '/a/custom2/']");
+        assert_eq!(parse_keybinding_list(&formatted), paths);
+    }
+
+    const DUMP: &str = "\
+[custom0]
+binding='<Control><Alt>m'
+command='/usr/bin/whis-desktop --toggle'
+name='Whis'
+
+[custom1]
+binding='<Super>t'
+command='gnome-terminal'
+name='Terminal'
+
+[custom2]
+binding='<Control><Alt>m'
+command='/tmp/old/whis-desktop --toggle'
+name='Whis (old)'
+";
+
+    fn active(sections: &[&str]) -> Vec<String> {
+        sections
+            .iter()
+            .map(|s| format!("{CUSTOM_KEYBINDINGS_DIR}{s}/"))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_whis_shortcuts_keeps_whis_toggle_entries() {
+        let shortcuts = parse_whis_shortcuts(DUMP, &active(&["custom0", "custom1", "custom2"]));
+        let paths: Vec<&str> = shortcuts.iter().map(|s| s.path.as_str()).collect();
+        assert_eq!(paths, ["custom0", "custom2"]);
+        assert!(shortcuts.iter().all(|s| s.duplicate));
+
+        let first = &shortcuts[0];
+        assert_eq!(first.name.as_deref(), Some("Whis"));
+        assert_eq!(first.binding.as_deref(), Some("Ctrl+Alt+M"));
+        assert_eq!(first.command, "/usr/bin/whis-desktop --toggle");
+    }
+
+    #[test]
+    fn test_parse_whis_shortcuts_skips_inactive_sections() {
+        // custom2 is still in the dump but no longer in the active list
+        let shortcuts = parse_whis_shortcuts(DUMP, &active(&["custom0", "custom1"]));
+        assert_eq!(shortcuts.len(), 1);
+        assert_eq!(shortcuts[0].path, "custom0");
+        assert!(!shortcuts[0].duplicate);
+
+        assert!(parse_whis_shortcuts(DUMP, &[]).is_empty());
+        assert!(parse_whis_shortcuts("", &active(&["custom0"])).is_empty());
+    }
+
+    #[test]
+    fn test_run_dconf_reports_failure() {
+        // Whether dconf is missing or rejects the bogus command, it's an error
+        assert!(run_dconf(&["no-such-command"]).is_err());
+    }
+}
@@ -22,7 +22,9 @@ pub use binding::{
     bind_shortcut_with_trigger, configure_with_preferred_trigger, open_configure_shortcuts,
 };
 pub use dconf::{
-    read_gnome_custom_shortcut, read_gnome_custom_shortcut_command, read_portal_shortcut_from_dconf,
+    GnomeWhisShortcut, list_gnome_whis_shortcuts, read_gnome_custom_shortcut,
+    read_gnome_custom_shortcut_command, read_portal_shortcut_from_dconf,
+    remove_gnome_custom_shortcut,
 };
 pub use registry::register_app_with_portal;
 
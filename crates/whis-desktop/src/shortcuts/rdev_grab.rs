@@ -7,16 +7,46 @@
 //! - User must be in the `input` group
 //! - uinput device must be accessible
 
-use std::sync::mpsc;
+use crate::state::AppState;
+use std::sync::{Arc, Mutex, mpsc};
 use std::time::Duration;
-use tauri::AppHandle;
-use whis_core::hotkey::Hotkey;
+use tauri::{AppHandle, Emitter, Manager};
+use whis_core::hotkey::{GrabHealth, Hotkey, grab_with_retry, lock_or_recover};
+
+/// Event emitted when the grab health changes (payload: error or null)
+pub const GRAB_ERROR_EVENT: &str = "whis://rdev-grab-error";
+
+/// Grab callback for the current hotkey
+type GrabCallback = Box<dyn Fn(rdev::Event) -> Option<rdev::Event> + Send>;
+
+/// Hotkey the grab thread is listening for, swappable at runtime
+struct Binding {
+    hotkey: Hotkey,
+    callback: GrabCallback,
+}
 
 /// Guard that keeps the keyboard grab thread alive.
 /// When dropped, the thread continues until process exit.
 pub struct RdevGrabGuard {
     #[allow(dead_code)]
     thread_handle: std::thread::JoinHandle<()>,
+    binding: Arc<Mutex<Binding>>,
+    debounce: Duration,
+    app_handle: AppHandle,
+}
+
+impl RdevGrabGuard {
+    /// Switch the running grab to a new shortcut
+    ///
+    /// rdev::grab() can't be stopped once started, so the grab thread stays
+    /// and only the hotkey callback it dispatches to is replaced (with fresh
+    /// key tracking). Takes effect on the next key event, no restart needed.
+    pub fn rebind(&self, shortcut_str: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let hotkey = Hotkey::parse(shortcut_str)?;
+        let callback = toggle_callback(hotkey.clone(), self.debounce, self.app_handle.clone());
+        *lock_or_recover(&self.binding) = Binding { hotkey, callback };
+        Ok(())
+    }
 }
 
 /// Setup global shortcuts using rdev::grab() on Linux Wayland.
@@ -24,15 +54,22 @@ pub struct RdevGrabGuard {
 pub fn setup_rdev_grab(
     app: &tauri::App,
     shortcut_str: &str,
+    debounce: Duration,
 ) -> Result<RdevGrabGuard, Box<dyn std::error::Error>> {
     let hotkey = Hotkey::parse(shortcut_str)?;
     let app_handle = app.handle().clone();
+    let binding = Arc::new(Mutex::new(Binding {
+        callback: toggle_callback(hotkey.clone(), debounce, app_handle.clone()),
+        hotkey,
+    }));
 
     // Channel to receive startup result from the thread
     let (startup_tx, startup_rx) = mpsc::channel::<Result<(), String>>();
 
+    let thread_binding = Arc::clone(&binding);
+    let thread_app_handle = app_handle.clone();
     let thread_handle = std::thread::spawn(move || {
-        match start_keyboard_grab(hotkey, app_handle) {
+        match start_keyboard_grab(thread_binding, debounce, thread_app_handle) {
             Ok(()) => {
                 // This only returns if grab() exits cleanly (unlikely)
             }
@@ -43,15 +80,22 @@ pub fn setup_rdev_grab(
         }
     });
 
+    let guard = || RdevGrabGuard {
+        thread_handle,
+        binding,
+        debounce,
+        app_handle,
+    };
+
     // Wait for either success signal or error (with timeout)
     // Note: rdev::grab() blocks indefinitely on success, so we use a short timeout
     // If we don't receive an error within 500ms, assume startup succeeded
     match startup_rx.recv_timeout(Duration::from_millis(500)) {
-        Ok(Ok(())) => Ok(RdevGrabGuard { thread_handle }),
+        Ok(Ok(())) => Ok(guard()),
         Ok(Err(e)) => Err(e.into()),
         Err(mpsc::RecvTimeoutError::Timeout) => {
             // No error received = grab is running successfully
-            Ok(RdevGrabGuard { thread_handle })
+            Ok(guard())
         }
         Err(mpsc::RecvTimeoutError::Disconnected) => {
             Err("Hotkey thread terminated unexpectedly".into())
@@ -60,12 +104,43 @@ pub fn setup_rdev_grab(
 }
 
 /// Start the keyboard grab and listen for hotkey events.
-/// This function blocks indefinitely while the grab is active.
-fn start_keyboard_grab(hotkey: Hotkey, app_handle: AppHandle) -> Result<(), String> {
+/// This function blocks indefinitely while the grab is active, re-establishing
+/// it when disrupted (e.g. by autotyping). It only returns if the grab can't
+/// be started at all.
+fn start_keyboard_grab(
+    binding: Arc<Mutex<Binding>>,
+    debounce: Duration,
+    app_handle: AppHandle,
+) -> Result<(), String> {
+    // Each attempt starts with fresh key tracking for the current hotkey and
+    // dispatches through `binding`, so `RdevGrabGuard::rebind` applies to the
+    // running grab
+    let trigger_handle = app_handle.clone();
+    let make_callback = || {
+        {
+            let mut binding = lock_or_recover(&binding);
+            binding.callback =
+                toggle_callback(binding.hotkey.clone(), debounce, trigger_handle.clone());
+        }
+        let binding = Arc::clone(&binding);
+        move |event: rdev::Event| (lock_or_recover(&binding).callback)(event)
+    };
+
+    // grab_with_retry() blocks the thread
+    let error = grab_with_retry(make_callback, move |health| {
+        report_health(&app_handle, health)
+    });
+    Err(format!("Failed to grab keyboard: {error:?}"))
+}
+
+/// Grab callback toggling recording on `hotkey`
+fn toggle_callback(hotkey: Hotkey, debounce: Duration, app_handle: AppHandle) -> GrabCallback {
     // Use shared callback from whis-core (same pattern as CLI)
-    // Desktop uses toggle mode only, so on_release is a no-op
-    let callback = whis_core::hotkey::create_grab_callback(
+    // Desktop uses toggle mode only, so on_release is a no-op; the debounce
+    // still keeps key bounce from toggling twice
+    Box::new(whis_core::hotkey::create_grab_callback(
         hotkey,
+        debounce,
         move || {
             let handle = app_handle.clone();
             tauri::async_runtime::spawn(async move {
@@ -73,12 +148,28 @@ fn start_keyboard_grab(hotkey: Hotkey, app_handle: AppHandle) -> Result<(), Stri
             });
         },
         || {}, // Desktop doesn't use push-to-talk
-    );
+    ))
+}
 
-    // rdev::grab() blocks the thread
-    if let Err(e) = rdev::grab(callback) {
-        return Err(format!("Failed to grab keyboard: {e:?}"));
-    }
+/// Expose grab health through `rdev_grab_error` and notify the UI
+fn report_health(app_handle: &AppHandle, health: &GrabHealth) {
+    let error = match health {
+        GrabHealth::Impaired {
+            failures,
+            last_error,
+        } => {
+            eprintln!("Keyboard grab impaired after {failures} failures: {last_error}");
+            Some(format!(
+                "Keyboard grab keeps failing ({failures} attempts, last error: {last_error}); the shortcut may not respond until it recovers"
+            ))
+        }
+        GrabHealth::Active => {
+            println!("Keyboard grab recovered");
+            None
+        }
+    };
 
-    Ok(())
+    let state = app_handle.state::<AppState>();
+    *state.rdev_grab_error.lock().unwrap() = error.clone();
+    let _ = app_handle.emit(GRAB_ERROR_EVENT, error);
 }
@@ -1,9 +1,11 @@
 use std::sync::Mutex;
+use std::time::Instant;
 use tauri::menu::MenuItem;
 use tokio::sync::oneshot;
 pub use whis_core::RecordingState;
-use whis_core::{AudioRecorder, Settings, TranscriptionProvider};
+use whis_core::{AudioRecorder, CancellationToken, Settings, TranscriptionProvider};
 
+use crate::recording::microphone::MicrophoneAccess;
 #[cfg(target_os = "linux")]
 use crate::shortcuts::RdevGrabGuard;
 
@@ -26,6 +28,10 @@ pub struct DownloadState {
 pub struct AppState {
     pub state: Mutex<RecordingState>,
     pub recorder: Mutex<Option<AudioRecorder>>,
+    /// Recorder feeding the settings mic meter (only while testing a device)
+    pub level_meter: Mutex<Option<AudioRecorder>>,
+    /// When the current recording started (for status reporting)
+    pub recording_started: Mutex<Option<Instant>>,
     pub transcription_config: Mutex<Option<TranscriptionConfig>>,
     pub record_menu_item: Mutex<Option<MenuItem<tauri::Wry>>>,
     pub settings: Mutex<Settings>,
@@ -39,9 +45,15 @@ pub struct AppState {
     pub active_download: Mutex<Option<DownloadState>>,
     /// Progressive transcription result receiver (if progressive mode active)
     pub transcription_rx: Mutex<Option<oneshot::Receiver<Result<String, String>>>>,
+    /// Cancellation token for the in-flight progressive transcription (if any)
+    pub transcription_cancel: Mutex<Option<CancellationToken>>,
     /// JoinHandle for pending idle model unload task (if any)
     /// Used to cancel the unload when a new recording starts
     pub idle_unload_handle: Mutex<Option<tauri::async_runtime::JoinHandle<()>>>,
+    /// Microphone access answered by the XDG Device portal (Flatpak only)
+    pub microphone_access: Mutex<Option<MicrophoneAccess>>,
+    /// Shortened last transcript, shown in the tray tooltip
+    pub last_transcript_preview: Mutex<Option<String>>,
     /// Guard for rdev::grab() keyboard listener (Linux only)
     #[cfg(target_os = "linux")]
     pub rdev_guard: Mutex<Option<RdevGrabGuard>>,
@@ -55,6 +67,8 @@ impl AppState {
         Self {
             state: Mutex::new(RecordingState::Idle),
             recorder: Mutex::new(None),
+            level_meter: Mutex::new(None),
+            recording_started: Mutex::new(None),
             transcription_config: Mutex::new(None),
             record_menu_item: Mutex::new(None),
             settings: Mutex::new(settings),
@@ -63,7 +77,10 @@ impl AppState {
             tray_available: Mutex::new(tray_available),
             active_download: Mutex::new(None),
             transcription_rx: Mutex::new(None),
+            transcription_cancel: Mutex::new(None),
             idle_unload_handle: Mutex::new(None),
+            microphone_access: Mutex::new(None),
+            last_transcript_preview: Mutex::new(None),
             #[cfg(target_os = "linux")]
             rdev_guard: Mutex::new(None),
             #[cfg(target_os = "linux")]
@@ -104,6 +121,13 @@ impl AppState {
         *self.tray_available.lock().unwrap()
     }
 
+    /// Abort the in-flight transcription (stops uploads and pending retries)
+    pub fn cancel_transcription(&self) {
+        if let Some(token) = self.transcription_cancel.lock().unwrap().take() {
+            token.cancel();
+        }
+    }
+
     /// Cancel any pending idle model unload task
     pub fn cancel_idle_unload(&self) {
         if let Some(handle) = self.idle_unload_handle.lock().unwrap().take() {
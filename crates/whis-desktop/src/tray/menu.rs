@@ -81,7 +81,20 @@ pub fn update_tray(app: &AppHandle, new_state: RecordingState) {
             RecordingState::Recording => "Whis - Recording... Click to stop",
             RecordingState::Transcribing => "Whis - Transcribing...",
         };
-        let _ = tray.set_tooltip(Some(tooltip));
+        let preview = app
+            .state::<AppState>()
+            .last_transcript_preview
+            .lock()
+            .unwrap()
+            .clone();
+        match preview {
+            Some(preview) if new_state == RecordingState::Idle => {
+                let _ = tray.set_tooltip(Some(format!("{tooltip}\nLast: {preview}")));
+            }
+            _ => {
+                let _ = tray.set_tooltip(Some(tooltip));
+            }
+        }
 
         // Set static icon based on state
         let icon = match new_state {
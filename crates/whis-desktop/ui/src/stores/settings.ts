@@ -1,4 +1,4 @@
-import type { AutotypeBackend, AutotypeToolStatus, BackendInfo, CliShortcutMode, OutputMethod, PostProcessor, Provider, Settings, ShortcutPathMismatch } from '../types'
+import type { AutotypeBackend, AutotypeToolStatus, BackendInfo, CliHotkeyBackend, CliShortcutMode, DefaultOutput, GnomeWhisShortcut, OutputMethod, PostProcessor, Provider, Settings, ShortcutPathMismatch } from '../types'
 import { invoke } from '@tauri-apps/api/core'
 import { nextTick, reactive, readonly, watch } from 'vue'
 
@@ -11,6 +11,7 @@ interface Defaults {
   desktop_key: string
   vad_enabled: boolean
   vad_threshold: number
+  post_processing_chunk_chars: number
 }
 
 // Debounce utility with cancel support
@@ -46,6 +47,7 @@ let defaults: Defaults = {
   desktop_key: 'Ctrl+Alt+W',
   vad_enabled: false,
   vad_threshold: 0.5,
+  post_processing_chunk_chars: 8000,
 }
 
 // Get default settings using cached defaults
@@ -55,21 +57,35 @@ function getDefaultSettings(): Settings {
       provider: defaults.provider,
       language: null,
       api_keys: {},
+      use_keyring: true,
+      confidence_warn: null,
       local_models: {
         whisper_path: null,
         parakeet_path: null,
       },
+      timeouts: {
+        connect_timeout_secs: 10,
+        upload_timeout_secs: 120,
+        timeout_secs: 300,
+      },
+      compress_uploads: false,
+      mp3_bitrate: 128,
+      smart_format: true,
+      format_numbers: false,
     },
     post_processing: {
       enabled: false,
       processor: defaults.post_processor,
       prompt: null,
+      chunk_chars: defaults.post_processing_chunk_chars,
+      cohesion_pass: false,
     },
     services: {
       ollama: {
         url: defaults.ollama_url,
         model: defaults.ollama_model,
         keep_alive: '5m',
+        system_prompt: null,
       },
     },
     shortcuts: {
@@ -77,11 +93,20 @@ function getDefaultSettings(): Settings {
       cli_key: defaults.desktop_key,
       cli_push_to_talk: false,
       desktop_key: defaults.desktop_key,
+      hotkey_debounce_ms: 30,
+      cli_hotkey_backend: 'auto' as CliHotkeyBackend,
     },
     ui: {
       clipboard_backend: 'auto',
+      copy_as_you_go: false,
+      default_output: 'clipboard' as DefaultOutput,
+      default_output_path: null,
+      cache_dir: null,
+      env_file: null,
       microphone_device: null,
       chunk_duration_secs: 90,
+      min_recording_ms: 300,
+      service_idle_shutdown_minutes: 0,
       output_method: 'clipboard' as OutputMethod,
       autotype_backend: 'auto' as AutotypeBackend,
       autotype_delay_ms: null,
@@ -93,6 +118,12 @@ function getDefaultSettings(): Settings {
       bubble: {
         enabled: false,
       },
+      notify_on_complete: false,
+      cues: {
+        sound: false,
+        volume: 0.3,
+        haptic: false,
+      },
       model_memory: {
         keep_model_loaded: true,
         unload_after_minutes: 10,
@@ -111,9 +142,12 @@ const state = reactive({
   portalShortcut: null as string | null,
   portalBindError: null as string | null,
   rdevGrabError: null as string | null,
+  rdevGrabImpaired: false, // Grab started but keeps failing (rdevGrabError has details)
   isInInputGroup: false,
   systemShortcut: null as string | null, // GNOME custom shortcut (RdevGrab backend)
   shortcutPathMismatch: null as ShortcutPathMismatch | null, // Path mismatch warning
+  gnomeWhisShortcuts: [] as GnomeWhisShortcut[], // All GNOME custom shortcuts toggling whis
+  rejectedApiKey: null as string | null, // Provider (api_keys entry) that answered 401/403
 
   // Autotype tool status
   autotypeToolStatus: null as AutotypeToolStatus | null,
@@ -190,21 +224,35 @@ async function load() {
       provider: settings.transcription.provider || defaults.provider,
       language: settings.transcription.language,
       api_keys: settings.transcription.api_keys || {},
+      use_keyring: settings.transcription.use_keyring ?? true,
+      confidence_warn: settings.transcription.confidence_warn ?? null,
       local_models: {
         whisper_path: settings.transcription.local_models.whisper_path,
         parakeet_path: settings.transcription.local_models.parakeet_path,
       },
+      timeouts: {
+        connect_timeout_secs: settings.transcription.timeouts?.connect_timeout_secs ?? 10,
+        upload_timeout_secs: settings.transcription.timeouts?.upload_timeout_secs ?? 120,
+        timeout_secs: settings.transcription.timeouts?.timeout_secs ?? 300,
+      },
+      compress_uploads: settings.transcription.compress_uploads ?? false,
+      mp3_bitrate: settings.transcription.mp3_bitrate ?? 128,
+      smart_format: settings.transcription.smart_format ?? true,
+      format_numbers: settings.transcription.format_numbers ?? false,
     }
     state.post_processing = {
       enabled: settings.post_processing.enabled ?? false,
       processor: settings.post_processing.processor || defaults.post_processor,
       prompt: settings.post_processing.prompt,
+      chunk_chars: settings.post_processing.chunk_chars ?? defaults.post_processing_chunk_chars,
+      cohesion_pass: settings.post_processing.cohesion_pass ?? false,
     }
     state.services = {
       ollama: {
         url: settings.services.ollama.url || defaults.ollama_url,
         model: settings.services.ollama.model || defaults.ollama_model,
         keep_alive: settings.services.ollama.keep_alive || '5m',
+        system_prompt: settings.services.ollama.system_prompt ?? null,
       },
     }
     state.shortcuts = {
@@ -212,11 +260,20 @@ async function load() {
       cli_key: settings.shortcuts?.cli_key || defaults.desktop_key,
       cli_push_to_talk: settings.shortcuts?.cli_push_to_talk ?? false,
       desktop_key: settings.shortcuts?.desktop_key || defaults.desktop_key,
+      hotkey_debounce_ms: settings.shortcuts?.hotkey_debounce_ms ?? 30,
+      cli_hotkey_backend: settings.shortcuts?.cli_hotkey_backend || 'auto',
     }
     state.ui = {
       clipboard_backend: settings.ui.clipboard_backend,
+      copy_as_you_go: settings.ui.copy_as_you_go ?? false,
+      default_output: settings.ui.default_output ?? 'clipboard',
+      default_output_path: settings.ui.default_output_path ?? null,
+      cache_dir: settings.ui.cache_dir ?? null,
+      env_file: settings.ui.env_file ?? null,
       microphone_device: settings.ui.microphone_device,
       chunk_duration_secs: Math.max(10, Math.min(300, settings.ui.chunk_duration_secs ?? 90)),
+      min_recording_ms: settings.ui.min_recording_ms ?? 300,
+      service_idle_shutdown_minutes: settings.ui.service_idle_shutdown_minutes ?? 0,
       output_method: settings.ui.output_method ?? 'clipboard',
       autotype_backend: settings.ui.autotype_backend ?? 'auto',
       autotype_delay_ms: settings.ui.autotype_delay_ms ?? null,
@@ -228,6 +285,12 @@ async function load() {
       bubble: {
         enabled: settings.ui.bubble?.enabled ?? false,
       },
+      notify_on_complete: settings.ui.notify_on_complete ?? false,
+      cues: {
+        sound: settings.ui.cues?.sound ?? false,
+        volume: settings.ui.cues?.volume ?? 0.3,
+        haptic: settings.ui.cues?.haptic ?? false,
+      },
       model_memory: {
         keep_model_loaded: settings.ui.model_memory?.keep_model_loaded ?? true,
         unload_after_minutes: settings.ui.model_memory?.unload_after_minutes ?? 10,
@@ -262,6 +325,7 @@ async function loadBackendInfo() {
       state.portalBindError = await invoke<string | null>('portal_bind_error')
       // Also read GNOME custom shortcut for display (Flatpak uses custom keybindings)
       state.systemShortcut = await invoke<string | null>('system_shortcut_from_dconf')
+      state.gnomeWhisShortcuts = await invoke<GnomeWhisShortcut[]>('gnome_whis_shortcuts')
     }
 
     // For RdevGrab backend, fetch any grab errors and check input group
@@ -274,6 +338,9 @@ async function loadBackendInfo() {
 
       // Check for shortcut path mismatch (configured vs current binary)
       state.shortcutPathMismatch = await invoke<ShortcutPathMismatch | null>('check_shortcut_path_mismatch')
+
+      // Check for duplicate or stale whis entries among the custom shortcuts
+      state.gnomeWhisShortcuts = await invoke<GnomeWhisShortcut[]>('gnome_whis_shortcuts')
     }
   }
   catch (e) {
@@ -281,6 +348,16 @@ async function loadBackendInfo() {
   }
 }
 
+// Remove GNOME custom shortcuts pointing to a missing whis binary,
+// returns the names of the removed shortcuts
+async function removeStaleGnomeShortcuts(): Promise<string[]> {
+  const removed = await invoke<string[]>('remove_stale_gnome_shortcuts')
+  state.gnomeWhisShortcuts = await invoke<GnomeWhisShortcut[]>('gnome_whis_shortcuts')
+  state.systemShortcut = await invoke<string | null>('system_shortcut_from_dconf')
+  state.shortcutPathMismatch = await invoke<ShortcutPathMismatch | null>('check_shortcut_path_mismatch')
+  return removed
+}
+
 async function loadDefaults() {
   try {
     defaults = await invoke<Defaults>('get_defaults')
@@ -408,6 +485,13 @@ function setLanguage(value: string | null) {
 
 function setApiKey(provider: string, key: string) {
   state.transcription.api_keys = { ...state.transcription.api_keys, [provider]: key }
+  if (state.rejectedApiKey === provider)
+    state.rejectedApiKey = null
+}
+
+// Key rejected by the provider (`api-key-rejected`), cleared once it's replaced
+function setRejectedApiKey(provider: string | null) {
+  state.rejectedApiKey = provider
 }
 
 function setWhisperModelPath(value: string | null) {
@@ -442,6 +526,12 @@ function setPortalShortcut(value: string | null) {
   state.portalShortcut = value
 }
 
+// Grab health pushed by the backend (`whis://rdev-grab-error`)
+function setRdevGrabHealth(error: string | null) {
+  state.rdevGrabError = error
+  state.rdevGrabImpaired = error !== null
+}
+
 function setMicrophoneDevice(value: string | null) {
   state.ui.microphone_device = value
 }
@@ -450,6 +540,14 @@ function setBubbleEnabled(value: boolean) {
   state.ui.bubble.enabled = value
 }
 
+function setNotifyOnComplete(value: boolean) {
+  state.ui.notify_on_complete = value
+}
+
+function setSoundCues(value: boolean) {
+  state.ui.cues.sound = value
+}
+
 function setChunkDuration(value: number) {
   // Clamp to valid range (10-300 seconds)
   state.ui.chunk_duration_secs = Math.max(10, Math.min(300, value))
@@ -559,6 +657,7 @@ export const settingsStore = {
   waitForLoaded,
   flush,
   getDefaultProvider,
+  removeStaleGnomeShortcuts,
 
   // Setters
   setProvider,
@@ -572,8 +671,12 @@ export const settingsStore = {
   setPostProcessingPrompt,
   setDesktopKey,
   setPortalShortcut,
+  setRdevGrabHealth,
+  setRejectedApiKey,
   setMicrophoneDevice,
   setBubbleEnabled,
+  setNotifyOnComplete,
+  setSoundCues,
   setChunkDuration,
   setKeepModelLoaded,
   setUnloadAfterMinutes,
@@ -25,6 +25,8 @@ export type PostProcessor = 'none' | 'openai' | 'mistral' | 'ollama'
 
 // CLI shortcut mode
 export type CliShortcutMode = 'system' | 'direct'
+export type CliHotkeyBackend = 'auto' | 'grab' | 'portal'
+export type StopReason = 'manual' | 'cancelled'
 
 // All settings from the backend (nested structure)
 export interface Settings {
@@ -32,21 +34,35 @@ export interface Settings {
     provider: Provider
     language: string | null
     api_keys: Record<string, string>
+    use_keyring: boolean
+    confidence_warn: number | null
     local_models: {
       whisper_path: string | null
       parakeet_path: string | null
     }
+    timeouts: {
+      connect_timeout_secs: number
+      upload_timeout_secs: number
+      timeout_secs: number
+    }
+    compress_uploads: boolean
+    mp3_bitrate: number
+    smart_format: boolean
+    format_numbers: boolean
   }
   post_processing: {
     enabled: boolean
     processor: PostProcessor
     prompt: string | null
+    chunk_chars: number
+    cohesion_pass: boolean
   }
   services: {
     ollama: {
       url: string | null
       model: string | null
       keep_alive: string | null
+      system_prompt: string | null
     }
   }
   shortcuts: {
@@ -54,11 +70,20 @@ export interface Settings {
     cli_key: string
     cli_push_to_talk: boolean
     desktop_key: string
+    hotkey_debounce_ms: number
+    cli_hotkey_backend: CliHotkeyBackend
   }
   ui: {
     clipboard_backend: string
+    copy_as_you_go: boolean
+    default_output: DefaultOutput
+    default_output_path: string | null
+    cache_dir: string | null
+    env_file: string | null
     microphone_device: string | null
     chunk_duration_secs: number
+    min_recording_ms: number
+    service_idle_shutdown_minutes: number
     output_method: OutputMethod
     autotype_backend: AutotypeBackend
     autotype_delay_ms: number | null
@@ -70,6 +95,12 @@ export interface Settings {
     bubble: {
       enabled: boolean
     }
+    notify_on_complete: boolean
+    cues: {
+      sound: boolean
+      volume: number
+      haptic: boolean
+    }
     model_memory: {
       keep_model_loaded: boolean
       unload_after_minutes: number
@@ -77,6 +108,9 @@ export interface Settings {
   }
 }
 
+// Where the CLI delivers transcripts by default (CLI-only, kept on save)
+export type DefaultOutput = 'clipboard' | 'stdout' | 'file'
+
 // How transcribed text should be output
 export type OutputMethod = 'clipboard' | 'autotype' | 'both'
 
@@ -99,16 +133,39 @@ export interface BackendInfo {
   is_flatpak: boolean
 }
 
+// Compositor-specific shortcut setup (from get_shortcut_instructions)
+export interface ShortcutInstructions {
+  compositor_name: string
+  instructions: string
+  config_path: string | null
+  config_snippet: string | null // Ready-to-paste config line(s)
+  has_settings_app: boolean
+}
+
 // Shortcut path mismatch information
 export interface ShortcutPathMismatch {
   configured_command: string
   current_command: string
 }
 
+// GNOME custom shortcut that toggles whis
+export interface GnomeWhisShortcut {
+  path: string
+  name: string | null
+  binding: string | null
+  command: string
+  stale: boolean // Binary no longer exists
+  duplicate: boolean // More than one whis entry is configured
+}
+
 // Status response from backend
 export interface StatusResponse {
   state: 'Idle' | 'Recording' | 'Transcribing'
   config_valid: boolean
+  // Seconds since recording started (null when not recording)
+  elapsed_secs: number | null
+  // Seconds of audio captured so far (null when not recording)
+  audio_secs: number | null
 }
 
 // Response when saving settings
@@ -150,12 +207,19 @@ export interface PresetDetails {
   is_builtin: boolean
 }
 
-// Cloud provider configuration
-export interface CloudProviderInfo {
-  value: Provider
-  label: string
-  keyUrl: string
-  placeholder: string
+// Provider metadata from whis-core (TranscriptionProvider::capabilities)
+export interface ProviderCapabilities {
+  id: Provider
+  display_name: string
+  requires_api_key: boolean
+  is_local: boolean
+  api_key_url: string | null
+  api_key_placeholder: string
+  realtime_variant: Provider | null
+  diarization: boolean
+  timestamps: boolean
+  translation: boolean
+  typical_cost: string
 }
 
 // Helper to check if provider is local
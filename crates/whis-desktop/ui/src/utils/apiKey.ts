@@ -0,0 +1,11 @@
+// Provider prefixes kept visible when masking (mirrors whis-core's mask_api_key)
+const KEY_PREFIXES = ['gsk_', 'sk-']
+
+// Masked form of an API key for display, e.g. "sk-...abcd"; short values are masked completely
+export function maskApiKey(key: string): string {
+  const chars = Array.from(key)
+  if (chars.length <= 12)
+    return '***'
+  const prefix = KEY_PREFIXES.find(p => key.startsWith(p)) ?? ''
+  return `${prefix}...${chars.slice(-4).join('')}`
+}
@@ -0,0 +1,13 @@
+// Live input level, emitted by the backend (~20/s) while recording or testing a mic
+export const LEVEL_EVENT = 'whis://level'
+
+const FLOOR_DB = -60
+
+// Map an RMS level (0-1) to a 0-1 meter position on a dB scale,
+// so quiet speech still moves the meter
+export function levelToMeter(rms: number): number {
+  if (rms <= 0)
+    return 0
+  const db = 20 * Math.log10(rms)
+  return Math.min(1, Math.max(0, (db - FLOOR_DB) / -FLOOR_DB))
+}
@@ -0,0 +1,45 @@
+//! Input device listing and selection.
+//!
+//! Phone users with a wired, USB or Bluetooth headset can pick it as the
+//! recording source. The choice is stored under `microphone_device` in the
+//! Tauri store (absent = system default).
+
+use tauri_plugin_store::StoreExt;
+use whis_core::AudioDeviceInfo;
+
+/// List available audio input devices.
+///
+/// On Android, `form_factor` and `bus` describe the device type (e.g.
+/// "microphone", "headset") and connection (e.g. "built-in", "bluetooth").
+#[tauri::command]
+pub async fn list_audio_devices() -> Result<Vec<AudioDeviceInfo>, String> {
+    tokio::task::spawn_blocking(whis_core::list_audio_devices)
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+}
+
+/// Set the input device (None = system default).
+#[tauri::command]
+pub fn set_input_device(app: tauri::AppHandle, name: Option<String>) -> Result<(), String> {
+    let store = app.store("settings.json").map_err(|e| e.to_string())?;
+
+    if let Some(device_name) = name {
+        store.set("microphone_device", serde_json::json!(device_name));
+    } else {
+        store.delete("microphone_device");
+    }
+
+    store.save().map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Get the selected input device name.
+#[tauri::command]
+pub fn get_input_device(app: tauri::AppHandle) -> Option<String> {
+    app.store("settings.json").ok().and_then(|store| {
+        store
+            .get("microphone_device")
+            .and_then(|v| v.as_str().map(String::from))
+    })
+}
@@ -3,13 +3,16 @@
 //! ## Modules
 //!
 //! - `system` - Status and validation commands
+//! - `devices` - Input device listing and selection
 //! - `presets` - Preset CRUD operations
 //! - `recording` - Audio transcription commands
 
+mod devices;
 pub mod presets;
 mod recording;
 mod system;
 
+pub use devices::*;
 pub use presets::*;
 pub use recording::*;
 pub use system::*;
@@ -146,6 +146,7 @@ pub fn create_preset(
         prompt: input.prompt,
         post_processor: None,
         model: None,
+        language: None,
     };
 
     preset.save_to(&presets_dir)?;
@@ -3,6 +3,7 @@
 //! Handles audio transcription via batch and streaming modes.
 //! Business logic is delegated to the `recording` module.
 
+use crate::recording::config::apply_request_timeouts;
 use crate::recording::pipeline::{apply_post_processing, is_post_processing_enabled};
 use crate::recording::provider::api_key_store_key;
 use crate::state::{AppState, RecordingState};
@@ -57,6 +58,7 @@ async fn transcribe_audio_inner(
 ) -> Result<String, String> {
     // Get transcription config from store
     let store = app.store("settings.json").map_err(|e| e.to_string())?;
+    apply_request_timeouts(&store);
 
     let provider_str = store
         .get("provider")
@@ -75,33 +77,21 @@ async fn transcribe_audio_inner(
         .get("language")
         .and_then(|v| v.as_str().map(String::from));
 
-    // Determine filename extension based on mime type
-    let filename = if mime_type.contains("webm") {
-        "audio.webm"
-    } else if mime_type.contains("ogg") {
-        "audio.ogg"
-    } else if mime_type.contains("mp4") || mime_type.contains("m4a") {
-        "audio.m4a"
-    } else {
-        "audio.mp3"
-    };
-
     // Transcribe using provider registry directly
     let client = whis_core::get_http_client().map_err(|e| e.to_string())?;
     let provider_impl = whis_core::registry()
         .get_by_kind(&provider)
         .map_err(|e| e.to_string())?;
 
-    let request = whis_core::TranscriptionRequest {
-        audio_data,
-        language: language.clone(),
-        filename: filename.to_string(),
-        mime_type: mime_type.clone(),
-        progress: None,
-    };
+    // Format (and filename extension) is derived from the MIME type
+    let request = whis_core::TranscriptionRequest::builder()
+        .audio_data(audio_data)
+        .language(language)
+        .mime_type(mime_type)
+        .build();
 
     let result = provider_impl
-        .transcribe_async(client, &api_key, request)
+        .transcribe_async(&client, &api_key, request)
         .await
         .map_err(|e| e.to_string())?;
 
@@ -177,13 +167,22 @@ pub async fn transcribe_streaming_start(
     let realtime_tx_arc = state.realtime_audio_tx.clone();
     let provider_for_task = provider_str.clone();
     tokio::spawn(async move {
+        // Interim text goes out as `transcription-partial`, like progressive mode
+        let on_partial = partial_emitter(app.clone());
+
         // Dispatch to correct streaming provider
         let result = match provider_for_task.as_str() {
             "openai" | "openai-realtime" => {
-                OpenAIRealtimeProvider::transcribe_stream(&api_key, audio_rx, language).await
+                OpenAIRealtimeProvider::transcribe_stream_with_partials(
+                    &api_key, audio_rx, language, on_partial,
+                )
+                .await
             }
             "deepgram" | "deepgram-realtime" => {
-                DeepgramRealtimeProvider::transcribe_stream(&api_key, audio_rx, language).await
+                DeepgramRealtimeProvider::transcribe_stream_with_partials(
+                    &api_key, audio_rx, language, on_partial,
+                )
+                .await
             }
             _ => Err(anyhow::anyhow!(
                 "Streaming not supported for {}",
@@ -285,14 +284,39 @@ pub async fn transcribe_streaming_stop(state: State<'_, AppState>) -> Result<(),
 // Progressive transcription matches the CLI/desktop architecture:
 // - Audio samples are chunked every ~90 seconds
 // - Chunks are transcribed in parallel (cloud providers)
+// - Interim text is emitted as `transcription-partial` after each chunk
 // - Results are combined when recording stops
 
 use crate::recording::config::load_transcription_config;
 use tokio::sync::{mpsc, oneshot};
-use whis_core::{ChunkerConfig, ProgressiveChunker, progressive_transcribe_cloud};
+use whis_core::{
+    ChunkerConfig, PartialTranscriptCallback, ProgressiveChunker,
+    progressive_transcribe_cloud_with_partials,
+};
 
-/// Default chunk duration in seconds for progressive transcription.
-const DEFAULT_CHUNK_DURATION_SECS: u64 = 90;
+/// Interim transcript emitted while recording (`transcription-partial` event).
+///
+/// `sequence` increases with each update; the frontend should ignore updates
+/// with a lower sequence than the last one shown, and replace the interim
+/// text entirely with the `transcription-complete` result.
+#[derive(Clone, serde::Serialize)]
+struct PartialTranscript {
+    sequence: usize,
+    text: String,
+}
+
+/// Callback that emits each interim transcript as a `transcription-partial` event.
+fn partial_emitter(app: tauri::AppHandle) -> PartialTranscriptCallback {
+    Box::new(move |sequence: usize, text: &str| {
+        let _ = app.emit(
+            "transcription-partial",
+            PartialTranscript {
+                sequence,
+                text: text.to_string(),
+            },
+        );
+    })
+}
 
 /// Start recording with progressive transcription.
 ///
@@ -321,6 +345,14 @@ pub async fn start_recording(
             .expect("recording_state mutex poisoned");
         *recording_state = RecordingState::Recording;
     }
+    *state
+        .recording_started
+        .lock()
+        .expect("recording_started mutex poisoned") = Some(std::time::Instant::now());
+    *state
+        .recorded_samples
+        .lock()
+        .expect("recorded_samples mutex poisoned") = 0;
 
     // Create unbounded channel for audio samples from frontend
     let (audio_tx, audio_rx) = mpsc::unbounded_channel::<Vec<f32>>();
@@ -335,12 +367,7 @@ pub async fn start_recording(
     let (chunk_tx, chunk_rx) = mpsc::unbounded_channel();
 
     // Create chunker config (no VAD on mobile)
-    let chunker_config = ChunkerConfig {
-        target_duration_secs: DEFAULT_CHUNK_DURATION_SECS,
-        min_duration_secs: DEFAULT_CHUNK_DURATION_SECS * 2 / 3,
-        max_duration_secs: DEFAULT_CHUNK_DURATION_SECS * 4 / 3,
-        vad_aware: false, // No VAD on mobile
-    };
+    let chunker_config = ChunkerConfig::from_target_secs(config.chunk_duration_secs, false);
 
     // Spawn chunker task with error handling
     // When chunker fails, it emits error event and drops chunk_tx, closing the channel
@@ -365,11 +392,18 @@ pub async fn start_recording(
 
     // Spawn transcription task
     // This task will complete when chunk_rx closes (either chunker finishes or fails)
+    let on_partial = partial_emitter(app.clone());
     tokio::spawn(async move {
-        let result =
-            progressive_transcribe_cloud(&provider, &api_key, language.as_deref(), chunk_rx, None)
-                .await
-                .map_err(|e| e.to_string());
+        let result = progressive_transcribe_cloud_with_partials(
+            &provider,
+            &api_key,
+            language.as_deref(),
+            chunk_rx,
+            on_partial,
+            None,
+        )
+        .await
+        .map_err(|e| e.to_string());
 
         if result_tx.send(result).is_err() {
             warn!("Failed to send transcription result - receiver dropped");
@@ -399,6 +433,11 @@ pub async fn send_audio_chunk(state: State<'_, AppState>, samples: Vec<f32>) ->
     let audio_tx = state.audio_tx.lock().expect("audio_tx mutex poisoned");
 
     if let Some(tx) = audio_tx.as_ref() {
+        *state
+            .recorded_samples
+            .lock()
+            .expect("recorded_samples mutex poisoned") += samples.len();
+
         // Use unbounded send (won't block)
         if tx.send(samples).is_err() {
             error!("send_audio_chunk: channel closed");
@@ -430,6 +469,11 @@ pub async fn stop_recording(
             .expect("recording_state mutex poisoned");
         *recording_state = RecordingState::Transcribing;
     }
+    state
+        .recording_started
+        .lock()
+        .expect("recording_started mutex poisoned")
+        .take();
 
     // Drop audio_tx to signal end of stream
     {
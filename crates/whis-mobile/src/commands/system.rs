@@ -4,13 +4,17 @@ use crate::recording::provider::api_key_store_key;
 use crate::state::{AppState, RecordingState};
 use tauri::State;
 use tauri_plugin_store::StoreExt;
-use whis_core::{TranscriptionProvider, WarmupConfig, warmup_configured};
+use whis_core::{ProviderCapabilities, TranscriptionProvider, WarmupConfig, warmup_configured};
 
 /// Status response for the frontend.
 #[derive(serde::Serialize)]
 pub struct StatusResponse {
     pub state: RecordingState,
     pub config_valid: bool,
+    /// Seconds since recording started (None when not recording)
+    pub elapsed_secs: Option<f64>,
+    /// Seconds of audio received so far (None when not recording)
+    pub audio_secs: Option<f64>,
 }
 
 /// Get current recording status and configuration state.
@@ -34,16 +38,33 @@ pub fn get_status(app: tauri::AppHandle, state: State<'_, AppState>) -> StatusRe
         })
         .unwrap_or(false);
 
+    let (elapsed_secs, audio_secs) = if recording_state == RecordingState::Recording {
+        let elapsed = state
+            .recording_started
+            .lock()
+            .unwrap()
+            .map(|started| started.elapsed().as_secs_f64());
+        let samples = *state.recorded_samples.lock().unwrap();
+        let audio = samples as f64 / whis_core::resample::WHISPER_SAMPLE_RATE as f64;
+        (elapsed, Some(audio))
+    } else {
+        (None, None)
+    };
+
     StatusResponse {
         state: recording_state,
         config_valid,
+        elapsed_secs,
+        audio_secs,
     }
 }
 
 /// Validate API key format for a given provider.
+///
+/// Errors with the reason when the key can't be right.
 #[tauri::command]
-pub fn validate_api_key(key: String, provider: String) -> bool {
-    crate::recording::provider::validate_api_key_format(&key, &provider)
+pub fn validate_api_key(key: String, provider: String) -> Result<bool, String> {
+    crate::recording::provider::validate_api_key_format(&key, &provider).map(|()| true)
 }
 
 /// Warm up HTTP client and cloud connections based on current configuration.
@@ -95,23 +116,14 @@ pub async fn warmup_connections(app: tauri::AppHandle) -> Result<(), String> {
     Ok(())
 }
 
-/// Cloud provider option for the frontend dropdown.
-#[derive(serde::Serialize)]
-pub struct CloudProviderOption {
-    pub value: String,
-    pub label: String,
-}
-
 /// Get cloud providers in recommended order.
 ///
 /// Returns cloud providers (excluding local and realtime variants) in the
-/// order defined by TranscriptionProvider::all() for consistent UI display.
+/// order defined by TranscriptionProvider::all() for consistent UI display,
+/// with everything the provider picker shows (key URL, streaming support, ...).
 #[tauri::command]
-pub fn get_cloud_providers() -> Vec<CloudProviderOption> {
+pub fn get_cloud_providers() -> Vec<ProviderCapabilities> {
     TranscriptionProvider::cloud_providers()
-        .map(|p| CloudProviderOption {
-            value: p.as_str().to_string(),
-            label: p.display_name().to_string(),
-        })
+        .map(TranscriptionProvider::capabilities)
         .collect()
 }
@@ -6,6 +6,7 @@
 //! whis-mobile/
 //! ├── commands/           - Tauri command handlers (organized by domain)
 //! │   ├── system.rs       - Status, validation
+//! │   ├── devices.rs      - Input device selection
 //! │   ├── presets.rs      - Preset CRUD
 //! │   └── recording.rs    - Audio transcription (batch + streaming)
 //! ├── recording/          - Recording business logic
@@ -76,6 +77,10 @@ pub fn run() {
             commands::validate_api_key,
             commands::warmup_connections,
             commands::get_cloud_providers,
+            // Device commands
+            commands::list_audio_devices,
+            commands::set_input_device,
+            commands::get_input_device,
             // Preset commands
             commands::list_presets,
             commands::get_preset_details,
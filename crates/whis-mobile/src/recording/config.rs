@@ -7,13 +7,33 @@ use super::provider::api_key_store_key;
 use crate::state::{AppState, TranscriptionConfig};
 use tauri_plugin_store::StoreExt;
 use whis_core::config::TranscriptionProvider;
+use whis_core::http::RequestTimeouts;
+
+/// Apply the network timeouts from the Tauri store (same keys and defaults
+/// as `transcription.timeouts` on desktop) to transcription requests.
+pub fn apply_request_timeouts(store: &tauri_plugin_store::Store<tauri::Wry>) {
+    let secs = |key: &str, default: u64| {
+        std::time::Duration::from_secs(store.get(key).and_then(|v| v.as_u64()).unwrap_or(default))
+    };
+    whis_core::http::set_request_timeouts(RequestTimeouts {
+        connect: secs(
+            "connect_timeout_secs",
+            whis_core::DEFAULT_CONNECT_TIMEOUT_SECS,
+        ),
+        upload: secs(
+            "upload_timeout_secs",
+            whis_core::DEFAULT_UPLOAD_TIMEOUT_SECS,
+        ),
+        response: secs("timeout_secs", whis_core::DEFAULT_TIMEOUT_SECS),
+    });
+}
 
 /// Load transcription configuration from Tauri store.
 ///
 /// Checks the cached config first. If not cached or if provider/key changed,
 /// loads fresh from the store.
 ///
-/// Returns the provider, API key, and optional language setting.
+/// Returns the provider, API key, optional language and chunk duration.
 pub fn load_transcription_config(
     app: &tauri::AppHandle,
     state: &AppState,
@@ -28,6 +48,7 @@ pub fn load_transcription_config(
 
     // Load from Tauri store
     let store = app.store("settings.json").map_err(|e| e.to_string())?;
+    apply_request_timeouts(&store);
 
     let provider_str = store
         .get("provider")
@@ -45,12 +66,19 @@ pub fn load_transcription_config(
 
     let language: Option<String> = store
         .get("language")
-        .and_then(|v| v.as_str().map(String::from));
+        .and_then(|v| v.as_str().map(String::from))
+        .map(|lang| whis_core::normalize_language(&lang).unwrap_or(lang));
+
+    let chunk_duration_secs = store
+        .get("chunk_duration_secs")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(whis_core::DEFAULT_CHUNK_DURATION_SECS);
 
     let config = TranscriptionConfig {
         provider,
         api_key,
         language,
+        chunk_duration_secs,
     };
 
     // Cache the config
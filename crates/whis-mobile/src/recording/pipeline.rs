@@ -5,10 +5,28 @@
 
 use tauri::Emitter;
 use whis_core::preset::Preset;
-use whis_core::{PostProcessor, error, post_process, warn};
+use whis_core::{
+    ChunkingConfig, PostProcessor, PromptVariables, error, post_process, render_prompt, warn,
+};
 
 use crate::commands::presets::get_presets_dir;
 
+/// Chunking of long transcripts, from the store (same keys and defaults as
+/// `post_processing` on desktop)
+fn post_processing_chunking(store: &tauri_plugin_store::Store<tauri::Wry>) -> ChunkingConfig {
+    let defaults = ChunkingConfig::default();
+    ChunkingConfig {
+        chunk_chars: store
+            .get("chunk_chars")
+            .and_then(|v| v.as_u64())
+            .map_or(defaults.chunk_chars, |chars| chars as usize),
+        cohesion_pass: store
+            .get("cohesion_pass")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(defaults.cohesion_pass),
+    }
+}
+
 /// Check if post-processing is enabled (has post-processor and active preset).
 pub fn is_post_processing_enabled(store: &tauri_plugin_store::Store<tauri::Wry>) -> bool {
     // Check post-processor setting
@@ -101,8 +119,27 @@ pub async fn apply_post_processing(
         }
     };
 
-    // Apply post-processing with preset's prompt
-    match post_process(&text, &post_processor, &api_key, &preset.prompt, None).await {
+    // Apply post-processing with preset's prompt (variables resolved now)
+    let language = store
+        .get("language")
+        .and_then(|v| v.as_str().map(String::from))
+        .filter(|l| l != "auto");
+    let prompt = render_prompt(
+        &preset.prompt,
+        &PromptVariables::for_prompt(&preset.prompt, language),
+    );
+    let chunking = post_processing_chunking(store);
+    match post_process(
+        &text,
+        &post_processor,
+        &api_key,
+        &prompt,
+        None,
+        None,
+        chunking,
+    )
+    .await
+    {
         Ok(processed) => processed,
         Err(e) => {
             error!("Post-processing failed: {}", e);
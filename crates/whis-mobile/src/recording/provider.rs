@@ -3,7 +3,7 @@
 //! This module centralizes provider → API key mapping and validation logic,
 //! avoiding duplication across commands and config loading.
 
-use whis_core::Settings;
+use whis_core::{Settings, TranscriptionProvider};
 
 /// Get the Tauri store key for a provider's API key.
 ///
@@ -24,21 +24,13 @@ pub fn api_key_store_key(provider: &str) -> Option<&'static str> {
 
 /// Validate API key format for a given provider.
 ///
-/// Performs basic format validation (prefix, length) without
-/// making network calls. Returns `true` if the key looks valid.
-///
-/// # Provider-specific validation:
-///
-/// - **OpenAI**: Must start with `sk-` and be longer than 20 chars
-/// - **Groq**: Must start with `gsk_` and be longer than 20 chars
-/// - **Others**: Just checks minimum length (> 20 chars)
-pub fn validate_api_key_format(key: &str, provider: &str) -> bool {
-    match provider {
-        "openai" | "openai-realtime" => key.starts_with("sk-") && key.len() > 20,
-        "groq" => key.starts_with("gsk_") && key.len() > 20,
-        "mistral" | "deepgram" | "deepgram-realtime" | "elevenlabs" => key.len() > 20,
-        _ => false,
-    }
+/// Performs basic format validation (prefix, length) without making network
+/// calls, using the same rules as the CLI and desktop app
+/// (`TranscriptionProvider::validate_key_format`). The error says why the
+/// key was rejected (wrong prefix, too short) or that the provider is unknown.
+pub fn validate_api_key_format(key: &str, provider: &str) -> Result<(), String> {
+    let provider: TranscriptionProvider = provider.parse()?;
+    provider.validate_key_format(key).map_err(|e| e.to_string())
 }
 
 /// Check if a provider supports realtime streaming.
@@ -4,6 +4,7 @@
 //! recording state, audio channels, and transcription configuration.
 
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 use tokio::sync::{mpsc, oneshot};
 pub use whis_core::RecordingState;
 use whis_core::config::TranscriptionProvider;
@@ -14,6 +15,8 @@ pub struct TranscriptionConfig {
     pub provider: TranscriptionProvider,
     pub api_key: String,
     pub language: Option<String>,
+    /// Progressive chunk length (seconds, clamped by `ChunkerConfig`)
+    pub chunk_duration_secs: u64,
 }
 
 /// Application state shared across Tauri commands.
@@ -28,6 +31,12 @@ pub struct AppState {
     /// Channel for progressive transcription audio samples (unbounded for chunker)
     pub audio_tx: Arc<Mutex<Option<mpsc::UnboundedSender<Vec<f32>>>>>,
 
+    /// When the current progressive recording started (for status reporting)
+    pub recording_started: Arc<Mutex<Option<Instant>>>,
+
+    /// Samples received in the current progressive recording (16kHz mono)
+    pub recorded_samples: Arc<Mutex<usize>>,
+
     /// Receiver for progressive transcription result
     #[allow(clippy::type_complexity)]
     pub transcription_rx: Arc<Mutex<Option<oneshot::Receiver<Result<String, String>>>>>,
@@ -45,6 +54,8 @@ impl AppState {
         Self {
             recording_state: Arc::new(Mutex::new(RecordingState::Idle)),
             audio_tx: Arc::new(Mutex::new(None)),
+            recording_started: Arc::new(Mutex::new(None)),
+            recorded_samples: Arc::new(Mutex::new(0)),
             transcription_rx: Arc::new(Mutex::new(None)),
             transcription_config: Arc::new(Mutex::new(None)),
             realtime_audio_tx: Arc::new(Mutex::new(None)),
@@ -1,6 +1,8 @@
+import type { AudioDevice } from '../types'
 import { invoke } from '@tauri-apps/api/core'
 import { listen } from '@tauri-apps/api/event'
 import { writeText } from '@tauri-apps/plugin-clipboard-manager'
+import { hapticPulse, stopNativeCapture } from 'tauri-plugin-floating-bubble'
 import { reactive, readonly } from 'vue'
 import { AudioStreamer } from '../utils/audioStreamer'
 import { settingsStore } from './settings'
@@ -12,11 +14,17 @@ const state = reactive({
   isPostProcessing: false,
   isStreaming: false,
   isProgressiveMode: false,
+  // Recording continues in the bubble's foreground service (app backgrounded)
+  isNativeHandoff: false,
   error: null as string | null,
   lastTranscription: null as string | null,
+  partialTranscription: null as string | null,
   configValid: false,
 })
 
+// Sequence of the last interim transcript shown (drops out-of-order updates)
+let partialSequence = 0
+
 // Audio streamer instance
 let audioStreamer: AudioStreamer | null = null
 
@@ -51,10 +59,19 @@ async function initialize() {
     state.isPostProcessing = true
   })
 
-  // Listen for transcription complete event
+  // Listen for interim transcripts during progressive recording
+  const unlistenPartial = await listen<{ sequence: number, text: string }>('transcription-partial', (event) => {
+    if (event.payload.sequence <= partialSequence)
+      return
+    partialSequence = event.payload.sequence
+    state.partialTranscription = event.payload.text
+  })
+
+  // Listen for transcription complete event (final text replaces interim text)
   const unlistenComplete = await listen<string>('transcription-complete', (event) => {
     state.lastTranscription = event.payload
     resetState()
+    pulse()
   })
 
   // Listen for transcription error event
@@ -68,7 +85,7 @@ async function initialize() {
     state.error = `Post-processing failed: ${event.payload}. Raw transcript copied.`
   })
 
-  cleanupListeners = [unlistenPostProcess, unlistenComplete, unlistenError, unlistenWarning]
+  cleanupListeners = [unlistenPostProcess, unlistenPartial, unlistenComplete, unlistenError, unlistenWarning]
 }
 
 /**
@@ -118,6 +135,14 @@ async function checkConfig() {
   }
 }
 
+/**
+ * Vibrate briefly if haptic feedback is enabled (best effort).
+ */
+function pulse() {
+  if (settingsStore.state.haptic_feedback)
+    hapticPulse().catch(e => console.error('Haptic pulse failed:', e))
+}
+
 /**
  * Reset all recording state to defaults.
  */
@@ -127,9 +152,30 @@ function resetState() {
   state.isPostProcessing = false
   state.isStreaming = false
   state.isProgressiveMode = false
+  state.isNativeHandoff = false
+  state.partialTranscription = null
+  partialSequence = 0
   recordingStartTime = null
 }
 
+/**
+ * Look up the input device selected in settings (null = system default).
+ */
+async function selectedInputDevice(): Promise<AudioDevice | null> {
+  const name = settingsStore.state.microphone_device
+  if (!name)
+    return null
+
+  try {
+    const devices = await invoke<AudioDevice[]>('list_audio_devices')
+    return devices.find(d => d.name === name) ?? null
+  }
+  catch (e) {
+    console.error('Failed to list audio devices:', e)
+    return null
+  }
+}
+
 /**
  * Start recording audio.
  */
@@ -154,6 +200,8 @@ async function startRecording() {
       throw new Error('No API key configured')
     }
 
+    const inputDevice = await selectedInputDevice()
+
     if (isRealtime) {
       // Start streaming transcription
       state.isStreaming = true
@@ -178,7 +226,7 @@ async function startRecording() {
           state.error = err.message
           stopRecording()
         },
-      })
+      }, inputDevice)
 
       await audioStreamer.start()
     }
@@ -206,10 +254,11 @@ async function startRecording() {
           state.error = err.message
           stopRecording()
         },
-      })
+      }, inputDevice)
 
       await audioStreamer.start()
     }
+    pulse()
   }
   catch (e) {
     console.error('Failed to start recording:', e)
@@ -237,7 +286,24 @@ async function stopRecording() {
     recordingStartTime = null
   }
 
-  if (state.isProgressiveMode && audioStreamer) {
+  if (state.isRecording)
+    pulse()
+
+  if (state.isNativeHandoff) {
+    // Native capture owns the microphone; its capture-stop event finalizes
+    // the recording through stop_recording
+    state.isTranscribing = true
+    state.isRecording = false
+    try {
+      await stopNativeCapture()
+    }
+    catch (e) {
+      console.error('Failed to stop native capture:', e)
+      state.error = String(e)
+      resetState()
+    }
+  }
+  else if (state.isProgressiveMode && audioStreamer) {
     // Stop progressive chunking mode
     audioStreamer.stop()
     audioStreamer = null
@@ -278,6 +344,21 @@ async function stopRecording() {
   }
 }
 
+/**
+ * Detach the WebView audio streamer so the bubble's foreground service can
+ * keep feeding the same backend recording while the app is backgrounded.
+ * Returns false if there is no progressive recording to hand off.
+ */
+function handOffToNativeCapture(): boolean {
+  if (!state.isProgressiveMode || !audioStreamer)
+    return false
+
+  audioStreamer.stop()
+  audioStreamer = null
+  state.isNativeHandoff = true
+  return true
+}
+
 /**
  * Toggle recording state - starts if not recording, stops if recording.
  * Returns true if recording was started, false if stopped or unable to record.
@@ -334,6 +415,7 @@ export const recordingStore = {
   checkConfig,
   startRecording,
   stopRecording,
+  handOffToNativeCapture,
   toggleRecording,
   copyLastTranscription,
   clearError,
@@ -13,6 +13,8 @@ const state = reactive({
   elevenlabs_api_key: null as string | null,
   post_processor: 'none' as PostProcessor,
   floating_bubble_enabled: false,
+  haptic_feedback: false,
+  microphone_device: null as string | null,
   loaded: false,
 })
 
@@ -33,6 +35,8 @@ async function getStore(): Promise<Store> {
         elevenlabs_api_key: null,
         post_processor: 'none',
         floating_bubble_enabled: false,
+        haptic_feedback: false,
+        microphone_device: null,
       },
     })
   }
@@ -54,6 +58,8 @@ async function initialize() {
     state.elevenlabs_api_key = (await s.get<string | null>('elevenlabs_api_key')) ?? null
     state.post_processor = (await s.get<PostProcessor>('post_processor')) || 'none'
     state.floating_bubble_enabled = (await s.get<boolean>('floating_bubble_enabled')) ?? false
+    state.haptic_feedback = (await s.get<boolean>('haptic_feedback')) ?? false
+    state.microphone_device = (await s.get<string | null>('microphone_device')) ?? null
     state.loaded = true
   }
   catch (e) {
@@ -116,6 +122,18 @@ async function setFloatingBubbleEnabled(value: boolean) {
   await s.set('floating_bubble_enabled', value)
 }
 
+async function setHapticFeedback(value: boolean) {
+  state.haptic_feedback = value
+  const s = await getStore()
+  await s.set('haptic_feedback', value)
+}
+
+async function setMicrophoneDevice(value: string | null) {
+  state.microphone_device = value
+  const s = await getStore()
+  await s.set('microphone_device', value)
+}
+
 // Export reactive state and actions
 export const settingsStore = {
   // Readonly state for reading
@@ -134,4 +152,6 @@ export const settingsStore = {
   setElevenlabsApiKey,
   setPostProcessor,
   setFloatingBubbleEnabled,
+  setHapticFeedback,
+  setMicrophoneDevice,
 }
@@ -23,12 +23,29 @@ export interface SettingsKeys {
   groq_api_key: string | null
   deepgram_api_key: string | null
   elevenlabs_api_key: string | null
+  microphone_device: string | null
+}
+
+// Audio input device from backend (whis_core::AudioDeviceInfo)
+export interface AudioDevice {
+  name: string
+  display_name: string | null
+  is_default: boolean
+  // Device type, e.g. "microphone" or "headset"
+  form_factor: string | null
+  // Connection, e.g. "built-in", "usb" or "bluetooth"
+  bus: string | null
+  is_monitor: boolean
 }
 
 // Status response from backend
 export interface StatusResponse {
   state: 'Idle' | 'Recording' | 'Transcribing'
   config_valid: boolean
+  // Seconds since recording started (null when not recording)
+  elapsed_secs: number | null
+  // Seconds of audio captured so far (null when not recording)
+  audio_secs: number | null
 }
 
 // Preset info from backend
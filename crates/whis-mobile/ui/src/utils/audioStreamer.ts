@@ -5,6 +5,8 @@
  * Emits chunks of f32 samples as they become available
  */
 
+import type { AudioDevice } from '../types'
+
 export interface AudioStreamerCallbacks {
   onChunk: (chunk: Float32Array) => void
   onError: (error: Error) => void
@@ -21,7 +23,10 @@ export class AudioStreamer {
   private readonly CHANNELS = 1 // Mono
   private readonly CHUNK_SIZE = 4096 // ~256ms at 16kHz
 
-  constructor(private callbacks: AudioStreamerCallbacks) {}
+  constructor(
+    private callbacks: AudioStreamerCallbacks,
+    private device: AudioDevice | null = null,
+  ) {}
 
   async start(): Promise<void> {
     try {
@@ -34,6 +39,20 @@ export class AudioStreamer {
         },
       })
 
+      // Switch to the selected input if the WebView exposes a matching one
+      const deviceId = await findMediaDeviceId(this.device)
+      if (deviceId && deviceId !== this.stream.getAudioTracks()[0]?.getSettings().deviceId) {
+        this.stream.getTracks().forEach(track => track.stop())
+        this.stream = await navigator.mediaDevices.getUserMedia({
+          audio: {
+            deviceId: { exact: deviceId },
+            echoCancellation: true,
+            noiseSuppression: true,
+            sampleRate: this.TARGET_SAMPLE_RATE,
+          },
+        })
+      }
+
       // Create audio context
       this.audioContext = new AudioContext({
         sampleRate: this.TARGET_SAMPLE_RATE,
@@ -102,3 +121,36 @@ export class AudioStreamer {
     }
   }
 }
+
+/**
+ * Find the WebView media device matching a backend input device
+ *
+ * Device IDs differ between the WebView and the native enumeration, and
+ * labels are only available after permission is granted, so this matches
+ * on the connection type (Bluetooth, USB, wired headset) and then the name.
+ * Returns undefined to keep the system default.
+ */
+async function findMediaDeviceId(device: AudioDevice | null): Promise<string | undefined> {
+  if (!device)
+    return undefined
+
+  const inputs = (await navigator.mediaDevices.enumerateDevices())
+    .filter(d => d.kind === 'audioinput' && d.label)
+
+  const bus = device.bus?.toLowerCase() ?? ''
+  const formFactor = device.form_factor?.toLowerCase() ?? ''
+  const keywords = bus === 'bluetooth'
+    ? ['bluetooth']
+    : bus === 'usb'
+      ? ['usb']
+      : formFactor === 'headset'
+        ? ['headset', 'wired']
+        : []
+
+  const byType = keywords.length > 0
+    ? inputs.find(d => keywords.some(k => d.label.toLowerCase().includes(k)))
+    : undefined
+  const byName = inputs.find(d => d.label === device.name || d.label === device.display_name)
+
+  return (byType ?? byName)?.deviceId
+}